@@ -209,16 +209,18 @@ pub fn eval_source(
 
     let (block, delta) = {
         let mut working_set = StateWorkingSet::new(engine_state);
-        let (output, err) = parse(
+        let (output, errors) = parse(
             &mut working_set,
             Some(fname), // format!("entry #{}", entry_num)
             source,
             false,
             &[],
         );
-        if let Some(err) = err {
+        if !errors.is_empty() {
             set_last_exit_code(stack, 1);
-            report_error(&working_set, &err);
+            for err in &errors {
+                report_error(&working_set, err);
+            }
             return false;
         }
 
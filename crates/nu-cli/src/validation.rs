@@ -10,9 +10,9 @@ pub struct NuValidator {
 impl Validator for NuValidator {
     fn validate(&self, line: &str) -> ValidationResult {
         let mut working_set = StateWorkingSet::new(&self.engine_state);
-        let (_, err) = parse(&mut working_set, None, line.as_bytes(), false, &[]);
+        let (_, errors) = parse(&mut working_set, None, line.as_bytes(), false, &[]);
 
-        if matches!(err, Some(ParseError::UnexpectedEof(..))) {
+        if matches!(errors.first(), Some(ParseError::UnexpectedEof(..))) {
             ValidationResult::Incomplete
         } else {
             ValidationResult::Complete
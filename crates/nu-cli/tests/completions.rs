@@ -664,7 +664,7 @@ fn run_external_completion(block: &str, input: &str) -> Vec<Suggestion> {
     let (_, delta) = {
         let mut working_set = StateWorkingSet::new(&engine_state);
         let (block, err) = parse(&mut working_set, None, block.as_bytes(), false, &[]);
-        assert!(err.is_none());
+        assert!(err.is_empty());
 
         (block, working_set.render())
     };
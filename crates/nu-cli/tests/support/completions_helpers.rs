@@ -148,7 +148,7 @@ pub fn merge_input(
 
         let (block, err) = parse(&mut working_set, None, input, false, &[]);
 
-        assert!(err.is_none());
+        assert!(err.is_empty());
 
         (block, working_set.render())
     };
@@ -26,7 +26,20 @@ impl Command for Def {
     }
 
     fn extra_usage(&self) -> &str {
-        r#"This command is a parser keyword. For details, check:
+        r#"A `def` may be preceded by one or more `@name [value]` attribute lines, which
+are attached to the resulting declaration's signature instead of being run as
+commands. For example, `@deprecated "use other-thing instead"` above a `def`
+marks it deprecated with that message, and one or more `@example` lines add
+extra copy-paste snippets that show up in `help` alongside the command's own
+examples.
+
+Doc comment lines of the form `# name: description` are matched against the
+def's parameters and flags by name, and fill in their description in `help`
+in place of an inline `# description` comment inside the signature. Lines
+that don't match a parameter or flag name are kept as part of the command's
+usage text as before.
+
+This command is a parser keyword. For details, check:
   https://www.nushell.sh/book/thinking_in_nu.html"#
     }
 
@@ -56,6 +69,26 @@ impl Command for Def {
                 example: r#"def say-sth [sth: string] { echo $sth }; say-sth hi"#,
                 result: Some(Value::test_string("hi")),
             },
+            Example {
+                description: "Mark a command deprecated; calling it prints a warning",
+                example: r#"@deprecated "use say-hi instead"
+def say-hi-old [] { echo 'hi' }"#,
+                result: None,
+            },
+            Example {
+                description: "Document a parameter from a doc comment instead of inline",
+                example: r#"# Say hi to someone
+#
+# sth: who to say hi to
+def say-sth-documented [sth: string] { echo $sth }"#,
+                result: None,
+            },
+            Example {
+                description: "Attach an extra copy-paste snippet that shows up in `help`",
+                example: r#"@example "say-hi-loud 'hey'"
+def say-hi-loud [sth: string] { print ($sth | str upcase) }"#,
+                result: None,
+            },
         ]
     }
 }
@@ -1,8 +1,9 @@
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
-    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Type, Value,
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Type, Value,
 };
+use std::collections::HashSet;
 
 #[derive(Clone)]
 pub struct Describe;
@@ -16,6 +17,12 @@ impl Command for Describe {
         "Describe the type and structure of the value(s) piped in."
     }
 
+    fn extra_usage(&self) -> &str {
+        "With --schema, expects a table and instead returns one record per column, giving the
+types seen in it, null count, min/max, and cardinality (distinct non-null value count),
+computed in a single pass over the input."
+    }
+
     fn signature(&self) -> Signature {
         Signature::build("describe")
             .input_output_types(vec![(Type::Any, Type::String)])
@@ -24,6 +31,11 @@ impl Command for Describe {
                 "do not collect streams of structured data",
                 Some('n'),
             )
+            .switch(
+                "schema",
+                "describe a table's columns instead of the overall type",
+                None,
+            )
             .category(Category::Core)
     }
 
@@ -36,6 +48,10 @@ impl Command for Describe {
     ) -> Result<PipelineData, ShellError> {
         let head = call.head;
 
+        if call.has_flag("schema") {
+            return describe_schema(input, head);
+        }
+
         let no_collect: bool = call.has_flag("no-collect");
 
         let description = match input {
@@ -76,6 +92,11 @@ impl Command for Describe {
                 example: "'hello' | describe",
                 result: Some(Value::test_string("string")),
             },
+            Example {
+                description: "Inspect the schema of a table",
+                example: "[{a: 1, b: x} {a: 2, b: null}] | describe --schema",
+                result: None,
+            },
             /*
             Example {
                 description: "Describe a stream of data, collecting it first",
@@ -96,6 +117,106 @@ impl Command for Describe {
     }
 }
 
+#[derive(Default)]
+struct ColumnStats {
+    types: Vec<String>,
+    null_count: i64,
+    distinct: HashSet<String>,
+    min: Option<Value>,
+    max: Option<Value>,
+}
+
+impl ColumnStats {
+    fn observe(&mut self, val: &Value) {
+        let ty = val.get_type().to_string();
+        if !self.types.contains(&ty) {
+            self.types.push(ty);
+        }
+
+        if matches!(val, Value::Nothing { .. }) {
+            self.null_count += 1;
+            return;
+        }
+
+        self.distinct
+            .insert(val.into_string(", ", &nu_protocol::Config::default()));
+
+        if !matches!(
+            self.min.as_ref().map(|cur| val.partial_cmp(cur)),
+            Some(Some(
+                std::cmp::Ordering::Greater | std::cmp::Ordering::Equal
+            ))
+        ) {
+            self.min = Some(val.clone());
+        }
+        if !matches!(
+            self.max.as_ref().map(|cur| val.partial_cmp(cur)),
+            Some(Some(std::cmp::Ordering::Less | std::cmp::Ordering::Equal))
+        ) {
+            self.max = Some(val.clone());
+        }
+    }
+
+    fn into_record(self, name: String, head: Span) -> Value {
+        Value::Record {
+            cols: vec![
+                "column".into(),
+                "type".into(),
+                "null_count".into(),
+                "min".into(),
+                "max".into(),
+                "cardinality".into(),
+            ],
+            vals: vec![
+                Value::string(name, head),
+                Value::string(self.types.join(" | "), head),
+                Value::int(self.null_count, head),
+                self.min.unwrap_or_else(|| Value::nothing(head)),
+                self.max.unwrap_or_else(|| Value::nothing(head)),
+                Value::int(self.distinct.len() as i64, head),
+            ],
+            span: head,
+        }
+    }
+}
+
+fn describe_schema(input: PipelineData, head: Span) -> Result<PipelineData, ShellError> {
+    let mut columns: Vec<(String, ColumnStats)> = Vec::new();
+
+    for row in input.into_iter() {
+        let Value::Record { cols, vals, .. } = &row else {
+            return Err(ShellError::UnsupportedInput(
+                "describe --schema expects a table (list of records)".into(),
+                "value originates from here".into(),
+                head,
+                row.expect_span(),
+            ));
+        };
+
+        for (col, val) in cols.iter().zip(vals.iter()) {
+            match columns.iter_mut().find(|(name, _)| name == col) {
+                Some((_, stats)) => stats.observe(val),
+                None => {
+                    let mut stats = ColumnStats::default();
+                    stats.observe(val);
+                    columns.push((col.clone(), stats));
+                }
+            }
+        }
+    }
+
+    let rows = columns
+        .into_iter()
+        .map(|(name, stats)| stats.into_record(name, head))
+        .collect();
+
+    Ok(Value::List {
+        vals: rows,
+        span: head,
+    }
+    .into_pipeline_data())
+}
+
 #[cfg(test)]
 mod test {
     #[test]
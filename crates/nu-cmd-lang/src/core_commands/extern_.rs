@@ -19,12 +19,26 @@ impl Command for Extern {
             .input_output_types(vec![(Type::Nothing, Type::Nothing)])
             .required("def_name", SyntaxShape::String, "definition name")
             .required("params", SyntaxShape::Signature, "parameters")
+            .switch(
+                "unknown-ok",
+                "allow calls to this external to pass unknown flags and extra positional arguments without a parse error",
+                None,
+            )
             .category(Category::Core)
     }
 
     fn extra_usage(&self) -> &str {
         r#"This command is a parser keyword. For details, check:
-  https://www.nushell.sh/book/thinking_in_nu.html"#
+  https://www.nushell.sh/book/thinking_in_nu.html
+
+A subcommand extern (e.g. `extern "git push"`) inherits any flags declared on
+its parent extern (e.g. `extern git`) that it doesn't redeclare itself, so
+flags shared across a whole subcommand hierarchy only need to be written once.
+
+By default, calls are validated against the declared signature (unknown flags
+and extra positional arguments are parse errors). Pass `--unknown-ok` to fall
+back to the old pass-everything-through behavior for externals whose full
+flag surface isn't worth declaring."#
     }
 
     fn is_parser_keyword(&self) -> bool {
@@ -42,10 +56,17 @@ impl Command for Extern {
     }
 
     fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            description: "Write a signature for an external command",
-            example: r#"extern echo [text: string]"#,
-            result: None,
-        }]
+        vec![
+            Example {
+                description: "Write a signature for an external command",
+                example: r#"extern echo [text: string]"#,
+                result: None,
+            },
+            Example {
+                description: "Share flags across a subcommand hierarchy",
+                example: r#"extern git [--git-dir: string]; extern "git push" [--force]"#,
+                result: None,
+            },
+        ]
     }
 }
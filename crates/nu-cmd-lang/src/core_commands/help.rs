@@ -35,6 +35,11 @@ impl Command for Help {
                 "string to find in command names, usage, and search terms",
                 Some('f'),
             )
+            .switch(
+                "json",
+                "return a list of structured records (signature, flags, examples, input/output types) instead of formatted text",
+                None,
+            )
             .category(Category::Core)
     }
 
@@ -56,6 +61,11 @@ impl Command for Help {
         let head = call.head;
         let find: Option<Spanned<String>> = call.get_flag(engine_state, stack, "find")?;
         let rest: Vec<Spanned<String>> = call.rest(engine_state, stack, 0)?;
+        let json = call.has_flag("json");
+
+        if json && !rest.is_empty() {
+            return help_json(engine_state, &rest, head);
+        }
 
         if rest.is_empty() && find.is_none() {
             let msg = r#"Welcome to Nushell.
@@ -132,10 +142,182 @@ You can also learn more at https://www.nushell.sh/book/"#;
                 example: "help --find char",
                 result: None,
             },
+            Example {
+                description: "get a command's signature, flags, examples, and input/output types as structured data",
+                example: "help str lpad --json",
+                result: None,
+            },
         ]
     }
 }
 
+fn help_json(
+    engine_state: &EngineState,
+    rest: &[Spanned<String>],
+    head: Span,
+) -> Result<PipelineData, ShellError> {
+    let name = rest
+        .iter()
+        .map(|r| r.item.as_str())
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let found: Vec<Value> = engine_state
+        .get_signatures_with_examples(false)
+        .iter()
+        .filter(|(signature, _, _, _, _)| signature.name == name)
+        .map(|(signature, examples, _, _, _)| command_to_record(signature, examples, head))
+        .collect();
+
+    if found.is_empty() {
+        let rest_spans: Vec<Span> = rest.iter().map(|arg| arg.span).collect();
+        return Err(ShellError::CommandNotFound(span(&rest_spans)));
+    }
+
+    Ok(Value::List {
+        vals: found,
+        span: head,
+    }
+    .into_pipeline_data())
+}
+
+fn command_to_record(signature: &Signature, examples: &[Example], head: Span) -> Value {
+    let positional_to_record = |p: &nu_protocol::PositionalArg| Value::Record {
+        cols: vec!["name".into(), "shape".into(), "desc".into()],
+        vals: vec![
+            Value::string(&p.name, head),
+            Value::string(p.shape.to_string(), head),
+            Value::string(&p.desc, head),
+        ],
+        span: head,
+    };
+
+    let required_positional: Vec<Value> = signature
+        .required_positional
+        .iter()
+        .map(positional_to_record)
+        .collect();
+    let optional_positional: Vec<Value> = signature
+        .optional_positional
+        .iter()
+        .map(positional_to_record)
+        .collect();
+    let rest_positional = signature
+        .rest_positional
+        .as_ref()
+        .map(positional_to_record)
+        .unwrap_or_else(|| Value::nothing(head));
+
+    let flags: Vec<Value> = signature
+        .named
+        .iter()
+        .map(|flag| Value::Record {
+            cols: vec![
+                "long".into(),
+                "short".into(),
+                "arg".into(),
+                "required".into(),
+                "desc".into(),
+            ],
+            vals: vec![
+                Value::string(&flag.long, head),
+                match flag.short {
+                    Some(c) => Value::string(c.to_string(), head),
+                    None => Value::nothing(head),
+                },
+                match &flag.arg {
+                    Some(shape) => Value::string(shape.to_string(), head),
+                    None => Value::nothing(head),
+                },
+                Value::boolean(flag.required, head),
+                Value::string(&flag.desc, head),
+            ],
+            span: head,
+        })
+        .collect();
+
+    let input_output_types: Vec<Value> = signature
+        .input_output_types
+        .iter()
+        .map(|(input, output)| Value::Record {
+            cols: vec!["input".into(), "output".into()],
+            vals: vec![
+                Value::string(input.to_string(), head),
+                Value::string(output.to_string(), head),
+            ],
+            span: head,
+        })
+        .collect();
+
+    let example_records: Vec<Value> = examples
+        .iter()
+        .map(|example| Value::Record {
+            cols: vec!["description".into(), "example".into(), "result".into()],
+            vals: vec![
+                Value::string(example.description, head),
+                Value::string(example.example, head),
+                example
+                    .result
+                    .clone()
+                    .unwrap_or_else(|| Value::nothing(head)),
+            ],
+            span: head,
+        })
+        .collect();
+
+    Value::Record {
+        cols: vec![
+            "name".into(),
+            "category".into(),
+            "usage".into(),
+            "extra_usage".into(),
+            "search_terms".into(),
+            "required_positional".into(),
+            "optional_positional".into(),
+            "rest_positional".into(),
+            "flags".into(),
+            "input_output_types".into(),
+            "examples".into(),
+        ],
+        vals: vec![
+            Value::string(&signature.name, head),
+            Value::string(signature.category.to_string(), head),
+            Value::string(&signature.usage, head),
+            Value::string(&signature.extra_usage, head),
+            Value::List {
+                vals: signature
+                    .search_terms
+                    .iter()
+                    .map(|t| Value::string(t, head))
+                    .collect(),
+                span: head,
+            },
+            Value::List {
+                vals: required_positional,
+                span: head,
+            },
+            Value::List {
+                vals: optional_positional,
+                span: head,
+            },
+            rest_positional,
+            Value::List {
+                vals: flags,
+                span: head,
+            },
+            Value::List {
+                vals: input_output_types,
+                span: head,
+            },
+            Value::List {
+                vals: example_records,
+                span: head,
+            },
+        ],
+        span: head,
+    }
+}
+
 pub fn highlight_search_in_table(
     table: Vec<Value>, // list of records
     search_string: &str,
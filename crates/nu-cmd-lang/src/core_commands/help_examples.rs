@@ -0,0 +1,188 @@
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack, StateWorkingSet},
+    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
+    Spanned, SyntaxShape, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct HelpExamples;
+
+impl Command for HelpExamples {
+    fn name(&self) -> &str {
+        "help examples"
+    }
+
+    fn usage(&self) -> &str {
+        "Show help for nushell commands' examples."
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("help examples")
+            .rest(
+                "rest",
+                SyntaxShape::String,
+                "the name of command to show examples of",
+            )
+            .switch(
+                "verify",
+                "run the command's declared examples and compare against their recorded results",
+                None,
+            )
+            .input_output_types(vec![(Type::Nothing, Type::Table(vec![]))])
+            .category(Category::Core)
+    }
+
+    fn extra_usage(&self) -> &str {
+        "With --verify, each example with a recorded result is evaluated in a fresh, empty stack and compared against that result, which is useful for catching documentation examples that have drifted from real behavior."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Show examples for a command",
+                example: "help examples str trim",
+                result: None,
+            },
+            Example {
+                description: "Verify a command's recorded example results still hold",
+                example: "help examples --verify str trim",
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let rest: Vec<Spanned<String>> = call.rest(engine_state, stack, 0)?;
+        let verify = call.has_flag("verify");
+
+        let name = rest
+            .iter()
+            .map(|r| r.item.as_str())
+            .collect::<Vec<_>>()
+            .join(" ");
+        let name_span = nu_protocol::span(&rest.iter().map(|r| r.span).collect::<Vec<_>>());
+
+        let decl_id = engine_state
+            .find_decl(name.as_bytes(), &[])
+            .ok_or(ShellError::CommandNotFound(name_span))?;
+        let decl = engine_state.get_decl(decl_id);
+
+        let rows: Vec<Value> = decl
+            .examples()
+            .into_iter()
+            .map(|example| {
+                if verify {
+                    verify_example(engine_state, &example, head)
+                } else {
+                    describe_example(&example, head)
+                }
+            })
+            .collect();
+
+        Ok(rows
+            .into_iter()
+            .into_pipeline_data(engine_state.ctrlc.clone()))
+    }
+}
+
+fn describe_example(example: &Example, head: Span) -> Value {
+    Value::Record {
+        cols: vec!["description".into(), "example".into(), "result".into()],
+        vals: vec![
+            Value::string(example.description, head),
+            Value::string(example.example, head),
+            example
+                .result
+                .clone()
+                .unwrap_or_else(|| Value::nothing(head)),
+        ],
+        span: head,
+    }
+}
+
+fn verify_example(engine_state: &EngineState, example: &Example, head: Span) -> Value {
+    let (status, actual) = match example.result.as_ref() {
+        None => ("not checked".to_string(), Value::nothing(head)),
+        Some(expected) => match eval_example(engine_state, example.example) {
+            Ok(actual) if &actual == expected => ("pass".to_string(), actual),
+            Ok(actual) => ("fail".to_string(), actual),
+            Err(err) => ("error".to_string(), Value::string(err.to_string(), head)),
+        },
+    };
+
+    Value::Record {
+        cols: vec![
+            "description".into(),
+            "example".into(),
+            "status".into(),
+            "expected".into(),
+            "actual".into(),
+        ],
+        vals: vec![
+            Value::string(example.description, head),
+            Value::string(example.example, head),
+            Value::string(status, head),
+            example
+                .result
+                .clone()
+                .unwrap_or_else(|| Value::nothing(head)),
+            actual,
+        ],
+        span: head,
+    }
+}
+
+fn eval_example(engine_state: &EngineState, source: &str) -> Result<Value, ShellError> {
+    // Evaluate in a cloned, throwaway engine state so a misbehaving example
+    // cannot leave defs, aliases, or env changes behind in the caller's shell.
+    let mut sandbox = engine_state.clone();
+
+    let block = {
+        let mut working_set = StateWorkingSet::new(&sandbox);
+        let (block, errors) =
+            nu_parser::parse(&mut working_set, None, source.as_bytes(), false, &[]);
+        if let Some(err) = errors.first() {
+            return Err(ShellError::GenericError(
+                format!("Could not parse example: {err:?}"),
+                "invalid example source".into(),
+                None,
+                None,
+                Vec::new(),
+            ));
+        }
+        let delta = working_set.render();
+        sandbox.merge_delta(delta)?;
+        block
+    };
+
+    let mut stack = Stack::new();
+    let result = nu_engine::eval_block(
+        &sandbox,
+        &mut stack,
+        &block,
+        PipelineData::empty(),
+        true,
+        true,
+    )?;
+
+    Ok(result.into_value(Span::test_data()))
+}
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn test_examples() {
+        use super::HelpExamples;
+        use crate::test_examples;
+        test_examples(HelpExamples {})
+    }
+}
@@ -20,6 +20,7 @@ mod for_;
 pub mod help;
 pub mod help_aliases;
 pub mod help_commands;
+pub mod help_examples;
 pub mod help_modules;
 mod help_operators;
 mod hide;
@@ -59,6 +60,7 @@ pub use for_::For;
 pub use help::Help;
 pub use help_aliases::HelpAliases;
 pub use help_commands::HelpCommands;
+pub use help_examples::HelpExamples;
 pub use help_modules::HelpModules;
 pub use help_operators::HelpOperators;
 pub use hide::Hide;
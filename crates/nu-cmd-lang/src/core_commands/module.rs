@@ -25,7 +25,14 @@ impl Command for Module {
     }
 
     fn extra_usage(&self) -> &str {
-        r#"This command is a parser keyword. For details, check:
+        r#"A top-level `if <condition> { ... } else { ... }` whose condition can be
+resolved without running the engine (a `const` value, a literal, or a
+comparison against `$nu.os-info.name`) is evaluated at parse time, and only
+the selected branch's declarations are merged into the module. This lets a
+module provide different `def`/`export def` for different platforms without
+keeping them in separate files. `else if` chains aren't supported.
+
+This command is a parser keyword. For details, check:
   https://www.nushell.sh/book/thinking_in_nu.html"#
     }
 
@@ -60,6 +67,20 @@ impl Command for Module {
                 example: r#"module foo { export def-env bar [] { let-env FOO_BAR = "BAZ" } }; use foo bar; bar; $env.FOO_BAR"#,
                 result: Some(Value::test_string("BAZ")),
             },
+            Example {
+                description:
+                    "Only the matching branch's declarations are kept, decided at parse time",
+                example: r#"module platform {
+    if $nu.os-info.name == "windows" {
+        export def greet [] { "hello from windows" }
+    } else {
+        export def greet [] { "hello from elsewhere" }
+    }
+}
+use platform greet
+greet"#,
+                result: None,
+            },
         ]
     }
 }
@@ -2,7 +2,7 @@ use nu_engine::CallExt;
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
-    Category, Example, PipelineData, ShellError, Signature, Spanned, SyntaxShape, Type,
+    Category, Example, PipelineData, ShellError, Signature, Spanned, SyntaxShape, Type, Value,
 };
 
 #[derive(Clone)]
@@ -22,6 +22,12 @@ impl Command for OverlayNew {
             .input_output_types(vec![(Type::Nothing, Type::Nothing)])
             .allow_variants_without_examples(true)
             .required("name", SyntaxShape::String, "Name of the overlay")
+            .named(
+                "from-record",
+                SyntaxShape::Record,
+                "populate the overlay's environment from a record; closures are stored as-is and can be called with `do`",
+                None,
+            )
             // TODO:
             // .switch(
             //     "prefix",
@@ -34,6 +40,14 @@ impl Command for OverlayNew {
     fn extra_usage(&self) -> &str {
         r#"The command will first create an empty module, then add it as an overlay.
 
+With --from-record, each column of the given record becomes an environment
+variable in the new overlay instead of the overlay staying empty. This lets
+scripts build a toolset (e.g. a per-project set of task closures) at
+runtime, without generating a temporary module file. Because overlays are
+normally populated from modules at parse time, a record entry holding a
+closure becomes a callable env var (`do $env.name ...args`) rather than a
+command that can be called by name directly.
+
 This command is a parser keyword. For details, check:
   https://www.nushell.sh/book/thinking_in_nu.html"#
     }
@@ -50,18 +64,33 @@ This command is a parser keyword. For details, check:
         _input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
         let name_arg: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let from_record: Option<Value> = call.get_flag(engine_state, stack, "from-record")?;
 
         stack.add_overlay(name_arg.item);
 
+        if let Some(record) = from_record {
+            let (cols, vals) = record.as_record()?;
+            for (col, val) in cols.iter().zip(vals.iter()) {
+                stack.add_env_var(col.clone(), val.clone());
+            }
+        }
+
         Ok(PipelineData::empty())
     }
 
     fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            description: "Create an empty overlay",
-            example: r#"overlay new spam"#,
-            result: None,
-        }]
+        vec![
+            Example {
+                description: "Create an empty overlay",
+                example: r#"overlay new spam"#,
+                result: None,
+            },
+            Example {
+                description: "Create an overlay with closures and values built from a record",
+                example: r#"overlay new tasks --from-record {build: {|| cargo build}, target: "debug"}"#,
+                result: None,
+            },
+        ]
     }
 }
 
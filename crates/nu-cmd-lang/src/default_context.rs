@@ -38,6 +38,7 @@ pub fn create_default_context() -> EngineState {
             Help,
             HelpAliases,
             HelpCommands,
+            HelpExamples,
             HelpModules,
             HelpOperators,
             Hide,
@@ -47,9 +47,23 @@ impl Command for BytesBuild {
         call: &Call,
         _input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
-        let mut output = vec![];
-        for expr in call.positional_iter() {
-            let val = eval_expression(engine_state, stack, expr)?;
+        // Evaluate every chunk before allocating so we can reserve the exact final size up
+        // front, instead of letting `output` grow (and reallocate/copy) one chunk at a time.
+        let chunks = call
+            .positional_iter()
+            .map(|expr| eval_expression(engine_state, stack, expr))
+            .collect::<Result<Vec<Value>, ShellError>>()?;
+
+        let total_len = chunks
+            .iter()
+            .map(|val| match val {
+                Value::Binary { val, .. } => val.len(),
+                _ => 0,
+            })
+            .sum();
+        let mut output = Vec::with_capacity(total_len);
+
+        for val in chunks {
             match val {
                 Value::Binary { mut val, .. } => output.append(&mut val),
                 // Explicitly propagate errors instead of dropping them.
@@ -42,8 +42,20 @@ impl Command for BytesCollect {
     ) -> Result<PipelineData, ShellError> {
         let separator: Option<Vec<u8>> = call.opt(engine_state, stack, 0)?;
         // input should be a list of binary data.
-        let mut output_binary = vec![];
-        for value in input {
+        let values: Vec<Value> = input.into_iter().collect();
+
+        // Reserve the exact final size up front instead of letting `output_binary` grow (and
+        // reallocate/copy) one chunk at a time.
+        let total_len: usize = values
+            .iter()
+            .map(|value| match value {
+                Value::Binary { val, .. } => val.len() + separator.as_ref().map_or(0, Vec::len),
+                _ => 0,
+            })
+            .sum();
+        let mut output_binary = Vec::with_capacity(total_len);
+
+        for value in values {
             match value {
                 Value::Binary { mut val, .. } => {
                     output_binary.append(&mut val);
@@ -0,0 +1,66 @@
+use nu_protocol::{ShellError, Span};
+
+/// A single fixed-width field in a `bytes pack`/`bytes unpack` layout.
+#[derive(Clone, Copy)]
+pub(super) enum Field {
+    U8,
+    I8,
+    U16Le,
+    U16Be,
+    I16Le,
+    I16Be,
+    U32Le,
+    U32Be,
+    I32Le,
+    I32Be,
+    U64Le,
+    U64Be,
+    I64Le,
+    I64Be,
+    F32Le,
+    F32Be,
+    F64Le,
+    F64Be,
+}
+
+impl Field {
+    pub(super) fn parse(spec: &str, span: Span) -> Result<Field, ShellError> {
+        match spec {
+            "u8" => Ok(Field::U8),
+            "i8" => Ok(Field::I8),
+            "u16le" => Ok(Field::U16Le),
+            "u16be" => Ok(Field::U16Be),
+            "i16le" => Ok(Field::I16Le),
+            "i16be" => Ok(Field::I16Be),
+            "u32le" => Ok(Field::U32Le),
+            "u32be" => Ok(Field::U32Be),
+            "i32le" => Ok(Field::I32Le),
+            "i32be" => Ok(Field::I32Be),
+            "u64le" => Ok(Field::U64Le),
+            "u64be" => Ok(Field::U64Be),
+            "i64le" => Ok(Field::I64Le),
+            "i64be" => Ok(Field::I64Be),
+            "f32le" => Ok(Field::F32Le),
+            "f32be" => Ok(Field::F32Be),
+            "f64le" => Ok(Field::F64Le),
+            "f64be" => Ok(Field::F64Be),
+            _ => Err(ShellError::IncorrectValue {
+                msg: format!("unknown layout field '{spec}'"),
+                span,
+            }),
+        }
+    }
+}
+
+pub(super) fn field_width(field: Field) -> usize {
+    match field {
+        Field::U8 | Field::I8 => 1,
+        Field::U16Le | Field::U16Be | Field::I16Le | Field::I16Be => 2,
+        Field::U32Le | Field::U32Be | Field::I32Le | Field::I32Be | Field::F32Le | Field::F32Be => {
+            4
+        }
+        Field::U64Le | Field::U64Be | Field::I64Le | Field::I64Be | Field::F64Le | Field::F64Be => {
+            8
+        }
+    }
+}
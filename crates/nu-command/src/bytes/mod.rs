@@ -5,11 +5,14 @@ mod bytes_;
 mod collect;
 mod ends_with;
 mod index_of;
+mod layout;
 mod length;
+mod pack;
 mod remove;
 mod replace;
 mod reverse;
 mod starts_with;
+mod unpack;
 
 pub use add::BytesAdd;
 pub use at::BytesAt;
@@ -19,7 +22,9 @@ pub use collect::BytesCollect;
 pub use ends_with::BytesEndsWith;
 pub use index_of::BytesIndexOf;
 pub use length::BytesLen;
+pub use pack::BytesPack;
 pub use remove::BytesRemove;
 pub use replace::BytesReplace;
 pub use reverse::BytesReverse;
 pub use starts_with::BytesStartsWith;
+pub use unpack::BytesUnpack;
@@ -0,0 +1,147 @@
+use byteorder::{BigEndian, ByteOrder, LittleEndian};
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Spanned,
+    SyntaxShape, Type, Value,
+};
+
+use super::layout::{field_width, Field};
+
+#[derive(Clone)]
+pub struct BytesPack;
+
+impl Command for BytesPack {
+    fn name(&self) -> &str {
+        "bytes pack"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("bytes pack")
+            .input_output_types(vec![(Type::List(Box::new(Type::Any)), Type::Binary)])
+            .required(
+                "layout",
+                SyntaxShape::List(Box::new(SyntaxShape::String)),
+                "the field types to pack, in order: u8, i8, u16le, u16be, u32le, u32be, u64le, u64be, i16le, i16be, i32le, i32be, i64le, i64be, f32le, f32be, f64le, f64be",
+            )
+            .category(Category::Bytes)
+    }
+
+    fn usage(&self) -> &str {
+        "Pack a list of numbers into binary data using an explicit field layout."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "The input list and the layout must be the same length; each input value is packed into the binary field at the same position. This is the inverse of `bytes unpack`."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["binary", "struct", "serialize", "endian"]
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Pack a little-endian u32 length followed by a single byte flag",
+            example: "[42 1] | bytes pack [u32le u8]",
+            result: Some(Value::Binary {
+                val: vec![42, 0, 0, 0, 1],
+                span: Span::test_data(),
+            }),
+        }]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let layout: Vec<Spanned<String>> = call.req(engine_state, stack, 0)?;
+        let fields = layout
+            .into_iter()
+            .map(|spec| Field::parse(&spec.item, spec.span))
+            .collect::<Result<Vec<Field>, ShellError>>()?;
+
+        let values: Vec<Value> = input.into_iter().collect();
+        if values.len() != fields.len() {
+            return Err(ShellError::IncorrectValue {
+                msg: format!(
+                    "layout has {} field(s) but input has {} value(s)",
+                    fields.len(),
+                    values.len()
+                ),
+                span: head,
+            });
+        }
+
+        let total_len = fields.iter().map(|f| field_width(*f)).sum();
+        let mut out = vec![0u8; total_len];
+        let mut offset = 0;
+
+        for (field, value) in fields.iter().zip(values) {
+            let width = field_width(*field);
+            let slot = &mut out[offset..offset + width];
+            let span = value.span()?;
+            let as_int = |v: Value| -> Result<i64, ShellError> {
+                v.as_integer().map_err(|_| ShellError::TypeMismatch {
+                    err_message: "expected an integer for this field".into(),
+                    span,
+                })
+            };
+            let as_float = |v: Value| -> Result<f64, ShellError> {
+                match v {
+                    Value::Float { val, .. } => Ok(val),
+                    Value::Int { val, .. } => Ok(val as f64),
+                    _ => Err(ShellError::TypeMismatch {
+                        err_message: "expected a number for this field".into(),
+                        span,
+                    }),
+                }
+            };
+
+            match field {
+                Field::U8 => slot[0] = as_int(value)? as u8,
+                Field::I8 => slot[0] = (as_int(value)? as i8) as u8,
+                Field::U16Le => LittleEndian::write_u16(slot, as_int(value)? as u16),
+                Field::U16Be => BigEndian::write_u16(slot, as_int(value)? as u16),
+                Field::I16Le => LittleEndian::write_i16(slot, as_int(value)? as i16),
+                Field::I16Be => BigEndian::write_i16(slot, as_int(value)? as i16),
+                Field::U32Le => LittleEndian::write_u32(slot, as_int(value)? as u32),
+                Field::U32Be => BigEndian::write_u32(slot, as_int(value)? as u32),
+                Field::I32Le => LittleEndian::write_i32(slot, as_int(value)? as i32),
+                Field::I32Be => BigEndian::write_i32(slot, as_int(value)? as i32),
+                Field::U64Le => LittleEndian::write_u64(slot, as_int(value)? as u64),
+                Field::U64Be => BigEndian::write_u64(slot, as_int(value)? as u64),
+                Field::I64Le => LittleEndian::write_i64(slot, as_int(value)? as i64),
+                Field::I64Be => BigEndian::write_i64(slot, as_int(value)? as i64),
+                Field::F32Le => LittleEndian::write_f32(slot, as_float(value)? as f32),
+                Field::F32Be => BigEndian::write_f32(slot, as_float(value)? as f32),
+                Field::F64Le => LittleEndian::write_f64(slot, as_float(value)?),
+                Field::F64Be => BigEndian::write_f64(slot, as_float(value)?),
+            }
+
+            offset += width;
+        }
+
+        Ok(Value::Binary {
+            val: out,
+            span: head,
+        }
+        .into_pipeline_data())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(BytesPack {})
+    }
+}
@@ -0,0 +1,148 @@
+use byteorder::{BigEndian, ByteOrder, LittleEndian};
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Spanned,
+    SyntaxShape, Type, Value,
+};
+
+use super::layout::{field_width, Field};
+
+#[derive(Clone)]
+pub struct BytesUnpack;
+
+impl Command for BytesUnpack {
+    fn name(&self) -> &str {
+        "bytes unpack"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("bytes unpack")
+            .input_output_types(vec![(Type::Binary, Type::List(Box::new(Type::Any)))])
+            .required(
+                "layout",
+                SyntaxShape::List(Box::new(SyntaxShape::String)),
+                "the field types to unpack, in order: u8, i8, u16le, u16be, u32le, u32be, u64le, u64be, i16le, i16be, i32le, i32be, i64le, i64be, f32le, f32be, f64le, f64be",
+            )
+            .category(Category::Bytes)
+    }
+
+    fn usage(&self) -> &str {
+        "Unpack binary data into a list of numbers using an explicit field layout."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "This is the inverse of `bytes pack`. The input must contain at least as many bytes as the layout requires; any trailing bytes beyond the layout's total width are ignored."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["binary", "struct", "deserialize", "endian"]
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Unpack a little-endian u32 length followed by a single byte flag",
+            example: "0x[2A 00 00 00 01] | bytes unpack [u32le u8]",
+            result: Some(Value::List {
+                vals: vec![Value::test_int(42), Value::test_int(1)],
+                span: Span::test_data(),
+            }),
+        }]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let layout: Vec<Spanned<String>> = call.req(engine_state, stack, 0)?;
+        let layout_span = call.positional_nth(0).map_or(head, |expr| expr.span);
+        let fields = layout
+            .into_iter()
+            .map(|spec| Field::parse(&spec.item, spec.span))
+            .collect::<Result<Vec<Field>, ShellError>>()?;
+
+        let bytes = collect_binary(input, head)?;
+
+        let total_len: usize = fields.iter().map(|f| field_width(*f)).sum();
+        if bytes.len() < total_len {
+            return Err(ShellError::IncorrectValue {
+                msg: format!(
+                    "layout needs {total_len} byte(s) but input only has {}",
+                    bytes.len()
+                ),
+                span: layout_span,
+            });
+        }
+
+        let mut vals = Vec::with_capacity(fields.len());
+        let mut offset = 0;
+
+        for field in fields {
+            let width = field_width(field);
+            let slot = &bytes[offset..offset + width];
+
+            let val = match field {
+                Field::U8 => Value::int(slot[0] as i64, head),
+                Field::I8 => Value::int((slot[0] as i8) as i64, head),
+                Field::U16Le => Value::int(LittleEndian::read_u16(slot) as i64, head),
+                Field::U16Be => Value::int(BigEndian::read_u16(slot) as i64, head),
+                Field::I16Le => Value::int(LittleEndian::read_i16(slot) as i64, head),
+                Field::I16Be => Value::int(BigEndian::read_i16(slot) as i64, head),
+                Field::U32Le => Value::int(LittleEndian::read_u32(slot) as i64, head),
+                Field::U32Be => Value::int(BigEndian::read_u32(slot) as i64, head),
+                Field::I32Le => Value::int(LittleEndian::read_i32(slot) as i64, head),
+                Field::I32Be => Value::int(BigEndian::read_i32(slot) as i64, head),
+                Field::U64Le => Value::int(LittleEndian::read_u64(slot) as i64, head),
+                Field::U64Be => Value::int(BigEndian::read_u64(slot) as i64, head),
+                Field::I64Le => Value::int(LittleEndian::read_i64(slot), head),
+                Field::I64Be => Value::int(BigEndian::read_i64(slot), head),
+                Field::F32Le => Value::float(LittleEndian::read_f32(slot) as f64, head),
+                Field::F32Be => Value::float(BigEndian::read_f32(slot) as f64, head),
+                Field::F64Le => Value::float(LittleEndian::read_f64(slot), head),
+                Field::F64Be => Value::float(BigEndian::read_f64(slot), head),
+            };
+            vals.push(val);
+
+            offset += width;
+        }
+
+        Ok(Value::List { vals, span: head }.into_pipeline_data())
+    }
+}
+
+fn collect_binary(input: PipelineData, span: Span) -> Result<Vec<u8>, ShellError> {
+    let mut bytes = vec![];
+
+    for value in input {
+        match value {
+            Value::Binary { val, .. } => bytes.extend_from_slice(&val),
+            other => {
+                return Err(ShellError::UnsupportedInput(
+                    "Expected binary from pipeline".to_string(),
+                    "value originates from here".into(),
+                    span,
+                    other.expect_span(),
+                ))
+            }
+        }
+    }
+
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(BytesUnpack {})
+    }
+}
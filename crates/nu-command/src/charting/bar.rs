@@ -0,0 +1,181 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, SyntaxShape,
+    Type, Value,
+};
+use terminal_size::{Height, Width};
+
+#[derive(Clone)]
+pub struct ChartBar;
+
+impl Command for ChartBar {
+    fn name(&self) -> &str {
+        "chart bar"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("chart bar")
+            .input_output_types(vec![(Type::List(Box::new(Type::Any)), Type::String)])
+            .optional(
+                "value-column",
+                SyntaxShape::String,
+                "the numeric column to chart, if the input is a table",
+            )
+            .optional(
+                "label-column",
+                SyntaxShape::String,
+                "the label column, if the input is a table",
+            )
+            .named(
+                "width",
+                SyntaxShape::Int,
+                "max total line width, defaults to the detected terminal width",
+                Some('w'),
+            )
+            .category(Category::Chart)
+    }
+
+    fn usage(&self) -> &str {
+        "Render values as a horizontal bar chart with axis labels."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Bars are scaled to fit the detected terminal width (override with --width), and the \
+result is a single string that composes with table output - pipe it into `print` or insert it \
+as a column."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["graph", "plot", "visualization", "histogram"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let config = engine_state.get_config().clone();
+        let value_column: Option<String> = call.opt(engine_state, stack, 0)?;
+        let label_column: Option<String> = call.opt(engine_state, stack, 1)?;
+        let width: Option<i64> = call.get_flag(engine_state, stack, "width")?;
+
+        let total_width = width.map(|w| w as usize).unwrap_or_else(|| {
+            terminal_size::terminal_size()
+                .map(|(Width(w), Height(_))| w as usize)
+                .unwrap_or(80)
+        });
+
+        let rows = input.into_iter().collect::<Vec<Value>>();
+        let mut labels = Vec::with_capacity(rows.len());
+        let mut numbers = Vec::with_capacity(rows.len());
+
+        for (index, row) in rows.into_iter().enumerate() {
+            let value = match &value_column {
+                Some(col) => {
+                    row.get_data_by_key(col)
+                        .ok_or_else(|| ShellError::CantFindColumn {
+                            col_name: col.clone(),
+                            span: head,
+                            src_span: row.expect_span(),
+                        })?
+                }
+                None => row.clone(),
+            };
+            numbers.push(as_f64(&value, head)?);
+
+            let label = match &label_column {
+                Some(col) => row
+                    .get_data_by_key(col)
+                    .map(|v| v.into_string(", ", &config))
+                    .unwrap_or_default(),
+                None => index.to_string(),
+            };
+            labels.push(label);
+        }
+
+        Ok(Value::String {
+            val: render_bar_chart(&labels, &numbers, total_width),
+            span: head,
+        }
+        .into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Chart a list of numbers",
+                example: "[3 7 2 9] | chart bar",
+                result: None,
+            },
+            Example {
+                description: "Chart a table by name and amount",
+                example: "[[fruit amount]; [apple 4] [pear 9]] | chart bar amount fruit",
+                result: None,
+            },
+        ]
+    }
+}
+
+fn as_f64(value: &Value, head: Span) -> Result<f64, ShellError> {
+    match value {
+        Value::Int { val, .. } => Ok(*val as f64),
+        Value::Float { val, .. } => Ok(*val),
+        Value::Filesize { val, .. } => Ok(*val as f64),
+        Value::Duration { val, .. } => Ok(*val as f64),
+        other => Err(ShellError::UnsupportedInput(
+            "chart bar requires numeric values".to_string(),
+            format!("input type: {:?}", other.get_type()),
+            head,
+            other.expect_span(),
+        )),
+    }
+}
+
+fn render_bar_chart(labels: &[String], numbers: &[f64], total_width: usize) -> String {
+    if numbers.is_empty() {
+        return String::new();
+    }
+
+    let max = numbers.iter().cloned().fold(0.0_f64, f64::max).max(1.0);
+    let label_width = labels.iter().map(|l| l.len()).max().unwrap_or(0);
+    let value_width = numbers
+        .iter()
+        .map(|n| format!("{n}").len())
+        .max()
+        .unwrap_or(1);
+    // "label │ bar value", with a minimum bar width so short terminals still show something.
+    let bar_width = total_width
+        .saturating_sub(label_width + value_width + 4)
+        .max(1);
+
+    labels
+        .iter()
+        .zip(numbers)
+        .map(|(label, &value)| {
+            let filled = ((value / max) * bar_width as f64).round() as usize;
+            format!(
+                "{label:>label_width$} │ {}{} {value}",
+                "█".repeat(filled),
+                " ".repeat(bar_width - filled),
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(ChartBar {})
+    }
+}
@@ -29,6 +29,14 @@ impl Command for Histogram {
             .optional("column-name", SyntaxShape::String, "column name to calc frequency, no need to provide if input is just a list")
             .optional("frequency-column-name", SyntaxShape::String, "histogram's frequency column, default to be frequency column output")
             .named("percentage-type", SyntaxShape::String, "percentage calculate method, can be 'normalize' or 'relative', in 'normalize', defaults to be 'normalize'", Some('t'))
+            .named("bins", SyntaxShape::Int, "divide the numeric range into this many equal-width bins, instead of counting each distinct value separately", Some('b'))
+            .named("bin-width", SyntaxShape::Number, "divide the numeric range into bins of this width, instead of --bins", None)
+    }
+
+    fn extra_usage(&self) -> &str {
+        "With --bins or --bin-width, every value must be numeric. Instead of one row per \
+distinct value, the output has one row per bin, with `bin_start`/`bin_end` columns bounding \
+each bin instead of a `value` column."
     }
 
     fn usage(&self) -> &str {
@@ -82,7 +90,12 @@ impl Command for Histogram {
                 description: "Compute a histogram for a list of numbers, and percentage is based on the maximum value",
                 example: "[1 2 3 1 1 1 2 2 1 1] | histogram --percentage-type relative",
                 result: None,
-            }
+            },
+            Example {
+                description: "Bucket continuous numeric data into 4 equal-width bins",
+                example: "[1 2 3 4 5 6 7 8 9 10] | histogram --bins 4",
+                result: None,
+            },
         ]
     }
 
@@ -128,6 +141,36 @@ impl Command for Histogram {
             },
         };
 
+        let bins: Option<Spanned<i64>> = call.get_flag(engine_state, stack, "bins")?;
+        let bin_width: Option<Spanned<f64>> = call.get_flag(engine_state, stack, "bin-width")?;
+        let binning = match (bins, bin_width) {
+            (Some(_), Some(bin_width)) => {
+                return Err(ShellError::IncompatibleParametersSingle {
+                    msg: "--bins and --bin-width can't be used together".to_string(),
+                    span: bin_width.span,
+                })
+            }
+            (Some(bins), None) => {
+                if bins.item <= 0 {
+                    return Err(ShellError::IncompatibleParametersSingle {
+                        msg: "--bins must be a positive number".to_string(),
+                        span: bins.span,
+                    });
+                }
+                Some(Binning::Count(bins.item as usize))
+            }
+            (None, Some(bin_width)) => {
+                if bin_width.item <= 0.0 {
+                    return Err(ShellError::IncompatibleParametersSingle {
+                        msg: "--bin-width must be a positive number".to_string(),
+                        span: bin_width.span,
+                    });
+                }
+                Some(Binning::Width(bin_width.item))
+            }
+            (None, None) => None,
+        };
+
         let span = call.head;
         let data_as_value = input.into_value(span);
         // `input` is not a list, here we can return an error.
@@ -136,6 +179,7 @@ impl Command for Histogram {
             column_name,
             frequency_column_name,
             calc_method,
+            binning,
             span,
             // Note that as_list() filters out Value::Error here.
             data_as_value.expect_span(),
@@ -143,11 +187,17 @@ impl Command for Histogram {
     }
 }
 
+enum Binning {
+    Count(usize),
+    Width(f64),
+}
+
 fn run_histogram(
     values: Vec<Value>,
     column_name: Option<Spanned<String>>,
     freq_column: String,
     calc_method: PercentageCalcMethod,
+    binning: Option<Binning>,
     head_span: Span,
     list_span: Span,
 ) -> Result<PipelineData, ShellError> {
@@ -213,6 +263,10 @@ fn run_histogram(
         }
     }
 
+    if let Some(binning) = binning {
+        return histogram_numeric_impl(inputs, binning, calc_method, &freq_column, head_span);
+    }
+
     let value_column_name = column_name
         .map(|x| x.item)
         .unwrap_or_else(|| "value".to_string());
@@ -225,6 +279,104 @@ fn run_histogram(
     ))
 }
 
+fn hashable_as_f64(value: &HashableValue, head_span: Span) -> Result<f64, ShellError> {
+    match value {
+        HashableValue::Int { val, .. } => Ok(*val as f64),
+        HashableValue::Float { val, .. } => Ok(f64::from_ne_bytes(*val)),
+        other => Err(ShellError::UnsupportedInput(
+            "--bins and --bin-width require every value to be numeric".to_string(),
+            format!("input type: {other:?}"),
+            head_span,
+            head_span,
+        )),
+    }
+}
+
+fn histogram_numeric_impl(
+    inputs: Vec<HashableValue>,
+    binning: Binning,
+    calc_method: PercentageCalcMethod,
+    freq_column: &str,
+    span: Span,
+) -> Result<PipelineData, ShellError> {
+    let values = inputs
+        .iter()
+        .map(|v| hashable_as_f64(v, span))
+        .collect::<Result<Vec<f64>, ShellError>>()?;
+
+    let min = values.iter().cloned().fold(f64::INFINITY, f64::min);
+    let max = values.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    let range = (max - min).max(0.0);
+
+    let (width, bin_count) = match binning {
+        Binning::Width(width) => (width, ((range / width).ceil() as usize).max(1)),
+        Binning::Count(bin_count) => {
+            let width = if range > 0.0 {
+                range / bin_count as f64
+            } else {
+                1.0
+            };
+            (width, bin_count)
+        }
+    };
+
+    let mut counts = vec![0usize; bin_count];
+    for value in &values {
+        let idx = if width > 0.0 {
+            (((value - min) / width).floor() as usize).min(bin_count - 1)
+        } else {
+            0
+        };
+        counts[idx] += 1;
+    }
+
+    let total_cnt = values.len();
+    let max_cnt = counts.iter().copied().max().unwrap_or(0);
+    let result_cols = vec![
+        "bin_start".to_string(),
+        "bin_end".to_string(),
+        "count".to_string(),
+        "quantile".to_string(),
+        "percentage".to_string(),
+        freq_column.to_string(),
+    ];
+    const MAX_FREQ_COUNT: f64 = 100.0;
+
+    let result: Vec<Value> = counts
+        .into_iter()
+        .enumerate()
+        .map(|(i, count)| {
+            let bin_start = min + (i as f64) * width;
+            let bin_end = bin_start + width;
+            let quantile = match calc_method {
+                PercentageCalcMethod::Normalize if total_cnt > 0 => count as f64 / total_cnt as f64,
+                PercentageCalcMethod::Relative if max_cnt > 0 => count as f64 / max_cnt as f64,
+                _ => 0.0,
+            };
+            let percentage = format!("{:.2}%", quantile * 100_f64);
+            let freq = "*".repeat((MAX_FREQ_COUNT * quantile).floor() as usize);
+
+            Value::Record {
+                cols: result_cols.clone(),
+                vals: vec![
+                    Value::float(bin_start, span),
+                    Value::float(bin_end, span),
+                    Value::int(count as i64, span),
+                    Value::float(quantile, span),
+                    Value::String {
+                        val: percentage,
+                        span,
+                    },
+                    Value::String { val: freq, span },
+                ],
+                span,
+            }
+        })
+        .collect();
+
+    Ok(Value::List { vals: result, span }.into_pipeline_data())
+}
+
 fn histogram_impl(
     inputs: Vec<HashableValue>,
     value_column_name: &str,
@@ -0,0 +1,311 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, SyntaxShape,
+    Type, Value,
+};
+
+// Each braille character packs a 2-wide by 4-tall grid of dots, giving a line chart much
+// finer vertical and horizontal resolution than one terminal cell per data point.
+const BRAILLE_BASE: u32 = 0x2800;
+const BRAILLE_BITS: [[u32; 2]; 4] = [[0x01, 0x08], [0x02, 0x10], [0x04, 0x20], [0x40, 0x80]];
+
+#[derive(Clone)]
+pub struct ChartLine;
+
+impl Command for ChartLine {
+    fn name(&self) -> &str {
+        "chart line"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("chart line")
+            .input_output_types(vec![(Type::Table(vec![]), Type::String)])
+            .optional(
+                "x-column",
+                SyntaxShape::String,
+                "the column to use for the x axis, defaults to the row index",
+            )
+            .optional(
+                "y-column",
+                SyntaxShape::String,
+                "the numeric column to plot, if the input is a table",
+            )
+            .named(
+                "group",
+                SyntaxShape::String,
+                "split the input into one series per distinct value of this column",
+                Some('g'),
+            )
+            .named(
+                "height",
+                SyntaxShape::Int,
+                "how many terminal rows tall each series is, defaults to 8",
+                None,
+            )
+            .category(Category::Chart)
+    }
+
+    fn usage(&self) -> &str {
+        "Render a time series as a terminal line chart using braille characters."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Points are scaled to fit the plot and connected with straight segments. With --group, \
+one braille plot is rendered per distinct value of the group column, sharing the same x and y \
+scale so the series stay comparable. If the x column holds datetime values, the axis labels are \
+formatted as dates instead of raw numbers."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["graph", "plot", "visualization", "timeseries", "braille"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let config = engine_state.get_config().clone();
+        let x_column: Option<String> = call.opt(engine_state, stack, 0)?;
+        let y_column: Option<String> = call.opt(engine_state, stack, 1)?;
+        let group_column: Option<String> = call.get_flag(engine_state, stack, "group")?;
+        let height: usize = call
+            .get_flag::<i64>(engine_state, stack, "height")?
+            .map(|h| h as usize)
+            .unwrap_or(8)
+            .max(1);
+
+        let rows = input.into_iter().collect::<Vec<Value>>();
+        let mut points = Vec::with_capacity(rows.len());
+
+        for (index, row) in rows.into_iter().enumerate() {
+            let x_value = match &x_column {
+                Some(col) => {
+                    row.get_data_by_key(col)
+                        .ok_or_else(|| ShellError::CantFindColumn {
+                            col_name: col.clone(),
+                            span: head,
+                            src_span: row.expect_span(),
+                        })?
+                }
+                None => Value::Int {
+                    val: index as i64,
+                    span: head,
+                },
+            };
+
+            let y_value = match &y_column {
+                Some(col) => {
+                    row.get_data_by_key(col)
+                        .ok_or_else(|| ShellError::CantFindColumn {
+                            col_name: col.clone(),
+                            span: head,
+                            src_span: row.expect_span(),
+                        })?
+                }
+                None => row.clone(),
+            };
+
+            let group = match &group_column {
+                Some(col) => row
+                    .get_data_by_key(col)
+                    .map(|v| v.into_string(", ", &config))
+                    .unwrap_or_default(),
+                None => String::new(),
+            };
+
+            points.push((
+                group,
+                as_f64(&x_value, head)?,
+                as_f64(&y_value, head)?,
+                x_value,
+            ));
+        }
+
+        if points.is_empty() {
+            return Ok(Value::String {
+                val: String::new(),
+                span: head,
+            }
+            .into_pipeline_data());
+        }
+
+        let x_min = points.iter().map(|p| p.1).fold(f64::INFINITY, f64::min);
+        let x_max = points.iter().map(|p| p.1).fold(f64::NEG_INFINITY, f64::max);
+        let y_min = points.iter().map(|p| p.2).fold(f64::INFINITY, f64::min);
+        let y_max = points.iter().map(|p| p.2).fold(f64::NEG_INFINITY, f64::max);
+
+        let width = terminal_width();
+
+        let mut groups: Vec<String> = Vec::new();
+        for (group, ..) in &points {
+            if !groups.contains(group) {
+                groups.push(group.clone());
+            }
+        }
+
+        let x_label_start = points
+            .iter()
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|p| p.3.into_string(", ", &config))
+            .unwrap_or_default();
+        let x_label_end = points
+            .iter()
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|p| p.3.into_string(", ", &config))
+            .unwrap_or_default();
+
+        let mut blocks = Vec::with_capacity(groups.len());
+        for group in &groups {
+            let series: Vec<(f64, f64)> = points
+                .iter()
+                .filter(|p| &p.0 == group)
+                .map(|p| (p.1, p.2))
+                .collect();
+
+            let mut block = String::new();
+            if !group.is_empty() {
+                block.push_str(group);
+                block.push('\n');
+            }
+            block.push_str(&render_braille(
+                &series, x_min, x_max, y_min, y_max, width, height,
+            ));
+            block.push('\n');
+            block.push_str(&format!(
+                "x: {x_label_start} .. {x_label_end}   y: {y_min} .. {y_max}"
+            ));
+            blocks.push(block);
+        }
+
+        Ok(Value::String {
+            val: blocks.join("\n\n"),
+            span: head,
+        }
+        .into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Plot a series of numbers against their row index",
+                example: "[1 4 2 8 3 9 2] | chart line",
+                result: None,
+            },
+            Example {
+                description: "Plot multiple series, one per host",
+                example: "$metrics | chart line timestamp latency --group host",
+                result: None,
+            },
+        ]
+    }
+}
+
+fn as_f64(value: &Value, head: Span) -> Result<f64, ShellError> {
+    match value {
+        Value::Int { val, .. } => Ok(*val as f64),
+        Value::Float { val, .. } => Ok(*val),
+        Value::Filesize { val, .. } => Ok(*val as f64),
+        Value::Duration { val, .. } => Ok(*val as f64),
+        Value::Date { val, .. } => Ok(val.timestamp() as f64),
+        other => Err(ShellError::UnsupportedInput(
+            "chart line requires numeric (or datetime, for the x axis) values".to_string(),
+            format!("input type: {:?}", other.get_type()),
+            head,
+            other.expect_span(),
+        )),
+    }
+}
+
+fn terminal_width() -> usize {
+    use terminal_size::{Height, Width};
+
+    terminal_size::terminal_size()
+        .map(|(Width(w), Height(_))| w as usize)
+        .unwrap_or(80)
+}
+
+fn render_braille(
+    series: &[(f64, f64)],
+    x_min: f64,
+    x_max: f64,
+    y_min: f64,
+    y_max: f64,
+    width: usize,
+    height: usize,
+) -> String {
+    let sub_cols = width * 2;
+    let sub_rows = height * 4;
+    let mut grid = vec![vec![false; sub_cols]; sub_rows];
+
+    let x_range = (x_max - x_min).max(f64::EPSILON);
+    let y_range = (y_max - y_min).max(f64::EPSILON);
+
+    let to_sub_col = |x: f64| (((x - x_min) / x_range) * (sub_cols - 1) as f64).round() as isize;
+    let to_sub_row = |y: f64| (((y_max - y) / y_range) * (sub_rows - 1) as f64).round() as isize;
+
+    let mut plotted: Vec<(isize, isize)> = series
+        .iter()
+        .map(|&(x, y)| (to_sub_col(x), to_sub_row(y)))
+        .collect();
+    plotted.sort_by_key(|p| p.0);
+
+    let set_dot = |grid: &mut Vec<Vec<bool>>, col: isize, row: isize| {
+        if col >= 0 && (col as usize) < sub_cols && row >= 0 && (row as usize) < sub_rows {
+            grid[row as usize][col as usize] = true;
+        }
+    };
+
+    let mut prev: Option<(isize, isize)> = None;
+    for (col, row) in plotted {
+        if let Some((prev_col, prev_row)) = prev {
+            let span = (col - prev_col).max(1);
+            for step in 0..=span {
+                let c = prev_col + step;
+                let interpolated_row = prev_row + (row - prev_row) * step / span;
+                set_dot(&mut grid, c, interpolated_row);
+            }
+        } else {
+            set_dot(&mut grid, col, row);
+        }
+        prev = Some((col, row));
+    }
+
+    (0..height)
+        .map(|row_block| {
+            (0..width)
+                .map(|col_block| {
+                    let mut bits = 0u32;
+                    for (local_row, row_bits) in BRAILLE_BITS.iter().enumerate() {
+                        for (local_col, bit) in row_bits.iter().enumerate() {
+                            let sub_row = row_block * 4 + local_row;
+                            let sub_col = col_block * 2 + local_col;
+                            if grid[sub_row][sub_col] {
+                                bits |= bit;
+                            }
+                        }
+                    }
+                    char::from_u32(BRAILLE_BASE + bits).unwrap_or(' ')
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(ChartLine {})
+    }
+}
@@ -1,4 +1,12 @@
+mod bar;
+mod chart;
 mod hashable_value;
 mod histogram;
+mod line;
+mod sparkline;
 
+pub use bar::ChartBar;
+pub use chart::Chart;
 pub use histogram::Histogram;
+pub use line::ChartLine;
+pub use sparkline::ChartSparkline;
@@ -0,0 +1,151 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, SyntaxShape,
+    Type, Value,
+};
+
+const BLOCKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+#[derive(Clone)]
+pub struct ChartSparkline;
+
+impl Command for ChartSparkline {
+    fn name(&self) -> &str {
+        "chart sparkline"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("chart sparkline")
+            .input_output_types(vec![(Type::List(Box::new(Type::Number)), Type::String)])
+            .optional(
+                "column-name",
+                SyntaxShape::String,
+                "the numeric column to render, if the input is a table",
+            )
+            .category(Category::Chart)
+    }
+
+    fn usage(&self) -> &str {
+        "Render a list of numbers as a single-line unicode sparkline."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Each value is scaled between the minimum and maximum of the input and mapped to one \
+of 8 block characters, so the result is a single string that composes with table output - \
+pipe it into `print`, a column via `insert`, or embed it in a wrapped record."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["graph", "plot", "visualization"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let column_name: Option<String> = call.opt(engine_state, stack, 0)?;
+
+        let values = input.into_iter().collect::<Vec<Value>>();
+        let numbers = numeric_values(&values, column_name.as_deref(), head)?;
+
+        Ok(Value::String {
+            val: render_sparkline(&numbers),
+            span: head,
+        }
+        .into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Render a sparkline for a list of numbers",
+                example: "[1 5 2 8 3 9 1] | chart sparkline",
+                result: None,
+            },
+            Example {
+                description: "Render a sparkline for a table column",
+                example: "ls | chart sparkline size",
+                result: None,
+            },
+        ]
+    }
+}
+
+fn numeric_values(
+    values: &[Value],
+    column_name: Option<&str>,
+    head: Span,
+) -> Result<Vec<f64>, ShellError> {
+    values
+        .iter()
+        .map(|value| {
+            let value = match column_name {
+                Some(col) => {
+                    value
+                        .get_data_by_key(col)
+                        .ok_or_else(|| ShellError::CantFindColumn {
+                            col_name: col.to_string(),
+                            span: head,
+                            src_span: value.expect_span(),
+                        })?
+                }
+                None => value.clone(),
+            };
+
+            match value {
+                Value::Int { val, .. } => Ok(val as f64),
+                Value::Float { val, .. } => Ok(val),
+                Value::Filesize { val, .. } => Ok(val as f64),
+                Value::Duration { val, .. } => Ok(val as f64),
+                other => Err(ShellError::UnsupportedInput(
+                    "chart sparkline requires numeric values".to_string(),
+                    format!("input type: {:?}", other.get_type()),
+                    head,
+                    other.expect_span(),
+                )),
+            }
+        })
+        .collect()
+}
+
+fn render_sparkline(values: &[f64]) -> String {
+    if values.is_empty() {
+        return String::new();
+    }
+
+    let min = values.iter().cloned().fold(f64::INFINITY, f64::min);
+    let max = values.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    let range = max - min;
+
+    values
+        .iter()
+        .map(|value| {
+            let ratio = if range > 0.0 {
+                (value - min) / range
+            } else {
+                0.0
+            };
+            let index =
+                ((ratio * (BLOCKS.len() - 1) as f64).round() as usize).min(BLOCKS.len() - 1);
+            BLOCKS[index]
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(ChartSparkline {})
+    }
+}
@@ -0,0 +1,66 @@
+use super::rgb::parse_color;
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, SyntaxShape, Type,
+    Value,
+};
+
+#[derive(Clone)]
+pub struct ColorContrast;
+
+impl Command for ColorContrast {
+    fn name(&self) -> &str {
+        "color contrast"
+    }
+
+    fn usage(&self) -> &str {
+        "Compute the WCAG contrast ratio between two colors."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Returns a ratio between 1.0 (no contrast) and 21.0 (black on white), following the
+WCAG 2.0 relative luminance formula. The WCAG AA threshold for normal text is 4.5."
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("color contrast")
+            .input_output_types(vec![(Type::Any, Type::Float)])
+            .required("color1", SyntaxShape::Any, "the first color")
+            .required("color2", SyntaxShape::Any, "the second color")
+            .category(Category::Conversions)
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let color1: Value = call.req(engine_state, stack, 0)?;
+        let color2: Value = call.req(engine_state, stack, 1)?;
+
+        let l1 = parse_color(&color1, head)?.relative_luminance();
+        let l2 = parse_color(&color2, head)?.relative_luminance();
+
+        let (lighter, darker) = if l1 >= l2 { (l1, l2) } else { (l2, l1) };
+        let ratio = (lighter + 0.05) / (darker + 0.05);
+
+        Ok(Value::Float {
+            val: ratio,
+            span: head,
+        }
+        .into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Check the contrast ratio of black text on a white background",
+            example: "color contrast '#000000' '#ffffff'",
+            result: Some(Value::test_float(21.0)),
+        }]
+    }
+}
@@ -0,0 +1,89 @@
+use super::rgb::parse_color;
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, SyntaxShape, Type,
+    Value,
+};
+
+#[derive(Clone)]
+pub struct ColorConvert;
+
+impl Command for ColorConvert {
+    fn name(&self) -> &str {
+        "color convert"
+    }
+
+    fn usage(&self) -> &str {
+        "Convert a color between hex, RGB, and HSL representations."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "The color can be given as a '#rrggbb' hex string, an {r, g, b} record, or an
+{h, s, l} record. `--to` selects the output representation: `hex`, `rgb`, or `hsl`."
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("color convert")
+            .input_output_types(vec![(Type::Any, Type::Any)])
+            .required("color", SyntaxShape::Any, "the color to convert")
+            .required_named(
+                "to",
+                SyntaxShape::String,
+                "target representation: hex, rgb, or hsl",
+                Some('t'),
+            )
+            .category(Category::Conversions)
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let color: Value = call.req(engine_state, stack, 0)?;
+        let to: String = call
+            .get_flag(engine_state, stack, "to")?
+            .expect("required value");
+
+        let rgb = parse_color(&color, head)?;
+
+        let result = match to.as_str() {
+            "hex" => Value::String {
+                val: rgb.to_hex(),
+                span: head,
+            },
+            "rgb" => rgb.to_record(head),
+            "hsl" => rgb.to_hsl_record(head),
+            other => {
+                return Err(ShellError::UnsupportedInput(
+                    format!("unknown target representation '{other}', expected hex, rgb, or hsl"),
+                    "value originates from here".into(),
+                    head,
+                    head,
+                ))
+            }
+        };
+
+        Ok(result.into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Convert a hex color to an RGB record",
+                example: "color convert '#ff8800' --to rgb",
+                result: None,
+            },
+            Example {
+                description: "Convert an RGB record to HSL",
+                example: "color convert {r: 255, g: 136, b: 0} --to hsl",
+                result: None,
+            },
+        ]
+    }
+}
@@ -0,0 +1,79 @@
+use super::rgb::parse_color;
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, SyntaxShape, Type,
+    Value,
+};
+
+#[derive(Clone)]
+pub struct ColorMix;
+
+impl Command for ColorMix {
+    fn name(&self) -> &str {
+        "color mix"
+    }
+
+    fn usage(&self) -> &str {
+        "Linearly interpolate between two colors in RGB space."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Each color can be given as a '#rrggbb' hex string, an {r, g, b} record, or an
+{h, s, l} record. --weight controls how far to mix towards the second color, from 0.0
+(the first color) to 1.0 (the second), defaulting to an even 0.5 blend."
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("color mix")
+            .input_output_types(vec![(Type::Any, Type::String)])
+            .required("color1", SyntaxShape::Any, "the first color")
+            .required("color2", SyntaxShape::Any, "the second color")
+            .named(
+                "weight",
+                SyntaxShape::Number,
+                "how far towards color2 to mix, from 0.0 to 1.0 (default 0.5)",
+                Some('w'),
+            )
+            .category(Category::Conversions)
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let color1: Value = call.req(engine_state, stack, 0)?;
+        let color2: Value = call.req(engine_state, stack, 1)?;
+        let weight: Option<f64> = call.get_flag(engine_state, stack, "weight")?;
+
+        let rgb1 = parse_color(&color1, head)?;
+        let rgb2 = parse_color(&color2, head)?;
+        let mixed = rgb1.mix(rgb2, weight.unwrap_or(0.5));
+
+        Ok(Value::String {
+            val: mixed.to_hex(),
+            span: head,
+        }
+        .into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Blend two colors evenly",
+                example: "color mix '#ff0000' '#0000ff'",
+                result: None,
+            },
+            Example {
+                description: "Mix mostly towards the second color",
+                example: "color mix '#ff0000' '#0000ff' --weight 0.8",
+                result: None,
+            },
+        ]
+    }
+}
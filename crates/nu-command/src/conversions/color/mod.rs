@@ -0,0 +1,10 @@
+mod color_;
+mod contrast;
+mod convert;
+mod mix;
+mod rgb;
+
+pub use color_::Color;
+pub use contrast::ColorContrast;
+pub use convert::ColorConvert;
+pub use mix::ColorMix;
@@ -0,0 +1,188 @@
+use nu_protocol::{ShellError, Span, Value};
+
+/// A color in 8-bit RGB space, shared by `color convert`, `color mix`, and `color contrast`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct Rgb {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Rgb {
+    pub fn to_hex(self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+    }
+
+    pub fn to_record(self, span: Span) -> Value {
+        Value::Record {
+            cols: vec!["r".into(), "g".into(), "b".into()],
+            vals: vec![
+                Value::int(self.r as i64, span),
+                Value::int(self.g as i64, span),
+                Value::int(self.b as i64, span),
+            ],
+            span,
+        }
+    }
+
+    pub fn to_hsl_record(self, span: Span) -> Value {
+        let (h, s, l) = self.to_hsl();
+        Value::Record {
+            cols: vec!["h".into(), "s".into(), "l".into()],
+            vals: vec![
+                Value::float(h, span),
+                Value::float(s, span),
+                Value::float(l, span),
+            ],
+            span,
+        }
+    }
+
+    /// Converts to HSL: hue in degrees [0, 360), saturation and lightness in [0, 1].
+    pub fn to_hsl(self) -> (f64, f64, f64) {
+        let r = self.r as f64 / 255.0;
+        let g = self.g as f64 / 255.0;
+        let b = self.b as f64 / 255.0;
+
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let l = (max + min) / 2.0;
+        let delta = max - min;
+
+        if delta == 0.0 {
+            return (0.0, 0.0, l);
+        }
+
+        let s = if l <= 0.5 {
+            delta / (max + min)
+        } else {
+            delta / (2.0 - max - min)
+        };
+
+        let h = if max == r {
+            ((g - b) / delta) % 6.0
+        } else if max == g {
+            (b - r) / delta + 2.0
+        } else {
+            (r - g) / delta + 4.0
+        };
+        let h = h * 60.0;
+        let h = if h < 0.0 { h + 360.0 } else { h };
+
+        (h, s, l)
+    }
+
+    pub fn from_hsl(h: f64, s: f64, l: f64) -> Self {
+        if s == 0.0 {
+            let v = (l * 255.0).round() as u8;
+            return Rgb { r: v, g: v, b: v };
+        }
+
+        let c = (1.0 - (2.0 * l - 1.0).abs()) * s;
+        let h_prime = (h % 360.0) / 60.0;
+        let x = c * (1.0 - (h_prime % 2.0 - 1.0).abs());
+        let m = l - c / 2.0;
+
+        let (r1, g1, b1) = if (0.0..1.0).contains(&h_prime) {
+            (c, x, 0.0)
+        } else if (1.0..2.0).contains(&h_prime) {
+            (x, c, 0.0)
+        } else if (2.0..3.0).contains(&h_prime) {
+            (0.0, c, x)
+        } else if (3.0..4.0).contains(&h_prime) {
+            (0.0, x, c)
+        } else if (4.0..5.0).contains(&h_prime) {
+            (x, 0.0, c)
+        } else {
+            (c, 0.0, x)
+        };
+
+        Rgb {
+            r: (((r1 + m) * 255.0).round()) as u8,
+            g: (((g1 + m) * 255.0).round()) as u8,
+            b: (((b1 + m) * 255.0).round()) as u8,
+        }
+    }
+
+    /// Relative luminance per the WCAG 2.0 definition, used by `color contrast`.
+    pub fn relative_luminance(self) -> f64 {
+        let channel = |c: u8| {
+            let c = c as f64 / 255.0;
+            if c <= 0.03928 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        };
+
+        0.2126 * channel(self.r) + 0.7152 * channel(self.g) + 0.0722 * channel(self.b)
+    }
+
+    pub fn mix(self, other: Rgb, weight: f64) -> Rgb {
+        let weight = weight.clamp(0.0, 1.0);
+        let lerp = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * weight).round() as u8;
+        Rgb {
+            r: lerp(self.r, other.r),
+            g: lerp(self.g, other.g),
+            b: lerp(self.b, other.b),
+        }
+    }
+}
+
+/// Parses a color given as a `#rrggbb`/`rrggbb` hex string, an `{r, g, b}` record, or an
+/// `{h, s, l}` record (hue in degrees, saturation/lightness in [0, 1]).
+pub(crate) fn parse_color(value: &Value, head: Span) -> Result<Rgb, ShellError> {
+    match value {
+        Value::String { val, .. } => parse_hex(val, head, value.expect_span()),
+        Value::Record { cols, vals, .. } => {
+            let get = |name: &str| -> Option<&Value> {
+                cols.iter().position(|c| c == name).map(|i| &vals[i])
+            };
+            let as_f64 = |v: &Value| -> Result<f64, ShellError> {
+                match v {
+                    Value::Int { val, .. } => Ok(*val as f64),
+                    Value::Float { val, .. } => Ok(*val),
+                    other => Err(bad_color(head, other.expect_span())),
+                }
+            };
+
+            if let (Some(r), Some(g), Some(b)) = (get("r"), get("g"), get("b")) {
+                Ok(Rgb {
+                    r: as_f64(r)?.clamp(0.0, 255.0) as u8,
+                    g: as_f64(g)?.clamp(0.0, 255.0) as u8,
+                    b: as_f64(b)?.clamp(0.0, 255.0) as u8,
+                })
+            } else if let (Some(h), Some(s), Some(l)) = (get("h"), get("s"), get("l")) {
+                Ok(Rgb::from_hsl(as_f64(h)?, as_f64(s)?, as_f64(l)?))
+            } else {
+                Err(bad_color(head, value.expect_span()))
+            }
+        }
+        other => Err(bad_color(head, other.expect_span())),
+    }
+}
+
+fn parse_hex(val: &str, head: Span, value_span: Span) -> Result<Rgb, ShellError> {
+    let hex = val.trim_start_matches('#');
+    if hex.len() != 6 {
+        return Err(bad_color(head, value_span));
+    }
+
+    let byte =
+        |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).map_err(|_| bad_color(head, value_span));
+
+    Ok(Rgb {
+        r: byte(0)?,
+        g: byte(2)?,
+        b: byte(4)?,
+    })
+}
+
+fn bad_color(head: Span, value_span: Span) -> ShellError {
+    ShellError::UnsupportedInput(
+        "expected a '#rrggbb' hex string, an {r, g, b} record, or an {h, s, l} record".into(),
+        "value originates from here".into(),
+        head,
+        value_span,
+    )
+}
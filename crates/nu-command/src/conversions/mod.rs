@@ -1,7 +1,11 @@
+pub(crate) mod color;
 mod fill;
 mod fmt;
 pub(crate) mod into;
+pub(crate) mod unit;
 
+pub use color::{Color, ColorContrast, ColorConvert, ColorMix};
 pub use fill::Fill;
 pub use fmt::Fmt;
 pub use into::*;
+pub use unit::{Unit, UnitConvert, UnitList};
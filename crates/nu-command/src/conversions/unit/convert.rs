@@ -0,0 +1,108 @@
+use super::table::{find_unit, parse_quantity};
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, SyntaxShape, Type,
+    Value,
+};
+
+#[derive(Clone)]
+pub struct UnitConvert;
+
+impl Command for UnitConvert {
+    fn name(&self) -> &str {
+        "unit convert"
+    }
+
+    fn usage(&self) -> &str {
+        "Convert a quantity from one measurement unit to another."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "The quantity is given as a number immediately followed by its unit, like '5mi' or
+'20 kg'. `--to` selects the target unit; both units must belong to the same measure
+(length, mass, temperature, or data rate). Run `unit list` to see all known units."
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("unit convert")
+            .input_output_types(vec![(Type::String, Type::Float)])
+            .required(
+                "value",
+                SyntaxShape::String,
+                "the quantity to convert, e.g. '5mi'",
+            )
+            .required_named(
+                "to",
+                SyntaxShape::String,
+                "the unit to convert into",
+                Some('t'),
+            )
+            .category(Category::Conversions)
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let value: String = call.req(engine_state, stack, 0)?;
+        let to: String = call
+            .get_flag(engine_state, stack, "to")?
+            .expect("required value");
+
+        let (quantity, from_name) = parse_quantity(&value, head)?;
+
+        let from = find_unit(&from_name).ok_or_else(|| unknown_unit(&from_name, head))?;
+        let to_unit = find_unit(&to).ok_or_else(|| unknown_unit(&to, head))?;
+
+        if from.measure != to_unit.measure {
+            return Err(ShellError::UnsupportedInput(
+                format!(
+                    "cannot convert a {} unit to a {} unit",
+                    from.measure.name(),
+                    to_unit.measure.name()
+                ),
+                "value originates from here".into(),
+                head,
+                head,
+            ));
+        }
+
+        let result = (to_unit.from_base)((from.to_base)(quantity));
+
+        Ok(Value::Float {
+            val: result,
+            span: head,
+        }
+        .into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Convert miles to kilometers",
+                example: "unit convert '5mi' --to km",
+                result: None,
+            },
+            Example {
+                description: "Convert Fahrenheit to Celsius",
+                example: "unit convert '98.6f' --to c",
+                result: None,
+            },
+        ]
+    }
+}
+
+fn unknown_unit(name: &str, head: nu_protocol::Span) -> ShellError {
+    ShellError::UnsupportedInput(
+        format!("'{name}' is not a known unit, see `unit list`"),
+        "value originates from here".into(),
+        head,
+        head,
+    )
+}
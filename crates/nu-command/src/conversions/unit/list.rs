@@ -0,0 +1,62 @@
+use super::table::UNITS;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct UnitList;
+
+impl Command for UnitList {
+    fn name(&self) -> &str {
+        "unit list"
+    }
+
+    fn usage(&self) -> &str {
+        "List all units known to `unit convert`, grouped by measure."
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("unit list")
+            .input_output_types(vec![(Type::Nothing, Type::Table(vec![]))])
+            .category(Category::Conversions)
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+
+        let rows = UNITS
+            .iter()
+            .map(|unit| Value::Record {
+                cols: vec!["unit".into(), "measure".into(), "aliases".into()],
+                vals: vec![
+                    Value::string(unit.names[0], head),
+                    Value::string(unit.measure.name(), head),
+                    Value::string(unit.names.join(", "), head),
+                ],
+                span: head,
+            })
+            .collect();
+
+        Ok(Value::List {
+            vals: rows,
+            span: head,
+        }
+        .into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "List all known units",
+            example: "unit list",
+            result: None,
+        }]
+    }
+}
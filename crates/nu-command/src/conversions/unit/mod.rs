@@ -0,0 +1,8 @@
+mod convert;
+mod list;
+mod table;
+mod unit_;
+
+pub use convert::UnitConvert;
+pub use list::UnitList;
+pub use unit_::Unit;
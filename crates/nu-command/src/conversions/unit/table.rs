@@ -0,0 +1,111 @@
+use nu_protocol::{ShellError, Span};
+
+/// A family of mutually-convertible measurement units, shared by `unit convert` and `unit list`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Measure {
+    Length,
+    Mass,
+    Temperature,
+    DataRate,
+}
+
+impl Measure {
+    pub fn name(self) -> &'static str {
+        match self {
+            Measure::Length => "length",
+            Measure::Mass => "mass",
+            Measure::Temperature => "temperature",
+            Measure::DataRate => "data rate",
+        }
+    }
+}
+
+pub(crate) struct UnitDef {
+    pub names: &'static [&'static str],
+    pub measure: Measure,
+    pub to_base: fn(f64) -> f64,
+    pub from_base: fn(f64) -> f64,
+}
+
+macro_rules! linear {
+    ($names:expr, $measure:expr, $factor:expr) => {
+        UnitDef {
+            names: $names,
+            measure: $measure,
+            to_base: |v| v * $factor,
+            from_base: |v| v / $factor,
+        }
+    };
+}
+
+pub(crate) static UNITS: &[UnitDef] = &[
+    // Length, base unit: meter
+    linear!(&["m", "meter", "meters"], Measure::Length, 1.0),
+    linear!(&["km", "kilometer", "kilometers"], Measure::Length, 1000.0),
+    linear!(&["cm", "centimeter", "centimeters"], Measure::Length, 0.01),
+    linear!(&["mm", "millimeter", "millimeters"], Measure::Length, 0.001),
+    linear!(&["mi", "mile", "miles"], Measure::Length, 1609.344),
+    linear!(&["yd", "yard", "yards"], Measure::Length, 0.9144),
+    linear!(&["ft", "foot", "feet"], Measure::Length, 0.3048),
+    linear!(&["in", "inch", "inches"], Measure::Length, 0.0254),
+    // Mass, base unit: kilogram
+    linear!(&["kg", "kilogram", "kilograms"], Measure::Mass, 1.0),
+    linear!(&["g", "gram", "grams"], Measure::Mass, 0.001),
+    linear!(&["lb", "pound", "pounds"], Measure::Mass, 0.453_592_37),
+    linear!(&["oz", "ounce", "ounces"], Measure::Mass, 0.028_349_523_125),
+    // Temperature, base unit: kelvin (non-linear, handled explicitly)
+    UnitDef {
+        names: &["k", "kelvin"],
+        measure: Measure::Temperature,
+        to_base: |v| v,
+        from_base: |v| v,
+    },
+    UnitDef {
+        names: &["c", "celsius"],
+        measure: Measure::Temperature,
+        to_base: |c| c + 273.15,
+        from_base: |k| k - 273.15,
+    },
+    UnitDef {
+        names: &["f", "fahrenheit"],
+        measure: Measure::Temperature,
+        to_base: |f| (f - 32.0) * 5.0 / 9.0 + 273.15,
+        from_base: |k| (k - 273.15) * 9.0 / 5.0 + 32.0,
+    },
+    // Data rate, base unit: bits per second
+    linear!(&["bps"], Measure::DataRate, 1.0),
+    linear!(&["kbps"], Measure::DataRate, 1_000.0),
+    linear!(&["mbps"], Measure::DataRate, 1_000_000.0),
+    linear!(&["gbps"], Measure::DataRate, 1_000_000_000.0),
+];
+
+pub(crate) fn find_unit(name: &str) -> Option<&'static UnitDef> {
+    let name = name.to_lowercase();
+    UNITS.iter().find(|u| u.names.contains(&name.as_str()))
+}
+
+/// Splits a quantity like `"5mi"` or `"-40 f"` into its numeric value and unit suffix.
+pub(crate) fn parse_quantity(text: &str, head: Span) -> Result<(f64, String), ShellError> {
+    let trimmed = text.trim();
+    let split_at = trimmed
+        .find(|c: char| c.is_alphabetic())
+        .ok_or_else(|| bad_quantity(trimmed, head))?;
+    let (number, unit) = trimmed.split_at(split_at);
+
+    let value = number
+        .trim()
+        .parse::<f64>()
+        .map_err(|_| bad_quantity(trimmed, head))?;
+
+    Ok((value, unit.trim().to_string()))
+}
+
+fn bad_quantity(text: &str, head: Span) -> ShellError {
+    ShellError::GenericError(
+        format!("'{text}' is not a valid quantity"),
+        "expected a number followed by a unit, like '5mi' or '20 kg'".into(),
+        Some(head),
+        None,
+        Vec::new(),
+    )
+}
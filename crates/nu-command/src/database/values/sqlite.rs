@@ -313,6 +313,10 @@ impl CustomValue for SQLiteDatabase {
     }
 
     fn follow_path_string(&self, _column_name: String, span: Span) -> Result<Value, ShellError> {
+        // This always runs `SELECT * FROM <table>` and loads the full result into memory;
+        // later commands in the pipeline (`where`, `select`, `first`, ...) filter afterward
+        // rather than being compiled into the query. For large tables, `query db` with a
+        // hand-written SQL statement still executes entirely inside SQLite.
         let db = open_sqlite_db(&self.path, span)?;
 
         read_single_table(db, _column_name, span, self.ctrlc.clone()).map_err(|e| {
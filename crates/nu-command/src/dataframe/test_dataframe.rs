@@ -50,7 +50,7 @@ pub fn test_dataframe(cmds: Vec<Box<dyn Command + 'static>>) {
 
         let (block, delta) = {
             let mut working_set = StateWorkingSet::new(&engine_state);
-            let (output, err) = parse(
+            let (output, errors) = parse(
                 &mut working_set,
                 None,
                 example.example.as_bytes(),
@@ -58,7 +58,7 @@ pub fn test_dataframe(cmds: Vec<Box<dyn Command + 'static>>) {
                 &[],
             );
 
-            if let Some(err) = err {
+            if let Some(err) = errors.first() {
                 panic!("test parse error in `{}`: {:?}", example.example, err)
             }
 
@@ -5,6 +5,7 @@ mod inspect;
 mod inspect_table;
 mod metadata;
 mod profile;
+mod stats;
 mod timeit;
 mod view;
 mod view_files;
@@ -18,6 +19,7 @@ pub use inspect::Inspect;
 pub use inspect_table::build_table;
 pub use metadata::Metadata;
 pub use profile::Profile;
+pub use stats::DebugStats;
 pub use timeit::TimeIt;
 pub use view::View;
 pub use view_files::ViewFiles;
@@ -0,0 +1,131 @@
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Value,
+};
+
+#[derive(Clone)]
+pub struct DebugStats;
+
+impl Command for DebugStats {
+    fn name(&self) -> &str {
+        "debug stats"
+    }
+
+    fn usage(&self) -> &str {
+        "View or control per-command call count and timing statistics."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Statistics are off by default, since tracking them costs an atomic load on every
+call. Turn them on with `--enable`, run whatever you want to measure, then call
+`debug stats` with no flags to see which declarations dominate your wall time."#
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("debug stats")
+            .switch("enable", "start collecting call statistics", None)
+            .switch("disable", "stop collecting call statistics", None)
+            .switch("reset", "clear all collected call statistics", None)
+            .category(Category::Debug)
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+
+        if call.has_flag("enable") {
+            engine_state.call_stats.set_enabled(true);
+            return Ok(PipelineData::empty());
+        }
+        if call.has_flag("disable") {
+            engine_state.call_stats.set_enabled(false);
+            return Ok(PipelineData::empty());
+        }
+        if call.has_flag("reset") {
+            engine_state.call_stats.clear();
+            return Ok(PipelineData::empty());
+        }
+
+        let mut rows: Vec<_> = engine_state
+            .call_stats
+            .snapshot()
+            .into_iter()
+            .map(|(decl_id, call_count, total_nanos)| {
+                let name = engine_state.get_decl(decl_id).name().to_string();
+                let mean_micros = if call_count > 0 {
+                    (total_nanos as f64 / call_count as f64) / 1000.0
+                } else {
+                    0.0
+                };
+
+                Value::Record {
+                    cols: vec![
+                        "name".to_string(),
+                        "calls".to_string(),
+                        "total_ms".to_string(),
+                        "mean_us".to_string(),
+                    ],
+                    vals: vec![
+                        Value::string(name, head),
+                        Value::int(call_count as i64, head),
+                        Value::float(total_nanos as f64 / 1_000_000.0, head),
+                        Value::float(mean_micros, head),
+                    ],
+                    span: head,
+                }
+            })
+            .collect();
+
+        rows.sort_by(|a, b| {
+            let total_ms = |row: &Value| match row {
+                Value::Record { vals, .. } => vals[2].as_float().unwrap_or(0.0),
+                _ => 0.0,
+            };
+            total_ms(b)
+                .partial_cmp(&total_ms(a))
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        Ok(Value::List {
+            vals: rows,
+            span: head,
+        }
+        .into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Turn on call statistics collection",
+                example: "debug stats --enable",
+                result: None,
+            },
+            Example {
+                description: "Show the collected statistics, sorted by total time",
+                example: "debug stats",
+                result: None,
+            },
+            Example {
+                description: "Clear the collected statistics without turning collection off",
+                example: "debug stats --reset",
+                result: None,
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn test_examples() {
+        use super::DebugStats;
+        use crate::test_examples;
+        test_examples(DebugStats {})
+    }
+}
@@ -28,6 +28,10 @@ pub fn create_default_context() -> EngineState {
 
         // Charts
         bind_command! {
+            Chart,
+            ChartBar,
+            ChartLine,
+            ChartSparkline,
             Histogram
         }
 
@@ -36,6 +40,7 @@ pub fn create_default_context() -> EngineState {
             All,
             Any,
             Append,
+            ChunkBy,
             Collect,
             Columns,
             Compact,
@@ -48,19 +53,25 @@ pub fn create_default_context() -> EngineState {
             Empty,
             Enumerate,
             Every,
+            Except,
             Filter,
             Find,
             First,
             Flatten,
+            Generate,
             Get,
             Group,
             GroupBy,
             Headers,
             Insert,
+            Intersect,
+            Intersperse,
+            Items,
             SplitBy,
             Take,
             Merge,
             Move,
+            NotEmpty,
             TakeWhile,
             TakeUntil,
             Last,
@@ -79,6 +90,7 @@ pub fn create_default_context() -> EngineState {
             RollLeft,
             RollRight,
             Rotate,
+            Scan,
             Select,
             Shuffle,
             Skip,
@@ -88,11 +100,13 @@ pub fn create_default_context() -> EngineState {
             SortBy,
             SplitList,
             Transpose,
+            Union,
             Uniq,
             UniqBy,
             Upsert,
             Update,
             UpdateCells,
+            Validate,
             Values,
             Where,
             Window,
@@ -111,13 +125,18 @@ pub fn create_default_context() -> EngineState {
         bind_command! {
             Path,
             PathBasename,
+            PathCommonPrefix,
             PathDirname,
+            PathEscapeGlob,
             PathExists,
             PathExpand,
             PathJoin,
+            PathNormalize,
             PathParse,
             PathRelativeTo,
             PathSplit,
+            PathToUnix,
+            PathToWindows,
             PathType,
         };
 
@@ -137,6 +156,7 @@ pub fn create_default_context() -> EngineState {
             Inspect,
             Metadata,
             Profile,
+            DebugStats,
             TimeIt,
             View,
             ViewFiles,
@@ -150,6 +170,9 @@ pub fn create_default_context() -> EngineState {
         #[cfg(windows)]
         bind_command! { RegistryQuery }
 
+        #[cfg(target_os = "macos")]
+        bind_command! { Prefs, PrefsGet, PrefsSet };
+
         #[cfg(any(
             target_os = "android",
             target_os = "linux",
@@ -229,10 +252,13 @@ pub fn create_default_context() -> EngineState {
             BytesCollect,
             BytesRemove,
             BytesBuild,
+            BytesPack,
+            BytesUnpack,
         }
 
         // FileSystem
         bind_command! {
+            ArchiveExtract,
             Cd,
             Cp,
             Ls,
@@ -254,15 +280,28 @@ pub fn create_default_context() -> EngineState {
             AnsiStrip,
             AnsiLink,
             Clear,
+            DirsApp,
             Du,
+            Form,
             KeybindingsDefault,
             Input,
             KeybindingsListen,
             Keybindings,
             Kill,
             KeybindingsList,
+            Poll,
+            Say,
             Sleep,
+            TermBell,
             TermSize,
+            Ulimit,
+        };
+
+        #[cfg(all(target_os = "linux", feature = "service"))]
+        bind_command! {
+            Service,
+            ServiceList,
+            ServiceStatus,
         };
 
         // Date
@@ -277,6 +316,13 @@ pub fn create_default_context() -> EngineState {
             DateToTimezone,
         };
 
+        // Time
+        bind_command! {
+            Time,
+            TimeNow,
+            TimeElapsedSince,
+        };
+
         // Shells
         bind_command! {
             Enter,
@@ -290,11 +336,32 @@ pub fn create_default_context() -> EngineState {
         // Formats
         bind_command! {
             From,
+            FromAvro,
+            FromCbor,
             FromCsv,
+            FromDot,
+            FromEdn,
+            FromFixedWidth,
+            FromGeojson,
+            FromHar,
+            FromHtml,
+            FromIni,
+            FromJournal,
             FromJson,
+            FromKv,
+            FromLtsv,
+            FromMsgpack,
             FromNuon,
             FromOds,
+            FromParquet,
+            FromPcap,
+            FromPlist,
+            FromPrometheus,
+            FromProtobuf,
             FromSsv,
+            FromSyslog,
+            FromTar,
+            FromTerraformState,
             FromToml,
             FromTsv,
             FromUrl,
@@ -302,22 +369,40 @@ pub fn create_default_context() -> EngineState {
             FromXml,
             FromYaml,
             FromYml,
+            FromZip,
             To,
+            ToCbor,
             ToCsv,
+            ToDot,
+            ToEdn,
             ToHtml,
+            ToIni,
             ToJson,
+            ToJsonl,
             ToMd,
+            ToMsgpack,
             ToNuon,
+            ToParquet,
+            ToPlist,
+            ToPrometheus,
+            ToQr,
             ToText,
             ToToml,
             ToTsv,
             Touch,
             Upsert,
             Where,
+            ToXlsx,
             ToXml,
             ToYaml,
         };
 
+        #[cfg(feature = "bson")]
+        bind_command! {
+            FromBson,
+            ToBson,
+        };
+
         // Viewers
         bind_command! {
             Griddle,
@@ -327,6 +412,10 @@ pub fn create_default_context() -> EngineState {
 
         // Conversions
         bind_command! {
+            Color,
+            ColorContrast,
+            ColorConvert,
+            ColorMix,
             Fill,
             Fmt,
             Into,
@@ -339,6 +428,9 @@ pub fn create_default_context() -> EngineState {
             IntoInt,
             IntoRecord,
             IntoString,
+            Unit,
+            UnitConvert,
+            UnitList,
         };
 
         // Env
@@ -360,7 +452,10 @@ pub fn create_default_context() -> EngineState {
             MathAbs,
             MathAvg,
             MathCeil,
+            MathDot,
+            MathEval,
             MathFloor,
+            MathMatmul,
             MathMax,
             MathMedian,
             MathMin,
@@ -368,8 +463,10 @@ pub fn create_default_context() -> EngineState {
             MathProduct,
             MathRound,
             MathSqrt,
+            MathStats,
             MathStddev,
             MathSum,
+            MathTranspose,
             MathVariance,
             MathSin,
             MathCos,
@@ -399,6 +496,9 @@ pub fn create_default_context() -> EngineState {
             HttpPatch,
             HttpPost,
             HttpPut,
+            Ping,
+            Send,
+            SendEmail,
             Url,
             UrlBuildQuery,
             UrlEncode,
@@ -407,9 +507,18 @@ pub fn create_default_context() -> EngineState {
             Port,
         }
 
+        #[cfg(feature = "s3")]
+        bind_command! {
+            S3,
+            S3Get,
+            S3Ls,
+            S3Put,
+        };
+
         // Random
         bind_command! {
             Random,
+            RandomBinary,
             RandomBool,
             RandomChars,
             RandomDecimal,
@@ -448,7 +557,6 @@ pub fn create_default_context() -> EngineState {
             StrDecimalDeprecated,
             StrIntDeprecated,
             StrFindReplaceDeprecated,
-            MathEvalDeprecated,
             OldAlias,
             ExportOldAlias,
         };
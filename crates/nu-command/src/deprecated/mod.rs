@@ -2,7 +2,6 @@ mod deprecated_commands;
 mod export_old_alias;
 mod hash_base64;
 mod lpad;
-mod math_eval;
 mod old_alias;
 mod rpad;
 mod source;
@@ -15,7 +14,6 @@ pub use deprecated_commands::*;
 pub use export_old_alias::ExportOldAlias;
 pub use hash_base64::HashBase64;
 pub use lpad::LPadDeprecated;
-pub use math_eval::SubCommand as MathEvalDeprecated;
 pub use old_alias::OldAlias;
 pub use rpad::RPadDeprecated;
 pub use source::Source;
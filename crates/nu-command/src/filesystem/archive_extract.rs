@@ -0,0 +1,178 @@
+use std::path::{Path, PathBuf};
+
+use nu_engine::env::current_dir;
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct ArchiveExtract;
+
+impl Command for ArchiveExtract {
+    fn name(&self) -> &str {
+        "archive extract"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("archive extract")
+            .input_output_types(vec![(Type::Table(vec![]), Type::Nothing)])
+            .optional(
+                "destination",
+                SyntaxShape::Directory,
+                "directory to extract into (defaults to the current directory)",
+            )
+            .category(Category::FileSystem)
+    }
+
+    fn usage(&self) -> &str {
+        "Write the entries produced by `from tar` or `from zip` out to disk."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Expects a table with `path`, `type` (`file` or `directory`; anything else is
+skipped) and `data` columns, the shape produced by `from tar`/`from zip`.
+Parent directories are created as needed, and on Unix the `mode` column,
+if present, is applied to each extracted file."#
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let destination: Option<String> = call.opt(engine_state, stack, 0)?;
+        let cwd = current_dir(engine_state, stack)?;
+        let destination = match destination {
+            Some(dest) => cwd.join(dest),
+            None => cwd,
+        };
+
+        extract(input, head, &destination)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Extract every entry of a tar archive into the current directory",
+                example: "open --raw project.tar | from tar | archive extract",
+                result: None,
+            },
+            Example {
+                description: "Extract only the Rust source files into ./src",
+                example: "open --raw project.tar | from tar | where path =~ '\\.rs$' | archive extract src",
+                result: None,
+            },
+        ]
+    }
+}
+
+fn extract(
+    input: PipelineData,
+    head: Span,
+    destination: &Path,
+) -> Result<PipelineData, ShellError> {
+    for value in input {
+        let span = value.expect_span();
+        let Value::Record { cols, vals, .. } = &value else {
+            return Err(ShellError::UnsupportedInput(
+                format!("{:?} is not a valid archive entry", value.get_type()),
+                "value originates from here".into(),
+                head,
+                span,
+            ));
+        };
+
+        let field = |name: &str| cols.iter().position(|c| c == name).map(|i| &vals[i]);
+
+        let path = match field("path") {
+            Some(Value::String { val, .. }) => val.clone(),
+            _ => {
+                return Err(ShellError::UnsupportedInput(
+                    "entry is missing its `path` column".into(),
+                    "value originates from here".into(),
+                    head,
+                    span,
+                ))
+            }
+        };
+
+        let entry_type = match field("type") {
+            Some(Value::String { val, .. }) => val.clone(),
+            _ => "file".to_string(),
+        };
+
+        let out_path = destination.join(&path);
+
+        match entry_type.as_str() {
+            "directory" => {
+                std::fs::create_dir_all(&out_path).map_err(|e| {
+                    ShellError::CreateNotPossible(
+                        format!("failed to create directory '{}': {e}", out_path.display()),
+                        span,
+                    )
+                })?;
+            }
+            "file" => {
+                let data = match field("data") {
+                    Some(Value::Binary { val, .. }) => val.clone(),
+                    _ => vec![],
+                };
+
+                if let Some(parent) = out_path.parent() {
+                    std::fs::create_dir_all(parent).map_err(|e| {
+                        ShellError::CreateNotPossible(
+                            format!("failed to create directory '{}': {e}", parent.display()),
+                            span,
+                        )
+                    })?;
+                }
+
+                std::fs::write(&out_path, &data).map_err(|e| {
+                    ShellError::CreateNotPossible(
+                        format!("failed to write file '{}': {e}", out_path.display()),
+                        span,
+                    )
+                })?;
+
+                apply_mode(&out_path, field("mode"));
+            }
+            _ => continue,
+        }
+    }
+
+    Ok(PipelineData::empty())
+}
+
+#[cfg(unix)]
+fn apply_mode(path: &PathBuf, mode: Option<&Value>) {
+    use std::os::unix::fs::PermissionsExt;
+
+    if let Some(Value::Int { val, .. }) = mode {
+        if let Ok(metadata) = std::fs::metadata(path) {
+            let mut permissions = metadata.permissions();
+            permissions.set_mode(*val as u32);
+            let _ = std::fs::set_permissions(path, permissions);
+        }
+    }
+}
+
+#[cfg(not(unix))]
+fn apply_mode(_path: &PathBuf, _mode: Option<&Value>) {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(ArchiveExtract {})
+    }
+}
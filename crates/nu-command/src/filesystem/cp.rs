@@ -16,7 +16,7 @@ use nu_protocol::{
 
 use super::util::try_interaction;
 
-use crate::filesystem::util::FileStructure;
+use crate::filesystem::util::{fs_op_result, FileStructure};
 use crate::progress_bar;
 
 const GLOB_PARAMS: nu_glob::MatchOptions = nu_glob::MatchOptions {
@@ -44,7 +44,7 @@ impl Command for Cp {
 
     fn signature(&self) -> Signature {
         Signature::build("cp")
-            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .input_output_types(vec![(Type::Nothing, Type::Table(vec![]))])
             .required("source", SyntaxShape::GlobPattern, "the place to copy from")
             .required("destination", SyntaxShape::Filepath, "the place to copy to")
             .switch(
@@ -66,6 +66,11 @@ impl Command for Cp {
                 Some('n'),
             )
             .switch("progress", "enable progress bar", Some('p'))
+            .switch(
+                "dry-run",
+                "only report what would be copied, without touching the filesystem",
+                None,
+            )
             .category(Category::FileSystem)
     }
 
@@ -88,6 +93,7 @@ impl Command for Cp {
         let verbose = call.has_flag("verbose");
         let interactive = call.has_flag("interactive");
         let progress = call.has_flag("progress");
+        let dry_run = call.has_flag("dry-run");
 
         let current_dir_path = current_dir(engine_state, stack)?;
         let source = current_dir_path.join(src.item.as_str());
@@ -177,6 +183,16 @@ impl Command for Cp {
                     if src.is_file() {
                         let dst =
                             canonicalize_with(dst.as_path(), &current_dir_path).unwrap_or(dst);
+                        if dry_run {
+                            result.push(fs_op_result(
+                                &src,
+                                Some(&dst),
+                                "skipped",
+                                Some("dry-run".into()),
+                                span,
+                            ));
+                            continue;
+                        }
                         let res = if src == dst {
                             let message = format!(
                                 "src {source:?} and dst {destination:?} are identical(not copied)"
@@ -231,15 +247,17 @@ impl Command for Cp {
                     }
                 };
 
-                std::fs::create_dir_all(&destination).map_err(|e| {
-                    ShellError::GenericError(
-                        e.to_string(),
-                        e.to_string(),
-                        Some(dst.span),
-                        None,
-                        Vec::new(),
-                    )
-                })?;
+                if !dry_run {
+                    std::fs::create_dir_all(&destination).map_err(|e| {
+                        ShellError::GenericError(
+                            e.to_string(),
+                            e.to_string(),
+                            Some(dst.span),
+                            None,
+                            Vec::new(),
+                        )
+                    })?;
+                }
 
                 let not_follow_symlink = call.has_flag("no-symlink");
                 let sources = sources.paths_applying_with(|(source_file, depth_level)| {
@@ -280,6 +298,17 @@ impl Command for Cp {
                         return Ok(PipelineData::empty());
                     }
 
+                    if dry_run {
+                        result.push(fs_op_result(
+                            &s,
+                            Some(&d),
+                            "skipped",
+                            Some("dry-run".into()),
+                            span,
+                        ));
+                        continue;
+                    }
+
                     if s.is_dir() && !d.exists() {
                         std::fs::create_dir_all(&d).map_err(|e| {
                             ShellError::GenericError(
@@ -323,20 +352,19 @@ impl Command for Cp {
             }
         }
 
-        if verbose {
+        let result = if verbose || dry_run {
             result
-                .into_iter()
-                .into_pipeline_data(ctrlc)
-                .print_not_formatted(engine_state, false, true)?;
         } else {
-            // filter to only errors
+            // only report the operations that didn't cleanly succeed
             result
                 .into_iter()
-                .filter(|v| matches!(v, Value::Error { .. }))
-                .into_pipeline_data(ctrlc)
-                .print_not_formatted(engine_state, false, true)?;
-        }
-        Ok(PipelineData::empty())
+                .filter(|v| {
+                    matches!(v, Value::Record { vals, .. } if vals.last().map(|v| !matches!(v, Value::Nothing { .. })).unwrap_or(false))
+                })
+                .collect()
+        };
+
+        Ok(result.into_pipeline_data(ctrlc))
     }
 
     fn examples(&self) -> Vec<Example> {
@@ -361,6 +389,11 @@ impl Command for Cp {
                 example: "cp *.txt dir_a",
                 result: None,
             },
+            Example {
+                description: "See what a recursive copy would do without touching the filesystem",
+                example: "cp -r --dry-run dir_a dir_b",
+                result: None,
+            },
         ]
     }
 }
@@ -378,18 +411,15 @@ fn interactive_copy(
         format!("cp: overwrite '{}'? ", dst.to_string_lossy()),
     );
     if let Err(e) = interaction {
-        Value::Error {
-            error: ShellError::GenericError(
-                e.to_string(),
-                e.to_string(),
-                Some(span),
-                None,
-                Vec::new(),
-            ),
-        }
+        fs_op_result(&src, Some(&dst), "error", Some(e.to_string()), span)
     } else if !confirmed {
-        let msg = format!("{:} not copied to {:}", src.display(), dst.display());
-        Value::String { val: msg, span }
+        fs_op_result(
+            &src,
+            Some(&dst),
+            "skipped",
+            Some("not confirmed".into()),
+            span,
+        )
     } else {
         copy_impl(src, dst, span, &None)
     }
@@ -406,10 +436,7 @@ fn copy_file(
     _ctrlc_status: &Option<Arc<AtomicBool>>,
 ) -> Value {
     match std::fs::copy(&src, &dst) {
-        Ok(_) => {
-            let msg = format!("copied {:} to {:}", src.display(), dst.display());
-            Value::String { val: msg, span }
-        }
+        Ok(_) => fs_op_result(&src, Some(&dst), "copied", None, span),
         Err(e) => convert_io_error(e, src, dst, span),
     }
 }
@@ -501,10 +528,9 @@ fn copy_file_with_progressbar(
         .file_name()
         .unwrap_or_else(|| std::ffi::OsStr::new(""))
         .to_string_lossy();
-    let msg = format!("copied {:} to {:}", src.display(), dst.display());
     bar.finished_msg(format!(" {} copied!", &file_name));
 
-    Value::String { val: msg, span }
+    fs_op_result(&src, Some(&dst), "copied", None, span)
 }
 
 fn copy_symlink(
@@ -516,17 +542,7 @@ fn copy_symlink(
     let target_path = read_link(src.as_path());
     let target_path = match target_path {
         Ok(p) => p,
-        Err(err) => {
-            return Value::Error {
-                error: ShellError::GenericError(
-                    err.to_string(),
-                    err.to_string(),
-                    Some(span),
-                    None,
-                    vec![],
-                ),
-            }
-        }
+        Err(err) => return fs_op_result(&src, Some(&dst), "error", Some(err.to_string()), span),
     };
 
     let create_symlink = {
@@ -546,13 +562,8 @@ fn copy_symlink(
     };
 
     match create_symlink(target_path.as_path(), dst.as_path()) {
-        Ok(_) => {
-            let msg = format!("copied {:} to {:}", src.display(), dst.display());
-            Value::String { val: msg, span }
-        }
-        Err(e) => Value::Error {
-            error: ShellError::GenericError(e.to_string(), e.to_string(), Some(span), None, vec![]),
-        },
+        Ok(_) => fs_op_result(&src, Some(&dst), "copied", None, span),
+        Err(e) => fs_op_result(&src, Some(&dst), "error", Some(e.to_string()), span),
     }
 }
 
@@ -593,5 +604,11 @@ fn convert_io_error(error: std::io::Error, src: PathBuf, dst: PathBuf, span: Spa
         _ => ShellError::IOErrorSpanned(message_src, span),
     };
 
-    Value::Error { error: shell_error }
+    fs_op_result(
+        &src,
+        Some(&dst),
+        "error",
+        Some(shell_error.to_string()),
+        span,
+    )
 }
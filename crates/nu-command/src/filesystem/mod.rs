@@ -1,3 +1,4 @@
+mod archive_extract;
 mod cd;
 mod cd_query;
 mod cp;
@@ -14,6 +15,7 @@ mod util;
 mod watch;
 
 pub use self::open::Open;
+pub use archive_extract::ArchiveExtract;
 pub use cd::Cd;
 pub use cd_query::query;
 pub use cp::Cp;
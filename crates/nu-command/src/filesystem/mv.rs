@@ -1,6 +1,6 @@
 use std::path::{Path, PathBuf};
 
-use super::util::try_interaction;
+use super::util::{fs_op_result, try_interaction};
 use nu_engine::env::current_dir;
 use nu_engine::CallExt;
 use nu_protocol::ast::Call;
@@ -35,7 +35,7 @@ impl Command for Mv {
 
     fn signature(&self) -> nu_protocol::Signature {
         Signature::build("mv")
-            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .input_output_types(vec![(Type::Nothing, Type::Table(vec![]))])
             .required(
                 "source",
                 SyntaxShape::GlobPattern,
@@ -53,6 +53,11 @@ impl Command for Mv {
             )
             .switch("force", "overwrite the destination.", Some('f'))
             .switch("interactive", "ask user to confirm action", Some('i'))
+            .switch(
+                "dry-run",
+                "only report what would be moved, without touching the filesystem",
+                None,
+            )
             .category(Category::FileSystem)
     }
 
@@ -75,6 +80,7 @@ impl Command for Mv {
         let verbose = call.has_flag("verbose");
         let interactive = call.has_flag("interactive");
         let force = call.has_flag("force");
+        let dry_run = call.has_flag("dry-run");
 
         let ctrlc = engine_state.ctrlc.clone();
 
@@ -177,11 +183,15 @@ impl Command for Mv {
         }
 
         let span = call.head;
-        sources
+        let results: Vec<Value> = sources
             .into_iter()
             .flatten()
-            .filter_map(move |entry| {
-                let result = move_file(
+            .map(move |entry| {
+                if dry_run {
+                    return fs_op_result(&entry, Some(&destination), "skipped", Some("dry-run".into()), span);
+                }
+
+                match move_file(
                     Spanned {
                         item: entry.clone(),
                         span: spanned_source.span,
@@ -191,30 +201,24 @@ impl Command for Mv {
                         span: spanned_destination.span,
                     },
                     interactive,
-                );
-                if let Err(error) = result {
-                    Some(Value::Error { error })
-                } else if verbose {
-                    let val = match result {
-                        Ok(true) => format!(
-                            "moved {:} to {:}",
-                            entry.to_string_lossy(),
-                            destination.to_string_lossy()
-                        ),
-                        _ => format!(
-                            "{:} not moved to {:}",
-                            entry.to_string_lossy(),
-                            destination.to_string_lossy()
-                        ),
-                    };
-                    Some(Value::String { val, span })
-                } else {
-                    None
+                ) {
+                    Ok(true) => fs_op_result(&entry, Some(&destination), "moved", None, span),
+                    Ok(false) => {
+                        fs_op_result(&entry, Some(&destination), "skipped", Some("not confirmed".into()), span)
+                    }
+                    Err(error) => {
+                        fs_op_result(&entry, Some(&destination), "error", Some(error.to_string()), span)
+                    }
                 }
             })
-            .into_pipeline_data(ctrlc)
-            .print_not_formatted(engine_state, false, true)?;
-        Ok(PipelineData::empty())
+            .filter(|v| {
+                dry_run
+                    || verbose
+                    || matches!(v, Value::Record { vals, .. } if vals.last().map(|v| !matches!(v, Value::Nothing { .. })).unwrap_or(false))
+            })
+            .collect();
+
+        Ok(results.into_pipeline_data(ctrlc))
     }
 
     fn examples(&self) -> Vec<Example> {
@@ -234,6 +238,11 @@ impl Command for Mv {
                 example: "mv *.txt my/subdirectory",
                 result: None,
             },
+            Example {
+                description: "See what a move would do without touching the filesystem",
+                example: "mv --dry-run *.txt my/subdirectory",
+                result: None,
+            },
         ]
     }
 }
@@ -151,8 +151,12 @@ impl Command for Open {
             let ext = if raw {
                 None
             } else {
-                path.extension()
-                    .map(|name| name.to_string_lossy().to_string())
+                path.extension().map(|name| {
+                    crate::decl_name_for_content_type(
+                        &name.to_string_lossy(),
+                        engine_state.get_config(),
+                    )
+                })
             };
 
             if let Some(ext) = ext {
@@ -9,7 +9,7 @@ use std::io::ErrorKind;
 use std::os::unix::prelude::FileTypeExt;
 use std::path::PathBuf;
 
-use super::util::try_interaction;
+use super::util::{fs_op_result, try_interaction};
 
 use nu_engine::env::current_dir;
 use nu_engine::CallExt;
@@ -45,7 +45,7 @@ impl Command for Rm {
 
     fn signature(&self) -> Signature {
         let sig = Signature::build("rm")
-            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .input_output_types(vec![(Type::Nothing, Type::Table(vec![]))])
             .required(
                 "filename",
                 SyntaxShape::Filepath,
@@ -76,6 +76,11 @@ impl Command for Rm {
                 "ask user to confirm action only once",
                 Some('I'),
             )
+            .switch(
+                "dry-run",
+                "only report what would be deleted, without touching the filesystem",
+                None,
+            )
             .rest(
                 "rest",
                 SyntaxShape::GlobPattern,
@@ -129,6 +134,11 @@ impl Command for Rm {
             example: "ls | where size == 0KB and type == file | each { rm $in.name } | null",
             result: None,
         });
+        examples.push(Example {
+            description: "See what a recursive delete would do without touching the filesystem",
+            example: "rm --recursive --dry-run some_dir",
+            result: None,
+        });
         examples
     }
 }
@@ -150,6 +160,7 @@ fn rm(
     let verbose = call.has_flag("verbose");
     let interactive = call.has_flag("interactive");
     let interactive_once = call.has_flag("interactive-once") && !interactive;
+    let dry_run = call.has_flag("dry-run");
 
     let ctrlc = engine_state.ctrlc.clone();
 
@@ -329,7 +340,7 @@ fn rm(
         }
     }
 
-    all_targets
+    let result = all_targets
         .into_keys()
         .map(move |f| {
             let is_empty = || match f.read_dir() {
@@ -355,6 +366,10 @@ fn rm(
                     || is_fifo
                     || is_empty()
                 {
+                    if dry_run {
+                        return fs_op_result(&f, None, "skipped", Some("dry-run".into()), span);
+                    }
+
                     let (interaction, confirmed) = try_interaction(
                         interactive,
                         format!("rm: remove '{}'? ", f.to_string_lossy()),
@@ -404,54 +419,27 @@ fn rm(
 
                     if let Err(e) = result {
                         let msg = format!("Could not delete because: {e:}");
-                        Value::Error {
-                            error: ShellError::GenericError(
-                                msg,
-                                e.to_string(),
-                                Some(span),
-                                None,
-                                Vec::new(),
-                            ),
-                        }
-                    } else if verbose {
-                        let msg = if interactive && !confirmed {
-                            "not deleted"
-                        } else {
-                            "deleted"
-                        };
-                        let val = format!("{} {:}", msg, f.to_string_lossy());
-                        Value::String { val, span }
+                        fs_op_result(&f, None, "error", Some(msg), span)
+                    } else if interactive && !confirmed {
+                        fs_op_result(&f, None, "skipped", Some("not confirmed".into()), span)
                     } else {
-                        Value::Nothing { span }
+                        fs_op_result(&f, None, "deleted", None, span)
                     }
                 } else {
                     let msg = format!("Cannot remove {:}. try --recursive", f.to_string_lossy());
-                    Value::Error {
-                        error: ShellError::GenericError(
-                            msg,
-                            "cannot remove non-empty directory".into(),
-                            Some(span),
-                            None,
-                            Vec::new(),
-                        ),
-                    }
+                    fs_op_result(&f, None, "error", Some(msg), span)
                 }
             } else {
                 let msg = format!("no such file or directory: {:}", f.to_string_lossy());
-                Value::Error {
-                    error: ShellError::GenericError(
-                        msg,
-                        "no such file or directory".into(),
-                        Some(span),
-                        None,
-                        Vec::new(),
-                    ),
-                }
+                fs_op_result(&f, None, "error", Some(msg), span)
             }
         })
-        .filter(|x| !matches!(x.get_type(), Type::Nothing))
-        .into_pipeline_data(ctrlc)
-        .print_not_formatted(engine_state, false, true)?;
+        .filter(|x| {
+            dry_run
+                || verbose
+                || matches!(x, Value::Record { vals, .. } if vals.last().map(|v| !matches!(v, Value::Nothing { .. })).unwrap_or(false))
+        })
+        .into_pipeline_data(ctrlc);
 
-    Ok(PipelineData::empty())
+    Ok(result)
 }
@@ -3,11 +3,41 @@ use std::path::{Path, PathBuf};
 use nu_engine::env::current_dir_str;
 use nu_path::canonicalize_with;
 use nu_protocol::engine::{EngineState, Stack};
-use nu_protocol::ShellError;
+use nu_protocol::{ShellError, Span, Value};
 
 use dialoguer::Input;
 use std::error::Error;
 
+/// Build a structured result record for a single file-manipulation operation
+/// (as performed by `cp`, `mv`, and `rm`), so that scripts can inspect which
+/// paths were touched and why a given path was skipped or failed.
+pub fn fs_op_result(
+    src: &Path,
+    dst: Option<&Path>,
+    status: &str,
+    reason: Option<String>,
+    span: Span,
+) -> Value {
+    let mut cols = vec!["path".to_string(), "status".to_string()];
+    let mut vals = vec![
+        Value::string(src.to_string_lossy(), span),
+        Value::string(status, span),
+    ];
+
+    if let Some(dst) = dst {
+        cols.insert(1, "dest".to_string());
+        vals.insert(1, Value::string(dst.to_string_lossy(), span));
+    }
+
+    cols.push("reason".to_string());
+    vals.push(match reason {
+        Some(reason) => Value::string(reason, span),
+        None => Value::nothing(span),
+    });
+
+    Value::Record { cols, vals, span }
+}
+
 #[derive(Default)]
 pub struct FileStructure {
     pub resources: Vec<Resource>,
@@ -1,9 +1,9 @@
-use nu_engine::{eval_block, CallExt};
+use super::any::{run_with_cell_path, run_with_closure};
+use nu_engine::CallExt;
 use nu_protocol::{
-    ast::Call,
+    ast::{Call, CellPath},
     engine::{Closure, Command, EngineState, Stack},
-    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, SyntaxShape, Type,
-    Value,
+    Category, Example, FromValue, PipelineData, ShellError, Signature, SyntaxShape, Type, Value,
 };
 
 #[derive(Clone)]
@@ -22,8 +22,16 @@ impl Command for All {
             ])
             .required(
                 "predicate",
-                SyntaxShape::Closure(Some(vec![SyntaxShape::Any, SyntaxShape::Int])),
-                "a closure that must evaluate to a boolean",
+                SyntaxShape::OneOf(vec![
+                    SyntaxShape::Closure(Some(vec![SyntaxShape::Any, SyntaxShape::Int])),
+                    SyntaxShape::CellPath,
+                ]),
+                "a closure that must evaluate to a boolean, or a column name/cell path to test for truthiness directly",
+            )
+            .switch(
+                "verbose",
+                "return a record with the result and the row that violated it, instead of just the boolean",
+                Some('v'),
             )
             .category(Category::Filters)
     }
@@ -32,6 +40,12 @@ impl Command for All {
         "Test if every element of the input fulfills a predicate expression."
     }
 
+    fn extra_usage(&self) -> &str {
+        "The predicate can be a closure, or a column name/cell path to check directly - `all \
+ok` is short for `all {|row| $row.ok }`. Checking stops as soon as a violating row is found, \
+even on an infinite stream."
+    }
+
     fn search_terms(&self) -> Vec<&str> {
         vec!["every", "and"]
     }
@@ -58,11 +72,13 @@ impl Command for All {
                 example: "let cond = {|el| ($el mod 2) == 0 }; [2 4 6 8] | all $cond",
                 result: Some(Value::test_bool(true)),
             },
+            Example {
+                description: "Check that every row's ok column is truthy, without a closure",
+                example: "[[ok]; [true] [true]] | all ok",
+                result: Some(Value::test_bool(true)),
+            },
         ]
     }
-    // This is almost entirely a copy-paste of `any`'s run(), so make sure any changes to this are
-    // reflected in the other!! (Or, you could figure out a way for both of them to use
-    // the same function...)
     fn run(
         &self,
         engine_state: &EngineState,
@@ -71,50 +87,25 @@ impl Command for All {
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
         let span = call.head;
-
-        let capture_block: Closure = call.req(engine_state, stack, 0)?;
-        let block_id = capture_block.block_id;
-
-        let block = engine_state.get_block(block_id);
-        let var_id = block.signature.get_positional(0).and_then(|arg| arg.var_id);
-        let mut stack = stack.captures_to_stack(&capture_block.captures);
-
-        let orig_env_vars = stack.env_vars.clone();
-        let orig_env_hidden = stack.env_hidden.clone();
-
-        let ctrlc = engine_state.ctrlc.clone();
-        let engine_state = engine_state.clone();
-
-        for value in input.into_interruptible_iter(ctrlc) {
-            // with_env() is used here to ensure that each iteration uses
-            // a different set of environment variables.
-            // Hence, a 'cd' in the first loop won't affect the next loop.
-            stack.with_env(&orig_env_vars, &orig_env_hidden);
-
-            if let Some(var_id) = var_id {
-                stack.add_var(var_id, value.clone());
-            }
-
-            let eval = eval_block(
-                &engine_state,
-                &mut stack,
-                block,
-                value.into_pipeline_data(),
-                call.redirect_stdout,
-                call.redirect_stderr,
+        let verbose = call.has_flag("verbose");
+        let predicate: Value = call.req(engine_state, stack, 0)?;
+
+        if matches!(predicate, Value::Closure { .. } | Value::Block { .. }) {
+            let capture_block = Closure::from_value(&predicate)?;
+            return run_with_closure(
+                engine_state,
+                stack,
+                call,
+                input,
+                capture_block,
+                span,
+                verbose,
+                false,
             );
-            match eval {
-                Err(e) => {
-                    return Err(e);
-                }
-                Ok(pipeline_data) => {
-                    if !pipeline_data.into_value(span).is_true() {
-                        return Ok(Value::Bool { val: false, span }.into_pipeline_data());
-                    }
-                }
-            }
         }
-        Ok(Value::Bool { val: true, span }.into_pipeline_data())
+
+        let cell_path = CellPath::from_value(&predicate)?;
+        run_with_cell_path(input, &cell_path, span, verbose, false)
     }
 }
 
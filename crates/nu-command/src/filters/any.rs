@@ -1,9 +1,9 @@
 use nu_engine::{eval_block, CallExt};
 use nu_protocol::{
-    ast::Call,
+    ast::{Call, CellPath},
     engine::{Closure, Command, EngineState, Stack},
-    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, SyntaxShape, Type,
-    Value,
+    Category, Example, FromValue, IntoPipelineData, PipelineData, ShellError, Signature, Span,
+    SyntaxShape, Type, Value,
 };
 
 #[derive(Clone)]
@@ -22,8 +22,16 @@ impl Command for Any {
             ])
             .required(
                 "predicate",
-                SyntaxShape::Closure(Some(vec![SyntaxShape::Any, SyntaxShape::Int])),
-                "a closure that must evaluate to a boolean",
+                SyntaxShape::OneOf(vec![
+                    SyntaxShape::Closure(Some(vec![SyntaxShape::Any, SyntaxShape::Int])),
+                    SyntaxShape::CellPath,
+                ]),
+                "a closure that must evaluate to a boolean, or a column name/cell path to test for truthiness directly",
+            )
+            .switch(
+                "verbose",
+                "return a record with the result and the row that satisfied it, instead of just the boolean",
+                Some('v'),
             )
             .category(Category::Filters)
     }
@@ -32,6 +40,12 @@ impl Command for Any {
         "Tests if any element of the input fulfills a predicate expression."
     }
 
+    fn extra_usage(&self) -> &str {
+        "The predicate can be a closure, or a column name/cell path to check directly - `any \
+status` is short for `any {|row| $row.status }`. Checking stops as soon as a matching row is \
+found, even on an infinite stream."
+    }
+
     fn search_terms(&self) -> Vec<&str> {
         vec!["some", "or"]
     }
@@ -58,11 +72,13 @@ impl Command for Any {
                 example: "let cond = {|e| $e mod 2 == 1 }; [2 4 1 6 8] | any $cond",
                 result: Some(Value::test_bool(true)),
             },
+            Example {
+                description: "Check if any row's ok column is truthy, without a closure",
+                example: "[[ok]; [true] [false]] | any ok",
+                result: Some(Value::test_bool(true)),
+            },
         ]
     }
-    // This is almost entirely a copy-paste of `all`'s run(), so make sure any changes to this are
-    // reflected in the other!! Or, you could figure out a way for both of them to use
-    // the same function...
     fn run(
         &self,
         engine_state: &EngineState,
@@ -71,50 +87,142 @@ impl Command for Any {
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
         let span = call.head;
+        let verbose = call.has_flag("verbose");
+        let predicate: Value = call.req(engine_state, stack, 0)?;
+
+        if matches!(predicate, Value::Closure { .. } | Value::Block { .. }) {
+            let capture_block = Closure::from_value(&predicate)?;
+            return run_with_closure(
+                engine_state,
+                stack,
+                call,
+                input,
+                capture_block,
+                span,
+                verbose,
+                true,
+            );
+        }
 
-        let capture_block: Closure = call.req(engine_state, stack, 0)?;
-        let block_id = capture_block.block_id;
-
-        let block = engine_state.get_block(block_id);
-        let var_id = block.signature.get_positional(0).and_then(|arg| arg.var_id);
-        let mut stack = stack.captures_to_stack(&capture_block.captures);
+        let cell_path = CellPath::from_value(&predicate)?;
+        run_with_cell_path(input, &cell_path, span, verbose, true)
+    }
+}
 
-        let orig_env_vars = stack.env_vars.clone();
-        let orig_env_hidden = stack.env_hidden.clone();
+// Shared by `any` and `all` - `want_true` picks which outcome short-circuits the scan, and
+// which outcome the predicate needs to produce for a row to be the one reported back.
+pub(super) fn run_with_closure(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+    input: PipelineData,
+    capture_block: Closure,
+    span: Span,
+    verbose: bool,
+    want_true: bool,
+) -> Result<PipelineData, ShellError> {
+    let block_id = capture_block.block_id;
+
+    let block = engine_state.get_block(block_id);
+    let var_id = block.signature.get_positional(0).and_then(|arg| arg.var_id);
+    let mut stack = stack.captures_to_stack(&capture_block.captures);
+
+    let orig_env_vars = stack.env_vars.clone();
+    let orig_env_hidden = stack.env_hidden.clone();
+
+    let ctrlc = engine_state.ctrlc.clone();
+    let engine_state = engine_state.clone();
+
+    for value in input.into_interruptible_iter(ctrlc) {
+        // with_env() is used here to ensure that each iteration uses
+        // a different set of environment variables.
+        // Hence, a 'cd' in the first loop won't affect the next loop.
+        stack.with_env(&orig_env_vars, &orig_env_hidden);
+
+        if let Some(var_id) = var_id {
+            stack.add_var(var_id, value.clone());
+        }
 
-        let ctrlc = engine_state.ctrlc.clone();
-        let engine_state = engine_state.clone();
+        let eval = eval_block(
+            &engine_state,
+            &mut stack,
+            block,
+            value.clone().into_pipeline_data(),
+            call.redirect_stdout,
+            call.redirect_stderr,
+        )?;
+
+        if eval.into_value(span).is_true() == want_true {
+            return Ok(found(want_true, value, span, verbose));
+        }
+    }
+    Ok(not_found(want_true, span, verbose))
+}
 
-        for value in input.into_interruptible_iter(ctrlc) {
-            // with_env() is used here to ensure that each iteration uses
-            // a different set of environment variables.
-            // Hence, a 'cd' in the first loop won't affect the next loop.
-            stack.with_env(&orig_env_vars, &orig_env_hidden);
+pub(super) fn run_with_cell_path(
+    input: PipelineData,
+    cell_path: &CellPath,
+    span: Span,
+    verbose: bool,
+    want_true: bool,
+) -> Result<PipelineData, ShellError> {
+    for value in input.into_iter() {
+        let matched = value
+            .clone()
+            .follow_cell_path(&cell_path.members, false, false)
+            .map(|v| v.is_true())
+            .unwrap_or(false);
+
+        if matched == want_true {
+            return Ok(found(want_true, value, span, verbose));
+        }
+    }
+    Ok(not_found(want_true, span, verbose))
+}
 
-            if let Some(var_id) = var_id {
-                stack.add_var(var_id, value.clone());
-            }
+fn found(want_true: bool, row: Value, span: Span, verbose: bool) -> PipelineData {
+    if verbose {
+        Value::Record {
+            cols: vec!["result".into(), "row".into()],
+            vals: vec![
+                Value::Bool {
+                    val: want_true,
+                    span,
+                },
+                row,
+            ],
+            span,
+        }
+        .into_pipeline_data()
+    } else {
+        Value::Bool {
+            val: want_true,
+            span,
+        }
+        .into_pipeline_data()
+    }
+}
 
-            let eval = eval_block(
-                &engine_state,
-                &mut stack,
-                block,
-                value.into_pipeline_data(),
-                call.redirect_stdout,
-                call.redirect_stderr,
-            );
-            match eval {
-                Err(e) => {
-                    return Err(e);
-                }
-                Ok(pipeline_data) => {
-                    if pipeline_data.into_value(span).is_true() {
-                        return Ok(Value::Bool { val: true, span }.into_pipeline_data());
-                    }
-                }
-            }
+fn not_found(want_true: bool, span: Span, verbose: bool) -> PipelineData {
+    if verbose {
+        Value::Record {
+            cols: vec!["result".into(), "row".into()],
+            vals: vec![
+                Value::Bool {
+                    val: !want_true,
+                    span,
+                },
+                Value::nothing(span),
+            ],
+            span,
+        }
+        .into_pipeline_data()
+    } else {
+        Value::Bool {
+            val: !want_true,
+            span,
         }
-        Ok(Value::Bool { val: false, span }.into_pipeline_data())
+        .into_pipeline_data()
     }
 }
 
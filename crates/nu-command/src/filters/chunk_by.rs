@@ -0,0 +1,213 @@
+use nu_engine::{eval_block_with_early_return, CallExt};
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Closure, Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
+    SyntaxShape, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct ChunkBy;
+
+impl Command for ChunkBy {
+    fn name(&self) -> &str {
+        "chunk-by"
+    }
+
+    fn usage(&self) -> &str {
+        "Groups consecutive elements while a key closure returns the same value, lazily."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Unlike `group-by`, which collects the whole input before grouping, `chunk-by` only
+ever needs to look one element ahead, so it can emit completed chunks from a stream as soon
+as the key changes - handy for sessionizing logs that are already sorted by time."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["group", "partition", "session", "itertools"]
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("chunk-by")
+            .input_output_types(vec![(
+                Type::List(Box::new(Type::Any)),
+                Type::List(Box::new(Type::List(Box::new(Type::Any)))),
+            )])
+            .required(
+                "closure",
+                SyntaxShape::Closure(Some(vec![SyntaxShape::Any])),
+                "the key closure; consecutive elements with equal results are chunked together",
+            )
+            .category(Category::Filters)
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let closure: Closure = call.req(engine_state, stack, 0)?;
+
+        let ctrlc = engine_state.ctrlc.clone();
+        let metadata = input.metadata();
+        let engine_state = engine_state.clone();
+        let block = engine_state.get_block(closure.block_id).clone();
+        let stack = stack.captures_to_stack(&closure.captures);
+        let span = call.head;
+        let redirect_stdout = call.redirect_stdout;
+        let redirect_stderr = call.redirect_stderr;
+
+        let chunk_iter = ChunkByIterator {
+            input: Box::new(input.into_iter()),
+            engine_state,
+            stack,
+            block,
+            span,
+            redirect_stdout,
+            redirect_stderr,
+            pending: None,
+            done: false,
+        };
+
+        Ok(chunk_iter.into_pipeline_data(ctrlc).set_metadata(metadata))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                example: "[1 1 2 2 2 3 1] | chunk-by {|x| $x }",
+                description: "Chunk consecutive equal elements together",
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::List {
+                            vals: vec![Value::test_int(1), Value::test_int(1)],
+                            span: Span::test_data(),
+                        },
+                        Value::List {
+                            vals: vec![Value::test_int(2), Value::test_int(2), Value::test_int(2)],
+                            span: Span::test_data(),
+                        },
+                        Value::List {
+                            vals: vec![Value::test_int(3)],
+                            span: Span::test_data(),
+                        },
+                        Value::List {
+                            vals: vec![Value::test_int(1)],
+                            span: Span::test_data(),
+                        },
+                    ],
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                example: "open log.txt | lines | chunk-by {|line| $line | str substring 0..10 }",
+                description: "Sessionize a sorted log by its timestamp prefix",
+                result: None,
+            },
+        ]
+    }
+}
+
+pub(super) struct ChunkByIterator {
+    pub(super) input: Box<dyn Iterator<Item = Value> + Send>,
+    pub(super) engine_state: EngineState,
+    pub(super) stack: Stack,
+    pub(super) block: nu_protocol::engine::Block,
+    pub(super) span: Span,
+    pub(super) redirect_stdout: bool,
+    pub(super) redirect_stderr: bool,
+    pub(super) pending: Option<(Value, Value)>,
+    pub(super) done: bool,
+}
+
+impl ChunkByIterator {
+    fn key_of(&mut self, value: &Value) -> Result<Value, ShellError> {
+        if let Some(var) = self.block.signature.get_positional(0) {
+            if let Some(var_id) = &var.var_id {
+                self.stack.add_var(*var_id, value.clone());
+            }
+        }
+
+        eval_block_with_early_return(
+            &self.engine_state,
+            &mut self.stack,
+            &self.block,
+            PipelineData::Empty,
+            self.redirect_stdout,
+            self.redirect_stderr,
+        )
+        .map(|data| data.into_value(self.span))
+    }
+}
+
+impl Iterator for ChunkByIterator {
+    type Item = Value;
+
+    fn next(&mut self) -> Option<Value> {
+        if self.done {
+            return None;
+        }
+
+        let (current_key, first) = match self.pending.take() {
+            Some((key, value)) => (key, value),
+            None => {
+                let first = self.input.next()?;
+                let key = match self.key_of(&first) {
+                    Ok(key) => key,
+                    Err(error) => {
+                        self.done = true;
+                        return Some(Value::Error { error });
+                    }
+                };
+                (key, first)
+            }
+        };
+
+        let mut group = vec![first];
+
+        loop {
+            match self.input.next() {
+                Some(value) => {
+                    let key = match self.key_of(&value) {
+                        Ok(key) => key,
+                        Err(error) => {
+                            self.done = true;
+                            return Some(Value::Error { error });
+                        }
+                    };
+
+                    if key == current_key {
+                        group.push(value);
+                    } else {
+                        self.pending = Some((key, value));
+                        break;
+                    }
+                }
+                None => {
+                    self.done = true;
+                    break;
+                }
+            }
+        }
+
+        Some(Value::List {
+            vals: group,
+            span: self.span,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(ChunkBy {})
+    }
+}
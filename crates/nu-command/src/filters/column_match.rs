@@ -0,0 +1,57 @@
+use nu_protocol::Value;
+
+/// The top-level column names `select`/`reject` glob and type matching are evaluated against:
+/// a record's own columns, or the columns of its first element if `val` is a list of records.
+pub(crate) fn schema_columns(val: &Value) -> Vec<String> {
+    match val {
+        Value::Record { cols, .. } => cols.clone(),
+        Value::List { vals, .. } => vals.first().map(schema_columns).unwrap_or_default(),
+        _ => vec![],
+    }
+}
+
+/// Expand a column name into every name in `columns` it matches. Names with no `*`/`?` are
+/// returned as-is (even if absent from `columns`) so non-glob lookups keep their existing
+/// "no such column" error behavior instead of silently matching nothing.
+pub(crate) fn expand_glob(name: &str, columns: &[String]) -> Vec<String> {
+    if !name.contains('*') && !name.contains('?') {
+        return vec![name.to_string()];
+    }
+
+    columns
+        .iter()
+        .filter(|col| glob_match(name, col))
+        .cloned()
+        .collect()
+}
+
+fn glob_match(pattern: &str, text: &str) -> bool {
+    fn inner(pattern: &[u8], text: &[u8]) -> bool {
+        match (pattern.first(), text.first()) {
+            (None, None) => true,
+            (Some(b'*'), _) => {
+                inner(&pattern[1..], text) || (!text.is_empty() && inner(pattern, &text[1..]))
+            }
+            (Some(b'?'), Some(_)) => inner(&pattern[1..], &text[1..]),
+            (Some(p), Some(t)) if p == t => inner(&pattern[1..], &text[1..]),
+            _ => false,
+        }
+    }
+
+    inner(pattern.as_bytes(), text.as_bytes())
+}
+
+/// Column names from `columns` whose value on `sample` has the given type name, matching
+/// the `Type` enum's own `Display` output (e.g. `"int"`, `"string"`, `"date"`).
+pub(crate) fn columns_of_type(columns: &[String], sample: &Value, type_name: &str) -> Vec<String> {
+    columns
+        .iter()
+        .filter(|col| {
+            sample
+                .get_data_by_key(col)
+                .map(|v| v.get_type().to_string() == type_name)
+                .unwrap_or(false)
+        })
+        .cloned()
+        .collect()
+}
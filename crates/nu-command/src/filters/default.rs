@@ -24,6 +24,16 @@ impl Command for Default {
                 "the value to use as a default",
             )
             .optional("column name", SyntaxShape::String, "the name of the column")
+            .switch(
+                "chain",
+                "treat the default value and any extra arguments as a list of fallbacks, using the first one that isn't null",
+                Some('c'),
+            )
+            .rest(
+                "more defaults",
+                SyntaxShape::Any,
+                "additional fallback values to try, in order, when using --chain",
+            )
             .category(Category::Filters)
     }
 
@@ -31,6 +41,11 @@ impl Command for Default {
         "Sets a default row's column if missing."
     }
 
+    fn extra_usage(&self) -> &str {
+        "With --chain, pass several candidate values and the first one that isn't null is used \
+as the default, instead of writing out `$a | default ($b | default $c)`."
+    }
+
     fn run(
         &self,
         engine_state: &EngineState,
@@ -67,6 +82,11 @@ impl Command for Default {
                     span: Span::test_data(),
                 }),
             },
+            Example {
+                description: "Use the first of several fallback values that isn't null",
+                example: "null | default --chain null null 3",
+                result: Some(Value::test_int(3)),
+            },
         ]
     }
 }
@@ -78,7 +98,25 @@ fn default(
     input: PipelineData,
 ) -> Result<PipelineData, ShellError> {
     let value: Value = call.req(engine_state, stack, 0)?;
-    let column: Option<Spanned<String>> = call.opt(engine_state, stack, 1)?;
+    let chain = call.has_flag("chain");
+
+    // In --chain mode there's no room for a "column name" positional: every extra
+    // argument is another fallback value to try instead.
+    let column: Option<Spanned<String>> = if chain {
+        None
+    } else {
+        call.opt(engine_state, stack, 1)?
+    };
+
+    let value = if chain {
+        let more_defaults: Vec<Value> = call.rest(engine_state, stack, 1)?;
+        std::iter::once(value)
+            .chain(more_defaults)
+            .find(|v| !matches!(v, Value::Nothing { .. }))
+            .unwrap_or_else(|| Value::nothing(call.head))
+    } else {
+        value
+    };
 
     let ctrlc = engine_state.ctrlc.clone();
 
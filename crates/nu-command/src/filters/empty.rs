@@ -22,6 +22,11 @@ impl Command for Empty {
                 SyntaxShape::CellPath,
                 "the names of the columns to check emptiness",
             )
+            .switch(
+                "whitespace",
+                "also treat strings made up entirely of whitespace as empty",
+                Some('w'),
+            )
             .category(Category::Filters)
     }
 
@@ -29,6 +34,11 @@ impl Command for Empty {
         "Check for empty values."
     }
 
+    fn extra_usage(&self) -> &str {
+        "A record or list is empty if it has no members, or if every one of its members is \
+itself empty - so {a: [], b: {}} and [[] []] both count as empty, not just [] and {}."
+    }
+
     fn run(
         &self,
         engine_state: &EngineState,
@@ -36,7 +46,7 @@ impl Command for Empty {
         call: &Call,
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
-        empty(engine_state, stack, call, input)
+        check_emptiness(engine_state, stack, call, input, true)
     }
 
     fn examples(&self) -> Vec<Example> {
@@ -57,17 +67,29 @@ impl Command for Empty {
                 example: "[[meal size]; [arepa small] [taco '']] | is-empty meal size",
                 result: Some(Value::test_bool(false)),
             },
+            Example {
+                description: "Check if a whitespace-only string counts as empty",
+                example: "'   ' | is-empty --whitespace",
+                result: Some(Value::test_bool(true)),
+            },
+            Example {
+                description: "A record of empty lists is itself considered empty",
+                example: "{a: [], b: []} | is-empty",
+                result: Some(Value::test_bool(true)),
+            },
         ]
     }
 }
 
-fn empty(
+pub(super) fn check_emptiness(
     engine_state: &EngineState,
     stack: &mut Stack,
     call: &Call,
     input: PipelineData,
+    want_empty: bool,
 ) -> Result<PipelineData, ShellError> {
     let head = call.head;
+    let whitespace = call.has_flag("whitespace");
     let columns: Vec<CellPath> = call.rest(engine_state, stack, 0)?;
 
     if !columns.is_empty() {
@@ -75,8 +97,11 @@ fn empty(
             for column in &columns {
                 let val = val.clone();
                 match val.follow_cell_path(&column.members, false, false) {
-                    Ok(Value::Nothing { .. }) => {}
-                    Ok(_) => return Ok(Value::boolean(false, head).into_pipeline_data()),
+                    Ok(value) => {
+                        if is_deeply_empty(&value, whitespace) != want_empty {
+                            return Ok(Value::boolean(false, head).into_pipeline_data());
+                        }
+                    }
                     Err(err) => return Err(err),
                 }
             }
@@ -85,25 +110,43 @@ fn empty(
         Ok(Value::boolean(true, head).into_pipeline_data())
     } else {
         match input {
-            PipelineData::Empty => Ok(PipelineData::Empty),
+            PipelineData::Empty => Ok(Value::boolean(want_empty, head).into_pipeline_data()),
             PipelineData::ExternalStream { stdout, .. } => match stdout {
                 Some(s) => {
-                    let bytes = s.into_bytes();
-
-                    match bytes {
-                        Ok(s) => Ok(Value::boolean(s.item.is_empty(), head).into_pipeline_data()),
-                        Err(err) => Err(err),
-                    }
+                    let bytes = s.into_bytes()?;
+                    Ok(Value::boolean(bytes.item.is_empty() == want_empty, head)
+                        .into_pipeline_data())
                 }
-                None => Ok(Value::boolean(true, head).into_pipeline_data()),
+                None => Ok(Value::boolean(want_empty, head).into_pipeline_data()),
             },
             PipelineData::ListStream(s, ..) => {
-                Ok(Value::boolean(s.count() == 0, head).into_pipeline_data())
+                Ok(Value::boolean((s.count() == 0) == want_empty, head).into_pipeline_data())
             }
-            PipelineData::Value(value, ..) => {
-                Ok(Value::boolean(value.is_empty(), head).into_pipeline_data())
+            PipelineData::Value(value, ..) => Ok(Value::boolean(
+                is_deeply_empty(&value, whitespace) == want_empty,
+                head,
+            )
+            .into_pipeline_data()),
+        }
+    }
+}
+
+/// Unlike `Value::is_empty`, this recurses into records and lists: a record whose every
+/// field is empty, or a list whose every element is empty, counts as empty too.
+fn is_deeply_empty(value: &Value, whitespace: bool) -> bool {
+    match value {
+        Value::String { val, .. } => {
+            if whitespace {
+                val.trim().is_empty()
+            } else {
+                val.is_empty()
             }
         }
+        Value::List { vals, .. } => vals.iter().all(|v| is_deeply_empty(v, whitespace)),
+        Value::Record { vals, .. } => vals.iter().all(|v| is_deeply_empty(v, whitespace)),
+        Value::Binary { val, .. } => val.is_empty(),
+        Value::Nothing { .. } => true,
+        _ => false,
     }
 }
 
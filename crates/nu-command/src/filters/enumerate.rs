@@ -1,8 +1,9 @@
+use nu_engine::CallExt;
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
     Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
-    Type, Value,
+    SyntaxShape, Type, Value,
 };
 
 #[derive(Clone)]
@@ -24,40 +25,78 @@ impl Command for Enumerate {
     fn signature(&self) -> nu_protocol::Signature {
         Signature::build("enumerate")
             .input_output_types(vec![(Type::Any, Type::Any)])
+            .named(
+                "start",
+                SyntaxShape::Int,
+                "the index to start counting from, defaults to 0",
+                Some('s'),
+            )
+            .named(
+                "step",
+                SyntaxShape::Int,
+                "the amount to increase the index by each element, defaults to 1",
+                None,
+            )
             .category(Category::Filters)
     }
 
     fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            description: "Add an index to each element of a list",
-            example: r#"[a, b, c] | enumerate "#,
-            result: Some(Value::List {
-                vals: vec![
-                    Value::Record {
-                        cols: vec!["index".into(), "item".into()],
-                        vals: vec![Value::test_int(0), Value::test_string("a")],
-                        span: Span::test_data(),
-                    },
-                    Value::Record {
-                        cols: vec!["index".into(), "item".into()],
-                        vals: vec![Value::test_int(1), Value::test_string("b")],
-                        span: Span::test_data(),
-                    },
-                    Value::Record {
-                        cols: vec!["index".into(), "item".into()],
-                        vals: vec![Value::test_int(2), Value::test_string("c")],
-                        span: Span::test_data(),
-                    },
-                ],
-                span: Span::test_data(),
-            }),
-        }]
+        vec![
+            Example {
+                description: "Add an index to each element of a list",
+                example: r#"[a, b, c] | enumerate "#,
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::Record {
+                            cols: vec!["index".into(), "item".into()],
+                            vals: vec![Value::test_int(0), Value::test_string("a")],
+                            span: Span::test_data(),
+                        },
+                        Value::Record {
+                            cols: vec!["index".into(), "item".into()],
+                            vals: vec![Value::test_int(1), Value::test_string("b")],
+                            span: Span::test_data(),
+                        },
+                        Value::Record {
+                            cols: vec!["index".into(), "item".into()],
+                            vals: vec![Value::test_int(2), Value::test_string("c")],
+                            span: Span::test_data(),
+                        },
+                    ],
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                description: "Number elements starting from 1, counting down by 2",
+                example: r#"[a, b, c] | enumerate --start 1 --step -2"#,
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::Record {
+                            cols: vec!["index".into(), "item".into()],
+                            vals: vec![Value::test_int(1), Value::test_string("a")],
+                            span: Span::test_data(),
+                        },
+                        Value::Record {
+                            cols: vec!["index".into(), "item".into()],
+                            vals: vec![Value::test_int(-1), Value::test_string("b")],
+                            span: Span::test_data(),
+                        },
+                        Value::Record {
+                            cols: vec!["index".into(), "item".into()],
+                            vals: vec![Value::test_int(-3), Value::test_string("c")],
+                            span: Span::test_data(),
+                        },
+                    ],
+                    span: Span::test_data(),
+                }),
+            },
+        ]
     }
 
     fn run(
         &self,
         engine_state: &EngineState,
-        _stack: &mut Stack,
+        stack: &mut Stack,
         call: &Call,
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
@@ -65,6 +104,9 @@ impl Command for Enumerate {
         let ctrlc = engine_state.ctrlc.clone();
         let span = call.head;
 
+        let start: i64 = call.get_flag(engine_state, stack, "start")?.unwrap_or(0);
+        let step: i64 = call.get_flag(engine_state, stack, "step")?.unwrap_or(1);
+
         Ok(input
             .into_iter()
             .enumerate()
@@ -72,7 +114,7 @@ impl Command for Enumerate {
                 cols: vec!["index".into(), "item".into()],
                 vals: vec![
                     Value::Int {
-                        val: idx as i64,
+                        val: start + (idx as i64) * step,
                         span,
                     },
                     x,
@@ -0,0 +1,105 @@
+use super::set_ops::{as_rows, key_for, key_set};
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
+    SyntaxShape, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct Except;
+
+impl Command for Except {
+    fn name(&self) -> &str {
+        "except"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("except")
+            .input_output_types(vec![(
+                Type::List(Box::new(Type::Any)),
+                Type::List(Box::new(Type::Any)),
+            )])
+            .required("other", SyntaxShape::Any, "the list or table to remove")
+            .rest(
+                "columns",
+                SyntaxShape::String,
+                "key column(s) to compare by, instead of comparing whole rows",
+            )
+            .category(Category::Filters)
+    }
+
+    fn usage(&self) -> &str {
+        "Return the elements of the input that are not present in the other list."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "The other list is hashed once up front, so this scales better than `where $it not-in \
+$other` for large inputs. Without key columns, whole rows (or values) are compared; with key \
+columns, only those columns determine membership, and the rows returned are still the full \
+rows from the input."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["set", "difference", "exclude"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let other: Value = call.req(engine_state, stack, 0)?;
+        let columns: Vec<String> = call.rest(engine_state, stack, 1)?;
+        let metadata = input.metadata();
+
+        let other_rows = as_rows(other);
+        let drop = key_set(&other_rows, &columns, head)?;
+
+        let mut result = Vec::new();
+        for value in input.into_iter() {
+            if !drop.contains(&key_for(&value, &columns, head)?) {
+                result.push(value);
+            }
+        }
+
+        Ok(result
+            .into_iter()
+            .into_pipeline_data(engine_state.ctrlc.clone())
+            .set_metadata(metadata))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Drop the numbers that are present in the other list",
+                example: "[1 2 3 4] | except [3 4 5]",
+                result: Some(Value::List {
+                    vals: vec![Value::test_int(1), Value::test_int(2)],
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                description: "Drop rows whose id is present in the other table",
+                example: "$a | except $b id",
+                result: None,
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(Except {})
+    }
+}
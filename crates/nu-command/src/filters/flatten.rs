@@ -4,7 +4,8 @@ use nu_protocol::ast::{Call, CellPath, PathMember};
 
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
-    Category, Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value,
+    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
+    SyntaxShape, Type, Value,
 };
 
 #[derive(Clone)]
@@ -30,6 +31,24 @@ impl Command for Flatten {
                 "optionally flatten data by column",
             )
             .switch("all", "flatten inner table one level out", Some('a'))
+            .named(
+                "depth",
+                SyntaxShape::Int,
+                "how many levels of nesting to flatten (default 1)",
+                Some('d'),
+            )
+            .named(
+                "separator",
+                SyntaxShape::String,
+                "string to join a nested column's name onto its parent's when they collide (default '_')",
+                Some('s'),
+            )
+            .named(
+                "collision",
+                SyntaxShape::String,
+                "how to handle colliding column names: rename (default), overwrite, or error",
+                Some('c'),
+            )
             .category(Category::Filters)
     }
 
@@ -37,6 +56,12 @@ impl Command for Flatten {
         "Flatten the table."
     }
 
+    fn extra_usage(&self) -> &str {
+        r#"By default, a nested column whose name collides with an existing one is kept by
+renaming it to "{parent}{separator}{child}". Pass `--collision overwrite` to replace the
+existing column instead, or `--collision error` to fail loudly rather than guess."#
+    }
+
     fn run(
         &self,
         engine_state: &EngineState,
@@ -107,11 +132,23 @@ impl Command for Flatten {
                     ],
                     span: Span::test_data(),
                 }),
-            }
+            },
+            Example {
+                description: "Flatten two levels of nesting at once",
+                example: "{ a: { b: { c: 1 } } } | flatten --depth 2",
+                result: None,
+            },
         ]
     }
 }
 
+#[derive(Clone, Copy)]
+enum Collision {
+    Rename,
+    Overwrite,
+    Error,
+}
+
 fn flatten(
     engine_state: &EngineState,
     stack: &mut Stack,
@@ -122,13 +159,47 @@ fn flatten(
     let columns: Vec<CellPath> = call.rest(engine_state, stack, 0)?;
     let metadata = input.metadata();
     let flatten_all = call.has_flag("all");
+    let depth: Option<usize> = call.get_flag(engine_state, stack, "depth")?;
+    let depth = depth.unwrap_or(1).max(1);
+    let separator: Option<String> = call.get_flag(engine_state, stack, "separator")?;
+    let separator = separator.unwrap_or_else(|| "_".into());
+    let collision: Option<String> = call.get_flag(engine_state, stack, "collision")?;
+    let collision = match collision.as_deref() {
+        None | Some("rename") => Collision::Rename,
+        Some("overwrite") => Collision::Overwrite,
+        Some("error") => Collision::Error,
+        Some(other) => {
+            return Err(ShellError::UnsupportedInput(
+                format!(
+                "'{other}' is not a valid collision policy, expected rename, overwrite, or error"
+            ),
+                "value originates from here".into(),
+                tag,
+                tag,
+            ))
+        }
+    };
 
-    input
-        .flat_map(
-            move |item| flat_value(&columns, &item, tag, flatten_all),
-            engine_state.ctrlc.clone(),
-        )
-        .map(|x| x.set_metadata(metadata))
+    let mut rows: Vec<Value> = input.into_iter().collect();
+    for _ in 0..depth {
+        let mut next = Vec::with_capacity(rows.len());
+        for item in rows {
+            next.extend(flat_value(
+                &columns,
+                &item,
+                tag,
+                flatten_all,
+                &separator,
+                collision,
+            ));
+        }
+        rows = next;
+    }
+
+    Ok(rows
+        .into_iter()
+        .into_pipeline_data(engine_state.ctrlc.clone())
+        .set_metadata(metadata))
 }
 
 enum TableInside<'a> {
@@ -151,7 +222,52 @@ enum TableInside<'a> {
     },
 }
 
-fn flat_value(columns: &[CellPath], item: &Value, _name_tag: Span, all: bool) -> Vec<Value> {
+/// Inserts `(col, val)` into `record_cols`/`record_vals`, resolving a name collision with an
+/// already-present column per `collision`. Returns an error only when `collision` is `Error`.
+fn push_with_collision(
+    record_cols: &mut Vec<String>,
+    record_vals: &mut Vec<Value>,
+    col: String,
+    val: Value,
+    parent: &str,
+    separator: &str,
+    collision: Collision,
+    head: Span,
+) -> Result<(), ShellError> {
+    if let Some(existing) = record_cols.iter().position(|c| c == &col) {
+        match collision {
+            Collision::Rename => {
+                record_cols.push(format!("{parent}{separator}{col}"));
+                record_vals.push(val);
+            }
+            Collision::Overwrite => {
+                record_vals[existing] = val;
+            }
+            Collision::Error => {
+                return Err(ShellError::GenericError(
+                    format!("flatten produced a duplicate column name '{col}'"),
+                    "pass --collision rename or --collision overwrite to resolve this".into(),
+                    Some(head),
+                    None,
+                    Vec::new(),
+                ))
+            }
+        }
+    } else {
+        record_cols.push(col);
+        record_vals.push(val);
+    }
+    Ok(())
+}
+
+fn flat_value(
+    columns: &[CellPath],
+    item: &Value,
+    _name_tag: Span,
+    all: bool,
+    separator: &str,
+    collision: Collision,
+) -> Vec<Value> {
     let tag = match item.span() {
         Ok(x) => x,
         Err(e) => return vec![Value::Error { error: e }],
@@ -209,18 +325,39 @@ fn flat_value(columns: &[CellPath], item: &Value, _name_tag: Span, all: bool) ->
                         span: _,
                     } => {
                         if need_flatten {
-                            cols.iter().enumerate().for_each(|(idx, inner_record_col)| {
+                            for (idx, inner_record_col) in cols.iter().enumerate() {
                                 if out.contains_key(inner_record_col) {
-                                    out.insert(
-                                        format!("{column}_{inner_record_col}"),
-                                        vals[idx].clone(),
-                                    );
+                                    match collision {
+                                        Collision::Rename => {
+                                            out.insert(
+                                                format!("{column}{separator}{inner_record_col}"),
+                                                vals[idx].clone(),
+                                            );
+                                        }
+                                        Collision::Overwrite => {
+                                            out.insert(
+                                                inner_record_col.to_string(),
+                                                vals[idx].clone(),
+                                            );
+                                        }
+                                        Collision::Error => {
+                                            return vec![Value::Error {
+                                                error: ShellError::GenericError(
+                                                    format!("flatten produced a duplicate column name '{inner_record_col}'"),
+                                                    "pass --collision rename or --collision overwrite to resolve this".into(),
+                                                    Some(s),
+                                                    None,
+                                                    Vec::new(),
+                                                ),
+                                            }];
+                                        }
+                                    }
                                 } else {
                                     out.insert(inner_record_col.to_string(), vals[idx].clone());
                                 }
-                            })
+                            }
                         } else if out.contains_key(column) {
-                            out.insert(format!("{column}_{column}"), value.clone());
+                            out.insert(format!("{column}{separator}{column}"), value.clone());
                         } else {
                             out.insert(column.to_string(), value.clone());
                         }
@@ -260,7 +397,7 @@ fn flat_value(columns: &[CellPath], item: &Value, _name_tag: Span, all: bool) ->
                                 parent_column_index: column_index,
                             });
                         } else if out.contains_key(column) {
-                            out.insert(format!("{column}_{column}"), value.clone());
+                            out.insert(format!("{column}{separator}{column}"), value.clone());
                         } else {
                             out.insert(column.to_string(), value.clone());
                         }
@@ -350,18 +487,25 @@ fn flat_value(columns: &[CellPath], item: &Value, _name_tag: Span, all: bool) ->
                         let base = out.clone();
                         let (mut record_cols, mut record_vals) = (vec![], vec![]);
                         let mut index = 0;
+                        let mut push_err = None;
 
                         for (base_col, base_val) in base.into_iter() {
                             // meet the flattened column, push them to result record first
                             // this can avoid output column order changed.
                             if index == parent_column_index {
                                 for (col, val) in inner_cols.iter().zip(inner_vals.iter()) {
-                                    if record_cols.contains(col) {
-                                        record_cols.push(format!("{parent_column_name}_{col}"));
-                                    } else {
-                                        record_cols.push(col.to_string());
+                                    if let Err(e) = push_with_collision(
+                                        &mut record_cols,
+                                        &mut record_vals,
+                                        col.clone(),
+                                        val.clone(),
+                                        parent_column_name,
+                                        separator,
+                                        collision,
+                                        tag,
+                                    ) {
+                                        push_err = Some(e);
                                     }
-                                    record_vals.push(val.clone());
                                 }
                             }
 
@@ -373,14 +517,25 @@ fn flat_value(columns: &[CellPath], item: &Value, _name_tag: Span, all: bool) ->
                         // the flattened column may be the last column in the original table.
                         if index == parent_column_index {
                             for (col, val) in inner_cols.iter().zip(inner_vals.iter()) {
-                                if record_cols.contains(col) {
-                                    record_cols.push(format!("{parent_column_name}_{col}"));
-                                } else {
-                                    record_cols.push(col.to_string());
+                                if let Err(e) = push_with_collision(
+                                    &mut record_cols,
+                                    &mut record_vals,
+                                    col.clone(),
+                                    val.clone(),
+                                    parent_column_name,
+                                    separator,
+                                    collision,
+                                    tag,
+                                ) {
+                                    push_err = Some(e);
                                 }
-                                record_vals.push(val.clone());
                             }
                         }
+
+                        if let Some(e) = push_err {
+                            return vec![Value::Error { error: e }];
+                        }
+
                         let record = Value::Record {
                             cols: record_cols,
                             vals: record_vals,
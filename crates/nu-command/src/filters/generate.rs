@@ -0,0 +1,166 @@
+use nu_engine::{eval_block, CallExt};
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Closure, Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
+    SyntaxShape, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct Generate;
+
+impl Command for Generate {
+    fn name(&self) -> &str {
+        "generate"
+    }
+
+    fn usage(&self) -> &str {
+        "Generate a list of values by successively invoking a closure."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"The closure takes the current state as its only argument, and must return either:
+- `null` to signal the end of the stream, or
+- a record `{out: value, next: state}` where `out` is appended to the output and `next`
+  becomes the state passed into the following call. If `next` is omitted, `out` is emitted
+  and the stream ends there.
+
+Since the resulting stream can be infinite, use `take`, `first`, or a similar command to
+limit how much of it gets consumed."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["unfold", "loop", "stream", "repeat"]
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("generate")
+            .input_output_types(vec![(Type::Nothing, Type::List(Box::new(Type::Any)))])
+            .required(
+                "closure",
+                SyntaxShape::Closure(Some(vec![SyntaxShape::Any])),
+                "closure that produces the next output and state, or null to end the stream",
+            )
+            .required(
+                "initial",
+                SyntaxShape::Any,
+                "initial value given to the closure",
+            )
+            .category(Category::Filters)
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let closure: Closure = call.req(engine_state, stack, 0)?;
+        let initial: Value = call.req(engine_state, stack, 1)?;
+
+        let span = call.head;
+        let ctrlc = engine_state.ctrlc.clone();
+        let engine_state = engine_state.clone();
+        let block = engine_state.get_block(closure.block_id).clone();
+        let mut stack = stack.captures_to_stack(&closure.captures);
+        let orig_env_vars = stack.env_vars.clone();
+        let orig_env_hidden = stack.env_hidden.clone();
+        let redirect_stdout = call.redirect_stdout;
+        let redirect_stderr = call.redirect_stderr;
+
+        let mut state = Some(initial);
+        let iter = std::iter::from_fn(move || {
+            let cur_state = state.take()?;
+
+            stack.with_env(&orig_env_vars, &orig_env_hidden);
+            if let Some(var) = block.signature.get_positional(0) {
+                if let Some(var_id) = &var.var_id {
+                    stack.add_var(*var_id, cur_state);
+                }
+            }
+
+            let result = eval_block(
+                &engine_state,
+                &mut stack,
+                &block,
+                PipelineData::Empty,
+                redirect_stdout,
+                redirect_stderr,
+            );
+
+            match result {
+                Ok(pipeline_data) => match pipeline_data.into_value(span) {
+                    Value::Nothing { .. } => None,
+                    Value::Record { cols, vals, .. } => {
+                        let mut out = None;
+                        for (col, val) in cols.into_iter().zip(vals) {
+                            match col.as_str() {
+                                "next" => state = Some(val),
+                                "out" => out = Some(val),
+                                _ => {}
+                            }
+                        }
+                        Some(out.unwrap_or_else(|| Value::nothing(span)))
+                    }
+                    other => Some(Value::Error {
+                        error: ShellError::UnsupportedInput(
+                            "generate's closure must return a record with an 'out' key, or null to end the stream".into(),
+                            "value originates from here".into(),
+                            span,
+                            other.expect_span(),
+                        ),
+                    }),
+                },
+                Err(err) => Some(Value::Error { error: err }),
+            }
+        });
+
+        Ok(iter.into_pipeline_data(ctrlc))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                example: "generate {|i| if $i <= 10 { {out: $i, next: $i + 2} } } 0",
+                description: "Generate a sequence of even numbers up to 10",
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::test_int(0),
+                        Value::test_int(2),
+                        Value::test_int(4),
+                        Value::test_int(6),
+                        Value::test_int(8),
+                        Value::test_int(10),
+                    ],
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                example: "generate {|fib| {out: $fib.0, next: [$fib.1, $fib.0 + $fib.1]} } [0, 1] | first 6",
+                description: "Generate the first 6 Fibonacci numbers",
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::test_int(0),
+                        Value::test_int(1),
+                        Value::test_int(1),
+                        Value::test_int(2),
+                        Value::test_int(3),
+                        Value::test_int(5),
+                    ],
+                    span: Span::test_data(),
+                }),
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn test_examples() {
+        use super::Generate;
+        use crate::test_examples;
+        test_examples(Generate {})
+    }
+}
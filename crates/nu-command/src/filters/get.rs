@@ -1,5 +1,5 @@
 use nu_engine::CallExt;
-use nu_protocol::ast::{Call, CellPath};
+use nu_protocol::ast::{Call, CellPath, PathMember};
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
     Category, Example, IntoInterruptiblePipelineData, IntoPipelineData, PipelineData, ShellError,
@@ -21,7 +21,15 @@ impl Command for Get {
     fn extra_usage(&self) -> &str {
         r#"This is equivalent to using the cell path access syntax: `$env.OS` is the same as `$env | get OS`.
 
-If multiple cell paths are given, this will produce a list of values."#
+If multiple cell paths are given, this will produce a list of values.
+
+A path member that is exactly `*` matches every column of a record or every element of a
+list at that level. A path member that is exactly `**` matches zero or more levels, searching
+every record and list nested underneath for the rest of the path. Both produce a flat list of
+every match; with `--keep-structure`, each cell path's matches come back as a list of their
+own instead of being merged with the other paths' matches, but the matches inside that list
+are still flat, not nested by the levels `**`/`*` walked through. This wildcard matching is implemented directly in `get`, not as a general cell path
+feature, so it isn't available from `update`, `select`, or the `$env.foo` access syntax."#
     }
 
     fn signature(&self) -> nu_protocol::Signature {
@@ -51,6 +59,11 @@ If multiple cell paths are given, this will produce a list of values."#
                 "get path in a case sensitive manner",
                 Some('s'),
             )
+            .switch(
+                "keep-structure",
+                "when a cell path contains `*` or `**`, return its matches as a list of their own instead of merging them into one list with the other paths' matches (the matches themselves stay flat)",
+                None,
+            )
             .category(Category::Filters)
     }
 
@@ -66,10 +79,11 @@ If multiple cell paths are given, this will produce a list of values."#
         let rest: Vec<CellPath> = call.rest(engine_state, stack, 1)?;
         let sensitive = call.has_flag("sensitive");
         let ignore_errors = call.has_flag("ignore-errors");
+        let keep_structure = call.has_flag("keep-structure");
         let ctrlc = engine_state.ctrlc.clone();
         let metadata = input.metadata();
 
-        if rest.is_empty() {
+        if rest.is_empty() && !has_wildcard(&cell_path.members) {
             input
                 .follow_cell_path(&cell_path.members, call.head, !sensitive, ignore_errors)
                 .map(|x| x.into_pipeline_data())
@@ -81,11 +95,25 @@ If multiple cell paths are given, this will produce a list of values."#
             let input = input.into_value(span);
 
             for path in paths {
-                let val = input
-                    .clone()
-                    .follow_cell_path(&path.members, !sensitive, false);
+                if has_wildcard(&path.members) {
+                    let matched =
+                        follow_cell_path_with_wildcards(&input, &path.members, sensitive, span)?;
+
+                    if keep_structure {
+                        output.push(Value::List {
+                            vals: matched,
+                            span,
+                        });
+                    } else {
+                        output.extend(matched);
+                    }
+                } else {
+                    let val = input
+                        .clone()
+                        .follow_cell_path(&path.members, !sensitive, false);
 
-                output.push(val?);
+                    output.push(val?);
+                }
             }
 
             Ok(output.into_iter().into_pipeline_data(ctrlc))
@@ -138,10 +166,111 @@ If multiple cell paths are given, this will produce a list of values."#
                 example: "$env | get -s Path",
                 result: None,
             },
+            Example {
+                description: "Get every 'name' field at any depth in a nested structure",
+                example: "{a: {name: foo}, b: [{name: bar}, {name: baz}]} | get **.name",
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::test_string("foo"),
+                        Value::test_string("bar"),
+                        Value::test_string("baz"),
+                    ],
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                description: "Get the 'id' field from every element of a list",
+                example: "[{id: 1}, {id: 2}] | get *.id",
+                result: Some(Value::List {
+                    vals: vec![Value::test_int(1), Value::test_int(2)],
+                    span: Span::test_data(),
+                }),
+            },
         ]
     }
 }
 
+/// True if any member of the cell path is the literal string `*` or `**`.
+fn has_wildcard(members: &[PathMember]) -> bool {
+    members.iter().any(|member| is_wildcard(member).is_some())
+}
+
+enum Wildcard {
+    /// `*`: every column of a record, or every element of a list, at this level.
+    Any,
+    /// `**`: this level, or any number of levels nested underneath it.
+    Recursive,
+}
+
+fn is_wildcard(member: &PathMember) -> Option<Wildcard> {
+    match member {
+        PathMember::String { val, .. } if val == "*" => Some(Wildcard::Any),
+        PathMember::String { val, .. } if val == "**" => Some(Wildcard::Recursive),
+        _ => None,
+    }
+}
+
+fn direct_children(value: &Value) -> Vec<Value> {
+    match value {
+        Value::Record { vals, .. } => vals.clone(),
+        Value::List { vals, .. } => vals.clone(),
+        _ => vec![],
+    }
+}
+
+/// Evaluate a cell path that may contain `*`/`**` wildcard members against `value`,
+/// returning every match as a flat list. Non-wildcard members are followed the same
+/// way the ordinary `get` path does, one step at a time.
+fn follow_cell_path_with_wildcards(
+    value: &Value,
+    members: &[PathMember],
+    sensitive: bool,
+    span: Span,
+) -> Result<Vec<Value>, ShellError> {
+    let Some((first, rest)) = members.split_first() else {
+        return Ok(vec![value.clone()]);
+    };
+
+    match is_wildcard(first) {
+        Some(Wildcard::Any) => {
+            let mut matches = vec![];
+            for child in direct_children(value) {
+                matches.extend(follow_cell_path_with_wildcards(
+                    &child, rest, sensitive, span,
+                )?);
+            }
+            Ok(matches)
+        }
+        Some(Wildcard::Recursive) => {
+            // Zero levels descended: try matching the rest of the path right here. A column
+            // name is not tried against a list, since that would map it over the list's
+            // records, and those records are each visited below anyway.
+            let column_on_list = matches!(value, Value::List { .. })
+                && matches!(rest.first(), Some(m @ PathMember::String { .. }) if is_wildcard(m).is_none());
+            let mut matches = if column_on_list {
+                vec![]
+            } else {
+                follow_cell_path_with_wildcards(value, rest, sensitive, span).unwrap_or_default()
+            };
+
+            // One or more levels descended: keep the `**` in the path and recurse.
+            for child in direct_children(value) {
+                matches.extend(follow_cell_path_with_wildcards(
+                    &child, members, sensitive, span,
+                )?);
+            }
+            Ok(matches)
+        }
+        None => {
+            let stepped =
+                value
+                    .clone()
+                    .follow_cell_path(std::slice::from_ref(first), !sensitive, false)?;
+            follow_cell_path_with_wildcards(&stepped, rest, sensitive, span)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
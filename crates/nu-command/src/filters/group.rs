@@ -1,6 +1,7 @@
+use super::chunk_by::ChunkByIterator;
 use nu_engine::CallExt;
 use nu_protocol::ast::Call;
-use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::engine::{Closure, Command, EngineState, Stack};
 use nu_protocol::{
     Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
     Spanned, SyntaxShape, Type, Value,
@@ -24,7 +25,13 @@ impl Command for Group {
                 Type::List(Box::new(Type::Any)),
                 Type::List(Box::new(Type::List(Box::new(Type::Any)))),
             )])
-            .required("group_size", SyntaxShape::Int, "the size of each group")
+            .optional("group_size", SyntaxShape::Int, "the size of each group")
+            .named(
+                "by",
+                SyntaxShape::Closure(Some(vec![SyntaxShape::Any])),
+                "group consecutive elements that share a key, instead of a fixed size",
+                None,
+            )
             .category(Category::Filters)
     }
 
@@ -32,6 +39,12 @@ impl Command for Group {
         "Groups input into groups of `group_size`."
     }
 
+    fn extra_usage(&self) -> &str {
+        "With --by, `group_size` is ignored and consecutive elements are grouped together as \
+long as the key closure returns the same value - an alias for `chunk-by` for callers that \
+think of it as a variant of `group`."
+    }
+
     fn examples(&self) -> Vec<Example> {
         let stream_test_1 = vec![
             Value::List {
@@ -44,14 +57,37 @@ impl Command for Group {
             },
         ];
 
-        vec![Example {
-            example: "[1 2 3 4] | group 2",
-            description: "Group the a list by pairs",
-            result: Some(Value::List {
-                vals: stream_test_1,
-                span: Span::test_data(),
-            }),
-        }]
+        vec![
+            Example {
+                example: "[1 2 3 4] | group 2",
+                description: "Group the a list by pairs",
+                result: Some(Value::List {
+                    vals: stream_test_1,
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                example: "[1 1 2 2 2 3] | group --by {|x| $x }",
+                description: "Group consecutive equal elements together",
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::List {
+                            vals: vec![Value::test_int(1), Value::test_int(1)],
+                            span: Span::test_data(),
+                        },
+                        Value::List {
+                            vals: vec![Value::test_int(2), Value::test_int(2), Value::test_int(2)],
+                            span: Span::test_data(),
+                        },
+                        Value::List {
+                            vals: vec![Value::test_int(3)],
+                            span: Span::test_data(),
+                        },
+                    ],
+                    span: Span::test_data(),
+                }),
+            },
+        ]
     }
 
     fn run(
@@ -61,12 +97,41 @@ impl Command for Group {
         call: &Call,
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
-        let group_size: Spanned<usize> = call.req(engine_state, stack, 0)?;
         let ctrlc = engine_state.ctrlc.clone();
         let metadata = input.metadata();
 
         //FIXME: add in support for external redirection when engine-q supports it generally
 
+        if let Some(closure) = call.get_flag::<Closure>(engine_state, stack, "by")? {
+            let engine_state = engine_state.clone();
+            let block = engine_state.get_block(closure.block_id).clone();
+            let stack = stack.captures_to_stack(&closure.captures);
+
+            let chunk_iter = ChunkByIterator {
+                input: Box::new(input.into_iter()),
+                engine_state,
+                stack,
+                block,
+                span: call.head,
+                redirect_stdout: call.redirect_stdout,
+                redirect_stderr: call.redirect_stderr,
+                pending: None,
+                done: false,
+            };
+
+            return Ok(chunk_iter.into_pipeline_data(ctrlc).set_metadata(metadata));
+        }
+
+        let group_size: Spanned<usize> = match call.opt(engine_state, stack, 0)? {
+            Some(group_size) => group_size,
+            None => {
+                return Err(ShellError::MissingParameter {
+                    param_name: "group_size or --by".into(),
+                    span: call.head,
+                })
+            }
+        };
+
         let each_group_iterator = EachGroupIterator {
             group_size: group_size.item,
             input: Box::new(input.into_iter()),
@@ -1,3 +1,5 @@
+use std::cmp::Ordering;
+
 use nu_engine::{eval_block, CallExt};
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Closure, Command, EngineState, Stack};
@@ -22,17 +24,38 @@ impl Command for GroupBy {
             // example. Perhaps Table should be a subtype of List, in which case
             // the current signature would suffice even when a Table example
             // exists.
-            .input_output_types(vec![(
-                Type::List(Box::new(Type::Any)),
-                Type::Record(vec![]),
-            )])
-            .optional("grouper", SyntaxShape::Any, "the grouper value to use")
+            .input_output_types(vec![
+                (Type::List(Box::new(Type::Any)), Type::Record(vec![])),
+                (Type::List(Box::new(Type::Any)), Type::Table(vec![])),
+            ])
+            .rest(
+                "grouper",
+                SyntaxShape::Any,
+                "the grouper value to use, or multiple column names to group by",
+            )
+            .named(
+                "agg",
+                SyntaxShape::Record,
+                "aggregation shorthand, e.g. {count: null, sum: price}, producing aggregate columns instead of a nested `items` column",
+                None,
+            )
     }
 
     fn usage(&self) -> &str {
         "Splits a list or table into groups, and returns a record containing those groups."
     }
 
+    fn extra_usage(&self) -> &str {
+        r#"Grouping by a single column name or a closure returns a record whose
+columns are the group keys and whose values are the grouped lists, same as
+always. Grouping by two or more column names instead returns a table, one
+row per group, with the key columns followed by a nested `items` column
+holding that group's rows - joining the group keys back on afterward is
+unnecessary. `--agg` replaces that `items` column with the aggregate
+columns it names (currently `count`, `sum`, `avg`, `min`, and `max`); for
+every key but `count`, the record value names the column to aggregate."#
+    }
+
     fn run(
         &self,
         engine_state: &EngineState,
@@ -50,6 +73,17 @@ impl Command for GroupBy {
                 example: r#"ls | group-by type"#,
                 result: None,
             },
+            Example {
+                description:
+                    "Group by multiple columns, returning a table with a nested `items` column",
+                example: r#"ls | group-by type size"#,
+                result: None,
+            },
+            Example {
+                description: "Group by a column and compute aggregates instead of nesting the rows",
+                example: r#"[[type price]; [a 1] [b 2] [a 3]] | group-by type --agg {count: null, sum: price}"#,
+                result: None,
+            },
             Example {
                 description: "You can also group by raw values by leaving out the argument",
                 example: "['1' '3' '1' '3' '2' '1' '1'] | group-by",
@@ -94,7 +128,14 @@ pub fn group_by(
 ) -> Result<PipelineData, ShellError> {
     let name = call.head;
 
-    let grouper: Option<Value> = call.opt(engine_state, stack, 0)?;
+    let groupers: Vec<Value> = call.rest(engine_state, stack, 0)?;
+    let agg: Option<Value> = call.get_flag(engine_state, stack, "agg")?;
+
+    if groupers.len() > 1 || agg.is_some() {
+        return group_by_multi(engine_state, stack, call, input, groupers, agg);
+    }
+
+    let grouper = groupers.into_iter().next();
     let values: Vec<Value> = input.into_iter().collect();
     let mut keys: Vec<Result<String, ShellError>> = vec![];
     let mut group_strategy = Grouper::ByColumn(None);
@@ -269,6 +310,186 @@ pub fn group(
     }
 }
 
+/// Handles `group-by` with more than one column name, or with `--agg`. Unlike
+/// the single-key/closure path above, this always returns a table (one row
+/// per group) rather than a record keyed by group, since there's no single
+/// string that identifies a multi-column group.
+fn group_by_multi(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+    input: PipelineData,
+    groupers: Vec<Value>,
+    agg: Option<Value>,
+) -> Result<PipelineData, ShellError> {
+    let name = call.head;
+
+    let key_columns = groupers
+        .into_iter()
+        .map(|grouper| {
+            grouper.as_string().map_err(|_| ShellError::TypeMismatch {
+                err_message: "expected a column name".into(),
+                span: grouper.expect_span(),
+            })
+        })
+        .collect::<Result<Vec<String>, ShellError>>()?;
+
+    let values: Vec<Value> = input.into_iter().collect();
+    if values.is_empty() {
+        return Err(ShellError::GenericError(
+            "expected table from pipeline".into(),
+            "requires a table input".into(),
+            Some(name),
+            None,
+            Vec::new(),
+        ));
+    }
+
+    let span = values[0].span().unwrap_or(name);
+
+    let mut groups: IndexMap<String, (Vec<Value>, Vec<Value>)> = IndexMap::new();
+    for row in values {
+        let mut key_vals = Vec::with_capacity(key_columns.len());
+        for column in &key_columns {
+            let value = row
+                .get_data_by_key(column)
+                .ok_or_else(|| ShellError::CantFindColumn {
+                    col_name: column.clone(),
+                    span: name,
+                    src_span: row.expect_span(),
+                })?;
+            key_vals.push(value);
+        }
+
+        let group_key = key_vals
+            .iter()
+            .map(|v| v.as_string().unwrap_or_default())
+            .collect::<Vec<_>>()
+            .join("\u{1}");
+
+        let group = groups
+            .entry(group_key)
+            .or_insert_with(|| (key_vals.clone(), Vec::new()));
+        group.1.push(row);
+    }
+
+    let mut rows = Vec::with_capacity(groups.len());
+    for (key_vals, rows_in_group) in groups.into_values() {
+        let mut cols = key_columns.clone();
+        let mut vals = key_vals;
+
+        if let Some(agg) = &agg {
+            let (agg_cols, agg_vals) = compute_aggregates(agg, &rows_in_group, span)?;
+            cols.extend(agg_cols);
+            vals.extend(agg_vals);
+        } else {
+            cols.push("items".to_string());
+            vals.push(Value::List {
+                vals: rows_in_group,
+                span,
+            });
+        }
+
+        rows.push(Value::Record { cols, vals, span });
+    }
+
+    Ok(PipelineData::Value(Value::List { vals: rows, span }, None))
+}
+
+/// Expands the `--agg` shorthand record (e.g. `{count: null, sum: price}`)
+/// into output column names and their computed values for one group. Each
+/// key names an aggregate operation; its value names the column to
+/// aggregate, except for `count`, whose value is ignored.
+fn compute_aggregates(
+    agg: &Value,
+    rows: &[Value],
+    span: Span,
+) -> Result<(Vec<String>, Vec<Value>), ShellError> {
+    let (cols, vals) = match agg {
+        Value::Record { cols, vals, .. } => (cols, vals),
+        _ => {
+            return Err(ShellError::TypeMismatch {
+                err_message: "expected a record, e.g. {count: null, sum: price}".into(),
+                span: agg.expect_span(),
+            })
+        }
+    };
+
+    let mut out_cols = Vec::with_capacity(cols.len());
+    let mut out_vals = Vec::with_capacity(cols.len());
+
+    for (op, column) in cols.iter().zip(vals.iter()) {
+        let value = match op.as_str() {
+            "count" => Value::int(rows.len() as i64, span),
+            "sum" => numeric_aggregate(&column.as_string()?, rows, span, NumericOp::Sum)?,
+            "avg" => numeric_aggregate(&column.as_string()?, rows, span, NumericOp::Avg)?,
+            "min" => extremum(&column.as_string()?, rows, span, Ordering::Less)?,
+            "max" => extremum(&column.as_string()?, rows, span, Ordering::Greater)?,
+            other => {
+                return Err(ShellError::TypeMismatch {
+                    err_message: format!(
+                        "unsupported --agg operation `{other}`, expected one of: count, sum, avg, min, max"
+                    ),
+                    span: agg.expect_span(),
+                })
+            }
+        };
+
+        out_cols.push(op.clone());
+        out_vals.push(value);
+    }
+
+    Ok((out_cols, out_vals))
+}
+
+enum NumericOp {
+    Sum,
+    Avg,
+}
+
+fn numeric_aggregate(
+    column: &str,
+    rows: &[Value],
+    span: Span,
+    op: NumericOp,
+) -> Result<Value, ShellError> {
+    let mut total = 0.0;
+    let mut count = 0;
+
+    for row in rows {
+        if let Some(value) = row.get_data_by_key(column) {
+            total += value.as_float()?;
+            count += 1;
+        }
+    }
+
+    match op {
+        NumericOp::Sum => Ok(Value::float(total, span)),
+        NumericOp::Avg => {
+            let avg = if count > 0 { total / count as f64 } else { 0.0 };
+            Ok(Value::float(avg, span))
+        }
+    }
+}
+
+fn extremum(column: &str, rows: &[Value], span: Span, want: Ordering) -> Result<Value, ShellError> {
+    let mut best: Option<Value> = None;
+
+    for row in rows {
+        if let Some(value) = row.get_data_by_key(column) {
+            best = match best {
+                None => Some(value),
+                Some(current) => match value.partial_cmp(&current) {
+                    Some(ordering) if ordering == want => Some(value),
+                    _ => Some(current),
+                },
+            };
+        }
+    }
+
+    Ok(best.unwrap_or_else(|| Value::nothing(span)))
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
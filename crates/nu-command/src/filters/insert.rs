@@ -1,3 +1,4 @@
+use super::multi_cell_path::record_to_path_pairs;
 use nu_engine::{eval_block, CallExt};
 use nu_protocol::ast::{Call, CellPath, PathMember};
 use nu_protocol::engine::{Closure, Command, EngineState, Stack};
@@ -22,13 +23,13 @@ impl Command for Insert {
             ])
             .required(
                 "field",
-                SyntaxShape::CellPath,
-                "the name of the column to insert",
+                SyntaxShape::OneOf(vec![SyntaxShape::CellPath, SyntaxShape::Record]),
+                "the name of the column to insert, or a record of column: value/closure pairs to insert several columns in one pass",
             )
-            .required(
+            .optional(
                 "new value",
                 SyntaxShape::Any,
-                "the new value to give the cell(s)",
+                "the new value to give the cell(s) (omitted when `field` is a record)",
             )
             .category(Category::Filters)
     }
@@ -37,6 +38,12 @@ impl Command for Insert {
         "Insert a new column, using an expression or closure to create each row's values."
     }
 
+    fn extra_usage(&self) -> &str {
+        "When `field` is a record, every column it names is inserted in the same pass over the \
+input, instead of running `insert` once per column. Each column's value or closure is \
+evaluated against the row as it was before any of the record's insertions were applied."
+    }
+
     fn search_terms(&self) -> Vec<&str> {
         vec!["add"]
     }
@@ -102,6 +109,19 @@ impl Command for Insert {
                 }],
                 span: Span::test_data(),
             }),
+        },
+        Example {
+            description: "Insert several columns into a single record in one pass",
+            example: "{'name': 'nu'} | insert {stars: 5, lang: 'Rust'}",
+            result: Some(Value::Record {
+                cols: vec!["name".into(), "stars".into(), "lang".into()],
+                vals: vec![
+                    Value::test_string("nu"),
+                    Value::test_int(5),
+                    Value::test_string("Rust"),
+                ],
+                span: Span::test_data(),
+            }),
         }]
     }
 }
@@ -114,8 +134,20 @@ fn insert(
 ) -> Result<PipelineData, ShellError> {
     let span = call.head;
 
-    let cell_path: CellPath = call.req(engine_state, stack, 0)?;
-    let replacement: Value = call.req(engine_state, stack, 1)?;
+    let field: Value = call.req(engine_state, stack, 0)?;
+
+    if let Value::Record { .. } = &field {
+        let pairs = record_to_path_pairs(&field)?;
+        return insert_many(engine_state, stack, call, input, pairs);
+    }
+
+    let cell_path: CellPath = FromValue::from_value(&field)?;
+    let replacement: Value =
+        call.opt(engine_state, stack, 1)?
+            .ok_or(ShellError::MissingParameter {
+                param_name: "new value".into(),
+                span,
+            })?;
 
     let redirect_stdout = call.redirect_stdout;
     let redirect_stderr = call.redirect_stderr;
@@ -209,6 +241,77 @@ fn insert(
     }
 }
 
+/// Apply every `(cell_path, replacement)` pair to each row in a single pass over `input`,
+/// instead of running `insert` once per pair. Each pair's value or closure is evaluated
+/// against the row as it looked before any of the other pairs in this call were applied.
+fn insert_many(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+    input: PipelineData,
+    pairs: Vec<(CellPath, Value)>,
+) -> Result<PipelineData, ShellError> {
+    let span = call.head;
+    let redirect_stdout = call.redirect_stdout;
+    let redirect_stderr = call.redirect_stderr;
+
+    let engine_state = engine_state.clone();
+    let ctrlc = engine_state.ctrlc.clone();
+    let stack = stack.clone();
+
+    input.map(
+        move |mut item| {
+            let original = item.clone();
+
+            for (cell_path, replacement) in &pairs {
+                let new_value = if replacement.as_block().is_ok() {
+                    let capture_block: Closure = match FromValue::from_value(replacement) {
+                        Ok(block) => block,
+                        Err(e) => return Value::Error { error: e },
+                    };
+                    let block = engine_state.get_block(capture_block.block_id).clone();
+
+                    let mut closure_stack = stack.captures_to_stack(&capture_block.captures);
+                    if let Some(var) = block.signature.get_positional(0) {
+                        if let Some(var_id) = &var.var_id {
+                            closure_stack.add_var(*var_id, original.clone())
+                        }
+                    }
+
+                    let output = eval_block(
+                        &engine_state,
+                        &mut closure_stack,
+                        &block,
+                        original.clone().into_pipeline_data(),
+                        redirect_stdout,
+                        redirect_stderr,
+                    );
+
+                    match output {
+                        Ok(pd) => {
+                            let value_span = pd.span().unwrap_or(span);
+                            pd.into_value(value_span)
+                        }
+                        Err(e) => return Value::Error { error: e },
+                    }
+                } else {
+                    replacement.clone()
+                };
+
+                let new_value_span = new_value.span().unwrap_or(span);
+                if let Err(e) =
+                    item.insert_data_at_cell_path(&cell_path.members, new_value, new_value_span)
+                {
+                    return Value::Error { error: e };
+                }
+            }
+
+            item
+        },
+        ctrlc,
+    )
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
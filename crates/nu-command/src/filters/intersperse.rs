@@ -0,0 +1,145 @@
+use nu_engine::{eval_block_with_early_return, CallExt};
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Closure, Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
+    SyntaxShape, Type, Value,
+};
+use std::collections::VecDeque;
+
+#[derive(Clone)]
+pub struct Intersperse;
+
+impl Command for Intersperse {
+    fn name(&self) -> &str {
+        "intersperse"
+    }
+
+    fn usage(&self) -> &str {
+        "Insert a computed boundary value between each pair of adjacent elements in a stream."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"The closure is called once per boundary with the element before it and the element
+after it, and its return value is inserted between them. Nothing is inserted before the
+first element or after the last one, and the input stays streaming the whole way through."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["interpose", "join", "separator"]
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("intersperse")
+            .input_output_types(vec![
+                (
+                    Type::List(Box::new(Type::Any)),
+                    Type::List(Box::new(Type::Any)),
+                ),
+                (Type::Table(vec![]), Type::List(Box::new(Type::Any))),
+            ])
+            .required(
+                "closure",
+                SyntaxShape::Closure(Some(vec![SyntaxShape::Any, SyntaxShape::Any])),
+                "closure taking the previous and next element, returning the value to insert between them",
+            )
+            .category(Category::Filters)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            example: "[1 2 3] | intersperse {|prev, next| $prev + $next }",
+            description: "Insert the sum of each adjacent pair between them",
+            result: Some(Value::List {
+                vals: vec![
+                    Value::test_int(1),
+                    Value::test_int(3),
+                    Value::test_int(2),
+                    Value::test_int(5),
+                    Value::test_int(3),
+                ],
+                span: Span::test_data(),
+            }),
+        }]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+
+        let capture_block: Closure = call.req(engine_state, stack, 0)?;
+        let mut stack = stack.captures_to_stack(&capture_block.captures);
+        let block = engine_state.get_block(capture_block.block_id).clone();
+        let ctrlc = engine_state.ctrlc.clone();
+        let engine_state = engine_state.clone();
+
+        let orig_env_vars = stack.env_vars.clone();
+        let orig_env_hidden = stack.env_hidden.clone();
+
+        let redirect_stdout = call.redirect_stdout;
+        let redirect_stderr = call.redirect_stderr;
+
+        let mut input_iter = input.into_iter();
+        let mut prev: Option<Value> = None;
+        let mut queue: VecDeque<Value> = VecDeque::new();
+
+        let iter = std::iter::from_fn(move || {
+            if let Some(queued) = queue.pop_front() {
+                return Some(queued);
+            }
+
+            let next_item = input_iter.next()?;
+
+            let Some(prev_item) = prev.replace(next_item.clone()) else {
+                return Some(next_item);
+            };
+
+            stack.with_env(&orig_env_vars, &orig_env_hidden);
+
+            if let Some(var) = block.signature.get_positional(0) {
+                if let Some(var_id) = &var.var_id {
+                    stack.add_var(*var_id, prev_item);
+                }
+            }
+            if let Some(var) = block.signature.get_positional(1) {
+                if let Some(var_id) = &var.var_id {
+                    stack.add_var(*var_id, next_item.clone());
+                }
+            }
+
+            let separator = match eval_block_with_early_return(
+                &engine_state,
+                &mut stack,
+                &block,
+                PipelineData::empty(),
+                redirect_stdout,
+                redirect_stderr,
+            ) {
+                Ok(pipeline_data) => pipeline_data.into_value(span),
+                Err(err) => Value::Error { error: err },
+            };
+
+            queue.push_back(next_item);
+            Some(separator)
+        });
+
+        Ok(iter.into_pipeline_data(ctrlc))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(Intersperse {})
+    }
+}
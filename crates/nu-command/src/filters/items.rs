@@ -0,0 +1,133 @@
+use nu_engine::{eval_block_with_early_return, CallExt};
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Closure, Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoInterruptiblePipelineData, IntoPipelineData, PipelineData, ShellError,
+    Signature, Span, SyntaxShape, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct Items;
+
+impl Command for Items {
+    fn name(&self) -> &str {
+        "items"
+    }
+
+    fn usage(&self) -> &str {
+        "Given a record, iterate on each pair of column name and value."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "This is the record equivalent of `each`, avoiding the need to `transpose` a record \
+into a table before iterating over it."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["for", "loop", "iterate", "map"]
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("items")
+            .input_output_types(vec![(
+                Type::Record(vec![]),
+                Type::List(Box::new(Type::Any)),
+            )])
+            .required(
+                "closure",
+                SyntaxShape::Closure(Some(vec![SyntaxShape::Any, SyntaxShape::Any])),
+                "the closure to run",
+            )
+            .category(Category::Filters)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            example: r#"{new: york, san: francisco} | items {|key, value| $"($key) ($value)" }"#,
+            description: "Iterate over each key-value pair of a record",
+            result: Some(Value::List {
+                vals: vec![
+                    Value::test_string("new york"),
+                    Value::test_string("san francisco"),
+                ],
+                span: Span::test_data(),
+            }),
+        }]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let capture_block: Closure = call.req(engine_state, stack, 0)?;
+        let metadata = input.metadata();
+        let ctrlc = engine_state.ctrlc.clone();
+        let span = call.head;
+        let redirect_stdout = call.redirect_stdout;
+        let redirect_stderr = call.redirect_stderr;
+
+        let (cols, vals) = match input.into_value(span) {
+            Value::Record { cols, vals, .. } => (cols, vals),
+            other => {
+                return Err(ShellError::UnsupportedInput(
+                    "items requires a record".to_string(),
+                    "value originates from here".to_string(),
+                    span,
+                    other.expect_span(),
+                ))
+            }
+        };
+
+        let engine_state = engine_state.clone();
+        let block = engine_state.get_block(capture_block.block_id).clone();
+        let mut stack = stack.captures_to_stack(&capture_block.captures);
+
+        let key_var_id = block.signature.get_positional(0).and_then(|v| v.var_id);
+        let value_var_id = block.signature.get_positional(1).and_then(|v| v.var_id);
+
+        let results = cols
+            .into_iter()
+            .zip(vals)
+            .map(|(col, val)| {
+                let key = Value::String { val: col, span };
+
+                if let Some(var_id) = key_var_id {
+                    stack.add_var(var_id, key);
+                }
+                if let Some(var_id) = value_var_id {
+                    stack.add_var(var_id, val);
+                }
+
+                eval_block_with_early_return(
+                    &engine_state,
+                    &mut stack,
+                    &block,
+                    PipelineData::Empty,
+                    redirect_stdout,
+                    redirect_stderr,
+                )
+                .map(|data| data.into_value(span))
+            })
+            .collect::<Result<Vec<Value>, ShellError>>()?;
+
+        Ok(results
+            .into_iter()
+            .into_pipeline_data(ctrlc)
+            .set_metadata(metadata))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(Items {})
+    }
+}
@@ -23,7 +23,12 @@ impl Command for Merge {
 
 When merging tables, row 0 of the input table is overwritten
 with values from row 0 of the provided table, then
-repeating this process with row 1, and so on."#
+repeating this process with row 1, and so on.
+
+With --deep, nested records are merged recursively instead of the inner record simply
+being overwritten. Nested lists are combined according to --list-strategy: "append"
+(the default) concatenates them, "replace" keeps only the incoming list, and "index"
+merges them element by element, keeping the longer list's extra elements."#
     }
 
     fn signature(&self) -> nu_protocol::Signature {
@@ -38,6 +43,17 @@ repeating this process with row 1, and so on."#
                 SyntaxShape::Any,
                 "the new value to merge with",
             )
+            .switch(
+                "deep",
+                "merge nested records recursively instead of overwriting them",
+                Some('d'),
+            )
+            .named(
+                "list-strategy",
+                SyntaxShape::String,
+                "with --deep, how to combine overlapping lists: append (default), replace, or index",
+                None,
+            )
             .category(Category::Filters)
     }
 
@@ -84,6 +100,18 @@ repeating this process with row 1, and so on."#
                     span: Span::test_data(),
                 }),
             },
+            Example {
+                example: "{a: {x: 1, y: 2}} | merge --deep {a: {y: 3, z: 4}}",
+                description: "Recursively merge nested records instead of overwriting them",
+                result: Some(Value::Record {
+                    cols: vec!["a".to_string()],
+                    vals: vec![Value::test_record(
+                        vec!["x", "y", "z"],
+                        vec![Value::test_int(1), Value::test_int(3), Value::test_int(4)],
+                    )],
+                    span: Span::test_data(),
+                }),
+            },
         ]
     }
 
@@ -95,6 +123,24 @@ repeating this process with row 1, and so on."#
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
         let merge_value: Value = call.req(engine_state, stack, 0)?;
+        let deep = call.has_flag("deep");
+        let list_strategy_flag: Option<String> =
+            call.get_flag(engine_state, stack, "list-strategy")?;
+        let list_strategy = match list_strategy_flag.as_deref() {
+            None | Some("append") => ListStrategy::Append,
+            Some("replace") => ListStrategy::Replace,
+            Some("index") => ListStrategy::Index,
+            Some(other) => {
+                return Err(ShellError::UnsupportedInput(
+                    format!(
+                        "'{other}' is not a valid list strategy, expected append, replace, or index"
+                    ),
+                    "value originates from here".into(),
+                    call.head,
+                    call.head,
+                ))
+            }
+        };
 
         let metadata = input.metadata();
         let ctrlc = engine_state.ctrlc.clone();
@@ -118,6 +164,8 @@ repeating this process with row 1, and so on."#
                                         let (cols, vals) = do_merge(
                                             (inp_cols.to_vec(), inp_vals.to_vec()),
                                             (to_merge_cols.to_vec(), to_merge_vals.to_vec()),
+                                            deep,
+                                            list_strategy,
                                         );
                                         Value::Record {
                                             cols,
@@ -157,6 +205,8 @@ repeating this process with row 1, and so on."#
                 let (cols, vals) = do_merge(
                     (inp_cols.to_vec(), inp_vals.to_vec()),
                     (to_merge_cols.to_vec(), to_merge_vals.to_vec()),
+                    deep,
+                    list_strategy,
                 );
                 Ok(Value::Record {
                     cols,
@@ -190,9 +240,18 @@ repeating this process with row 1, and so on."#
     }
 }
 
+#[derive(Clone, Copy)]
+enum ListStrategy {
+    Append,
+    Replace,
+    Index,
+}
+
 fn do_merge(
     input_record: (Vec<String>, Vec<Value>),
     to_merge_record: (Vec<String>, Vec<Value>),
+    deep: bool,
+    list_strategy: ListStrategy,
 ) -> (Vec<String>, Vec<Value>) {
     let (mut result_cols, mut result_vals) = input_record;
     let (to_merge_cols, to_merge_vals) = to_merge_record;
@@ -202,7 +261,11 @@ fn do_merge(
         // if find, replace existing data, else, push new data.
         match pos {
             Some(index) => {
-                result_vals[index] = val;
+                result_vals[index] = if deep {
+                    merge_value(result_vals[index].clone(), val, list_strategy)
+                } else {
+                    val
+                };
             }
             None => {
                 result_cols.push(col);
@@ -213,6 +276,45 @@ fn do_merge(
     (result_cols, result_vals)
 }
 
+fn merge_value(existing: Value, incoming: Value, list_strategy: ListStrategy) -> Value {
+    match (existing, incoming) {
+        (
+            Value::Record {
+                cols: a_cols,
+                vals: a_vals,
+                span,
+            },
+            Value::Record {
+                cols: b_cols,
+                vals: b_vals,
+                ..
+            },
+        ) => {
+            let (cols, vals) = do_merge((a_cols, a_vals), (b_cols, b_vals), true, list_strategy);
+            Value::Record { cols, vals, span }
+        }
+        (Value::List { vals: a_vals, span }, Value::List { vals: b_vals, .. }) => {
+            let vals = match list_strategy {
+                ListStrategy::Replace => b_vals,
+                ListStrategy::Append => a_vals.into_iter().chain(b_vals).collect(),
+                ListStrategy::Index => {
+                    let len = a_vals.len().max(b_vals.len());
+                    (0..len)
+                        .map(|i| match (a_vals.get(i), b_vals.get(i)) {
+                            (Some(a), Some(b)) => merge_value(a.clone(), b.clone(), list_strategy),
+                            (Some(a), None) => a.clone(),
+                            (None, Some(b)) => b.clone(),
+                            (None, None) => Value::nothing(span),
+                        })
+                        .collect()
+                }
+            };
+            Value::List { vals, span }
+        }
+        (_, incoming) => incoming,
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
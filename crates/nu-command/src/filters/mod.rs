@@ -1,7 +1,9 @@
 mod all;
 mod any;
 mod append;
+mod chunk_by;
 mod collect;
+mod column_match;
 mod columns;
 mod compact;
 mod default;
@@ -11,20 +13,27 @@ mod each_while;
 mod empty;
 mod enumerate;
 mod every;
+mod except_;
 mod filter;
 mod find;
 mod first;
 mod flatten;
+mod generate;
 mod get;
 mod group;
 mod group_by;
 mod headers;
 mod insert;
+mod intersect;
+mod intersperse;
+mod items;
 mod last;
 mod length;
 mod lines;
 mod merge;
 mod move_;
+mod multi_cell_path;
+mod not_empty;
 mod par_each;
 mod prepend;
 mod range;
@@ -34,7 +43,9 @@ mod rename;
 mod reverse;
 mod roll;
 mod rotate;
+mod scan;
 mod select;
+mod set_ops;
 mod shuffle;
 mod skip;
 mod sort;
@@ -42,12 +53,14 @@ mod sort_by;
 mod split_by;
 mod take;
 mod transpose;
+mod union;
 mod uniq;
 mod uniq_by;
 mod update;
 mod update_cells;
 mod upsert;
 mod utils;
+mod validate;
 mod values;
 mod where_;
 mod window;
@@ -57,6 +70,7 @@ mod zip;
 pub use all::All;
 pub use any::Any;
 pub use append::Append;
+pub use chunk_by::ChunkBy;
 pub use collect::Collect;
 pub use columns::Columns;
 pub use compact::Compact;
@@ -67,20 +81,26 @@ pub use each_while::EachWhile;
 pub use empty::Empty;
 pub use enumerate::Enumerate;
 pub use every::Every;
+pub use except_::Except;
 pub use filter::Filter;
 pub use find::Find;
 pub use first::First;
 pub use flatten::Flatten;
+pub use generate::Generate;
 pub use get::Get;
 pub use group::Group;
 pub use group_by::GroupBy;
 pub use headers::Headers;
 pub use insert::Insert;
+pub use intersect::Intersect;
+pub use intersperse::Intersperse;
+pub use items::Items;
 pub use last::Last;
 pub use length::Length;
 pub use lines::Lines;
 pub use merge::Merge;
 pub use move_::Move;
+pub use not_empty::NotEmpty;
 pub use par_each::ParEach;
 pub use prepend::Prepend;
 pub use range::Range;
@@ -90,6 +110,7 @@ pub use rename::Rename;
 pub use reverse::Reverse;
 pub use roll::*;
 pub use rotate::Rotate;
+pub use scan::Scan;
 pub use select::Select;
 pub use shuffle::Shuffle;
 pub use skip::*;
@@ -98,11 +119,13 @@ pub use sort_by::SortBy;
 pub use split_by::SplitBy;
 pub use take::*;
 pub use transpose::Transpose;
+pub use union::Union;
 pub use uniq::*;
 pub use uniq_by::UniqBy;
 pub use update::Update;
 pub use update_cells::UpdateCells;
 pub use upsert::Upsert;
+pub use validate::Validate;
 pub use values::Values;
 pub use where_::Where;
 pub use window::Window;
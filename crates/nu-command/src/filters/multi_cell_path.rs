@@ -0,0 +1,35 @@
+use nu_protocol::ast::{CellPath, PathMember};
+use nu_protocol::{ShellError, Span, Value};
+
+/// Split a record key like `b.c` into the `CellPath` it denotes, so `update`/`insert`/`upsert`
+/// can accept a record of `path: value` pairs in place of their usual single `field` argument.
+/// Every member is treated as a string lookup; there's no syntax here for an int index inside a
+/// dotted key, since record keys are always strings to begin with.
+pub(crate) fn cell_path_from_key(key: &str, span: Span) -> CellPath {
+    CellPath {
+        members: key
+            .split('.')
+            .map(|part| PathMember::String {
+                val: part.to_string(),
+                span,
+            })
+            .collect(),
+    }
+}
+
+/// Turn a `field` argument that came in as a record (rather than the usual single cell path)
+/// into the list of `(path, value-or-closure)` pairs to apply in one traversal of the input.
+pub(crate) fn record_to_path_pairs(record: &Value) -> Result<Vec<(CellPath, Value)>, ShellError> {
+    let span = record.span()?;
+    match record {
+        Value::Record { cols, vals, .. } => Ok(cols
+            .iter()
+            .zip(vals.iter())
+            .map(|(col, val)| (cell_path_from_key(col, span), val.clone()))
+            .collect()),
+        other => Err(ShellError::TypeMismatch {
+            err_message: "expected a cell path or a record of cell path: value pairs".into(),
+            span: other.span()?,
+        }),
+    }
+}
@@ -0,0 +1,82 @@
+use super::empty::check_emptiness;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, PipelineData, ShellError, Signature, SyntaxShape, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct NotEmpty;
+
+impl Command for NotEmpty {
+    fn name(&self) -> &str {
+        "is-not-empty"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("is-not-empty")
+            .input_output_types(vec![(Type::Any, Type::Bool)])
+            .rest(
+                "rest",
+                SyntaxShape::CellPath,
+                "the names of the columns to check emptiness",
+            )
+            .switch(
+                "whitespace",
+                "also treat strings made up entirely of whitespace as empty",
+                Some('w'),
+            )
+            .category(Category::Filters)
+    }
+
+    fn usage(&self) -> &str {
+        "Check for non-empty values."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "The exact opposite of is-empty, sharing the same deep emptiness rules for records and \
+lists - see `help is-empty` for details."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        check_emptiness(engine_state, stack, call, input, false)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Check if a string is not empty",
+                example: "'hello' | is-not-empty",
+                result: Some(Value::test_bool(true)),
+            },
+            Example {
+                description: "Check if a list is not empty",
+                example: "[1 2 3] | is-not-empty",
+                result: Some(Value::test_bool(true)),
+            },
+            Example {
+                description: "Check that none of the given columns are empty",
+                example: "[[meal size]; [arepa small]] | is-not-empty meal size",
+                result: Some(Value::test_bool(true)),
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(NotEmpty {})
+    }
+}
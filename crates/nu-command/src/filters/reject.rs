@@ -1,5 +1,6 @@
+use super::column_match::{columns_of_type, expand_glob, schema_columns};
 use nu_engine::CallExt;
-use nu_protocol::ast::{Call, CellPath};
+use nu_protocol::ast::{Call, CellPath, PathMember};
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
     Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, SyntaxShape,
@@ -25,6 +26,12 @@ impl Command for Reject {
                 SyntaxShape::CellPath,
                 "the names of columns to remove from the table",
             )
+            .named(
+                "type",
+                SyntaxShape::String,
+                "also remove every top-level column whose value has this type (e.g. 'int', 'string', 'date')",
+                None,
+            )
             .category(Category::Filters)
     }
 
@@ -33,7 +40,11 @@ impl Command for Reject {
     }
 
     fn extra_usage(&self) -> &str {
-        "To remove a quantity of rows or columns, use `skip`, `drop`, or `drop column`."
+        "To remove a quantity of rows or columns, use `skip`, `drop`, or `drop column`.
+
+A single-segment column name may contain `*`/`?` glob characters (e.g. `reject col_*`), which
+are expanded against the columns of the first input row before rejecting. `--type` rejects
+every column whose value's type matches, in addition to any columns named directly."
     }
 
     fn run(
@@ -43,8 +54,59 @@ impl Command for Reject {
         call: &Call,
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
-        let columns: Vec<CellPath> = call.rest(engine_state, stack, 0)?;
+        let mut columns: Vec<CellPath> = call.rest(engine_state, stack, 0)?;
         let span = call.head;
+        let type_filter: Option<String> = call.get_flag(engine_state, stack, "type")?;
+
+        let has_glob = columns.iter().any(|c| match c.members.first() {
+            Some(PathMember::String { val, .. }) => val.contains('*') || val.contains('?'),
+            _ => false,
+        });
+
+        let input = if has_glob || type_filter.is_some() {
+            let value = input.into_value(span);
+            let schema = schema_columns(&value);
+
+            let mut expanded = vec![];
+            for cell_path in columns {
+                match cell_path.members.first() {
+                    Some(PathMember::String {
+                        val,
+                        span: member_span,
+                    }) if val.contains('*') || val.contains('?') => {
+                        for name in expand_glob(val, &schema) {
+                            let mut members = cell_path.members.clone();
+                            members[0] = PathMember::String {
+                                val: name,
+                                span: *member_span,
+                            };
+                            expanded.push(CellPath { members });
+                        }
+                    }
+                    _ => expanded.push(cell_path),
+                }
+            }
+            columns = expanded;
+
+            if let Some(type_name) = &type_filter {
+                let sample = match &value {
+                    Value::List { vals, .. } => {
+                        vals.first().cloned().unwrap_or_else(|| value.clone())
+                    }
+                    other => other.clone(),
+                };
+                for name in columns_of_type(&schema, &sample, type_name) {
+                    columns.push(CellPath {
+                        members: vec![PathMember::String { val: name, span }],
+                    });
+                }
+            }
+
+            value.into_pipeline_data()
+        } else {
+            input
+        };
+
         reject(engine_state, span, input, columns)
     }
 
@@ -89,6 +151,16 @@ impl Command for Reject {
                     span: Span::test_data(),
                 }),
             },
+            Example {
+                description: "Reject every column whose name starts with 'size'",
+                example: "ls | reject size_*",
+                result: None,
+            },
+            Example {
+                description: "Reject every column holding a date",
+                example: "ls | reject --type date",
+                result: None,
+            },
         ]
     }
 }
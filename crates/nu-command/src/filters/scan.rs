@@ -0,0 +1,164 @@
+use nu_engine::{eval_block_with_early_return, CallExt};
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Closure, Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
+    SyntaxShape, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct Scan;
+
+impl Command for Scan {
+    fn name(&self) -> &str {
+        "scan"
+    }
+
+    fn usage(&self) -> &str {
+        "Aggregate a list, table or range to a stream of intermediate accumulator values."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Like `reduce`, but instead of returning only the final accumulator, emits every
+intermediate value as it's produced - one per input element - which keeps the whole
+operation streaming instead of forcing a `collect`. Use `--noinit` to drop the leading,
+unmodified initial value from the output."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["reduce", "fold", "running"]
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("scan")
+            .input_output_types(vec![
+                (
+                    Type::List(Box::new(Type::Any)),
+                    Type::List(Box::new(Type::Any)),
+                ),
+                (Type::Table(vec![]), Type::List(Box::new(Type::Any))),
+            ])
+            .required(
+                "initial",
+                SyntaxShape::Any,
+                "initial value for the accumulator",
+            )
+            .required(
+                "closure",
+                SyntaxShape::Closure(Some(vec![SyntaxShape::Any, SyntaxShape::Any])),
+                "accumulator closure taking current accumulator and next element",
+            )
+            .switch("noinit", "do not emit the initial value", None)
+            .category(Category::Filters)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                example: "[ 1 2 3 ] | scan 0 {|acc, it| $acc + $it }",
+                description: "Running sum of a list, including the initial value",
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::test_int(0),
+                        Value::test_int(1),
+                        Value::test_int(3),
+                        Value::test_int(6),
+                    ],
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                example: "[ 1 2 3 ] | scan 0 {|acc, it| $acc + $it } --noinit",
+                description: "Running sum of a list, without the initial value",
+                result: Some(Value::List {
+                    vals: vec![Value::test_int(1), Value::test_int(3), Value::test_int(6)],
+                    span: Span::test_data(),
+                }),
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+
+        let initial: Value = call.req(engine_state, stack, 0)?;
+        let capture_block: Closure = call.req(engine_state, stack, 1)?;
+        let noinit = call.has_flag("noinit");
+
+        let mut stack = stack.captures_to_stack(&capture_block.captures);
+        let block = engine_state.get_block(capture_block.block_id).clone();
+        let ctrlc = engine_state.ctrlc.clone();
+        let engine_state = engine_state.clone();
+
+        let orig_env_vars = stack.env_vars.clone();
+        let orig_env_hidden = stack.env_hidden.clone();
+
+        let redirect_stdout = call.redirect_stdout;
+        let redirect_stderr = call.redirect_stderr;
+
+        let mut acc = Some(initial);
+        let mut input_iter = input.into_iter();
+        let mut emitted_init = false;
+
+        let iter = std::iter::from_fn(move || {
+            if !noinit && !emitted_init {
+                emitted_init = true;
+                return acc.clone();
+            }
+
+            let cur_acc = acc.take()?;
+            let x = input_iter.next()?;
+
+            stack.with_env(&orig_env_vars, &orig_env_hidden);
+
+            if let Some(var) = block.signature.get_positional(0) {
+                if let Some(var_id) = &var.var_id {
+                    stack.add_var(*var_id, cur_acc);
+                }
+            }
+            if let Some(var) = block.signature.get_positional(1) {
+                if let Some(var_id) = &var.var_id {
+                    stack.add_var(*var_id, x);
+                }
+            }
+
+            let result = eval_block_with_early_return(
+                &engine_state,
+                &mut stack,
+                &block,
+                PipelineData::empty(),
+                redirect_stdout,
+                redirect_stderr,
+            );
+
+            match result {
+                Ok(pipeline_data) => {
+                    let next_acc = pipeline_data.into_value(span);
+                    acc = Some(next_acc.clone());
+                    Some(next_acc)
+                }
+                Err(err) => Some(Value::Error { error: err }),
+            }
+        });
+
+        Ok(iter.into_pipeline_data(ctrlc))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(Scan {})
+    }
+}
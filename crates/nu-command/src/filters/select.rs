@@ -1,3 +1,4 @@
+use super::column_match::{columns_of_type, expand_glob, schema_columns};
 use nu_engine::CallExt;
 use nu_protocol::ast::{Call, CellPath, PathMember};
 use nu_protocol::engine::{Command, EngineState, Stack};
@@ -32,6 +33,12 @@ impl Command for Select {
                 SyntaxShape::CellPath,
                 "the columns to select from the table",
             )
+            .named(
+                "type",
+                SyntaxShape::String,
+                "also select every top-level column whose value has this type (e.g. 'int', 'string', 'date')",
+                None,
+            )
             .category(Category::Filters)
     }
 
@@ -42,7 +49,11 @@ impl Command for Select {
     fn extra_usage(&self) -> &str {
         r#"This differs from `get` in that, rather than accessing the given value in the data structure,
 it removes all non-selected values from the structure. Hence, using `select` on a table will
-produce a table, a list will produce a list, and a record will produce a record."#
+produce a table, a list will produce a list, and a record will produce a record.
+
+A single-segment column name may contain `*`/`?` glob characters (e.g. `select col_*`), which
+are expanded against the columns of the first input row before selecting. `--type` selects
+every column whose value's type matches, in addition to any columns named directly."#
     }
 
     fn search_terms(&self) -> Vec<&str> {
@@ -56,9 +67,59 @@ produce a table, a list will produce a list, and a record will produce a record.
         call: &Call,
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
-        let columns: Vec<CellPath> = call.rest(engine_state, stack, 0)?;
+        let mut columns: Vec<CellPath> = call.rest(engine_state, stack, 0)?;
         let span = call.head;
         let ignore_errors = call.has_flag("ignore-errors");
+        let type_filter: Option<String> = call.get_flag(engine_state, stack, "type")?;
+
+        let has_glob = columns.iter().any(|c| match c.members.first() {
+            Some(PathMember::String { val, .. }) => val.contains('*') || val.contains('?'),
+            _ => false,
+        });
+
+        let input = if has_glob || type_filter.is_some() {
+            let value = input.into_value(span);
+            let schema = schema_columns(&value);
+
+            let mut expanded = vec![];
+            for cell_path in columns {
+                match cell_path.members.first() {
+                    Some(PathMember::String {
+                        val,
+                        span: member_span,
+                    }) if val.contains('*') || val.contains('?') => {
+                        for name in expand_glob(val, &schema) {
+                            let mut members = cell_path.members.clone();
+                            members[0] = PathMember::String {
+                                val: name,
+                                span: *member_span,
+                            };
+                            expanded.push(CellPath { members });
+                        }
+                    }
+                    _ => expanded.push(cell_path),
+                }
+            }
+            columns = expanded;
+
+            if let Some(type_name) = &type_filter {
+                let sample = match &value {
+                    Value::List { vals, .. } => {
+                        vals.first().cloned().unwrap_or_else(|| value.clone())
+                    }
+                    other => other.clone(),
+                };
+                for name in columns_of_type(&schema, &sample, type_name) {
+                    columns.push(CellPath {
+                        members: vec![PathMember::String { val: name, span }],
+                    });
+                }
+            }
+
+            value.into_pipeline_data()
+        } else {
+            input
+        };
 
         select(engine_state, span, columns, input, ignore_errors)
     }
@@ -88,6 +149,16 @@ produce a table, a list will produce a list, and a record will produce a record.
                 example: "ls | select 0 1 2 3",
                 result: None,
             },
+            Example {
+                description: "Select every column whose name starts with 'size'",
+                example: "ls | select size_*",
+                result: None,
+            },
+            Example {
+                description: "Select every column holding a date",
+                example: "ls | select --type date",
+                result: None,
+            },
         ]
     }
 }
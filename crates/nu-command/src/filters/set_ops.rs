@@ -0,0 +1,45 @@
+use crate::formats::value_to_string;
+use nu_protocol::{ShellError, Span, Value};
+use std::collections::HashSet;
+
+/// Turn a bare value into the single-element list it would have been compared against
+/// had it been a list all along, so `intersect`/`union`/`except` can take either a list
+/// or a single value as their "other" argument.
+pub(super) fn as_rows(value: Value) -> Vec<Value> {
+    match value {
+        Value::List { vals, .. } => vals,
+        other => vec![other],
+    }
+}
+
+/// The hashable key a value is compared by: the whole (span-erased) value, or just the
+/// given column(s) when present.
+pub(super) fn key_for(value: &Value, columns: &[String], span: Span) -> Result<String, ShellError> {
+    if columns.is_empty() {
+        value_to_string(&value.clone().with_span(Span::unknown()), span)
+    } else {
+        let selected: Vec<Value> = columns
+            .iter()
+            .map(|col| {
+                value
+                    .get_data_by_key(col)
+                    .unwrap_or_else(|| Value::nothing(span))
+            })
+            .collect();
+        value_to_string(
+            &Value::List {
+                vals: selected,
+                span,
+            },
+            span,
+        )
+    }
+}
+
+pub(super) fn key_set(
+    values: &[Value],
+    columns: &[String],
+    span: Span,
+) -> Result<HashSet<String>, ShellError> {
+    values.iter().map(|v| key_for(v, columns, span)).collect()
+}
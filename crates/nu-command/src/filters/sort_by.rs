@@ -2,9 +2,13 @@ use nu_engine::CallExt;
 use nu_protocol::{
     ast::Call,
     engine::{Command, EngineState, Stack},
-    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
-    SyntaxShape, Type, Value,
+    Category, Example, IntoInterruptiblePipelineData, IntoPipelineData, PipelineData, ShellError,
+    Signature, Span, SyntaxShape, Type, Value,
 };
+use std::io::{BufRead, BufReader, Write};
+use tempfile::NamedTempFile;
+
+const DEFAULT_EXTERNAL_MAX_MEMORY: i64 = 100 * 1024 * 1024;
 
 #[derive(Clone)]
 pub struct SortBy;
@@ -29,6 +33,17 @@ impl Command for SortBy {
                 "Sort alphanumeric string-based columns naturally (1, 9, 10, 99, 100, ...)",
                 Some('n'),
             )
+            .switch(
+                "external",
+                "Use an external merge sort, spilling sorted runs to temp files, for input too large to sort in memory",
+                Some('e'),
+            )
+            .named(
+                "max-memory",
+                SyntaxShape::Filesize,
+                "with --external, how much input to buffer before spilling a sorted run to disk (default: 100MB)",
+                None,
+            )
             .category(Category::Filters)
     }
 
@@ -36,6 +51,13 @@ impl Command for SortBy {
         "Sort by the given columns, in increasing order."
     }
 
+    fn extra_usage(&self) -> &str {
+        "With --external, sort-by sorts input in chunks bounded by --max-memory, spills each \
+sorted chunk to a temp file, and does a k-way merge of those files to produce the final order. \
+Only the sorting of chunks is bounded by --max-memory: the merged result is collected in memory \
+before it is returned, so the whole sorted output is held in memory at once."
+    }
+
     fn examples(&self) -> Vec<Example> {
         vec![
             Example {
@@ -69,6 +91,11 @@ impl Command for SortBy {
                     span: Span::test_data(),
                 }),
             },
+            Example {
+                description: "Sort a huge table by timestamp, sorting it in chunks spilled to disk",
+                example: "open big.jsonl | sort-by --external --max-memory 50mb timestamp",
+                result: None,
+            },
         ]
     }
 
@@ -83,8 +110,6 @@ impl Command for SortBy {
         let reverse = call.has_flag("reverse");
         let insensitive = call.has_flag("ignore-case");
         let natural = call.has_flag("natural");
-        let metadata = &input.metadata();
-        let mut vec: Vec<_> = input.into_iter_strict(call.head)?.collect();
 
         if columns.is_empty() {
             return Err(ShellError::MissingParameter {
@@ -93,6 +118,24 @@ impl Command for SortBy {
             });
         }
 
+        if call.has_flag("external") {
+            let max_memory: Option<i64> = call.get_flag(engine_state, stack, "max-memory")?;
+            return external_sort_by(
+                engine_state,
+                stack,
+                call,
+                input,
+                columns,
+                reverse,
+                insensitive,
+                natural,
+                max_memory.unwrap_or(DEFAULT_EXTERNAL_MAX_MEMORY).max(1) as usize,
+            );
+        }
+
+        let metadata = &input.metadata();
+        let mut vec: Vec<_> = input.into_iter_strict(call.head)?.collect();
+
         crate::sort(&mut vec, columns, call.head, insensitive, natural)?;
 
         if reverse {
@@ -109,6 +152,230 @@ impl Command for SortBy {
     }
 }
 
+/// One sorted chunk of the input, spilled to a temp file as one nuon-encoded record per line.
+struct Run {
+    reader: BufReader<std::fs::File>,
+    // The most recently read, not-yet-consumed row from this run, used as the merge key.
+    head: Option<Value>,
+    // Kept alive so the backing temp file isn't deleted out from under `reader`.
+    _file: NamedTempFile,
+}
+
+impl Run {
+    fn advance(
+        &mut self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        from_nuon: &dyn Command,
+        span: Span,
+    ) -> Result<(), ShellError> {
+        let mut line = String::new();
+        self.head = if read_line_io(&mut self.reader, &mut line)? == 0 {
+            None
+        } else {
+            let parsed = from_nuon.run(
+                engine_state,
+                stack,
+                &Call::new(span),
+                Value::String { val: line, span }.into_pipeline_data(),
+            )?;
+            Some(parsed.into_value(span))
+        };
+        Ok(())
+    }
+}
+
+fn read_line_io(
+    reader: &mut BufReader<std::fs::File>,
+    line: &mut String,
+) -> Result<usize, ShellError> {
+    reader.read_line(line).map_err(|e| {
+        ShellError::GenericError(
+            format!("could not read a spilled sort run: {e}"),
+            "while reading a sort run from disk".into(),
+            None,
+            None,
+            Vec::new(),
+        )
+    })
+}
+
+/// Sort `buffer` in place and write it to a fresh temp file as one nuon record per line,
+/// draining `buffer` in the process so the caller can reuse its allocation for the next run.
+fn spill_run(
+    buffer: &mut Vec<Value>,
+    columns: &[String],
+    span: Span,
+    insensitive: bool,
+    natural: bool,
+) -> Result<NamedTempFile, ShellError> {
+    crate::sort(buffer, columns.to_vec(), span, insensitive, natural)?;
+
+    let mut file = NamedTempFile::new().map_err(|e| {
+        ShellError::GenericError(
+            format!("could not create a temp file for an external sort run: {e}"),
+            "while spilling a sorted run to disk".into(),
+            Some(span),
+            None,
+            Vec::new(),
+        )
+    })?;
+
+    for value in buffer.drain(..) {
+        writeln!(file, "{}", crate::value_to_string(&value, span)?).map_err(|e| {
+            ShellError::GenericError(
+                format!("could not write a sort run to disk: {e}"),
+                "while spilling a sorted run to disk".into(),
+                Some(span),
+                None,
+                Vec::new(),
+            )
+        })?;
+    }
+    file.flush().map_err(|e| {
+        ShellError::GenericError(
+            format!("could not flush a sort run to disk: {e}"),
+            "while spilling a sorted run to disk".into(),
+            Some(span),
+            None,
+            Vec::new(),
+        )
+    })?;
+
+    Ok(file)
+}
+
+/// K-way merge the already-individually-sorted runs back into a single sorted `Vec<Value>`.
+fn merge_runs(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    files: Vec<NamedTempFile>,
+    columns: &[String],
+    span: Span,
+    insensitive: bool,
+    natural: bool,
+) -> Result<Vec<Value>, ShellError> {
+    let from_nuon_id = engine_state.find_decl(b"from nuon", &[]).ok_or_else(|| {
+        ShellError::GenericError(
+            "the `from nuon` command is not available".into(),
+            "needed to read back a spilled sort run".into(),
+            Some(span),
+            None,
+            Vec::new(),
+        )
+    })?;
+    let from_nuon = engine_state.get_decl(from_nuon_id);
+
+    let mut runs = Vec::with_capacity(files.len());
+    for file in files {
+        let reader = BufReader::new(file.reopen().map_err(|e| {
+            ShellError::GenericError(
+                format!("could not reopen a spilled sort run: {e}"),
+                "while merging sorted runs".into(),
+                Some(span),
+                None,
+                Vec::new(),
+            )
+        })?);
+        let mut run = Run {
+            reader,
+            head: None,
+            _file: file,
+        };
+        run.advance(engine_state, stack, from_nuon, span)?;
+        runs.push(run);
+    }
+
+    let mut merged = Vec::new();
+    loop {
+        let smallest = runs
+            .iter()
+            .enumerate()
+            .filter_map(|(i, run)| run.head.as_ref().map(|value| (i, value)))
+            .min_by(|(_, a), (_, b)| crate::compare(a, b, columns, span, insensitive, natural));
+
+        let Some((index, _)) = smallest else {
+            break;
+        };
+
+        let run = &mut runs[index];
+        merged.push(run.head.take().expect("just matched Some above"));
+        run.advance(engine_state, stack, from_nuon, span)?;
+    }
+
+    Ok(merged)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn external_sort_by(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+    input: PipelineData,
+    columns: Vec<String>,
+    reverse: bool,
+    insensitive: bool,
+    natural: bool,
+    max_memory: usize,
+) -> Result<PipelineData, ShellError> {
+    let span = call.head;
+    let metadata = input.metadata();
+
+    let mut files = Vec::new();
+    let mut buffer = Vec::new();
+    let mut buffered_bytes = 0usize;
+
+    for value in input.into_iter_strict(span)? {
+        buffered_bytes += crate::value_to_string(&value, span)?.len();
+        buffer.push(value);
+        if buffered_bytes >= max_memory {
+            files.push(spill_run(
+                &mut buffer,
+                &columns,
+                span,
+                insensitive,
+                natural,
+            )?);
+            buffered_bytes = 0;
+        }
+    }
+
+    let mut result = if files.is_empty() {
+        // Everything fit in the first chunk; no need to round-trip it through disk.
+        crate::sort(&mut buffer, columns, span, insensitive, natural)?;
+        buffer
+    } else {
+        if !buffer.is_empty() {
+            files.push(spill_run(
+                &mut buffer,
+                &columns,
+                span,
+                insensitive,
+                natural,
+            )?);
+        }
+        merge_runs(
+            engine_state,
+            stack,
+            files,
+            &columns,
+            span,
+            insensitive,
+            natural,
+        )?
+    };
+
+    if reverse {
+        result.reverse();
+    }
+
+    let iter = result.into_iter();
+    Ok(match metadata {
+        Some(m) => iter.into_pipeline_data_with_metadata(m, engine_state.ctrlc.clone()),
+        None => iter.into_pipeline_data(engine_state.ctrlc.clone()),
+    })
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
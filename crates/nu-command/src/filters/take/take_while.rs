@@ -25,7 +25,7 @@ impl Command for TakeWhile {
             ])
             .required(
                 "predicate",
-                SyntaxShape::Closure(Some(vec![SyntaxShape::Any, SyntaxShape::Int])),
+                SyntaxShape::Closure(Some(vec![SyntaxShape::Any, SyntaxShape::Any])),
                 "the predicate that element(s) must match",
             )
             .category(Category::Filters)
@@ -35,6 +35,12 @@ impl Command for TakeWhile {
         "Take elements of the input while a predicate is true."
     }
 
+    fn extra_usage(&self) -> &str {
+        "The predicate can optionally take a second parameter, bound to the next element in
+the input (or `null` after the last one), to detect a boundary without a separate `window`
+pass."
+    }
+
     fn examples(&self) -> Vec<Example> {
         vec![
             Example {
@@ -64,6 +70,14 @@ impl Command for TakeWhile {
                     span: Span::test_data(),
                 }),
             },
+            Example {
+                description: "Take elements while they are no greater than the one that follows",
+                example: "[1 2 3 2 1] | take while {|cur, next| $next == null or $cur <= $next }",
+                result: Some(Value::List {
+                    vals: vec![Value::test_int(1), Value::test_int(2)],
+                    span: Span::test_data(),
+                }),
+            },
         ]
     }
 
@@ -80,6 +94,7 @@ impl Command for TakeWhile {
 
         let block = engine_state.get_block(capture_block.block_id).clone();
         let var_id = block.signature.get_positional(0).and_then(|arg| arg.var_id);
+        let next_var_id = block.signature.get_positional(1).and_then(|arg| arg.var_id);
 
         let mut stack = stack.captures_to_stack(&capture_block.captures);
 
@@ -89,26 +104,38 @@ impl Command for TakeWhile {
         let redirect_stdout = call.redirect_stdout;
         let redirect_stderr = call.redirect_stderr;
 
-        Ok(input
-            .into_iter_strict(span)?
-            .take_while(move |value| {
-                if let Some(var_id) = var_id {
-                    stack.add_var(var_id, value.clone());
-                }
-
-                eval_block(
-                    &engine_state,
-                    &mut stack,
-                    &block,
-                    PipelineData::empty(),
-                    redirect_stdout,
-                    redirect_stderr,
-                )
-                .map_or(false, |pipeline_data| {
-                    pipeline_data.into_value(span).is_true()
-                })
-            })
-            .into_pipeline_data(ctrlc))
+        let mut input_iter = input.into_iter_strict(span)?.peekable();
+
+        let iter = std::iter::from_fn(move || {
+            let value = input_iter.next()?;
+
+            if let Some(var_id) = var_id {
+                stack.add_var(var_id, value.clone());
+            }
+            if let Some(next_var_id) = next_var_id {
+                let next = input_iter
+                    .peek()
+                    .cloned()
+                    .unwrap_or_else(|| Value::nothing(span));
+                stack.add_var(next_var_id, next);
+            }
+
+            let matched = eval_block(
+                &engine_state,
+                &mut stack,
+                &block,
+                PipelineData::empty(),
+                redirect_stdout,
+                redirect_stderr,
+            )
+            .map_or(false, |pipeline_data| {
+                pipeline_data.into_value(span).is_true()
+            });
+
+            matched.then_some(value)
+        });
+
+        Ok(iter.into_pipeline_data(ctrlc))
     }
 }
 
@@ -0,0 +1,111 @@
+use super::set_ops::{as_rows, key_for};
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
+    SyntaxShape, Type, Value,
+};
+use std::collections::HashSet;
+
+#[derive(Clone)]
+pub struct Union;
+
+impl Command for Union {
+    fn name(&self) -> &str {
+        "union"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("union")
+            .input_output_types(vec![(
+                Type::List(Box::new(Type::Any)),
+                Type::List(Box::new(Type::Any)),
+            )])
+            .required("other", SyntaxShape::Any, "the list or table to union with")
+            .rest(
+                "columns",
+                SyntaxShape::String,
+                "key column(s) to compare by, instead of comparing whole rows",
+            )
+            .category(Category::Filters)
+    }
+
+    fn usage(&self) -> &str {
+        "Return the distinct elements of the input followed by any new elements from the \
+other list."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Comparisons are hashed, so this scales better than `append | uniq-by` for large \
+inputs. Without key columns, whole rows (or values) are compared; with key columns, only those \
+columns determine membership, and the first row seen for a given key is the one kept."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["set", "distinct", "combine"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let other: Value = call.req(engine_state, stack, 0)?;
+        let columns: Vec<String> = call.rest(engine_state, stack, 1)?;
+        let metadata = input.metadata();
+
+        let mut seen: HashSet<String> = HashSet::new();
+        let mut result = Vec::new();
+
+        for value in input.into_iter().chain(as_rows(other)) {
+            if seen.insert(key_for(&value, &columns, head)?) {
+                result.push(value);
+            }
+        }
+
+        Ok(result
+            .into_iter()
+            .into_pipeline_data(engine_state.ctrlc.clone())
+            .set_metadata(metadata))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Combine two lists, keeping only the first occurrence of each value",
+                example: "[1 2 3] | union [3 4 5]",
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::test_int(1),
+                        Value::test_int(2),
+                        Value::test_int(3),
+                        Value::test_int(4),
+                        Value::test_int(5),
+                    ],
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                description: "Combine two tables, keeping one row per id",
+                example: "$a | union $b id",
+                result: None,
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(Union {})
+    }
+}
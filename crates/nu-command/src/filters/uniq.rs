@@ -1,13 +1,17 @@
 use crate::formats::value_to_string;
 use itertools::Itertools;
+use nu_engine::CallExt;
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
     Category, Example, IntoPipelineData, PipelineData, PipelineMetadata, ShellError, Signature,
-    Span, Type, Value,
+    Span, SyntaxShape, Type, Value,
 };
-use std::collections::hash_map::IntoIter;
+use std::collections::hash_map::{DefaultHasher, IntoIter};
 use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+const DEFAULT_APPROX_MAX_MEMORY: i64 = 10 * 1024 * 1024;
 
 #[derive(Clone)]
 pub struct Uniq;
@@ -50,6 +54,19 @@ impl Command for Uniq {
                 "Return the input values that occur once only",
                 Some('u'),
             )
+            .switch(
+                "approx",
+                "Use an approximate dedup backed by a probabilistic filter whose size is set by \
+--max-memory, instead of exact counts. Incompatible with --count, --repeated and \
+--unique, which all need exact counts.",
+                None,
+            )
+            .named(
+                "max-memory",
+                SyntaxShape::Filesize,
+                "with --approx, the memory budget for the probabilistic filter (default: 10MB)",
+                None,
+            )
             .category(Category::Filters)
     }
 
@@ -57,6 +74,14 @@ impl Command for Uniq {
         "Return the distinct values in the input."
     }
 
+    fn extra_usage(&self) -> &str {
+        "With --approx, uniq tracks the values it has seen in a Bloom filter sized from \
+--max-memory instead of exact counts. Only the filter is bounded: every value kept is collected \
+into the `values` list of the returned record, so the output is not streamed. This can let \
+through a small fraction of duplicates (the estimated false-positive rate is reported alongside \
+the result), and loses the ability to report exact counts."
+    }
+
     fn search_terms(&self) -> Vec<&str> {
         vec!["distinct", "deduplicate"]
     }
@@ -73,6 +98,20 @@ impl Command for Uniq {
         });
 
         let metadata = input.metadata();
+
+        if call.has_flag("approx") {
+            let max_memory: Option<i64> = call.get_flag(engine_state, stack, "max-memory")?;
+            return uniq_approx(
+                engine_state,
+                call,
+                input.into_iter(),
+                mapper,
+                call.has_flag("ignore-case"),
+                max_memory.unwrap_or(DEFAULT_APPROX_MAX_MEMORY).max(1) as usize,
+                metadata,
+            );
+        }
+
         uniq(
             engine_state,
             stack,
@@ -136,6 +175,11 @@ impl Command for Uniq {
                     span: Span::test_data(),
                 }),
             },
+            Example {
+                description: "Deduplicate a huge stream with a fixed-size filter, accepting a small false-positive rate",
+                example: "open --raw big.log | lines | uniq --approx --max-memory 20mb",
+                result: None,
+            },
         ]
     }
 }
@@ -338,6 +382,127 @@ fn sort(iter: IntoIter<String, ValueCounter>) -> Vec<ValueCounter> {
         .collect()
 }
 
+/// Run `input` through a Bloom filter sized from `max_memory` bytes, keeping only the first
+/// occurrence of each distinct value. Only the filter bits are bounded by `max_memory`; the
+/// kept values are collected into the returned record's `values` list. Unlike `uniq`, this can't report exact counts, so `--count`/`--repeated`/
+/// `--unique` aren't supported here; the result instead carries the filter's estimated
+/// false-positive rate so callers can judge how much duplication may have slipped through.
+pub fn uniq_approx(
+    engine_state: &EngineState,
+    call: &Call,
+    input: impl Iterator<Item = Value>,
+    item_mapper: Box<dyn Fn(ItemMapperState) -> ValueCounter>,
+    flag_ignore_case: bool,
+    max_memory: usize,
+    metadata: Option<Box<PipelineMetadata>>,
+) -> Result<PipelineData, ShellError> {
+    let ctrlc = engine_state.ctrlc.clone();
+    let head = call.head;
+
+    let mut filter = BloomFilter::new(max_memory);
+    let mut values_seen: u64 = 0;
+    let mut result = Vec::new();
+
+    for (index, item) in input.enumerate() {
+        if nu_utils::ctrl_c::was_pressed(&ctrlc) {
+            break;
+        }
+
+        let counted = item_mapper(ItemMapperState {
+            item,
+            flag_ignore_case,
+            index,
+        });
+        let key = generate_key(&counted)?;
+        values_seen += 1;
+
+        if filter.insert(&key) {
+            result.push(counted.val);
+        }
+    }
+
+    Ok(Value::Record {
+        cols: vec![
+            "values".to_string(),
+            "estimated_false_positive_rate".to_string(),
+        ],
+        vals: vec![
+            Value::List {
+                vals: result,
+                span: head,
+            },
+            Value::float(filter.estimated_false_positive_rate(values_seen), head),
+        ],
+        span: head,
+    }
+    .into_pipeline_data()
+    .set_metadata(metadata))
+}
+
+/// A fixed-size bit-array Bloom filter, using two independent `DefaultHasher` digests combined
+/// via double hashing (Kirsch-Mitzenmacher) to derive any number of bit positions without
+/// needing a family of real hash functions.
+struct BloomFilter {
+    bits: Vec<u64>,
+    num_bits: usize,
+    num_hashes: u64,
+}
+
+impl BloomFilter {
+    fn new(max_memory_bytes: usize) -> Self {
+        let num_bits = (max_memory_bytes.max(1) * 8).max(64);
+        let num_words = num_bits.div_ceil(64);
+        BloomFilter {
+            bits: vec![0u64; num_words],
+            num_bits,
+            num_hashes: 4,
+        }
+    }
+
+    fn hash_pair(&self, key: &str) -> (u64, u64) {
+        let mut first = DefaultHasher::new();
+        key.hash(&mut first);
+        let mut second = DefaultHasher::new();
+        key.hash(&mut second);
+        "uniq-approx".hash(&mut second);
+        (first.finish(), second.finish())
+    }
+
+    fn bit_positions(&self, key: &str) -> impl Iterator<Item = usize> {
+        let (first, second) = self.hash_pair(key);
+        let num_bits = self.num_bits;
+        (0..self.num_hashes)
+            .map(move |i| (first.wrapping_add(i.wrapping_mul(second)) as usize) % num_bits)
+    }
+
+    /// Records `key` as seen and returns `true` if it looks new (none of its bits were already
+    /// set), or `false` if it was already possibly present.
+    fn insert(&mut self, key: &str) -> bool {
+        let positions: Vec<usize> = self.bit_positions(key).collect();
+        let already_present = positions
+            .iter()
+            .all(|&bit| self.bits[bit / 64] & (1 << (bit % 64)) != 0);
+
+        if !already_present {
+            for bit in positions {
+                self.bits[bit / 64] |= 1 << (bit % 64);
+            }
+        }
+
+        !already_present
+    }
+
+    fn estimated_false_positive_rate(&self, values_seen: u64) -> f64 {
+        if values_seen == 0 {
+            return 0.0;
+        }
+        let k = self.num_hashes as f64;
+        let m = self.num_bits as f64;
+        let n = values_seen as f64;
+        (1.0 - (-k * n / m).exp()).powf(k)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
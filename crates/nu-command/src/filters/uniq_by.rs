@@ -1,4 +1,5 @@
 pub use super::uniq;
+use crate::filters::uniq::uniq_approx;
 use nu_engine::column::nonexistent_column;
 use nu_engine::CallExt;
 use nu_protocol::ast::Call;
@@ -7,6 +8,8 @@ use nu_protocol::{
     Category, Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value,
 };
 
+const DEFAULT_APPROX_MAX_MEMORY: i64 = 10 * 1024 * 1024;
+
 #[derive(Clone)]
 pub struct UniqBy;
 
@@ -39,6 +42,19 @@ impl Command for UniqBy {
                 "Return the input values that occur once only",
                 Some('u'),
             )
+            .switch(
+                "approx",
+                "Use an approximate dedup backed by a probabilistic filter whose size is set by \
+--max-memory, instead of exact counts. Incompatible with --count, --repeated and \
+--unique, which all need exact counts.",
+                None,
+            )
+            .named(
+                "max-memory",
+                SyntaxShape::Filesize,
+                "with --approx, the memory budget for the probabilistic filter (default: 10MB)",
+                None,
+            )
             .category(Category::Filters)
     }
 
@@ -46,6 +62,13 @@ impl Command for UniqBy {
         "Return the distinct values in the input by the given column(s)."
     }
 
+    fn extra_usage(&self) -> &str {
+        "With --approx, this tracks the keys it has seen in a Bloom filter sized from \
+--max-memory instead, skipping the upfront column validation exact mode does. Only the filter is \
+bounded: every row kept is collected into the `values` list of the returned record, so the output \
+is not streamed. The estimated false-positive rate is reported alongside the result."
+    }
+
     fn search_terms(&self) -> Vec<&str> {
         vec!["distinct", "deduplicate"]
     }
@@ -68,6 +91,20 @@ impl Command for UniqBy {
 
         let metadata = input.metadata();
 
+        if call.has_flag("approx") {
+            let max_memory: Option<i64> = call.get_flag(engine_state, stack, "max-memory")?;
+            let mapper = Box::new(item_mapper_by_col(columns));
+            return uniq_approx(
+                engine_state,
+                call,
+                input.into_iter(),
+                mapper,
+                call.has_flag("ignore-case"),
+                max_memory.unwrap_or(DEFAULT_APPROX_MAX_MEMORY).max(1) as usize,
+                metadata,
+            );
+        }
+
         let vec: Vec<_> = input.into_iter().collect();
         match validate(vec.clone(), &columns, call.head) {
             Ok(_) => {}
@@ -82,27 +119,34 @@ impl Command for UniqBy {
     }
 
     fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            description: "Get rows from table filtered by column uniqueness ",
-            example: "[[fruit count]; [apple 9] [apple 2] [pear 3] [orange 7]] | uniq-by fruit",
-            result: Some(Value::List {
-                vals: vec![
-                    Value::test_record(
-                        vec!["fruit", "count"],
-                        vec![Value::test_string("apple"), Value::test_int(9)],
-                    ),
-                    Value::test_record(
-                        vec!["fruit", "count"],
-                        vec![Value::test_string("pear"), Value::test_int(3)],
-                    ),
-                    Value::test_record(
-                        vec!["fruit", "count"],
-                        vec![Value::test_string("orange"), Value::test_int(7)],
-                    ),
-                ],
-                span: Span::test_data(),
-            }),
-        }]
+        vec![
+            Example {
+                description: "Get rows from table filtered by column uniqueness ",
+                example: "[[fruit count]; [apple 9] [apple 2] [pear 3] [orange 7]] | uniq-by fruit",
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::test_record(
+                            vec!["fruit", "count"],
+                            vec![Value::test_string("apple"), Value::test_int(9)],
+                        ),
+                        Value::test_record(
+                            vec!["fruit", "count"],
+                            vec![Value::test_string("pear"), Value::test_int(3)],
+                        ),
+                        Value::test_record(
+                            vec!["fruit", "count"],
+                            vec![Value::test_string("orange"), Value::test_int(7)],
+                        ),
+                    ],
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                description: "Deduplicate a huge table by key with a fixed-size filter",
+                example: "open --raw big.jsonl | lines | each {|l| $l | from json} | uniq-by --approx --max-memory 20mb id",
+                result: None,
+            },
+        ]
     }
 }
 
@@ -1,3 +1,4 @@
+use super::multi_cell_path::record_to_path_pairs;
 use nu_engine::{eval_block, CallExt};
 use nu_protocol::ast::{Call, CellPath, PathMember};
 use nu_protocol::engine::{Closure, Command, EngineState, Stack};
@@ -22,13 +23,13 @@ impl Command for Update {
             ])
             .required(
                 "field",
-                SyntaxShape::CellPath,
-                "the name of the column to update",
+                SyntaxShape::OneOf(vec![SyntaxShape::CellPath, SyntaxShape::Record]),
+                "the name of the column to update, or a record of column: value/closure pairs to update several columns in one pass",
             )
-            .required(
+            .optional(
                 "replacement value",
                 SyntaxShape::Any,
-                "the new value to give the cell(s), or a closure to create the value",
+                "the new value to give the cell(s), or a closure to create the value (omitted when `field` is a record)",
             )
             .category(Category::Filters)
     }
@@ -37,6 +38,12 @@ impl Command for Update {
         "Update an existing column to have a new value."
     }
 
+    fn extra_usage(&self) -> &str {
+        "When `field` is a record, every column it names is updated in the same pass over the \
+input, instead of running `update` once per column. Each column's value or closure is \
+evaluated against the row as it was before any of the record's updates were applied."
+    }
+
     fn run(
         &self,
         engine_state: &EngineState,
@@ -75,6 +82,15 @@ impl Command for Update {
                 example: "[[project, authors]; ['nu', ['Andrés', 'JT', 'Yehuda']]] | update authors {|row| $row.authors | str join ','}",
                 result: Some(Value::List { vals: vec![Value::Record { cols: vec!["project".into(), "authors".into()], vals: vec![Value::test_string("nu"), Value::test_string("Andrés,JT,Yehuda")], span: Span::test_data()}], span: Span::test_data()}),
             },
+            Example {
+                description: "Update several columns in a single pass over the table",
+                example: "{'name': 'nu', 'stars': 5} | update {name: 'Nushell', stars: {|row| $row.stars + 1}}",
+                result: Some(Value::Record {
+                    cols: vec!["name".into(), "stars".into()],
+                    vals: vec![Value::test_string("Nushell"), Value::test_int(6)],
+                    span: Span::test_data(),
+                }),
+            },
         ]
     }
 }
@@ -87,8 +103,20 @@ fn update(
 ) -> Result<PipelineData, ShellError> {
     let span = call.head;
 
-    let cell_path: CellPath = call.req(engine_state, stack, 0)?;
-    let replacement: Value = call.req(engine_state, stack, 1)?;
+    let field: Value = call.req(engine_state, stack, 0)?;
+
+    if let Value::Record { .. } = &field {
+        let pairs = record_to_path_pairs(&field)?;
+        return update_many(engine_state, stack, call, input, pairs);
+    }
+
+    let cell_path: CellPath = FromValue::from_value(&field)?;
+    let replacement: Value =
+        call.opt(engine_state, stack, 1)?
+            .ok_or(ShellError::MissingParameter {
+                param_name: "replacement value".into(),
+                span,
+            })?;
 
     let redirect_stdout = call.redirect_stdout;
     let redirect_stderr = call.redirect_stderr;
@@ -184,6 +212,71 @@ fn update(
     }
 }
 
+/// Apply every `(cell_path, replacement)` pair to each row in a single pass over `input`,
+/// instead of running `update` once per pair. Each pair's value or closure is evaluated
+/// against the row as it looked before any of the other pairs in this call were applied.
+fn update_many(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+    input: PipelineData,
+    pairs: Vec<(CellPath, Value)>,
+) -> Result<PipelineData, ShellError> {
+    let span = call.head;
+    let redirect_stdout = call.redirect_stdout;
+    let redirect_stderr = call.redirect_stderr;
+
+    let engine_state = engine_state.clone();
+    let ctrlc = engine_state.ctrlc.clone();
+    let stack = stack.clone();
+
+    input.map(
+        move |mut item| {
+            let original = item.clone();
+
+            for (cell_path, replacement) in &pairs {
+                let new_value = if replacement.as_block().is_ok() {
+                    let capture_block: Closure = match FromValue::from_value(replacement) {
+                        Ok(block) => block,
+                        Err(e) => return Value::Error { error: e },
+                    };
+                    let block = engine_state.get_block(capture_block.block_id).clone();
+
+                    let mut closure_stack = stack.captures_to_stack(&capture_block.captures);
+                    if let Some(var) = block.signature.get_positional(0) {
+                        if let Some(var_id) = &var.var_id {
+                            closure_stack.add_var(*var_id, original.clone())
+                        }
+                    }
+
+                    let output = eval_block(
+                        &engine_state,
+                        &mut closure_stack,
+                        &block,
+                        original.clone().into_pipeline_data(),
+                        redirect_stdout,
+                        redirect_stderr,
+                    );
+
+                    match output {
+                        Ok(pd) => pd.into_value(span),
+                        Err(e) => return Value::Error { error: e },
+                    }
+                } else {
+                    replacement.clone()
+                };
+
+                if let Err(e) = item.update_data_at_cell_path(&cell_path.members, new_value) {
+                    return Value::Error { error: e };
+                }
+            }
+
+            item
+        },
+        ctrlc,
+    )
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
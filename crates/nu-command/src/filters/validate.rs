@@ -0,0 +1,294 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, SyntaxShape,
+    Type, Value,
+};
+
+#[derive(Clone)]
+pub struct Validate;
+
+impl Command for Validate {
+    fn name(&self) -> &str {
+        "validate"
+    }
+
+    fn usage(&self) -> &str {
+        "Check a value or table against a schema, returning violations instead of an error."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"The schema is a record mapping field names to constraint records. Recognized
+constraints are `type` (one of `bool`, `int`, `float`, `string`, `list`, `record`, `date`,
+`duration`, `filesize`), `required`, `min`, `max`, and `pattern` (a regular expression, for
+strings). If the input satisfies the schema, it is passed through unchanged; otherwise
+`validate` returns a table of violations, each with the offending field's cell path, a
+message, and the span of the value that failed."#
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("validate")
+            .input_output_types(vec![(Type::Any, Type::Any)])
+            .required(
+                "schema",
+                SyntaxShape::Record,
+                "a record describing the expected shape of the input",
+            )
+            .category(Category::Filters)
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["schema", "check", "assert"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let schema: Value = call.req(engine_state, stack, 0)?;
+        let fields = schema_fields(&schema, head)?;
+
+        let value = input.into_value(head);
+        let mut violations = Vec::new();
+
+        match &value {
+            Value::List { vals, .. } => {
+                for (i, row) in vals.iter().enumerate() {
+                    check_row(row, &fields, &i.to_string(), &mut violations);
+                }
+            }
+            Value::Record { .. } => check_row(&value, &fields, "", &mut violations),
+            other => {
+                return Err(ShellError::UnsupportedInput(
+                    "validate expects a record or a table of records".into(),
+                    "value originates from here".into(),
+                    head,
+                    other.expect_span(),
+                ))
+            }
+        }
+
+        if violations.is_empty() {
+            Ok(value.into_pipeline_data())
+        } else {
+            Ok(Value::List {
+                vals: violations,
+                span: head,
+            }
+            .into_pipeline_data())
+        }
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Validate a record against a schema",
+                example: "{name: 'Alice', age: 30} | validate {name: {type: string, required: true}, age: {type: int, min: 0}}",
+                result: None,
+            },
+            Example {
+                description: "Validate a table, returning a table of violations",
+                example: "[{name: 'Bob', age: -1}] | validate {age: {type: int, min: 0}}",
+                result: None,
+            },
+        ]
+    }
+}
+
+struct Field {
+    name: String,
+    ty: Option<String>,
+    required: bool,
+    min: Option<f64>,
+    max: Option<f64>,
+    pattern: Option<regex::Regex>,
+}
+
+fn schema_fields(schema: &Value, head: Span) -> Result<Vec<Field>, ShellError> {
+    let Value::Record { cols, vals, .. } = schema else {
+        return Err(ShellError::UnsupportedInput(
+            "the schema must be a record mapping field names to constraint records".into(),
+            "value originates from here".into(),
+            head,
+            schema.expect_span(),
+        ));
+    };
+
+    let mut fields = Vec::with_capacity(cols.len());
+
+    for (name, constraint) in cols.iter().zip(vals.iter()) {
+        let Value::Record {
+            cols: ccols,
+            vals: cvals,
+            ..
+        } = constraint
+        else {
+            return Err(ShellError::UnsupportedInput(
+                format!("the constraints for '{name}' must be a record"),
+                "value originates from here".into(),
+                head,
+                constraint.expect_span(),
+            ));
+        };
+
+        let get = |key: &str| -> Option<&Value> {
+            ccols.iter().position(|c| c == key).map(|i| &cvals[i])
+        };
+
+        let ty = get("type").map(|v| v.as_string()).transpose()?;
+        let required = get("required").map(|v| v.is_true()).unwrap_or(false);
+        let min = get("min").map(|v| v.as_float()).transpose()?;
+        let max = get("max").map(|v| v.as_float()).transpose()?;
+        let pattern = get("pattern")
+            .map(|v| v.as_string())
+            .transpose()?
+            .map(|p| regex::Regex::new(&p))
+            .transpose()
+            .map_err(|e| {
+                ShellError::GenericError(
+                    format!("invalid pattern for '{name}': {e}"),
+                    "could not compile pattern".into(),
+                    Some(head),
+                    None,
+                    Vec::new(),
+                )
+            })?;
+
+        fields.push(Field {
+            name: name.clone(),
+            ty,
+            required,
+            min,
+            max,
+            pattern,
+        });
+    }
+
+    Ok(fields)
+}
+
+fn check_row(row: &Value, fields: &[Field], row_path: &str, violations: &mut Vec<Value>) {
+    let Value::Record { cols, vals, .. } = row else {
+        violations.push(violation(row_path, "expected a record", row.expect_span()));
+        return;
+    };
+
+    for field in fields {
+        let cell_path = if row_path.is_empty() {
+            field.name.clone()
+        } else {
+            format!("{row_path}.{}", field.name)
+        };
+
+        let found = cols.iter().position(|c| c == &field.name).map(|i| &vals[i]);
+
+        let Some(val) = found else {
+            if field.required {
+                violations.push(violation(
+                    &cell_path,
+                    "required field is missing",
+                    row.expect_span(),
+                ));
+            }
+            continue;
+        };
+
+        if let Some(ty) = &field.ty {
+            let actual = value_type_name(val);
+            if actual != ty {
+                violations.push(violation(
+                    &cell_path,
+                    &format!("expected type '{ty}', got '{actual}'"),
+                    val.expect_span(),
+                ));
+                continue;
+            }
+        }
+
+        if let (Some(min), Ok(n)) = (field.min, val.as_float()) {
+            if n < min {
+                violations.push(violation(
+                    &cell_path,
+                    &format!("value {n} is less than the minimum of {min}"),
+                    val.expect_span(),
+                ));
+            }
+        }
+
+        if let (Some(max), Ok(n)) = (field.max, val.as_float()) {
+            if n > max {
+                violations.push(violation(
+                    &cell_path,
+                    &format!("value {n} is greater than the maximum of {max}"),
+                    val.expect_span(),
+                ));
+            }
+        }
+
+        if let (Some(pattern), Ok(s)) = (&field.pattern, val.as_string()) {
+            if !pattern.is_match(&s) {
+                violations.push(violation(
+                    &cell_path,
+                    &format!("value '{s}' does not match pattern '{pattern}'"),
+                    val.expect_span(),
+                ));
+            }
+        }
+    }
+}
+
+fn value_type_name(val: &Value) -> &'static str {
+    match val {
+        Value::Bool { .. } => "bool",
+        Value::Int { .. } => "int",
+        Value::Float { .. } => "float",
+        Value::Filesize { .. } => "filesize",
+        Value::Duration { .. } => "duration",
+        Value::Date { .. } => "date",
+        Value::Range { .. } => "range",
+        Value::String { .. } => "string",
+        Value::Record { .. } => "record",
+        Value::List { .. } => "list",
+        Value::Block { .. } | Value::Closure { .. } => "closure",
+        Value::Nothing { .. } => "nothing",
+        Value::Error { .. } => "error",
+        Value::Binary { .. } => "binary",
+        Value::CellPath { .. } => "cell path",
+        Value::CustomValue { .. } => "custom",
+        Value::LazyRecord { .. } => "record",
+    }
+}
+
+fn violation(cell_path: &str, message: &str, span: Span) -> Value {
+    Value::Record {
+        cols: vec!["cell_path".into(), "message".into(), "span".into()],
+        vals: vec![
+            Value::string(cell_path, span),
+            Value::string(message, span),
+            Value::Record {
+                cols: vec!["start".into(), "end".into()],
+                vals: vec![
+                    Value::int(span.start as i64, span),
+                    Value::int(span.end as i64, span),
+                ],
+                span,
+            },
+        ],
+        span,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn test_examples() {
+        use super::*;
+        use crate::test_examples;
+        test_examples(Validate {})
+    }
+}
@@ -1,10 +1,11 @@
 use nu_engine::{eval_block, CallExt};
-use nu_protocol::ast::Call;
+use nu_protocol::ast::{Block, Call, Comparison, Expr, Operator, PathMember, PipelineElement};
 use nu_protocol::engine::{Closure, Command, EngineState, Stack};
 use nu_protocol::{
     Category, Example, IntoInterruptiblePipelineData, IntoPipelineData, PipelineData, ShellError,
     Signature, Span, SyntaxShape, Type, Value,
 };
+use std::collections::HashSet;
 
 #[derive(Clone)]
 pub struct Where;
@@ -21,7 +22,11 @@ impl Command for Where {
     fn extra_usage(&self) -> &str {
         r#"This command works similar to 'filter' but allows extra shorthands for working with
 tables, known as "row conditions". On the other hand, reading the condition from a variable is
-not supported."#
+not supported.
+
+A condition of the form `col in [...]` or `col not-in [...]`, where the right-hand side is a
+literal list of numbers or strings, is recognized as a set-membership check: the list is hashed
+once before the rows are filtered, rather than scanned from scratch for every row."#
     }
 
     fn signature(&self) -> nu_protocol::Signature {
@@ -60,6 +65,15 @@ not supported."#
         let mut stack = stack.captures_to_stack(&closure.captures);
         let block = engine_state.get_block(closure.block_id).clone();
 
+        if let Some(plan) = SetMembershipPlan::from_block(&block) {
+            let ctrlc = engine_state.ctrlc.clone();
+            return Ok(input
+                .into_iter_strict(span)?
+                .filter_map(move |value| plan.keep(value))
+                .into_pipeline_data(ctrlc)
+                .set_metadata(metadata));
+        }
+
         let orig_env_vars = stack.env_vars.clone();
         let orig_env_hidden = stack.env_hidden.clone();
 
@@ -155,6 +169,92 @@ not supported."#
     }
 }
 
+/// Recognizes a row condition of the shape `col in [...]` / `col not-in [...]`, where the
+/// right-hand side is a literal list of ints or strings, and turns it into a single hashed
+/// set check that's built once instead of linearly scanning the list on every row.
+struct SetMembershipPlan {
+    tail: Vec<PathMember>,
+    set: HashSet<SetKey>,
+    negate: bool,
+}
+
+#[derive(PartialEq, Eq, Hash)]
+enum SetKey {
+    Int(i64),
+    String(String),
+}
+
+fn set_key(value: &Value) -> Option<SetKey> {
+    match value {
+        Value::Int { val, .. } => Some(SetKey::Int(*val)),
+        Value::String { val, .. } => Some(SetKey::String(val.clone())),
+        _ => None,
+    }
+}
+
+impl SetMembershipPlan {
+    fn from_block(block: &Block) -> Option<Self> {
+        let [pipeline] = block.pipelines.as_slice() else {
+            return None;
+        };
+        let [PipelineElement::Expression(_, expression)] = pipeline.elements.as_slice() else {
+            return None;
+        };
+        let Expr::BinaryOp(lhs, op, rhs) = &expression.expr else {
+            return None;
+        };
+        let negate = match &op.expr {
+            Expr::Operator(Operator::Comparison(Comparison::In)) => false,
+            Expr::Operator(Operator::Comparison(Comparison::NotIn)) => true,
+            _ => return None,
+        };
+
+        let Expr::FullCellPath(full_cell_path) = &lhs.expr else {
+            return None;
+        };
+        let Expr::Var(var_id) = &full_cell_path.head.expr else {
+            return None;
+        };
+        let row_var_id = block.signature.get_positional(0).and_then(|p| p.var_id);
+        if row_var_id != Some(*var_id) {
+            return None;
+        }
+        let tail = full_cell_path.tail.clone();
+
+        let Expr::List(items) = &rhs.expr else {
+            return None;
+        };
+
+        let mut set = HashSet::with_capacity(items.len());
+        for item in items {
+            let key = match &item.expr {
+                Expr::Int(val) => SetKey::Int(*val),
+                Expr::String(val) => SetKey::String(val.clone()),
+                _ => return None,
+            };
+            set.insert(key);
+        }
+
+        Some(SetMembershipPlan { tail, set, negate })
+    }
+
+    fn keep(&self, value: Value) -> Option<Value> {
+        match value.clone().follow_cell_path(&self.tail, false, false) {
+            Ok(looked_up) => {
+                let is_member = set_key(&looked_up)
+                    .map(|key| self.set.contains(&key))
+                    .unwrap_or(false);
+                if is_member != self.negate {
+                    Some(value)
+                } else {
+                    None
+                }
+            }
+            Err(err) => Some(Value::Error { error: err }),
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -1,10 +1,12 @@
+use indexmap::IndexMap;
 use nu_engine::CallExt;
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
-    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
-    Spanned, SyntaxShape, Type, Value,
+    Category, Example, IntoInterruptiblePipelineData, IntoPipelineData, PipelineData, ShellError,
+    Signature, Span, Spanned, SyntaxShape, Type, Value,
 };
+use std::cmp::Ordering;
 
 #[derive(Clone)]
 pub struct Window;
@@ -16,11 +18,15 @@ impl Command for Window {
 
     fn signature(&self) -> Signature {
         Signature::build("window")
-            .input_output_types(vec![(
-                Type::List(Box::new(Type::Any)),
-                Type::List(Box::new(Type::List(Box::new(Type::Any)))),
-            )])
-            .required("window_size", SyntaxShape::Int, "the size of each window")
+            .input_output_types(vec![
+                (
+                    Type::List(Box::new(Type::Any)),
+                    Type::List(Box::new(Type::List(Box::new(Type::Any)))),
+                ),
+                (Type::Table(vec![]), Type::Table(vec![])),
+            ])
+            .allow_variants_without_examples(true)
+            .optional("window_size", SyntaxShape::Int, "the size of each window")
             .named(
                 "stride",
                 SyntaxShape::Int,
@@ -32,6 +38,35 @@ impl Command for Window {
                 "yield last chunks even if they have fewer elements than size",
                 Some('r'),
             )
+            .named(
+                "over",
+                SyntaxShape::String,
+                "partition the table by this column and compute running columns within each partition, instead of sliding a fixed-size window",
+                None,
+            )
+            .named(
+                "sort",
+                SyntaxShape::String,
+                "with --over, sort rows within each partition by this column first",
+                None,
+            )
+            .named(
+                "cumsum",
+                SyntaxShape::String,
+                "with --over, add a `cumsum` column: the running total of this column within each partition",
+                None,
+            )
+            .named(
+                "lag",
+                SyntaxShape::String,
+                "with --over, add a `lag` column: this column's value from the previous row in the partition",
+                None,
+            )
+            .switch(
+                "rank",
+                "with --over, add a `rank` column: each row's 1-based position within its partition",
+                None,
+            )
             .category(Category::Filters)
     }
 
@@ -39,6 +74,10 @@ impl Command for Window {
         "Creates a sliding window of `window_size` that slide by n rows/elements across input."
     }
 
+    fn extra_usage(&self) -> &str {
+        "With --over, ignores `window_size`/`--stride`/`--remainder` and instead partitions the table, optionally sorting within each partition, and adds running-aggregate columns (--cumsum, --lag, --rank) computed over each partition independently."
+    }
+
     fn examples(&self) -> Vec<Example> {
         let stream_test_1 = vec![
             Value::List {
@@ -106,6 +145,11 @@ impl Command for Window {
                     span: Span::test_data(),
                 }),
             },
+            Example {
+                example: "$table | window --over group --sort date --cumsum amount --lag amount --rank",
+                description: "Partition by `group`, sort each partition by `date`, and add running-sum, lag, and rank columns",
+                result: None,
+            },
         ]
     }
 
@@ -116,7 +160,24 @@ impl Command for Window {
         call: &Call,
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
-        let group_size: Spanned<usize> = call.req(engine_state, stack, 0)?;
+        let over: Option<String> = call.get_flag(engine_state, stack, "over")?;
+        if let Some(over) = over {
+            let sort: Option<String> = call.get_flag(engine_state, stack, "sort")?;
+            let cumsum: Option<String> = call.get_flag(engine_state, stack, "cumsum")?;
+            let lag: Option<String> = call.get_flag(engine_state, stack, "lag")?;
+            let rank = call.has_flag("rank");
+            return window_over(call, input, over, sort, cumsum, lag, rank);
+        }
+
+        let group_size: Spanned<usize> = match call.opt(engine_state, stack, 0)? {
+            Some(group_size) => group_size,
+            None => {
+                return Err(ShellError::MissingParameter {
+                    param_name: "window_size".into(),
+                    span: call.head,
+                })
+            }
+        };
         let ctrlc = engine_state.ctrlc.clone();
         let metadata = input.metadata();
         let stride: Option<usize> = call.get_flag(engine_state, stack, "stride")?;
@@ -231,6 +292,108 @@ impl Iterator for EachWindowIterator {
     }
 }
 
+fn window_over(
+    call: &Call,
+    input: PipelineData,
+    over: String,
+    sort: Option<String>,
+    cumsum: Option<String>,
+    lag: Option<String>,
+    rank: bool,
+) -> Result<PipelineData, ShellError> {
+    let head = call.head;
+    let metadata = input.metadata();
+
+    let values: Vec<Value> = input.into_iter().collect();
+    let span = values.first().and_then(|v| v.span().ok()).unwrap_or(head);
+
+    let mut groups: IndexMap<String, Vec<Value>> = IndexMap::new();
+    for row in values {
+        let key = row
+            .get_data_by_key(&over)
+            .ok_or_else(|| ShellError::CantFindColumn {
+                col_name: over.clone(),
+                span: head,
+                src_span: row.expect_span(),
+            })?
+            .as_string()
+            .unwrap_or_default();
+
+        groups.entry(key).or_default().push(row);
+    }
+
+    let mut output = Vec::new();
+    for (_, mut rows) in groups {
+        if let Some(sort_col) = &sort {
+            rows.sort_by(
+                |a, b| match (a.get_data_by_key(sort_col), b.get_data_by_key(sort_col)) {
+                    (Some(a_val), Some(b_val)) => {
+                        a_val.partial_cmp(&b_val).unwrap_or(Ordering::Equal)
+                    }
+                    _ => Ordering::Equal,
+                },
+            );
+        }
+
+        let mut running_sum = 0.0;
+        let mut previous: Option<Value> = None;
+
+        for (i, row) in rows.into_iter().enumerate() {
+            let (mut cols, mut vals, row_span) = match row {
+                Value::Record { cols, vals, span } => (cols, vals, span),
+                other => {
+                    return Err(ShellError::TypeMismatch {
+                        err_message: "expected a table of records".into(),
+                        span: other.expect_span(),
+                    })
+                }
+            };
+
+            if let Some(cumsum_col) = &cumsum {
+                let value = cols
+                    .iter()
+                    .zip(vals.iter())
+                    .find(|(col, _)| *col == cumsum_col)
+                    .map(|(_, val)| val.as_float().unwrap_or(0.0))
+                    .unwrap_or(0.0);
+                running_sum += value;
+                cols.push("cumsum".to_string());
+                vals.push(Value::float(running_sum, row_span));
+            }
+
+            if let Some(lag_col) = &lag {
+                let lag_value = previous
+                    .as_ref()
+                    .and_then(|prev| prev.get_data_by_key(lag_col))
+                    .unwrap_or_else(|| Value::nothing(row_span));
+                cols.push("lag".to_string());
+                vals.push(lag_value);
+            }
+
+            if rank {
+                cols.push("rank".to_string());
+                vals.push(Value::int(i as i64 + 1, row_span));
+            }
+
+            let finished_row = Value::Record {
+                cols: cols.clone(),
+                vals: vals.clone(),
+                span: row_span,
+            };
+            previous = Some(Value::Record {
+                cols,
+                vals,
+                span: row_span,
+            });
+            output.push(finished_row);
+        }
+    }
+
+    Ok(Value::List { vals: output, span }
+        .into_pipeline_data()
+        .set_metadata(metadata))
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -0,0 +1,250 @@
+use apache_avro::types::Value as AvroValue;
+use apache_avro::{Reader, Schema};
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
+    SyntaxShape, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct FromAvro;
+
+impl Command for FromAvro {
+    fn name(&self) -> &str {
+        "from avro"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("from avro")
+            .input_output_types(vec![(Type::Binary, Type::Table(vec![]))])
+            .named(
+                "schema",
+                SyntaxShape::String,
+                "Use this Avro schema (as JSON) instead of the schema embedded in the file",
+                None,
+            )
+            .category(Category::Formats)
+    }
+
+    fn usage(&self) -> &str {
+        "Parse an Avro object container file into a table."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"By default the schema embedded in the object container file is used to
+decode records. Deflate- and snappy-compressed blocks are decompressed
+transparently, like any other Avro reader. Date and timestamp-millis/micros
+logical types become dates; decimals become a string of their unscaled
+integer value, since their scale is not applied. The whole input is read and
+every record decoded before the first row is passed downstream."#
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let schema: Option<String> = call.get_flag(engine_state, stack, "schema")?;
+
+        from_avro(input, head, schema, engine_state.ctrlc.clone())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Convert an Avro object container file into a table",
+                example: "open --raw test.avro | from avro",
+                result: None,
+            },
+            Example {
+                description: "Decode using an explicit schema instead of the embedded one",
+                example: r#"open --raw test.avro | from avro --schema (open schema.avsc)"#,
+                result: None,
+            },
+        ]
+    }
+}
+
+fn collect_binary(input: PipelineData, span: Span) -> Result<Vec<u8>, ShellError> {
+    let mut bytes = vec![];
+
+    for value in input {
+        match value {
+            Value::Binary { val, .. } => bytes.extend_from_slice(&val),
+            other => {
+                return Err(ShellError::UnsupportedInput(
+                    "Expected binary from pipeline".to_string(),
+                    "value originates from here".into(),
+                    span,
+                    other.expect_span(),
+                ))
+            }
+        }
+    }
+
+    Ok(bytes)
+}
+
+fn from_avro(
+    input: PipelineData,
+    head: Span,
+    schema: Option<String>,
+    ctrlc: Option<std::sync::Arc<std::sync::atomic::AtomicBool>>,
+) -> Result<PipelineData, ShellError> {
+    let span = input.span().unwrap_or(head);
+    let bytes = collect_binary(input, head)?;
+
+    let parsed_schema = schema
+        .map(|raw| {
+            Schema::parse_str(&raw).map_err(|e| {
+                ShellError::UnsupportedInput(
+                    format!("Could not parse Avro schema: {e}"),
+                    "value originates from here".into(),
+                    head,
+                    span,
+                )
+            })
+        })
+        .transpose()?;
+
+    let reader_err = |e: apache_avro::Error| {
+        ShellError::UnsupportedInput(
+            format!("Could not read Avro data: {e}"),
+            "value originates from here".into(),
+            head,
+            span,
+        )
+    };
+
+    let mut rows = vec![];
+
+    if let Some(parsed_schema) = &parsed_schema {
+        let reader = Reader::with_schema(parsed_schema, bytes.as_slice()).map_err(reader_err)?;
+        for record in reader {
+            rows.push(avro_value_to_nu(record.map_err(reader_err)?, head));
+        }
+    } else {
+        let reader = Reader::new(bytes.as_slice()).map_err(reader_err)?;
+        for record in reader {
+            rows.push(avro_value_to_nu(record.map_err(reader_err)?, head));
+        }
+    }
+
+    Ok(rows.into_iter().into_pipeline_data(ctrlc))
+}
+
+fn avro_value_to_nu(value: AvroValue, span: Span) -> Value {
+    match value {
+        AvroValue::Null => Value::nothing(span),
+        AvroValue::Boolean(b) => Value::boolean(b, span),
+        AvroValue::Int(i) => Value::int(i as i64, span),
+        AvroValue::Long(i) => Value::int(i, span),
+        AvroValue::Float(f) => Value::float(f as f64, span),
+        AvroValue::Double(f) => Value::float(f, span),
+        AvroValue::Bytes(b) | AvroValue::Fixed(_, b) => Value::binary(b, span),
+        AvroValue::String(s) | AvroValue::Enum(_, s) => Value::string(s, span),
+        AvroValue::Union(_, val) => avro_value_to_nu(*val, span),
+        AvroValue::Array(vals) => Value::List {
+            vals: vals
+                .into_iter()
+                .map(|v| avro_value_to_nu(v, span))
+                .collect(),
+            span,
+        },
+        AvroValue::Map(entries) => {
+            let mut cols = vec![];
+            let mut vals = vec![];
+            for (k, v) in entries {
+                cols.push(k);
+                vals.push(avro_value_to_nu(v, span));
+            }
+            Value::Record { cols, vals, span }
+        }
+        AvroValue::Record(fields) => {
+            let mut cols = vec![];
+            let mut vals = vec![];
+            for (k, v) in fields {
+                cols.push(k);
+                vals.push(avro_value_to_nu(v, span));
+            }
+            Value::Record { cols, vals, span }
+        }
+        AvroValue::Date(days) => date_from_days(days, span),
+        AvroValue::Decimal(decimal) => match <Vec<u8>>::try_from(&decimal) {
+            Ok(bytes) => Value::string(decimal_bytes_to_string(&bytes), span),
+            Err(_) => Value::nothing(span),
+        },
+        AvroValue::TimeMillis(ms) => Value::Duration {
+            val: ms as i64 * 1_000_000,
+            span,
+        },
+        AvroValue::TimeMicros(us) => Value::Duration {
+            val: us * 1_000,
+            span,
+        },
+        AvroValue::TimestampMillis(ms) => timestamp_from_millis(ms, span),
+        AvroValue::TimestampMicros(us) => timestamp_from_millis(us / 1_000, span),
+        AvroValue::Duration(_) => Value::string("<avro duration>".to_string(), span),
+        AvroValue::Uuid(uuid) => Value::string(uuid.to_string(), span),
+    }
+}
+
+fn timestamp_from_millis(ms: i64, span: Span) -> Value {
+    let seconds = ms.div_euclid(1000);
+    let nanos = (ms.rem_euclid(1000) * 1_000_000) as u32;
+    match chrono::NaiveDateTime::from_timestamp_opt(seconds, nanos) {
+        Some(naive) => Value::Date {
+            val: chrono::DateTime::<chrono::Utc>::from_utc(naive, chrono::Utc).into(),
+            span,
+        },
+        None => Value::nothing(span),
+    }
+}
+
+fn date_from_days(days: i32, span: Span) -> Value {
+    match chrono::NaiveDate::from_ymd_opt(1970, 1, 1)
+        .and_then(|epoch| epoch.checked_add_signed(chrono::Duration::days(days as i64)))
+    {
+        Some(date) => match date.and_hms_opt(0, 0, 0) {
+            Some(naive) => Value::Date {
+                val: chrono::DateTime::<chrono::Utc>::from_utc(naive, chrono::Utc).into(),
+                span,
+            },
+            None => Value::nothing(span),
+        },
+        None => Value::nothing(span),
+    }
+}
+
+fn decimal_bytes_to_string(bytes: &[u8]) -> String {
+    // Avro decimals are stored as an arbitrary-precision two's-complement
+    // integer; without the field's declared scale we can only surface the
+    // unscaled value, so show it as a plain (unscaled) integer.
+    let mut value: i128 = 0;
+    for &b in bytes {
+        value = (value << 8) | b as i128;
+    }
+    if let Some(&first) = bytes.first() {
+        if first & 0x80 != 0 {
+            value -= 1i128 << (8 * bytes.len());
+        }
+    }
+    value.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(FromAvro {})
+    }
+}
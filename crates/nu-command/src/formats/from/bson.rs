@@ -0,0 +1,215 @@
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Type, Value,
+};
+use std::io::Cursor;
+
+#[derive(Clone)]
+pub struct FromBson;
+
+impl Command for FromBson {
+    fn name(&self) -> &str {
+        "from bson"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("from bson")
+            .input_output_types(vec![(Type::Binary, Type::Any)])
+            .category(Category::Formats)
+    }
+
+    fn usage(&self) -> &str {
+        "Parse binary data as .bson and create table."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "A mongodump-style file of multiple concatenated BSON documents is parsed into a list, one entry per document. Types without a direct nushell equivalent (ObjectId, Binary, Timestamp, Regex, JavaScript code) round-trip through `to bson` as records with `$`-prefixed keys, such as `{$object_id: \"...\"}`."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            example: "open sample.bson | get root | select 0 | get b",
+            description: "Read a field out of a BSON document",
+            result: None,
+        }]
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let bytes = collect_binary(input, head)?;
+        let docs = parse_all_documents(&bytes, head)?;
+
+        let value = if docs.len() == 1 {
+            bson_document_to_value(&docs[0], head)
+        } else {
+            Value::List {
+                vals: docs
+                    .iter()
+                    .map(|doc| bson_document_to_value(doc, head))
+                    .collect(),
+                span: head,
+            }
+        };
+
+        Ok(value.into_pipeline_data())
+    }
+}
+
+fn collect_binary(input: PipelineData, span: Span) -> Result<Vec<u8>, ShellError> {
+    let mut bytes = vec![];
+
+    for value in input {
+        match value {
+            Value::Binary { val, .. } => bytes.extend_from_slice(&val),
+            other => {
+                return Err(ShellError::UnsupportedInput(
+                    "Expected binary from pipeline".to_string(),
+                    "value originates from here".into(),
+                    span,
+                    other.expect_span(),
+                ))
+            }
+        }
+    }
+
+    Ok(bytes)
+}
+
+fn parse_all_documents(bytes: &[u8], head: Span) -> Result<Vec<bson::Document>, ShellError> {
+    let mut cursor = Cursor::new(bytes);
+    let mut docs = vec![];
+
+    while (cursor.position() as usize) < bytes.len() {
+        let doc =
+            bson::Document::from_reader(&mut cursor).map_err(|e| ShellError::CantConvert {
+                to_type: "structured bson data".into(),
+                from_type: "binary".into(),
+                span: head,
+                help: Some(e.to_string()),
+            })?;
+        docs.push(doc);
+    }
+
+    if docs.is_empty() {
+        return Err(ShellError::CantConvert {
+            to_type: "structured bson data".into(),
+            from_type: "binary".into(),
+            span: head,
+            help: Some("input was empty".into()),
+        });
+    }
+
+    Ok(docs)
+}
+
+fn bson_document_to_value(doc: &bson::Document, span: Span) -> Value {
+    let mut cols = vec![];
+    let mut vals = vec![];
+    for (k, v) in doc {
+        cols.push(k.clone());
+        vals.push(bson_to_value(v, span));
+    }
+    Value::Record { cols, vals, span }
+}
+
+fn bson_to_value(value: &bson::Bson, span: Span) -> Value {
+    match value {
+        bson::Bson::Double(f) => Value::float(*f, span),
+        bson::Bson::String(s) => Value::string(s.clone(), span),
+        bson::Bson::Array(arr) => Value::List {
+            vals: arr.iter().map(|v| bson_to_value(v, span)).collect(),
+            span,
+        },
+        bson::Bson::Document(doc) => bson_document_to_value(doc, span),
+        bson::Bson::Boolean(b) => Value::bool(*b, span),
+        bson::Bson::Null => Value::nothing(span),
+        bson::Bson::RegularExpression(regex) => Value::Record {
+            cols: vec!["$regex".into(), "$options".into()],
+            vals: vec![
+                Value::string(regex.pattern.clone(), span),
+                Value::string(regex.options.clone(), span),
+            ],
+            span,
+        },
+        bson::Bson::JavaScriptCode(code) => Value::Record {
+            cols: vec!["$javascript".into()],
+            vals: vec![Value::string(code.clone(), span)],
+            span,
+        },
+        bson::Bson::JavaScriptCodeWithScope(code_with_scope) => Value::Record {
+            cols: vec!["$javascript".into(), "$scope".into()],
+            vals: vec![
+                Value::string(code_with_scope.code.clone(), span),
+                bson_document_to_value(&code_with_scope.scope, span),
+            ],
+            span,
+        },
+        bson::Bson::Int32(i) => Value::int(*i as i64, span),
+        bson::Bson::Int64(i) => Value::int(*i, span),
+        bson::Bson::Timestamp(ts) => Value::Record {
+            cols: vec!["$timestamp".into(), "$increment".into()],
+            vals: vec![
+                Value::int(ts.time as i64, span),
+                Value::int(ts.increment as i64, span),
+            ],
+            span,
+        },
+        bson::Bson::Binary(bin) => Value::Record {
+            cols: vec!["$binary_subtype".into(), "$binary".into()],
+            vals: vec![
+                Value::string(binary_subtype_name(bin.subtype), span),
+                Value::binary(bin.bytes.clone(), span),
+            ],
+            span,
+        },
+        bson::Bson::ObjectId(oid) => Value::Record {
+            cols: vec!["$object_id".into()],
+            vals: vec![Value::string(oid.to_hex(), span)],
+            span,
+        },
+        bson::Bson::DateTime(dt) => Value::Date {
+            val: dt.to_chrono().into(),
+            span,
+        },
+        bson::Bson::Symbol(s) => Value::string(s.clone(), span),
+        bson::Bson::Decimal128(d) => Value::string(d.to_string(), span),
+        bson::Bson::Undefined => Value::nothing(span),
+        bson::Bson::MaxKey => Value::string("$maxKey", span),
+        bson::Bson::MinKey => Value::string("$minKey", span),
+        bson::Bson::DbPointer(_) => Value::nothing(span),
+    }
+}
+
+fn binary_subtype_name(subtype: bson::spec::BinarySubtype) -> String {
+    use bson::spec::BinarySubtype::*;
+    match subtype {
+        Generic => "generic",
+        Function => "function",
+        BinaryOld => "binary_old",
+        UuidOld => "uuid_old",
+        Uuid => "uuid",
+        Md5 => "md5",
+        Encrypted => "encrypted",
+        _ => "generic",
+    }
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(FromBson {})
+    }
+}
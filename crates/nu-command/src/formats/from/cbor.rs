@@ -0,0 +1,223 @@
+use std::io::Cursor;
+
+use base64::{engine::general_purpose::STANDARD, engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
+    Type, Value,
+};
+
+#[derive(Clone)]
+pub struct FromCbor;
+
+impl Command for FromCbor {
+    fn name(&self) -> &str {
+        "from cbor"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("from cbor")
+            .input_output_types(vec![(Type::Binary, Type::Any)])
+            .switch(
+                "seq",
+                "read a CBOR sequence (RFC 8742) of concatenated top-level items instead of a single one",
+                Some('s'),
+            )
+            .category(Category::Formats)
+    }
+
+    fn usage(&self) -> &str {
+        "Convert from CBOR binary data into structured data."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Maps use string keys where possible. Tag 0 (date/time string) and tag 1 (epoch date/time) decode to dates; tags 2 and 3 (positive/negative bignums) decode to a record with a `$bignum` key holding the value as a hex string, since it may not fit in a 64-bit integer. Tags 21, 22 and 23 (suggested base64url/base64/base16 conversion, common in WebAuthn/COSE payloads) decode the tagged byte string straight to that encoded string, since that's the whole point of the tag; this direction isn't reversible through `to cbor`."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let seq = call.has_flag("seq");
+        from_cbor(input, head, seq, engine_state.ctrlc.clone())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Convert CBOR binary data into a record",
+                example: "0x[A1616101] | from cbor",
+                result: Some(Value::Record {
+                    cols: vec!["a".to_string()],
+                    vals: vec![Value::test_int(1)],
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                description: "Read a CBOR sequence of concatenated top-level items",
+                example: "open data.cbor --raw | from cbor --seq",
+                result: None,
+            },
+        ]
+    }
+}
+
+fn collect_binary(input: PipelineData, span: Span) -> Result<Vec<u8>, ShellError> {
+    let mut bytes = vec![];
+
+    for value in input {
+        match value {
+            Value::Binary { val, .. } => bytes.extend_from_slice(&val),
+            other => {
+                return Err(ShellError::UnsupportedInput(
+                    "Expected binary from pipeline".to_string(),
+                    "value originates from here".into(),
+                    span,
+                    other.expect_span(),
+                ))
+            }
+        }
+    }
+
+    Ok(bytes)
+}
+
+fn from_cbor(
+    input: PipelineData,
+    head: Span,
+    seq: bool,
+    ctrlc: Option<std::sync::Arc<std::sync::atomic::AtomicBool>>,
+) -> Result<PipelineData, ShellError> {
+    let span = input.span().unwrap_or(head);
+    let bytes = collect_binary(input, head)?;
+
+    if !seq {
+        let value: ciborium::value::Value =
+            ciborium::de::from_reader(Cursor::new(bytes.as_slice())).map_err(|e| {
+                ShellError::UnsupportedInput(
+                    format!("Could not parse CBOR data: {e}"),
+                    "value originates from here".into(),
+                    head,
+                    span,
+                )
+            })?;
+
+        return Ok(cbor_to_value(value, head).into_pipeline_data());
+    }
+
+    let mut cursor = Cursor::new(bytes.as_slice());
+    let mut values = vec![];
+
+    while (cursor.position() as usize) < bytes.len() {
+        let value: ciborium::value::Value =
+            ciborium::de::from_reader(&mut cursor).map_err(|e| {
+                ShellError::UnsupportedInput(
+                    format!("Could not parse CBOR item: {e}"),
+                    "value originates from here".into(),
+                    head,
+                    span,
+                )
+            })?;
+        values.push(cbor_to_value(value, head));
+    }
+
+    Ok(values.into_iter().into_pipeline_data(ctrlc))
+}
+
+fn cbor_to_value(value: ciborium::value::Value, span: Span) -> Value {
+    use ciborium::value::Value as Cbor;
+
+    match value {
+        Cbor::Null => Value::nothing(span),
+        Cbor::Bool(b) => Value::bool(b, span),
+        Cbor::Integer(i) => match i64::try_from(i) {
+            Ok(i) => Value::int(i, span),
+            Err(_) => Value::string(format!("{i:?}"), span),
+        },
+        Cbor::Float(f) => Value::float(f, span),
+        Cbor::Text(s) => Value::string(s, span),
+        Cbor::Bytes(b) => Value::binary(b, span),
+        Cbor::Array(vals) => Value::List {
+            vals: vals.into_iter().map(|v| cbor_to_value(v, span)).collect(),
+            span,
+        },
+        Cbor::Map(entries) => {
+            let mut cols = vec![];
+            let mut vals = vec![];
+            for (k, v) in entries {
+                let key = match k {
+                    Cbor::Text(s) => s,
+                    other => cbor_to_value(other, span).into_string(",", &Default::default()),
+                };
+                cols.push(key);
+                vals.push(cbor_to_value(v, span));
+            }
+            Value::Record { cols, vals, span }
+        }
+        Cbor::Tag(0, inner) => match *inner {
+            Cbor::Text(s) => match chrono::DateTime::parse_from_rfc3339(&s) {
+                Ok(date) => Value::Date { val: date, span },
+                Err(_) => Value::string(s, span),
+            },
+            other => cbor_to_value(other, span),
+        },
+        Cbor::Tag(1, inner) => {
+            let seconds = match *inner {
+                Cbor::Integer(i) => i64::try_from(i).ok().map(|i| (i, 0u32)),
+                Cbor::Float(f) => Some((f.trunc() as i64, (f.fract() * 1e9).round() as u32)),
+                _ => None,
+            };
+            match seconds
+                .and_then(|(secs, nanos)| chrono::NaiveDateTime::from_timestamp_opt(secs, nanos))
+            {
+                Some(naive) => Value::Date {
+                    val: chrono::DateTime::<chrono::Utc>::from_utc(naive, chrono::Utc).into(),
+                    span,
+                },
+                None => Value::nothing(span),
+            }
+        }
+        Cbor::Tag(tag @ (2 | 3), inner) => match *inner {
+            Cbor::Bytes(bytes) => {
+                let sign = if tag == 3 { "-" } else { "" };
+                let hex: String = bytes.iter().map(|b| format!("{b:02x}")).collect();
+                Value::Record {
+                    cols: vec!["$bignum".into()],
+                    vals: vec![Value::string(format!("{sign}0x{hex}"), span)],
+                    span,
+                }
+            }
+            other => cbor_to_value(other, span),
+        },
+        Cbor::Tag(tag @ (21 | 22 | 23), inner) => match *inner {
+            Cbor::Bytes(bytes) => {
+                let encoded = match tag {
+                    21 => URL_SAFE_NO_PAD.encode(&bytes),
+                    22 => STANDARD.encode(&bytes),
+                    _ => bytes.iter().map(|b| format!("{b:02x}")).collect(),
+                };
+                Value::string(encoded, span)
+            }
+            other => cbor_to_value(other, span),
+        },
+        Cbor::Tag(_, inner) => cbor_to_value(*inner, span),
+        _ => Value::nothing(span),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(FromCbor {})
+    }
+}
@@ -1,4 +1,6 @@
-use super::delimited::{from_delimited_data, trim_from_str, DelimitedReaderConfig};
+use super::delimited::{
+    from_delimited_data, on_error_from_str, schema_from_value, trim_from_str, DelimitedReaderConfig,
+};
 
 use nu_engine::CallExt;
 use nu_protocol::ast::Call;
@@ -59,6 +61,20 @@ impl Command for FromCsv {
                 "drop leading and trailing whitespaces around headers names and/or field values",
                 Some('t'),
             )
+            .named(
+                "schema",
+                SyntaxShape::Record,
+                "a record mapping column names to types (int, float, bool, datetime, string), \
+                 parsed instead of inferred",
+                None,
+            )
+            .named(
+                "on-error",
+                SyntaxShape::String,
+                "what to do with a --schema field that fails to parse: 'fail' (default), \
+                 'null', or 'skip' the whole row",
+                None,
+            )
             .category(Category::Formats)
     }
 
@@ -123,6 +139,18 @@ impl Command for FromCsv {
                 example: "open data.txt | from csv --trim fields",
                 result: None,
             },
+            Example {
+                description: "Parse specific columns as a given type instead of relying on inference, keeping a zip code a string",
+                example: "\"zip,dist\n02134,1.5\" | from csv --schema {zip: string, dist: float}",
+                result: Some(Value::List {
+                    vals: vec![Value::Record {
+                        cols: vec!["zip".to_string(), "dist".to_string()],
+                        vals: vec![Value::test_string("02134"), Value::test_float(1.5)],
+                        span: Span::test_data(),
+                    }],
+                    span: Span::test_data(),
+                }),
+            },
         ]
     }
 }
@@ -157,6 +185,8 @@ fn from_csv(
     let noheaders = call.has_flag("noheaders");
     let flexible = call.has_flag("flexible");
     let trim = trim_from_str(call.get_flag(engine_state, stack, "trim")?)?;
+    let schema = schema_from_value(call.get_flag(engine_state, stack, "schema")?)?;
+    let on_error = on_error_from_str(call.get_flag(engine_state, stack, "on-error")?)?;
 
     let config = DelimitedReaderConfig {
         separator,
@@ -167,6 +197,8 @@ fn from_csv(
         flexible,
         no_infer,
         trim,
+        schema,
+        on_error,
     };
 
     from_delimited_data(config, input, name)
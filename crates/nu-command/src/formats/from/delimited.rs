@@ -1,3 +1,4 @@
+use crate::parse_date_from_string;
 use csv::{ReaderBuilder, Trim};
 use nu_protocol::{IntoPipelineData, PipelineData, ShellError, Span, Value};
 
@@ -11,10 +12,12 @@ fn from_delimited_string_to_value(
         flexible,
         no_infer,
         trim,
+        schema,
+        on_error,
     }: DelimitedReaderConfig,
     s: String,
     span: Span,
-) -> Result<Value, csv::Error> {
+) -> Result<Value, ShellError> {
     let mut reader = ReaderBuilder::new()
         .has_headers(!noheaders)
         .flexible(flexible)
@@ -25,35 +28,55 @@ fn from_delimited_string_to_value(
         .trim(trim)
         .from_reader(s.as_bytes());
 
+    let to_csv_err = |err: csv::Error| ShellError::DelimiterError {
+        msg: err.to_string(),
+        span,
+    };
+
     let headers = if noheaders {
-        (1..=reader.headers()?.len())
+        (1..=reader.headers().map_err(to_csv_err)?.len())
             .map(|i| format!("column{i}"))
             .collect::<Vec<String>>()
     } else {
-        reader.headers()?.iter().map(String::from).collect()
+        reader
+            .headers()
+            .map_err(to_csv_err)?
+            .iter()
+            .map(String::from)
+            .collect()
     };
 
     let mut rows = vec![];
-    for row in reader.records() {
+    'rows: for row in reader.records() {
+        let row = row.map_err(to_csv_err)?;
         let mut output_row = vec![];
-        for value in row?.iter() {
-            if no_infer {
-                output_row.push(Value::String {
+        for (header, value) in headers.iter().zip(row.iter()) {
+            let column_type = schema.as_ref().and_then(|schema| {
+                schema
+                    .iter()
+                    .find(|(name, _)| name == header)
+                    .map(|(_, ty)| ty.as_str())
+            });
+
+            let parsed = match column_type {
+                Some(ty) => match parse_typed_value(value, ty, span) {
+                    Ok(value) => Some(value),
+                    Err(err) => match on_error {
+                        OnError::Fail => return Err(err),
+                        OnError::Null => Some(Value::nothing(span)),
+                        OnError::Skip => None,
+                    },
+                },
+                None if no_infer => Some(Value::String {
                     span,
                     val: value.into(),
-                });
-                continue;
-            }
+                }),
+                None => Some(infer_value(value, span)),
+            };
 
-            if let Ok(i) = value.parse::<i64>() {
-                output_row.push(Value::Int { val: i, span });
-            } else if let Ok(f) = value.parse::<f64>() {
-                output_row.push(Value::Float { val: f, span });
-            } else {
-                output_row.push(Value::String {
-                    val: value.into(),
-                    span,
-                });
+            match parsed {
+                Some(value) => output_row.push(value),
+                None => continue 'rows,
             }
         }
         rows.push(Value::Record {
@@ -66,6 +89,63 @@ fn from_delimited_string_to_value(
     Ok(Value::List { vals: rows, span })
 }
 
+fn infer_value(value: &str, span: Span) -> Value {
+    if let Ok(i) = value.parse::<i64>() {
+        Value::Int { val: i, span }
+    } else if let Ok(f) = value.parse::<f64>() {
+        Value::Float { val: f, span }
+    } else {
+        Value::String {
+            val: value.into(),
+            span,
+        }
+    }
+}
+
+/// Parses a single field according to a `--schema` column type, used to
+/// bypass the heuristic inference in [`infer_value`] when the caller already
+/// knows the shape of a column (e.g. a zip code that shouldn't become an
+/// int).
+fn parse_typed_value(value: &str, type_name: &str, span: Span) -> Result<Value, ShellError> {
+    match type_name {
+        "int" => value
+            .parse::<i64>()
+            .map(|val| Value::Int { val, span })
+            .map_err(|_| schema_type_error(value, type_name, span)),
+        "float" => value
+            .parse::<f64>()
+            .map(|val| Value::Float { val, span })
+            .map_err(|_| schema_type_error(value, type_name, span)),
+        "bool" => match value.to_ascii_lowercase().as_str() {
+            "true" => Ok(Value::boolean(true, span)),
+            "false" => Ok(Value::boolean(false, span)),
+            _ => Err(schema_type_error(value, type_name, span)),
+        },
+        "datetime" => parse_date_from_string(value, span)
+            .map(|val| Value::Date { val, span })
+            .map_err(|_| schema_type_error(value, type_name, span)),
+        "string" => Ok(Value::String {
+            val: value.into(),
+            span,
+        }),
+        _ => Err(ShellError::UnsupportedInput(
+            format!("unknown schema type '{type_name}'"),
+            "schema defined here".into(),
+            span,
+            span,
+        )),
+    }
+}
+
+fn schema_type_error(value: &str, type_name: &str, span: Span) -> ShellError {
+    ShellError::CantConvert {
+        to_type: type_name.into(),
+        from_type: "string".into(),
+        span,
+        help: Some(format!("'{value}' could not be parsed as {type_name}")),
+    }
+}
+
 pub(super) struct DelimitedReaderConfig {
     pub separator: char,
     pub comment: Option<char>,
@@ -75,6 +155,19 @@ pub(super) struct DelimitedReaderConfig {
     pub flexible: bool,
     pub no_infer: bool,
     pub trim: Trim,
+    pub schema: Option<Vec<(String, String)>>,
+    pub on_error: OnError,
+}
+
+/// What to do with a field that fails to parse against its `--schema` type.
+#[derive(Clone, Copy)]
+pub enum OnError {
+    /// Drop the whole row.
+    Skip,
+    /// Replace the field with `null`.
+    Null,
+    /// Stop and return an error (the default).
+    Fail,
 }
 
 pub(super) fn from_delimited_data(
@@ -84,11 +177,7 @@ pub(super) fn from_delimited_data(
 ) -> Result<PipelineData, ShellError> {
     let (concat_string, _span, metadata) = input.collect_string_strict(name)?;
 
-    Ok(from_delimited_string_to_value(config, concat_string, name)
-        .map_err(|x| ShellError::DelimiterError {
-            msg: x.to_string(),
-            span: name,
-        })?
+    Ok(from_delimited_string_to_value(config, concat_string, name)?
         .into_pipeline_data_with_metadata(metadata))
 }
 
@@ -109,3 +198,38 @@ pub fn trim_from_str(trim: Option<Value>) -> Result<Trim, ShellError> {
         _ => Ok(Trim::None),
     }
 }
+
+pub fn schema_from_value(
+    schema: Option<Value>,
+) -> Result<Option<Vec<(String, String)>>, ShellError> {
+    match schema {
+        Some(Value::Record { cols, vals, .. }) => {
+            let mut columns = vec![];
+            for (col, val) in cols.into_iter().zip(vals) {
+                columns.push((col, val.as_string()?));
+            }
+            Ok(Some(columns))
+        }
+        Some(val) => Err(ShellError::TypeMismatch {
+            err_message: "schema must be a record mapping column names to type names".into(),
+            span: val.expect_span(),
+        }),
+        None => Ok(None),
+    }
+}
+
+pub fn on_error_from_str(on_error: Option<Value>) -> Result<OnError, ShellError> {
+    match on_error {
+        Some(Value::String { val: item, span }) => match item.as_str() {
+            "skip" => Ok(OnError::Skip),
+            "null" => Ok(OnError::Null),
+            "fail" => Ok(OnError::Fail),
+            _ => Err(ShellError::TypeMismatch {
+                err_message: "the only possible values for on-error are 'skip', 'null' and 'fail'"
+                    .into(),
+                span,
+            }),
+        },
+        _ => Ok(OnError::Fail),
+    }
+}
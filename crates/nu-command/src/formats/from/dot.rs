@@ -0,0 +1,389 @@
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct FromDot;
+
+impl Command for FromDot {
+    fn name(&self) -> &str {
+        "from dot"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("from dot")
+            .input_output_types(vec![(Type::String, Type::Record(vec![]))])
+            .category(Category::Formats)
+    }
+
+    fn usage(&self) -> &str {
+        "Parse text as Graphviz DOT and create a record of its nodes and edges."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Supports a practical subset of the DOT language: a single top-level
+`graph`/`digraph` (optionally `strict`) containing node statements, edge
+statements (a single `->`/`--` per statement) and `key=value` graph
+attributes, each with an optional `[attr=val, ...]` attribute list.
+Subgraphs, multi-edge chains (`a -> b -> c`), ports, and `node`/`edge`
+default-attribute blocks are not parsed; any statement using them is
+skipped."#
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        from_dot(input, head)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Parse a small digraph into a record of nodes and edges",
+            example: "'digraph { a -> b; a [color=red]; }' | from dot",
+            result: Some(Value::Record {
+                cols: vec![
+                    "strict".to_string(),
+                    "directed".to_string(),
+                    "name".to_string(),
+                    "attributes".to_string(),
+                    "nodes".to_string(),
+                    "edges".to_string(),
+                ],
+                vals: vec![
+                    Value::test_bool(false),
+                    Value::test_bool(true),
+                    Value::test_string(""),
+                    Value::Record {
+                        cols: vec![],
+                        vals: vec![],
+                        span: Span::test_data(),
+                    },
+                    Value::List {
+                        vals: vec![Value::Record {
+                            cols: vec!["id".to_string(), "color".to_string()],
+                            vals: vec![Value::test_string("a"), Value::test_string("red")],
+                            span: Span::test_data(),
+                        }],
+                        span: Span::test_data(),
+                    },
+                    Value::List {
+                        vals: vec![Value::Record {
+                            cols: vec!["from".to_string(), "to".to_string()],
+                            vals: vec![Value::test_string("a"), Value::test_string("b")],
+                            span: Span::test_data(),
+                        }],
+                        span: Span::test_data(),
+                    },
+                ],
+                span: Span::test_data(),
+            }),
+        }]
+    }
+}
+
+fn from_dot(input: PipelineData, head: Span) -> Result<PipelineData, ShellError> {
+    let (input_string, span, metadata) = input.collect_string_strict(head)?;
+
+    let graph = parse_dot(&input_string, head, span)?;
+
+    Ok(graph.into_pipeline_data_with_metadata(metadata))
+}
+
+/// Strips `//...` and `/*...*/` comments, which DOT allows anywhere outside quotes.
+fn strip_comments(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    let mut chars = input.chars().peekable();
+    let mut in_quotes = false;
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => {
+                in_quotes = !in_quotes;
+                out.push(c);
+            }
+            '/' if !in_quotes && chars.peek() == Some(&'/') => {
+                while let Some(&next) = chars.peek() {
+                    if next == '\n' {
+                        break;
+                    }
+                    chars.next();
+                }
+            }
+            '/' if !in_quotes && chars.peek() == Some(&'*') => {
+                chars.next();
+                while let Some(next) = chars.next() {
+                    if next == '*' && chars.peek() == Some(&'/') {
+                        chars.next();
+                        break;
+                    }
+                }
+            }
+            _ => out.push(c),
+        }
+    }
+
+    out
+}
+
+fn parse_dot(input: &str, head: Span, span: Span) -> Result<Value, ShellError> {
+    let input = strip_comments(input);
+    let trimmed = input.trim_start();
+
+    let parse_err = |message: &str| {
+        ShellError::GenericError(
+            "Error while parsing DOT text".into(),
+            message.into(),
+            Some(head),
+            None,
+            vec![],
+        )
+    };
+
+    let (strict, rest) = match trimmed.strip_prefix("strict") {
+        Some(rest) => (true, rest.trim_start()),
+        None => (false, trimmed),
+    };
+
+    let (directed, rest) = if let Some(rest) = rest.strip_prefix("digraph") {
+        (true, rest)
+    } else if let Some(rest) = rest.strip_prefix("graph") {
+        (false, rest)
+    } else {
+        return Err(parse_err("expected `graph` or `digraph`"));
+    };
+
+    let open = rest.find('{').ok_or_else(|| parse_err("expected `{`"))?;
+    let name = rest[..open].trim().trim_matches('"').to_string();
+
+    let close = rest
+        .rfind('}')
+        .ok_or_else(|| parse_err("expected closing `}`"))?;
+    let body = &rest[open + 1..close];
+
+    let mut attributes_cols = vec![];
+    let mut attributes_vals = vec![];
+    let mut nodes = vec![];
+    let mut edges = vec![];
+
+    for statement in split_statements(body) {
+        if let Some((left, _op, right)) = find_edge(&statement) {
+            let attrs = trailing_attrs(right);
+            let (right_id, _) = split_trailing_attrs(right);
+
+            // Chains, ports and subgraph endpoints aren't modeled; see extra_usage.
+            let unmodeled = |end: &str| {
+                let end = end.trim();
+                end.starts_with('{')
+                    || end.starts_with("subgraph")
+                    || (!end.starts_with('"') && end.contains(':'))
+            };
+            if find_edge(right_id).is_some() || unmodeled(left) || unmodeled(right_id) {
+                continue;
+            }
+
+            let mut cols = vec!["from".to_string(), "to".to_string()];
+            let mut vals = vec![
+                Value::string(unquote(left.trim()), span),
+                Value::string(unquote(right_id.trim()), span),
+            ];
+            for (key, val) in attrs {
+                cols.push(key);
+                vals.push(Value::string(val, span));
+            }
+            edges.push(Value::Record { cols, vals, span });
+        } else if statement.starts_with("subgraph") || statement.starts_with('{') {
+            // Subgraphs aren't modeled; see extra_usage.
+            continue;
+        } else if let Some(eq) = top_level_eq(&statement) {
+            let (key, val) = statement.split_at(eq);
+            let val = &val[1..];
+            attributes_cols.push(unquote(key.trim()));
+            attributes_vals.push(Value::string(unquote(val.trim()), span));
+        } else {
+            let (id, attrs_str) = split_trailing_attrs(&statement);
+            let id = id.trim();
+            if id.is_empty() || id == "node" || id == "edge" {
+                // Default attribute blocks for nodes/edges aren't modeled; see extra_usage.
+                continue;
+            }
+
+            let mut cols = vec!["id".to_string()];
+            let mut vals = vec![Value::string(unquote(id), span)];
+            for (key, val) in parse_attr_list(attrs_str) {
+                cols.push(key);
+                vals.push(Value::string(val, span));
+            }
+            nodes.push(Value::Record { cols, vals, span });
+        }
+    }
+
+    Ok(Value::Record {
+        cols: vec![
+            "strict".to_string(),
+            "directed".to_string(),
+            "name".to_string(),
+            "attributes".to_string(),
+            "nodes".to_string(),
+            "edges".to_string(),
+        ],
+        vals: vec![
+            Value::bool(strict, span),
+            Value::bool(directed, span),
+            Value::string(name, span),
+            Value::Record {
+                cols: attributes_cols,
+                vals: attributes_vals,
+                span,
+            },
+            Value::List { vals: nodes, span },
+            Value::List { vals: edges, span },
+        ],
+        span,
+    })
+}
+
+/// Splits `body` into top-level statements, respecting quoted strings and
+/// `[...]` attribute lists so neither a `;` nor a newline inside one ends
+/// the statement early.
+fn split_statements(body: &str) -> Vec<String> {
+    let mut statements = vec![];
+    let mut current = String::new();
+    let mut bracket_depth = 0i32;
+    let mut in_quotes = false;
+
+    for c in body.chars() {
+        match c {
+            '"' => {
+                in_quotes = !in_quotes;
+                current.push(c);
+            }
+            '[' if !in_quotes => {
+                bracket_depth += 1;
+                current.push(c);
+            }
+            ']' if !in_quotes => {
+                bracket_depth -= 1;
+                current.push(c);
+            }
+            ';' | '\n' if !in_quotes && bracket_depth == 0 => {
+                if !current.trim().is_empty() {
+                    statements.push(current.trim().to_string());
+                }
+                current.clear();
+            }
+            _ => current.push(c),
+        }
+    }
+    if !current.trim().is_empty() {
+        statements.push(current.trim().to_string());
+    }
+
+    statements
+}
+
+/// Finds a single top-level `->`/`--` edge operator outside quotes, returning
+/// the text on either side.
+fn find_edge(statement: &str) -> Option<(&str, &str, &str)> {
+    let mut in_quotes = false;
+    let chars: Vec<char> = statement.chars().collect();
+
+    let mut i = 0;
+    while i < chars.len() {
+        match chars[i] {
+            '"' => in_quotes = !in_quotes,
+            '-' if !in_quotes
+                && i + 1 < chars.len()
+                && (chars[i + 1] == '>' || chars[i + 1] == '-') =>
+            {
+                let op_end = i + 2;
+                return Some((&statement[..i], &statement[i..op_end], &statement[op_end..]));
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+
+    None
+}
+
+/// A bare `key=value` graph attribute statement has no `[`/edge operator at
+/// all; this returns the byte offset of its top-level `=`, if any.
+fn top_level_eq(statement: &str) -> Option<usize> {
+    if statement.contains('[') || find_edge(statement).is_some() {
+        return None;
+    }
+    statement.find('=')
+}
+
+fn split_trailing_attrs(s: &str) -> (&str, &str) {
+    match s.find('[') {
+        Some(i) => (&s[..i], &s[i..]),
+        None => (s, ""),
+    }
+}
+
+fn trailing_attrs(s: &str) -> Vec<(String, String)> {
+    let (_, attrs) = split_trailing_attrs(s);
+    parse_attr_list(attrs)
+}
+
+/// Parses a `[key=val, key2="val 2"; key3=val3]` attribute list (both `,`
+/// and `;` separate entries).
+fn parse_attr_list(s: &str) -> Vec<(String, String)> {
+    let inner = s.trim().trim_start_matches('[').trim_end_matches(']');
+
+    let mut entries = vec![];
+    let mut current = String::new();
+    let mut in_quotes = false;
+
+    for c in inner.chars() {
+        match c {
+            '"' => {
+                in_quotes = !in_quotes;
+                current.push(c);
+            }
+            ',' | ';' if !in_quotes => {
+                entries.push(current.clone());
+                current.clear();
+            }
+            _ => current.push(c),
+        }
+    }
+    if !current.trim().is_empty() {
+        entries.push(current);
+    }
+
+    entries
+        .into_iter()
+        .filter_map(|entry| {
+            let (key, val) = entry.split_once('=')?;
+            Some((unquote(key.trim()), unquote(val.trim())))
+        })
+        .collect()
+}
+
+fn unquote(s: &str) -> String {
+    s.strip_prefix('"')
+        .and_then(|s| s.strip_suffix('"'))
+        .unwrap_or(s)
+        .to_string()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(FromDot {})
+    }
+}
@@ -0,0 +1,369 @@
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Type, Value,
+};
+use std::iter::Peekable;
+use std::str::CharIndices;
+
+#[derive(Clone)]
+pub struct FromEdn;
+
+impl Command for FromEdn {
+    fn name(&self) -> &str {
+        "from edn"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("from edn")
+            .input_output_types(vec![(Type::String, Type::Any)])
+            .category(Category::Formats)
+    }
+
+    fn usage(&self) -> &str {
+        "Parse text as EDN (extensible data notation) and create structured data."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Keywords (`:foo`, `:ns/foo`) become strings with the leading colon kept, so
+they can be told apart from plain strings on the way back out through `to
+edn`. Lists and vectors both become lists. Sets become a record with a
+single `edn-set` column holding the list of members. Tagged literals (`#tag
+value`) become a record with `tag` and `value` columns. Rationals (`3/4`)
+are converted to their floating-point value."#
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        from_edn(input, head)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                example: "'{:a 1 :b 2}' | from edn",
+                description: "Converts edn formatted string to record",
+                result: Some(Value::Record {
+                    cols: vec![":a".to_string(), ":b".to_string()],
+                    vals: vec![Value::test_int(1), Value::test_int(2)],
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                example: "'#inst \"2023-01-01\"' | from edn",
+                description: "Tagged literals become a record with tag and value",
+                result: Some(Value::Record {
+                    cols: vec!["tag".to_string(), "value".to_string()],
+                    vals: vec![Value::test_string("inst"), Value::test_string("2023-01-01")],
+                    span: Span::test_data(),
+                }),
+            },
+        ]
+    }
+}
+
+fn from_edn(input: PipelineData, head: Span) -> Result<PipelineData, ShellError> {
+    let (input_string, span, metadata) = input.collect_string_strict(head)?;
+
+    let mut parser = EdnParser {
+        chars: input_string.char_indices().peekable(),
+        source: &input_string,
+        span,
+        head,
+    };
+
+    parser.skip_whitespace();
+    let value = parser.parse_value()?;
+    parser.skip_whitespace();
+
+    if parser.chars.peek().is_some() {
+        return Err(ShellError::GenericError(
+            "Error while parsing edn text".into(),
+            "unexpected trailing data".into(),
+            Some(head),
+            None,
+            vec![],
+        ));
+    }
+
+    Ok(value.into_pipeline_data_with_metadata(metadata))
+}
+
+struct EdnParser<'a> {
+    chars: Peekable<CharIndices<'a>>,
+    source: &'a str,
+    /// Span of the whole collected input, used for every value produced
+    /// (the hand-rolled parser below doesn't track per-token positions).
+    span: Span,
+    /// Span of the `from edn` call itself, used for error messages.
+    head: Span,
+}
+
+impl<'a> EdnParser<'a> {
+    fn error(&self, msg: &str) -> ShellError {
+        ShellError::GenericError(
+            "Error while parsing edn text".into(),
+            msg.into(),
+            Some(self.head),
+            None,
+            vec![],
+        )
+    }
+
+    fn skip_whitespace(&mut self) {
+        loop {
+            match self.chars.peek() {
+                Some((_, c)) if c.is_whitespace() || *c == ',' => {
+                    self.chars.next();
+                }
+                Some((_, ';')) => {
+                    while let Some((_, c)) = self.chars.peek() {
+                        if *c == '\n' {
+                            break;
+                        }
+                        self.chars.next();
+                    }
+                }
+                _ => break,
+            }
+        }
+    }
+
+    fn parse_value(&mut self) -> Result<Value, ShellError> {
+        self.skip_whitespace();
+        let span = self.span;
+        let (_, c) = *self
+            .chars
+            .peek()
+            .ok_or_else(|| self.error("unexpected end of input"))?;
+
+        match c {
+            '{' => self.parse_map(),
+            '[' => self
+                .parse_collection('[', ']')
+                .map(|vals| Value::List { vals, span }),
+            '(' => self
+                .parse_collection('(', ')')
+                .map(|vals| Value::List { vals, span }),
+            '#' => self.parse_dispatch(),
+            '"' => self.parse_string(),
+            ':' => self.parse_keyword(),
+            '\\' => self.parse_char(),
+            _ => self.parse_atom(),
+        }
+    }
+
+    fn parse_collection(&mut self, open: char, close: char) -> Result<Vec<Value>, ShellError> {
+        let (_, c) = self.chars.next().expect("caller already peeked");
+        debug_assert_eq!(c, open);
+
+        let mut vals = vec![];
+        loop {
+            self.skip_whitespace();
+            match self.chars.peek() {
+                Some((_, c)) if *c == close => {
+                    self.chars.next();
+                    break;
+                }
+                Some(_) => vals.push(self.parse_value()?),
+                None => return Err(self.error("unexpected end of input inside collection")),
+            }
+        }
+        Ok(vals)
+    }
+
+    fn parse_map(&mut self) -> Result<Value, ShellError> {
+        let span = self.span;
+        self.chars.next(); // consume '{'
+
+        let mut entries = vec![];
+        loop {
+            self.skip_whitespace();
+            match self.chars.peek() {
+                Some((_, '}')) => {
+                    self.chars.next();
+                    break;
+                }
+                Some(_) => {
+                    let key = self.parse_value()?;
+                    self.skip_whitespace();
+                    let value = self.parse_value()?;
+                    entries.push((key, value));
+                }
+                None => return Err(self.error("unexpected end of input inside map")),
+            }
+        }
+
+        // A map with only string/keyword keys round-trips as a record; any
+        // other key shape becomes a list of [key, value] pairs instead, since
+        // nu records require string column names.
+        let all_string_keys = entries
+            .iter()
+            .all(|(key, _)| matches!(key, Value::String { .. }));
+
+        if all_string_keys {
+            let mut cols = vec![];
+            let mut vals = vec![];
+            for (key, value) in entries {
+                if let Value::String { val, .. } = key {
+                    cols.push(val);
+                }
+                vals.push(value);
+            }
+            Ok(Value::Record { cols, vals, span })
+        } else {
+            let vals = entries
+                .into_iter()
+                .map(|(key, value)| Value::Record {
+                    cols: vec!["key".to_string(), "value".to_string()],
+                    vals: vec![key, value],
+                    span,
+                })
+                .collect();
+            Ok(Value::List { vals, span })
+        }
+    }
+
+    fn parse_dispatch(&mut self) -> Result<Value, ShellError> {
+        let span = self.span;
+        self.chars.next(); // consume '#'
+
+        match self.chars.peek() {
+            Some((_, '{')) => {
+                let vals = self.parse_collection('{', '}')?;
+                Ok(Value::Record {
+                    cols: vec!["edn-set".to_string()],
+                    vals: vec![Value::List { vals, span }],
+                    span,
+                })
+            }
+            Some(_) => {
+                let tag = self.read_symbol();
+                self.skip_whitespace();
+                let value = self.parse_value()?;
+                Ok(Value::Record {
+                    cols: vec!["tag".to_string(), "value".to_string()],
+                    vals: vec![Value::string(tag, span), value],
+                    span,
+                })
+            }
+            None => Err(self.error("unexpected end of input after '#'")),
+        }
+    }
+
+    fn parse_string(&mut self) -> Result<Value, ShellError> {
+        let span = self.span;
+        self.chars.next(); // consume opening quote
+
+        let mut result = String::new();
+        loop {
+            match self.chars.next() {
+                Some((_, '"')) => break,
+                Some((_, '\\')) => match self.chars.next() {
+                    Some((_, 'n')) => result.push('\n'),
+                    Some((_, 't')) => result.push('\t'),
+                    Some((_, 'r')) => result.push('\r'),
+                    Some((_, '\\')) => result.push('\\'),
+                    Some((_, '"')) => result.push('"'),
+                    Some((_, other)) => result.push(other),
+                    None => return Err(self.error("unexpected end of input inside string")),
+                },
+                Some((_, c)) => result.push(c),
+                None => return Err(self.error("unexpected end of input inside string")),
+            }
+        }
+
+        Ok(Value::string(result, span))
+    }
+
+    fn parse_keyword(&mut self) -> Result<Value, ShellError> {
+        let span = self.span;
+        let mut keyword = String::from(":");
+        self.chars.next(); // consume ':'
+        keyword.push_str(&self.read_symbol());
+        Ok(Value::string(keyword, span))
+    }
+
+    fn parse_char(&mut self) -> Result<Value, ShellError> {
+        let span = self.span;
+        self.chars.next(); // consume '\'
+        let literal = self.read_symbol();
+        let ch = match literal.as_str() {
+            "newline" => '\n',
+            "space" => ' ',
+            "tab" => '\t',
+            "return" => '\r',
+            single if single.chars().count() == 1 => single.chars().next().unwrap(),
+            _ => return Err(self.error("unrecognized character literal")),
+        };
+        Ok(Value::string(ch.to_string(), span))
+    }
+
+    fn parse_atom(&mut self) -> Result<Value, ShellError> {
+        let span = self.span;
+        let token = self.read_symbol();
+
+        match token.as_str() {
+            "nil" => return Ok(Value::nothing(span)),
+            "true" => return Ok(Value::boolean(true, span)),
+            "false" => return Ok(Value::boolean(false, span)),
+            _ => {}
+        }
+
+        if let Some((numer, denom)) = token.split_once('/') {
+            if let (Ok(numer), Ok(denom)) = (numer.parse::<f64>(), denom.parse::<f64>()) {
+                return Ok(Value::float(numer / denom, span));
+            }
+        }
+
+        let trimmed = token.trim_end_matches(['N', 'M']);
+        if let Ok(i) = trimmed.parse::<i64>() {
+            return Ok(Value::int(i, span));
+        }
+        if let Ok(f) = trimmed.parse::<f64>() {
+            return Ok(Value::float(f, span));
+        }
+
+        // Symbols fall back to plain strings.
+        Ok(Value::string(token, span))
+    }
+
+    /// Reads a run of characters that make up a symbol, keyword name, or
+    /// number literal (everything up to the next delimiter or whitespace).
+    fn read_symbol(&mut self) -> String {
+        let start = match self.chars.peek() {
+            Some((idx, _)) => *idx,
+            None => return String::new(),
+        };
+        let mut end = start;
+
+        while let Some((idx, c)) = self.chars.peek() {
+            if c.is_whitespace() || matches!(c, ',' | '{' | '}' | '[' | ']' | '(' | ')' | '"' | ';')
+            {
+                break;
+            }
+            end = idx + c.len_utf8();
+            self.chars.next();
+        }
+
+        self.source[start..end].to_string()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(FromEdn {})
+    }
+}
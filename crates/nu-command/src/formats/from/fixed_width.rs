@@ -0,0 +1,282 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, SyntaxShape,
+    Type, Value,
+};
+
+#[derive(Clone)]
+pub struct FromFixedWidth;
+
+struct ColumnSpec {
+    name: String,
+    start: usize,
+    len: Option<usize>,
+    kind: String,
+}
+
+impl Command for FromFixedWidth {
+    fn name(&self) -> &str {
+        "from fixed-width"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("from fixed-width")
+            .input_output_types(vec![(Type::String, Type::Table(vec![]))])
+            .named(
+                "spec",
+                SyntaxShape::Any,
+                "a list of {name, start, len, type} records describing each column; \
+type is one of int/float/bool/string and defaults to string",
+                Some('s'),
+            )
+            .switch(
+                "skip-header",
+                "skip the first line instead of reading it as data",
+                None,
+            )
+            .category(Category::Formats)
+    }
+
+    fn usage(&self) -> &str {
+        "Parse fixed-width columns into a table."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Without `--spec`, column boundaries are inferred from the first line
+(treated as a header, always consumed whether or not `--skip-header` is
+given): each run of non-space characters becomes a column named after that
+text, starting at its column offset and running up to the next column's
+start (or to the end of the line for the last column). Rows shorter than a
+column's range are padded with an empty value rather than erroring, which
+matters for mainframe-style exports where trailing columns are sometimes
+blank."#
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let spec: Option<Value> = call.get_flag(engine_state, stack, "spec")?;
+        let skip_header = call.has_flag("skip-header");
+
+        let value = input.into_value(head);
+        let Value::String { val: text, .. } = &value else {
+            return Err(ShellError::UnsupportedInput(
+                format!("{:?} is not valid fixed-width input", value.get_type()),
+                "value originates from here".into(),
+                head,
+                value.expect_span(),
+            ));
+        };
+
+        from_fixed_width(text, spec, skip_header, head)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Infer columns from a header row",
+                example: r#""ID   NAME
+1    Alice
+22   Bob" | from fixed-width"#,
+                result: None,
+            },
+            Example {
+                description: "Read columns using an explicit spec",
+                example: r#""1       Alice
+22      Bob" | from fixed-width --spec [[name, start, len, type]; [id, 0, 8, int] [name, 8, 10, string]]"#,
+                result: None,
+            },
+        ]
+    }
+}
+
+fn from_fixed_width(
+    text: &str,
+    spec: Option<Value>,
+    skip_header: bool,
+    head: Span,
+) -> Result<PipelineData, ShellError> {
+    let mut lines = text.lines();
+
+    let columns = match spec {
+        Some(spec) => {
+            if skip_header {
+                lines.next();
+            }
+            parse_spec(&spec, head)?
+        }
+        None => {
+            let header = lines.next().unwrap_or("");
+            infer_columns(header)
+        }
+    };
+
+    let rows = lines
+        .filter(|line| !line.is_empty())
+        .map(|line| row_to_value(line, &columns, head))
+        .collect::<Vec<_>>();
+
+    Ok(rows.into_iter().into_pipeline_data())
+}
+
+fn infer_columns(header: &str) -> Vec<ColumnSpec> {
+    let mut columns = vec![];
+    let chars: Vec<char> = header.chars().collect();
+    let mut i = 0;
+
+    while i < chars.len() {
+        if chars[i].is_whitespace() {
+            i += 1;
+            continue;
+        }
+
+        let start = i;
+        while i < chars.len() && !chars[i].is_whitespace() {
+            i += 1;
+        }
+
+        columns.push(ColumnSpec {
+            name: chars[start..i].iter().collect(),
+            start,
+            len: None,
+            kind: "string".to_string(),
+        });
+    }
+
+    for idx in 0..columns.len() {
+        if idx + 1 < columns.len() {
+            let next_start = columns[idx + 1].start;
+            columns[idx].len = Some(next_start - columns[idx].start);
+        }
+    }
+
+    columns
+}
+
+fn parse_spec(spec: &Value, head: Span) -> Result<Vec<ColumnSpec>, ShellError> {
+    let Value::List { vals, .. } = spec else {
+        return Err(ShellError::UnsupportedInput(
+            format!("{:?} is not a valid --spec value", spec.get_type()),
+            "value originates from here".into(),
+            head,
+            spec.expect_span(),
+        ));
+    };
+
+    vals.iter()
+        .map(|entry| {
+            let Value::Record { cols, vals, .. } = entry else {
+                return Err(ShellError::UnsupportedInput(
+                    "each --spec entry must be a record".into(),
+                    "value originates from here".into(),
+                    head,
+                    entry.expect_span(),
+                ));
+            };
+
+            let field = |name: &str| cols.iter().position(|c| c == name).map(|i| &vals[i]);
+
+            let name = match field("name") {
+                Some(Value::String { val, .. }) => val.clone(),
+                _ => {
+                    return Err(ShellError::UnsupportedInput(
+                        "--spec entry is missing its `name` column".into(),
+                        "value originates from here".into(),
+                        head,
+                        entry.expect_span(),
+                    ))
+                }
+            };
+
+            let start = match field("start") {
+                Some(Value::Int { val, .. }) => *val as usize,
+                _ => {
+                    return Err(ShellError::UnsupportedInput(
+                        "--spec entry is missing its `start` column".into(),
+                        "value originates from here".into(),
+                        head,
+                        entry.expect_span(),
+                    ))
+                }
+            };
+
+            let len = match field("len") {
+                Some(Value::Int { val, .. }) => Some(*val as usize),
+                _ => None,
+            };
+
+            let kind = match field("type") {
+                Some(Value::String { val, .. }) => val.clone(),
+                _ => "string".to_string(),
+            };
+
+            Ok(ColumnSpec {
+                name,
+                start,
+                len,
+                kind,
+            })
+        })
+        .collect()
+}
+
+fn row_to_value(line: &str, columns: &[ColumnSpec], span: Span) -> Value {
+    let chars: Vec<char> = line.chars().collect();
+
+    let mut cols = vec![];
+    let mut vals = vec![];
+
+    for column in columns {
+        let end = column
+            .len
+            .map(|len| column.start + len)
+            .unwrap_or(chars.len());
+        let start = column.start.min(chars.len());
+        let end = end.min(chars.len()).max(start);
+
+        let raw: String = chars[start..end].iter().collect();
+
+        cols.push(column.name.clone());
+        vals.push(convert_field(raw.trim(), &column.kind, span));
+    }
+
+    Value::Record { cols, vals, span }
+}
+
+fn convert_field(raw: &str, kind: &str, span: Span) -> Value {
+    match kind {
+        "int" => raw
+            .parse::<i64>()
+            .map(|val| Value::int(val, span))
+            .unwrap_or_else(|_| Value::string(raw, span)),
+        "float" => raw
+            .parse::<f64>()
+            .map(|val| Value::float(val, span))
+            .unwrap_or_else(|_| Value::string(raw, span)),
+        "bool" => match raw {
+            "true" | "1" => Value::bool(true, span),
+            "false" | "0" => Value::bool(false, span),
+            _ => Value::string(raw, span),
+        },
+        _ => Value::string(raw, span),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(FromFixedWidth {})
+    }
+}
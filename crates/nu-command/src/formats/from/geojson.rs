@@ -0,0 +1,267 @@
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
+    Type, Value,
+};
+
+#[derive(Clone)]
+pub struct FromGeojson;
+
+impl Command for FromGeojson {
+    fn name(&self) -> &str {
+        "from geojson"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("from geojson")
+            .input_output_types(vec![(Type::String, Type::Table(vec![]))])
+            .category(Category::Formats)
+    }
+
+    fn usage(&self) -> &str {
+        "Convert from geojson to a table, one row per feature."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Each row carries the feature's `properties` as top-level columns, plus a
+`geometry` record (`type` and `coordinates`), a `bbox` list if the feature
+carries one, and a `centroid` point computed as the plain average of every
+coordinate pair in the geometry. That centroid is a quick-triage estimate,
+not a true geometric centroid — it isn't area- or length-weighted, so it
+can land outside oddly-shaped polygons. A bare `Feature` or geometry object
+is accepted too, producing a single row."#
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        from_geojson(input, head, engine_state.ctrlc.clone())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Flatten a FeatureCollection of points into a table",
+            example: r#"'{"type": "FeatureCollection", "features": [{"type": "Feature", "properties": {"name": "home"}, "geometry": {"type": "Point", "coordinates": [1, 2]}}]}' | from geojson"#,
+            result: None,
+        }]
+    }
+}
+
+fn collect_string(input: PipelineData, span: Span) -> Result<String, ShellError> {
+    let value = input.into_value(span);
+    match value {
+        Value::String { val, .. } => Ok(val),
+        other => Err(ShellError::UnsupportedInput(
+            "Expected string from pipeline".to_string(),
+            "value originates from here".into(),
+            span,
+            other.expect_span(),
+        )),
+    }
+}
+
+fn from_geojson(
+    input: PipelineData,
+    head: Span,
+    ctrlc: Option<std::sync::Arc<std::sync::atomic::AtomicBool>>,
+) -> Result<PipelineData, ShellError> {
+    let span = input.span().unwrap_or(head);
+    let text = collect_string(input, head)?;
+
+    let root: nu_json::Value = nu_json::from_str(&text).map_err(|e| {
+        ShellError::UnsupportedInput(
+            format!("Could not parse GeoJSON: {e}"),
+            "value originates from here".into(),
+            head,
+            span,
+        )
+    })?;
+
+    let features = match object_field(&root, "type").and_then(|t| t.as_str()) {
+        Some("FeatureCollection") => object_field(&root, "features")
+            .and_then(|f| f.as_array())
+            .cloned()
+            .unwrap_or_default(),
+        _ => vec![root],
+    };
+
+    let rows = features
+        .into_iter()
+        .map(|feature| feature_to_row(&feature, head))
+        .collect::<Vec<_>>();
+
+    Ok(rows.into_iter().into_pipeline_data(ctrlc))
+}
+
+fn object_field<'a>(value: &'a nu_json::Value, name: &str) -> Option<&'a nu_json::Value> {
+    match value {
+        nu_json::Value::Object(map) => map.get(name),
+        _ => None,
+    }
+}
+
+fn feature_to_row(feature: &nu_json::Value, span: Span) -> Value {
+    let properties = object_field(feature, "properties");
+    let geometry = object_field(feature, "geometry").unwrap_or(feature);
+
+    let mut cols = vec![];
+    let mut vals = vec![];
+
+    if let Some(nu_json::Value::Object(map)) = properties {
+        for (key, val) in map.iter() {
+            cols.push(key.clone());
+            vals.push(convert_nujson_to_value(val, span));
+        }
+    }
+
+    cols.push("geometry".to_string());
+    vals.push(geometry_to_value(geometry, span));
+
+    cols.push("bbox".to_string());
+    vals.push(
+        match object_field(feature, "bbox").and_then(|b| b.as_array()) {
+            Some(bbox) => Value::List {
+                vals: bbox
+                    .iter()
+                    .map(|v| convert_nujson_to_value(v, span))
+                    .collect(),
+                span,
+            },
+            None => Value::nothing(span),
+        },
+    );
+
+    cols.push("centroid".to_string());
+    vals.push(centroid_to_value(geometry, span));
+
+    Value::Record { cols, vals, span }
+}
+
+fn geometry_to_value(geometry: &nu_json::Value, span: Span) -> Value {
+    let geometry_type = object_field(geometry, "type").and_then(|t| t.as_str());
+    let coordinates = object_field(geometry, "coordinates");
+
+    match (geometry_type, coordinates) {
+        (Some(geometry_type), Some(coordinates)) => Value::Record {
+            cols: vec!["type".to_string(), "coordinates".to_string()],
+            vals: vec![
+                Value::string(geometry_type, span),
+                convert_nujson_to_value(coordinates, span),
+            ],
+            span,
+        },
+        _ => Value::nothing(span),
+    }
+}
+
+/// Walks the (arbitrarily nested) `coordinates` array collecting every
+/// `[x, y, ...]` leaf pair and averages them, giving a quick centroid
+/// estimate without needing real geometry math for each GeoJSON type.
+fn centroid_to_value(geometry: &nu_json::Value, span: Span) -> Value {
+    let mut sum_x = 0.0;
+    let mut sum_y = 0.0;
+    let mut count = 0;
+
+    if let Some(coordinates) = object_field(geometry, "coordinates") {
+        collect_points(coordinates, &mut sum_x, &mut sum_y, &mut count);
+    }
+
+    if count == 0 {
+        return Value::nothing(span);
+    }
+
+    Value::Record {
+        cols: vec!["x".to_string(), "y".to_string()],
+        vals: vec![
+            Value::float(sum_x / count as f64, span),
+            Value::float(sum_y / count as f64, span),
+        ],
+        span,
+    }
+}
+
+fn collect_points(value: &nu_json::Value, sum_x: &mut f64, sum_y: &mut f64, count: &mut usize) {
+    match value {
+        nu_json::Value::Array(items) => {
+            let all_numbers = items.iter().all(|item| {
+                matches!(
+                    item,
+                    nu_json::Value::F64(_) | nu_json::Value::I64(_) | nu_json::Value::U64(_)
+                )
+            });
+
+            if all_numbers && items.len() >= 2 {
+                if let (Some(x), Some(y)) = (as_f64(&items[0]), as_f64(&items[1])) {
+                    *sum_x += x;
+                    *sum_y += y;
+                    *count += 1;
+                }
+            } else {
+                for item in items {
+                    collect_points(item, sum_x, sum_y, count);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+fn as_f64(value: &nu_json::Value) -> Option<f64> {
+    match value {
+        nu_json::Value::F64(f) => Some(*f),
+        nu_json::Value::I64(i) => Some(*i as f64),
+        nu_json::Value::U64(u) => Some(*u as f64),
+        _ => None,
+    }
+}
+
+fn convert_nujson_to_value(value: &nu_json::Value, span: Span) -> Value {
+    match value {
+        nu_json::Value::Array(array) => Value::List {
+            vals: array
+                .iter()
+                .map(|x| convert_nujson_to_value(x, span))
+                .collect(),
+            span,
+        },
+        nu_json::Value::Bool(b) => Value::Bool { val: *b, span },
+        nu_json::Value::F64(f) => Value::Float { val: *f, span },
+        nu_json::Value::I64(i) => Value::Int { val: *i, span },
+        nu_json::Value::Null => Value::Nothing { span },
+        nu_json::Value::Object(k) => {
+            let mut cols = vec![];
+            let mut vals = vec![];
+            for item in k {
+                cols.push(item.0.clone());
+                vals.push(convert_nujson_to_value(item.1, span));
+            }
+            Value::Record { cols, vals, span }
+        }
+        nu_json::Value::U64(u) => Value::Int {
+            val: *u as i64,
+            span,
+        },
+        nu_json::Value::String(s) => Value::String {
+            val: s.clone(),
+            span,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(FromGeojson {})
+    }
+}
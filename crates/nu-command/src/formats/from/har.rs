@@ -0,0 +1,274 @@
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
+    Type, Value,
+};
+
+#[derive(Clone)]
+pub struct FromHar;
+
+impl Command for FromHar {
+    fn name(&self) -> &str {
+        "from har"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("from har")
+            .input_output_types(vec![(Type::String, Type::Table(vec![]))])
+            .category(Category::Formats)
+    }
+
+    fn usage(&self) -> &str {
+        "Convert a HAR (HTTP Archive) export into a table of requests."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Each row is one `log.entries[]` item: `method`, `url`, `status`,
+`status_text`, `started` (a date), `time` (total time in ms), `request_size`
+and `response_size` (header + body bytes), `content_type`, a `timings`
+record (`blocked`/`dns`/`connect`/`send`/`wait`/`receive`, each in ms, `-1`
+entries from the export become `null`), and `request_headers`/
+`response_headers` records keyed by header name. Repeated header names keep
+only the last occurrence, since a record can't have duplicate columns."#
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        from_har(input, head, engine_state.ctrlc.clone())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Summarize requests from a browser HAR export",
+            example: "open network.har --raw | from har | select method url status time",
+            result: None,
+        }]
+    }
+}
+
+fn collect_string(input: PipelineData, span: Span) -> Result<String, ShellError> {
+    let value = input.into_value(span);
+    match value {
+        Value::String { val, .. } => Ok(val),
+        other => Err(ShellError::UnsupportedInput(
+            "Expected string from pipeline".to_string(),
+            "value originates from here".into(),
+            span,
+            other.expect_span(),
+        )),
+    }
+}
+
+fn from_har(
+    input: PipelineData,
+    head: Span,
+    ctrlc: Option<std::sync::Arc<std::sync::atomic::AtomicBool>>,
+) -> Result<PipelineData, ShellError> {
+    let span = input.span().unwrap_or(head);
+    let text = collect_string(input, head)?;
+
+    let root: nu_json::Value = nu_json::from_str(&text).map_err(|e| {
+        ShellError::UnsupportedInput(
+            format!("Could not parse HAR data: {e}"),
+            "value originates from here".into(),
+            head,
+            span,
+        )
+    })?;
+
+    let entries = object_field(&root, "log")
+        .and_then(|log| object_field(log, "entries"))
+        .and_then(|entries| entries.as_array())
+        .cloned()
+        .unwrap_or_default();
+
+    let rows = entries
+        .into_iter()
+        .map(|entry| entry_to_row(&entry, head))
+        .collect::<Vec<_>>();
+
+    Ok(rows.into_iter().into_pipeline_data(ctrlc))
+}
+
+fn object_field<'a>(value: &'a nu_json::Value, name: &str) -> Option<&'a nu_json::Value> {
+    match value {
+        nu_json::Value::Object(map) => map.get(name),
+        _ => None,
+    }
+}
+
+fn as_str<'a>(value: Option<&'a nu_json::Value>) -> Option<&'a str> {
+    value.and_then(|v| v.as_str())
+}
+
+fn as_f64(value: Option<&nu_json::Value>) -> Option<f64> {
+    match value {
+        Some(nu_json::Value::F64(f)) => Some(*f),
+        Some(nu_json::Value::I64(i)) => Some(*i as f64),
+        Some(nu_json::Value::U64(u)) => Some(*u as f64),
+        _ => None,
+    }
+}
+
+fn entry_to_row(entry: &nu_json::Value, span: Span) -> Value {
+    let request = object_field(entry, "request");
+    let response = object_field(entry, "response");
+    let timings = object_field(entry, "timings");
+
+    let method = as_str(request.and_then(|r| object_field(r, "method")))
+        .unwrap_or("")
+        .to_string();
+    let url = as_str(request.and_then(|r| object_field(r, "url")))
+        .unwrap_or("")
+        .to_string();
+    let status = as_f64(response.and_then(|r| object_field(r, "status"))).unwrap_or(0.0) as i64;
+    let status_text = as_str(response.and_then(|r| object_field(r, "statusText")))
+        .unwrap_or("")
+        .to_string();
+
+    let started = match as_str(object_field(entry, "startedDateTime"))
+        .and_then(|s| chrono::DateTime::parse_from_rfc3339(s).ok())
+    {
+        Some(date) => Value::Date { val: date, span },
+        None => Value::nothing(span),
+    };
+
+    let time = match as_f64(object_field(entry, "time")) {
+        Some(t) => Value::float(t, span),
+        None => Value::nothing(span),
+    };
+
+    let request_size = as_f64(request.and_then(|r| object_field(r, "headersSize"))).unwrap_or(0.0)
+        + as_f64(request.and_then(|r| object_field(r, "bodySize"))).unwrap_or(0.0);
+    let response_size = as_f64(response.and_then(|r| object_field(r, "headersSize")))
+        .unwrap_or(0.0)
+        + as_f64(response.and_then(|r| object_field(r, "bodySize"))).unwrap_or(0.0);
+
+    let content_type = as_str(
+        response
+            .and_then(|r| object_field(r, "content"))
+            .and_then(|c| object_field(c, "mimeType")),
+    )
+    .unwrap_or("")
+    .to_string();
+
+    let timings_value = timings_to_value(timings, span);
+    let request_headers = headers_to_value(request, span);
+    let response_headers = headers_to_value(response, span);
+
+    Value::Record {
+        cols: vec![
+            "method".to_string(),
+            "url".to_string(),
+            "status".to_string(),
+            "status_text".to_string(),
+            "started".to_string(),
+            "time".to_string(),
+            "request_size".to_string(),
+            "response_size".to_string(),
+            "content_type".to_string(),
+            "timings".to_string(),
+            "request_headers".to_string(),
+            "response_headers".to_string(),
+        ],
+        vals: vec![
+            Value::string(method, span),
+            Value::string(url, span),
+            Value::int(status, span),
+            Value::string(status_text, span),
+            started,
+            time,
+            Value::float(request_size, span),
+            Value::float(response_size, span),
+            Value::string(content_type, span),
+            timings_value,
+            request_headers,
+            response_headers,
+        ],
+        span,
+    }
+}
+
+fn timings_to_value(timings: Option<&nu_json::Value>, span: Span) -> Value {
+    let Some(timings) = timings else {
+        return Value::nothing(span);
+    };
+
+    let field = |name: &str| match as_f64(object_field(timings, name)) {
+        Some(v) if v >= 0.0 => Value::float(v, span),
+        _ => Value::nothing(span),
+    };
+
+    Value::Record {
+        cols: vec![
+            "blocked".to_string(),
+            "dns".to_string(),
+            "connect".to_string(),
+            "send".to_string(),
+            "wait".to_string(),
+            "receive".to_string(),
+        ],
+        vals: vec![
+            field("blocked"),
+            field("dns"),
+            field("connect"),
+            field("send"),
+            field("wait"),
+            field("receive"),
+        ],
+        span,
+    }
+}
+
+fn headers_to_value(message: Option<&nu_json::Value>, span: Span) -> Value {
+    let headers = message
+        .and_then(|m| object_field(m, "headers"))
+        .and_then(|h| h.as_array());
+
+    let Some(headers) = headers else {
+        return Value::Record {
+            cols: vec![],
+            vals: vec![],
+            span,
+        };
+    };
+
+    let mut cols = vec![];
+    let mut vals = vec![];
+
+    for header in headers {
+        let name = as_str(object_field(header, "name"));
+        let value = as_str(object_field(header, "value"));
+
+        if let (Some(name), Some(value)) = (name, value) {
+            match cols.iter().position(|c: &String| c == name) {
+                Some(i) => vals[i] = Value::string(value, span),
+                None => {
+                    cols.push(name.to_string());
+                    vals.push(Value::string(value, span));
+                }
+            }
+        }
+    }
+
+    Value::Record { cols, vals, span }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(FromHar {})
+    }
+}
@@ -0,0 +1,219 @@
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, SyntaxShape,
+    Type, Value,
+};
+use scraper::{ElementRef, Html, Selector as ScraperSelector};
+
+#[derive(Clone)]
+pub struct FromHtml;
+
+impl Command for FromHtml {
+    fn name(&self) -> &str {
+        "from html"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("from html")
+            .input_output_types(vec![(Type::String, Type::Any)])
+            .named(
+                "query",
+                SyntaxShape::String,
+                "a css selector; extract matching elements instead of <table>s",
+                Some('q'),
+            )
+            .named(
+                "attribute",
+                SyntaxShape::String,
+                "with --query, return this attribute instead of the element's text",
+                Some('a'),
+            )
+            .category(Category::Formats)
+    }
+
+    fn usage(&self) -> &str {
+        "Parse text as .html and extract its <table>s, or elements matching a css selector."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "With no flags, every <table> in the document becomes a table, using its first row as \
+column names when that row looks like a header. With --query, every element matching the \
+given css selector becomes a row instead, holding its text, or, with --attribute, the named \
+attribute's value."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Parse a table out of an html fragment",
+                example: "'<table><tr><th>a</th><th>b</th></tr><tr><td>1</td><td>2</td></tr></table>' | from html",
+                result: Some(Value::List {
+                    vals: vec![Value::test_record(
+                        vec!["a", "b"],
+                        vec![Value::test_string("1"), Value::test_string("2")],
+                    )],
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                description: "Extract the text of every link on a page",
+                example: "'<a href=\"/a\">A</a><a href=\"/b\">B</a>' | from html --query a",
+                result: Some(Value::List {
+                    vals: vec![Value::test_string("A"), Value::test_string("B")],
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                description: "Extract the href attribute of every link on a page",
+                example: "'<a href=\"/a\">A</a><a href=\"/b\">B</a>' | from html --query a --attribute href",
+                result: Some(Value::List {
+                    vals: vec![Value::test_string("/a"), Value::test_string("/b")],
+                    span: Span::test_data(),
+                }),
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let query: Option<String> = call.get_flag(engine_state, stack, "query")?;
+        let attribute: Option<String> = call.get_flag(engine_state, stack, "attribute")?;
+        let (text, _span, metadata) = input.collect_string_strict(head)?;
+
+        let value = match query {
+            Some(query) => select(&text, &query, attribute.as_deref(), head)?,
+            None => extract_tables(&text, head),
+        };
+
+        Ok(value.into_pipeline_data_with_metadata(metadata))
+    }
+}
+
+fn parse_selector(query: &str, head: Span) -> Result<ScraperSelector, ShellError> {
+    ScraperSelector::parse(query).map_err(|_| ShellError::IncorrectValue {
+        msg: format!("'{query}' is not a valid css selector"),
+        span: head,
+    })
+}
+
+fn select(
+    html: &str,
+    query: &str,
+    attribute: Option<&str>,
+    head: Span,
+) -> Result<Value, ShellError> {
+    let selector = parse_selector(query, head)?;
+    let doc = Html::parse_fragment(html);
+
+    let vals = doc
+        .select(&selector)
+        .map(|el| match attribute {
+            Some(attr) => Value::string(el.value().attr(attr).unwrap_or("").to_string(), head),
+            None => Value::string(el.text().collect::<String>(), head),
+        })
+        .collect();
+
+    Ok(Value::List { vals, span: head })
+}
+
+/// Extracts every `<table>` in `html`. A single table is returned on its own;
+/// more than one is wrapped in an outer list, one entry per table.
+fn extract_tables(html: &str, head: Span) -> Value {
+    let doc = Html::parse_fragment(html);
+    let table_selector = ScraperSelector::parse("table").expect("static selector is valid");
+
+    let mut tables: Vec<Value> = doc
+        .select(&table_selector)
+        .map(|table| extract_table(table, head))
+        .collect();
+
+    match tables.len() {
+        1 => tables.remove(0),
+        _ => Value::List {
+            vals: tables,
+            span: head,
+        },
+    }
+}
+
+fn extract_table(table: ElementRef, head: Span) -> Value {
+    let row_selector = ScraperSelector::parse("tr").expect("static selector is valid");
+    let header_selector = ScraperSelector::parse("th").expect("static selector is valid");
+    let cell_selector = ScraperSelector::parse("td").expect("static selector is valid");
+
+    let mut rows = table.select(&row_selector);
+
+    let headers: Vec<String> = rows
+        .next()
+        .map(|row| {
+            row.select(&header_selector)
+                .map(|cell| cell.text().collect::<String>().trim().to_string())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let mut vals: Vec<Value> = Vec::new();
+
+    // The first row was consumed above looking for headers; if it didn't
+    // have any, treat it as a data row instead of dropping it.
+    if headers.is_empty() {
+        if let Some(first_row) = table.select(&row_selector).next() {
+            vals.push(row_to_value(first_row, &cell_selector, &headers, head));
+        }
+        vals.extend(
+            table
+                .select(&row_selector)
+                .skip(1)
+                .map(|row| row_to_value(row, &cell_selector, &headers, head)),
+        );
+    } else {
+        vals.extend(rows.map(|row| row_to_value(row, &cell_selector, &headers, head)));
+    }
+
+    Value::List { vals, span: head }
+}
+
+fn row_to_value(
+    row: ElementRef,
+    cell_selector: &ScraperSelector,
+    headers: &[String],
+    head: Span,
+) -> Value {
+    let cells: Vec<String> = row
+        .select(cell_selector)
+        .map(|cell| cell.text().collect::<String>().trim().to_string())
+        .collect();
+
+    let cols = if headers.is_empty() {
+        (0..cells.len()).map(|i| format!("column{i}")).collect()
+    } else {
+        headers.to_vec()
+    };
+
+    let vals = cells.into_iter().map(|c| Value::string(c, head)).collect();
+
+    Value::Record {
+        cols,
+        vals,
+        span: head,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(FromHtml {})
+    }
+}
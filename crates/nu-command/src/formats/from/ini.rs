@@ -0,0 +1,222 @@
+use indexmap::IndexMap;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct FromIni;
+
+impl Command for FromIni {
+    fn name(&self) -> &str {
+        "from ini"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("from ini")
+            .input_output_types(vec![(Type::String, Type::Record(vec![]))])
+            .switch(
+                "no-infer",
+                "don't try to convert key values to int, float or bool",
+                None,
+            )
+            .category(Category::Formats)
+    }
+
+    fn usage(&self) -> &str {
+        "Parse text as .ini and create a record."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Section names containing dots (e.g. `[a.b]`) become nested records, and a
+key that's repeated within the same section becomes a list of its values.
+Keys before the first section header end up at the top level of the record."#
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let no_infer = call.has_flag("no-infer");
+        from_ini(input, head, no_infer)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                example: "'[foo]
+a=1
+b=2' | from ini",
+                description: "Converts ini formatted string to record",
+                result: Some(Value::Record {
+                    cols: vec!["foo".to_string()],
+                    vals: vec![Value::Record {
+                        cols: vec!["a".to_string(), "b".to_string()],
+                        vals: vec![Value::test_int(1), Value::test_int(2)],
+                        span: Span::test_data(),
+                    }],
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                example: "'[a.b]
+c=1' | from ini",
+                description: "Dotted section names become nested records",
+                result: Some(Value::Record {
+                    cols: vec!["a".to_string()],
+                    vals: vec![Value::Record {
+                        cols: vec!["b".to_string()],
+                        vals: vec![Value::Record {
+                            cols: vec!["c".to_string()],
+                            vals: vec![Value::test_int(1)],
+                            span: Span::test_data(),
+                        }],
+                        span: Span::test_data(),
+                    }],
+                    span: Span::test_data(),
+                }),
+            },
+        ]
+    }
+}
+
+/// A parsed `.ini` tree: either a section of further keys, or the values seen
+/// for a single key (more than one if the key was repeated).
+enum IniNode {
+    Section(IndexMap<String, IniNode>),
+    Values(Vec<Value>),
+}
+
+fn from_ini(input: PipelineData, head: Span, no_infer: bool) -> Result<PipelineData, ShellError> {
+    let (input_string, span, metadata) = input.collect_string_strict(head)?;
+
+    let mut root: IndexMap<String, IniNode> = IndexMap::new();
+    let mut current_path: Vec<String> = vec![];
+
+    for line in input_string.lines() {
+        let line = line.trim();
+
+        if line.is_empty() || line.starts_with(';') || line.starts_with('#') {
+            continue;
+        }
+
+        if let Some(section) = line.strip_prefix('[').and_then(|s| s.strip_suffix(']')) {
+            current_path = section.split('.').map(|s| s.trim().to_string()).collect();
+            section_mut(&mut root, &current_path);
+            continue;
+        }
+
+        let Some((key, value)) = line.split_once('=') else {
+            return Err(ShellError::GenericError(
+                "Error while parsing ini text".into(),
+                "not a `[section]`, `key=value` pair, or comment".into(),
+                Some(head),
+                None,
+                vec![],
+            ));
+        };
+
+        let key = key.trim().to_string();
+        let value = infer_ini_value(value.trim(), span, no_infer);
+
+        let section = section_mut(&mut root, &current_path);
+        match section
+            .entry(key)
+            .or_insert_with(|| IniNode::Values(vec![]))
+        {
+            IniNode::Values(values) => values.push(value),
+            IniNode::Section(_) => {
+                return Err(ShellError::GenericError(
+                    "Error while parsing ini text".into(),
+                    "this key was already used as a section name".into(),
+                    Some(head),
+                    None,
+                    vec![],
+                ))
+            }
+        }
+    }
+
+    Ok(ini_tree_to_value(root, head).into_pipeline_data_with_metadata(metadata))
+}
+
+/// Walks (creating as needed) the sections named by `path`, returning the
+/// innermost one.
+fn section_mut<'a>(
+    root: &'a mut IndexMap<String, IniNode>,
+    path: &[String],
+) -> &'a mut IndexMap<String, IniNode> {
+    let mut current = root;
+    for segment in path {
+        current = match current
+            .entry(segment.clone())
+            .or_insert_with(|| IniNode::Section(IndexMap::new()))
+        {
+            IniNode::Section(section) => section,
+            // A leaf key and a section share a name; treat the rest of this
+            // path as a fresh section, overwriting the leaf.
+            value @ IniNode::Values(_) => {
+                *value = IniNode::Section(IndexMap::new());
+                match value {
+                    IniNode::Section(section) => section,
+                    IniNode::Values(_) => unreachable!(),
+                }
+            }
+        };
+    }
+    current
+}
+
+fn ini_tree_to_value(tree: IndexMap<String, IniNode>, span: Span) -> Value {
+    let mut cols = vec![];
+    let mut vals = vec![];
+
+    for (key, node) in tree {
+        cols.push(key);
+        vals.push(match node {
+            IniNode::Section(section) => ini_tree_to_value(section, span),
+            IniNode::Values(mut values) if values.len() == 1 => values.remove(0),
+            IniNode::Values(values) => Value::List { vals: values, span },
+        });
+    }
+
+    Value::Record { cols, vals, span }
+}
+
+fn infer_ini_value(value: &str, span: Span, no_infer: bool) -> Value {
+    let value = value
+        .strip_prefix('"')
+        .and_then(|v| v.strip_suffix('"'))
+        .unwrap_or(value);
+
+    if no_infer {
+        return Value::string(value, span);
+    }
+
+    if let Ok(i) = value.parse::<i64>() {
+        Value::int(i, span)
+    } else if let Ok(f) = value.parse::<f64>() {
+        Value::float(f, span)
+    } else if let Ok(b) = value.parse::<bool>() {
+        Value::boolean(b, span)
+    } else {
+        Value::string(value, span)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(FromIni {})
+    }
+}
@@ -0,0 +1,202 @@
+use chrono::TimeZone;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
+    Type, Value,
+};
+
+#[derive(Clone)]
+pub struct FromJournal;
+
+impl Command for FromJournal {
+    fn name(&self) -> &str {
+        "from journal"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("from journal")
+            .input_output_types(vec![(Type::String, Type::Table(vec![]))])
+            .category(Category::Formats)
+    }
+
+    fn usage(&self) -> &str {
+        "Parse a `journalctl -o export` stream into a table of records."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Each entry becomes one row, with one column per field exactly as
+journald named it (`MESSAGE`, `_PID`, `_HOSTNAME`, `PRIORITY`, and so on).
+Field values are kept as strings since journald doesn't type them; the two
+exceptions are `__REALTIME_TIMESTAMP` and `__MONOTONIC_TIMESTAMP`, which are
+microseconds and get a derived `timestamp`/`monotonic` column of their own
+(a date and a duration, respectively) alongside the original field. A field
+that repeats within one entry keeps only its last value, same as `from
+har`'s header handling."#
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        from_journal(input, head, engine_state.ctrlc.clone())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Parse the output of `journalctl -o export`",
+            example: r#"journalctl -o export | from journal"#,
+            result: None,
+        }]
+    }
+}
+
+fn collect_string(input: PipelineData, span: Span) -> Result<String, ShellError> {
+    let value = input.into_value(span);
+    match value {
+        Value::String { val, .. } => Ok(val),
+        other => Err(ShellError::UnsupportedInput(
+            "Expected string from pipeline".to_string(),
+            "value originates from here".into(),
+            span,
+            other.expect_span(),
+        )),
+    }
+}
+
+fn from_journal(
+    input: PipelineData,
+    head: Span,
+    ctrlc: Option<std::sync::Arc<std::sync::atomic::AtomicBool>>,
+) -> Result<PipelineData, ShellError> {
+    let span = input.span().unwrap_or(head);
+    let text = collect_string(input, head)?;
+
+    let rows = parse_export(text.as_bytes(), span);
+
+    Ok(rows.into_iter().into_pipeline_data(ctrlc))
+}
+
+/// Parses a `journalctl -o export` byte stream into one record per entry.
+///
+/// Entries are separated by a blank line. Each field is either a plain
+/// `NAME=value` text line, or, for binary-safe values, a `NAME` line
+/// followed by an 8-byte little-endian length and that many raw bytes.
+fn parse_export(bytes: &[u8], span: Span) -> Vec<Value> {
+    let mut entries = vec![];
+    let mut cols: Vec<String> = vec![];
+    let mut vals: Vec<Value> = vec![];
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let line_end = bytes[i..]
+            .iter()
+            .position(|&b| b == b'\n')
+            .map(|p| i + p)
+            .unwrap_or(bytes.len());
+        let line = &bytes[i..line_end];
+
+        if line.is_empty() {
+            if !cols.is_empty() {
+                entries.push(finish_entry(
+                    std::mem::take(&mut cols),
+                    std::mem::take(&mut vals),
+                    span,
+                ));
+            }
+            i = line_end + 1;
+            continue;
+        }
+
+        if let Some(eq) = line.iter().position(|&b| b == b'=') {
+            let name = String::from_utf8_lossy(&line[..eq]).into_owned();
+            let value = String::from_utf8_lossy(&line[eq + 1..]).into_owned();
+            set_field(&mut cols, &mut vals, name, Value::string(value, span));
+            i = line_end + 1;
+        } else {
+            let name = String::from_utf8_lossy(line).into_owned();
+            let len_start = line_end + 1;
+            if len_start + 8 > bytes.len() {
+                break;
+            }
+            let mut len_bytes = [0u8; 8];
+            len_bytes.copy_from_slice(&bytes[len_start..len_start + 8]);
+            let length = u64::from_le_bytes(len_bytes) as usize;
+            let value_start = len_start + 8;
+            let value_end = (value_start + length).min(bytes.len());
+            let value_bytes = &bytes[value_start..value_end];
+            let value = match std::str::from_utf8(value_bytes) {
+                Ok(s) => Value::string(s, span),
+                Err(_) => Value::binary(value_bytes, span),
+            };
+            set_field(&mut cols, &mut vals, name, value);
+            i = value_end + 1;
+        }
+    }
+
+    if !cols.is_empty() {
+        entries.push(finish_entry(cols, vals, span));
+    }
+
+    entries
+}
+
+fn set_field(cols: &mut Vec<String>, vals: &mut Vec<Value>, name: String, value: Value) {
+    if let Some(pos) = cols.iter().position(|c| c == &name) {
+        vals[pos] = value;
+    } else {
+        cols.push(name);
+        vals.push(value);
+    }
+}
+
+fn finish_entry(mut cols: Vec<String>, mut vals: Vec<Value>, span: Span) -> Value {
+    if let Some(pos) = cols.iter().position(|c| c == "__REALTIME_TIMESTAMP") {
+        if let Some(date) = microseconds_to_date(&vals[pos], span) {
+            cols.push("timestamp".to_string());
+            vals.push(date);
+        }
+    }
+
+    if let Some(pos) = cols.iter().position(|c| c == "__MONOTONIC_TIMESTAMP") {
+        if let Value::String { val, .. } = &vals[pos] {
+            if let Ok(micros) = val.parse::<i64>() {
+                cols.push("monotonic".to_string());
+                vals.push(Value::Duration {
+                    val: micros * 1000,
+                    span,
+                });
+            }
+        }
+    }
+
+    Value::Record { cols, vals, span }
+}
+
+fn microseconds_to_date(value: &Value, span: Span) -> Option<Value> {
+    let Value::String { val, .. } = value else {
+        return None;
+    };
+    let micros: i64 = val.parse().ok()?;
+    let date = chrono::Utc.timestamp_nanos(micros * 1000);
+    Some(Value::Date {
+        val: date.into(),
+        span,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(FromJournal {})
+    }
+}
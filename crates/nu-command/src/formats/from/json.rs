@@ -1,9 +1,10 @@
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
-    Category, Example, IntoInterruptiblePipelineData, IntoPipelineData, PipelineData, ShellError,
-    Signature, Span, Type, Value,
+    Category, Example, IntoInterruptiblePipelineData, IntoPipelineData, PipelineData, RawStream,
+    ShellError, Signature, Span, Type, Value,
 };
+use std::collections::VecDeque;
 
 #[derive(Clone)]
 pub struct FromJson;
@@ -24,6 +25,14 @@ impl Command for FromJson {
             .category(Category::Formats)
     }
 
+    fn extra_usage(&self) -> &str {
+        r#"With --objects, lines are parsed as NDJSON. If the input is a raw byte
+stream (e.g. piped from an external command), lines are parsed as they
+arrive instead of waiting for the whole input, so following a growing JSON
+log doesn't have to buffer it all in memory. A line that fails to parse
+becomes an error value in the output instead of aborting the whole stream."#
+    }
+
     fn examples(&self) -> Vec<Example> {
         vec![
             Example {
@@ -61,14 +70,26 @@ impl Command for FromJson {
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
         let span = call.head;
-        let (string_input, span, metadata) = input.collect_string_strict(span)?;
-
-        if string_input.is_empty() {
-            return Ok(PipelineData::new_with_metadata(metadata, span));
-        }
 
         // TODO: turn this into a structured underline of the nu_json error
         if call.has_flag("objects") {
+            if let PipelineData::ExternalStream {
+                stdout: Some(stdout),
+                metadata,
+                ..
+            } = input
+            {
+                let lines = NdjsonLines::new(stdout, span);
+                return Ok(
+                    lines.into_pipeline_data_with_metadata(metadata, engine_state.ctrlc.clone())
+                );
+            }
+
+            let (string_input, span, metadata) = input.collect_string_strict(span)?;
+            if string_input.is_empty() {
+                return Ok(PipelineData::new_with_metadata(metadata, span));
+            }
+
             let converted_lines: Vec<Value> = string_input
                 .lines()
                 .filter_map(move |x| {
@@ -85,12 +106,90 @@ impl Command for FromJson {
             Ok(converted_lines
                 .into_pipeline_data_with_metadata(metadata, engine_state.ctrlc.clone()))
         } else {
+            let (string_input, span, metadata) = input.collect_string_strict(span)?;
+            if string_input.is_empty() {
+                return Ok(PipelineData::new_with_metadata(metadata, span));
+            }
+
             Ok(convert_string_to_value(string_input, span)?
                 .into_pipeline_data_with_metadata(metadata))
         }
     }
 }
 
+/// Lazily splits a raw byte stream into NDJSON lines, parsing each line to a
+/// value as soon as a full line is available instead of buffering the whole
+/// stream first. A line that fails to parse becomes an error value rather
+/// than aborting the rest of the stream.
+struct NdjsonLines {
+    chunks: RawStream,
+    buffer: String,
+    pending: VecDeque<String>,
+    span: Span,
+    done: bool,
+}
+
+impl NdjsonLines {
+    fn new(chunks: RawStream, span: Span) -> Self {
+        Self {
+            chunks,
+            buffer: String::new(),
+            pending: VecDeque::new(),
+            span,
+            done: false,
+        }
+    }
+}
+
+impl Iterator for NdjsonLines {
+    type Item = Value;
+
+    fn next(&mut self) -> Option<Value> {
+        loop {
+            if let Some(line) = self.pending.pop_front() {
+                if line.trim().is_empty() {
+                    continue;
+                }
+                return Some(match convert_string_to_value(line, self.span) {
+                    Ok(value) => value,
+                    Err(error) => Value::Error { error },
+                });
+            }
+
+            if self.done {
+                return None;
+            }
+
+            match self.chunks.next() {
+                Some(Ok(chunk)) => match chunk.as_string() {
+                    Ok(text) => {
+                        self.buffer.push_str(&text);
+                        while let Some(pos) = self.buffer.find('\n') {
+                            let line: String = self.buffer.drain(..=pos).collect();
+                            self.pending
+                                .push_back(line.trim_end_matches('\n').to_string());
+                        }
+                    }
+                    Err(error) => {
+                        self.done = true;
+                        return Some(Value::Error { error });
+                    }
+                },
+                Some(Err(error)) => {
+                    self.done = true;
+                    return Some(Value::Error { error });
+                }
+                None => {
+                    self.done = true;
+                    if !self.buffer.trim().is_empty() {
+                        self.pending.push_back(std::mem::take(&mut self.buffer));
+                    }
+                }
+            }
+        }
+    }
+}
+
 fn convert_nujson_to_value(value: &nu_json::Value, span: Span) -> Value {
     match value {
         nu_json::Value::Array(array) => {
@@ -0,0 +1,155 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
+    SyntaxShape, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct FromKv;
+
+impl Command for FromKv {
+    fn name(&self) -> &str {
+        "from kv"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("from kv")
+            .input_output_types(vec![(Type::String, Type::Table(vec![]))])
+            .named(
+                "pair-separator",
+                SyntaxShape::String,
+                "separator between key=value pairs on a line (default ';')",
+                Some('p'),
+            )
+            .named(
+                "field-separator",
+                SyntaxShape::String,
+                "separator between a key and its value (default '=')",
+                Some('f'),
+            )
+            .category(Category::Formats)
+    }
+
+    fn usage(&self) -> &str {
+        "Parse generic `key=value` lines, with configurable pair and field separators."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Each line is one row. By default pairs are separated by `;` and each
+pair's key and value by `=`, matching the ad-hoc `k=v;k2=v2` style common in
+firewall, load balancer, and other appliance logs. A pair with no separator
+becomes a column named after the whole pair with an empty value, rather
+than erroring out a whole log stream over one malformed field."#
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let pair_separator: Option<String> =
+            call.get_flag(engine_state, stack, "pair-separator")?;
+        let field_separator: Option<String> =
+            call.get_flag(engine_state, stack, "field-separator")?;
+
+        from_kv(
+            input,
+            head,
+            pair_separator.unwrap_or_else(|| ";".to_string()),
+            field_separator.unwrap_or_else(|| "=".to_string()),
+            engine_state.ctrlc.clone(),
+        )
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Parse generic key=value pairs",
+                example: "'foo=1;bar=2' | from kv",
+                result: Some(Value::List {
+                    vals: vec![Value::Record {
+                        cols: vec!["foo".to_string(), "bar".to_string()],
+                        vals: vec![Value::test_string("1"), Value::test_string("2")],
+                        span: Span::test_data(),
+                    }],
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                description: "Parse comma/colon separated pairs",
+                example: "'foo:1,bar:2' | from kv --pair-separator ',' --field-separator ':'",
+                result: None,
+            },
+        ]
+    }
+}
+
+fn collect_string(input: PipelineData, span: Span) -> Result<String, ShellError> {
+    let value = input.into_value(span);
+    match value {
+        Value::String { val, .. } => Ok(val),
+        other => Err(ShellError::UnsupportedInput(
+            "Expected string from pipeline".to_string(),
+            "value originates from here".into(),
+            span,
+            other.expect_span(),
+        )),
+    }
+}
+
+fn from_kv(
+    input: PipelineData,
+    head: Span,
+    pair_separator: String,
+    field_separator: String,
+    ctrlc: Option<std::sync::Arc<std::sync::atomic::AtomicBool>>,
+) -> Result<PipelineData, ShellError> {
+    let span = input.span().unwrap_or(head);
+    let text = collect_string(input, head)?;
+
+    let rows = text
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line_to_value(line, &pair_separator, &field_separator, span))
+        .collect::<Vec<_>>();
+
+    Ok(rows.into_iter().into_pipeline_data(ctrlc))
+}
+
+fn line_to_value(line: &str, pair_separator: &str, field_separator: &str, span: Span) -> Value {
+    let mut cols = vec![];
+    let mut vals = vec![];
+
+    for pair in line.split(pair_separator) {
+        let pair = pair.trim();
+        if pair.is_empty() {
+            continue;
+        }
+
+        let (key, value) = match pair.split_once(field_separator) {
+            Some((key, value)) => (key, value),
+            None => (pair, ""),
+        };
+        cols.push(key.to_string());
+        vals.push(Value::string(value, span));
+    }
+
+    Value::Record { cols, vals, span }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(FromKv {})
+    }
+}
@@ -0,0 +1,121 @@
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
+    Type, Value,
+};
+
+#[derive(Clone)]
+pub struct FromLtsv;
+
+impl Command for FromLtsv {
+    fn name(&self) -> &str {
+        "from ltsv"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("from ltsv")
+            .input_output_types(vec![(Type::String, Type::Table(vec![]))])
+            .category(Category::Formats)
+    }
+
+    fn usage(&self) -> &str {
+        "Parse text as labeled tab-separated values (LTSV) and create a table."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Each line is one row, made of tab-separated `label:value` fields. A
+field with no `:` becomes a column named after the whole field with an
+empty value, rather than erroring out a whole log stream over one
+malformed field."#
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        from_ltsv(input, head, engine_state.ctrlc.clone())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Parse text as LTSV",
+            example:
+                "\"time:2013-09-07T10:14:58\thost:192.168.0.1\treq:GET /v1/ HTTP/1.1\" | from ltsv",
+            result: Some(Value::List {
+                vals: vec![Value::Record {
+                    cols: vec!["time".to_string(), "host".to_string(), "req".to_string()],
+                    vals: vec![
+                        Value::test_string("2013-09-07T10:14:58"),
+                        Value::test_string("192.168.0.1"),
+                        Value::test_string("GET /v1/ HTTP/1.1"),
+                    ],
+                    span: Span::test_data(),
+                }],
+                span: Span::test_data(),
+            }),
+        }]
+    }
+}
+
+fn collect_string(input: PipelineData, span: Span) -> Result<String, ShellError> {
+    let value = input.into_value(span);
+    match value {
+        Value::String { val, .. } => Ok(val),
+        other => Err(ShellError::UnsupportedInput(
+            "Expected string from pipeline".to_string(),
+            "value originates from here".into(),
+            span,
+            other.expect_span(),
+        )),
+    }
+}
+
+fn from_ltsv(
+    input: PipelineData,
+    head: Span,
+    ctrlc: Option<std::sync::Arc<std::sync::atomic::AtomicBool>>,
+) -> Result<PipelineData, ShellError> {
+    let span = input.span().unwrap_or(head);
+    let text = collect_string(input, head)?;
+
+    let rows = text
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line_to_value(line, span))
+        .collect::<Vec<_>>();
+
+    Ok(rows.into_iter().into_pipeline_data(ctrlc))
+}
+
+fn line_to_value(line: &str, span: Span) -> Value {
+    let mut cols = vec![];
+    let mut vals = vec![];
+
+    for field in line.split('\t') {
+        let (label, value) = match field.split_once(':') {
+            Some((label, value)) => (label, value),
+            None => (field, ""),
+        };
+        cols.push(label.to_string());
+        vals.push(Value::string(value, span));
+    }
+
+    Value::Record { cols, vals, span }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(FromLtsv {})
+    }
+}
@@ -1,27 +1,75 @@
+mod avro;
+#[cfg(feature = "bson")]
+mod bson;
+mod cbor;
 mod command;
 mod csv;
 mod delimited;
+mod dot;
+mod edn;
+mod fixed_width;
+mod geojson;
+mod har;
+mod html;
+mod ini;
+mod journal;
 mod json;
+mod kv;
+mod ltsv;
+mod msgpack;
 mod nuon;
 mod ods;
+mod parquet;
+mod pcap;
+mod plist;
+mod prometheus;
+mod protobuf;
 mod ssv;
+mod syslog;
+mod tar;
+mod terraform_state;
 mod toml;
 mod tsv;
 mod url;
 mod xlsx;
 mod xml;
 mod yaml;
+mod zip;
 
 pub use self::csv::FromCsv;
 pub use self::toml::FromToml;
 pub use self::url::FromUrl;
+pub use avro::FromAvro;
+#[cfg(feature = "bson")]
+pub use bson::FromBson;
+pub use cbor::FromCbor;
 pub use command::From;
+pub use dot::FromDot;
+pub use edn::FromEdn;
+pub use fixed_width::FromFixedWidth;
+pub use geojson::FromGeojson;
+pub use har::FromHar;
+pub use html::FromHtml;
+pub use ini::FromIni;
+pub use journal::FromJournal;
 pub use json::FromJson;
+pub use kv::FromKv;
+pub use ltsv::FromLtsv;
+pub use msgpack::FromMsgpack;
 pub use nuon::FromNuon;
 pub use ods::FromOds;
+pub use parquet::FromParquet;
+pub use pcap::FromPcap;
+pub use plist::FromPlist;
+pub use prometheus::FromPrometheus;
+pub use protobuf::FromProtobuf;
 pub use ssv::FromSsv;
+pub use syslog::FromSyslog;
+pub use tar::FromTar;
+pub use terraform_state::FromTerraformState;
 pub use tsv::FromTsv;
 pub use xlsx::FromXlsx;
 pub use xml::FromXml;
 pub use yaml::FromYaml;
 pub use yaml::FromYml;
+pub use zip::FromZip;
@@ -0,0 +1,198 @@
+use std::io::Cursor;
+
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
+    Type, Value,
+};
+
+#[derive(Clone)]
+pub struct FromMsgpack;
+
+impl Command for FromMsgpack {
+    fn name(&self) -> &str {
+        "from msgpack"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("from msgpack")
+            .input_output_types(vec![(Type::Binary, Type::Any)])
+            .switch(
+                "objects",
+                "read a stream of concatenated MessagePack values instead of a single one",
+                Some('o'),
+            )
+            .category(Category::Formats)
+    }
+
+    fn usage(&self) -> &str {
+        "Convert from MessagePack binary data into structured data."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Maps use string keys where possible; MessagePack timestamp extensions (type -1) are decoded to dates."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let objects = call.has_flag("objects");
+        from_msgpack(input, head, objects, engine_state.ctrlc.clone())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Convert MessagePack binary data into a record",
+                example: "0x[81A16101] | from msgpack",
+                result: Some(Value::Record {
+                    cols: vec!["a".to_string()],
+                    vals: vec![Value::test_int(1)],
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                description: "Read a stream of concatenated MessagePack values",
+                example: "open data.msgpack --raw | from msgpack --objects",
+                result: None,
+            },
+        ]
+    }
+}
+
+fn collect_binary(input: PipelineData, span: Span) -> Result<Vec<u8>, ShellError> {
+    let mut bytes = vec![];
+
+    for value in input {
+        match value {
+            Value::Binary { val, .. } => bytes.extend_from_slice(&val),
+            other => {
+                return Err(ShellError::UnsupportedInput(
+                    "Expected binary from pipeline".to_string(),
+                    "value originates from here".into(),
+                    span,
+                    other.expect_span(),
+                ))
+            }
+        }
+    }
+
+    Ok(bytes)
+}
+
+fn from_msgpack(
+    input: PipelineData,
+    head: Span,
+    objects: bool,
+    ctrlc: Option<std::sync::Arc<std::sync::atomic::AtomicBool>>,
+) -> Result<PipelineData, ShellError> {
+    let span = input.span().unwrap_or(head);
+    let bytes = collect_binary(input, head)?;
+
+    if !objects {
+        let value = rmpv::decode::read_value(&mut Cursor::new(bytes.as_slice())).map_err(|e| {
+            ShellError::UnsupportedInput(
+                format!("Could not parse MessagePack data: {e}"),
+                "value originates from here".into(),
+                head,
+                span,
+            )
+        })?;
+
+        return Ok(rmpv_to_value(value, head).into_pipeline_data());
+    }
+
+    let mut cursor = Cursor::new(bytes.as_slice());
+    let mut values = vec![];
+
+    while (cursor.position() as usize) < bytes.len() {
+        let value = rmpv::decode::read_value(&mut cursor).map_err(|e| {
+            ShellError::UnsupportedInput(
+                format!("Could not parse MessagePack object: {e}"),
+                "value originates from here".into(),
+                head,
+                span,
+            )
+        })?;
+        values.push(rmpv_to_value(value, head));
+    }
+
+    Ok(values.into_iter().into_pipeline_data(ctrlc))
+}
+
+fn rmpv_to_value(value: rmpv::Value, span: Span) -> Value {
+    match value {
+        rmpv::Value::Nil => Value::nothing(span),
+        rmpv::Value::Boolean(b) => Value::boolean(b, span),
+        rmpv::Value::Integer(i) => match i.as_i64() {
+            Some(i) => Value::int(i, span),
+            None => Value::float(i.as_f64().unwrap_or_default(), span),
+        },
+        rmpv::Value::F32(f) => Value::float(f as f64, span),
+        rmpv::Value::F64(f) => Value::float(f, span),
+        rmpv::Value::String(s) => Value::string(s.as_str().unwrap_or_default(), span),
+        rmpv::Value::Binary(b) => Value::binary(b, span),
+        rmpv::Value::Array(vals) => Value::List {
+            vals: vals.into_iter().map(|v| rmpv_to_value(v, span)).collect(),
+            span,
+        },
+        rmpv::Value::Map(entries) => {
+            let mut cols = vec![];
+            let mut vals = vec![];
+            for (k, v) in entries {
+                let key = match k {
+                    rmpv::Value::String(s) => s.as_str().unwrap_or_default().to_string(),
+                    other => other.to_string(),
+                };
+                cols.push(key);
+                vals.push(rmpv_to_value(v, span));
+            }
+            Value::Record { cols, vals, span }
+        }
+        rmpv::Value::Ext(-1, ref bytes) => match decode_timestamp_ext(bytes) {
+            Some(date) => Value::Date { val: date, span },
+            None => Value::binary(bytes.clone(), span),
+        },
+        rmpv::Value::Ext(_, bytes) => Value::binary(bytes, span),
+    }
+}
+
+/// Decodes a MessagePack timestamp extension (type -1), which comes in 32-, 64-,
+/// or 96-bit payload forms depending on how large the seconds value is.
+/// See the "Timestamp extension type" section of the MessagePack spec.
+fn decode_timestamp_ext(bytes: &[u8]) -> Option<chrono::DateTime<chrono::FixedOffset>> {
+    let (seconds, nanos): (i64, u32) = match bytes.len() {
+        4 => (u32::from_be_bytes(bytes.try_into().ok()?) as i64, 0),
+        8 => {
+            let value = u64::from_be_bytes(bytes.try_into().ok()?);
+            ((value & 0x0003_ffff_ffff) as i64, (value >> 34) as u32)
+        }
+        12 => {
+            let nanos = u32::from_be_bytes(bytes[0..4].try_into().ok()?);
+            let seconds = i64::from_be_bytes(bytes[4..12].try_into().ok()?);
+            (seconds, nanos)
+        }
+        _ => return None,
+    };
+
+    chrono::NaiveDateTime::from_timestamp_opt(seconds, nanos)
+        .map(|naive| chrono::DateTime::<chrono::Utc>::from_utc(naive, chrono::Utc).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(FromMsgpack {})
+    }
+}
@@ -64,10 +64,9 @@ impl Command for FromNuon {
         let engine_state = engine_state.clone();
 
         let mut working_set = StateWorkingSet::new(&engine_state);
-        let mut error = None;
         let (mut block, err) =
             nu_parser::parse(&mut working_set, None, string_input.as_bytes(), false, &[]);
-        error = error.or(err);
+        let mut error = err.into_iter().next();
 
         if let Some(pipeline) = block.pipelines.get(1) {
             if let Some(element) = pipeline.elements.get(0) {
@@ -0,0 +1,264 @@
+use std::io::Cursor;
+
+use arrow2::array::Array;
+use arrow2::datatypes::{DataType as ArrowDataType, PhysicalType};
+use arrow2::io::parquet::read;
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, SyntaxShape,
+    Type, Value,
+};
+
+#[derive(Clone)]
+pub struct FromParquet;
+
+impl Command for FromParquet {
+    fn name(&self) -> &str {
+        "from parquet"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("from parquet")
+            .input_output_types(vec![(Type::Binary, Type::Table(vec![]))])
+            .named(
+                "columns",
+                SyntaxShape::List(Box::new(SyntaxShape::String)),
+                "Only output the given columns (every column of each row group is still decoded)",
+                Some('c'),
+            )
+            .category(Category::Formats)
+    }
+
+    fn usage(&self) -> &str {
+        "Parse binary Parquet data and create a table."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Reads the row groups into the usual table representation, so this does
+not require building nushell with the `dataframe` feature to work with
+Parquet files. The whole input is read, and every row of every row group
+collected into one table, before it is returned."#
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let columns: Option<Vec<String>> = call
+            .get_flag(engine_state, stack, "columns")?
+            .map(|cols: Vec<Value>| cols.iter().map(|c| c.as_string()).collect())
+            .transpose()?;
+
+        from_parquet(input, head, columns)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Convert binary parquet data to a table",
+                example: "open --raw test.parquet | from parquet",
+                result: None,
+            },
+            Example {
+                description: "Convert binary parquet data to a table, keeping only some columns",
+                example: "open --raw test.parquet | from parquet --columns [name age]",
+                result: None,
+            },
+        ]
+    }
+}
+
+fn collect_binary(input: PipelineData, span: Span) -> Result<Vec<u8>, ShellError> {
+    let mut bytes = vec![];
+
+    for value in input {
+        match value {
+            Value::Binary { val, .. } => bytes.extend_from_slice(&val),
+            other => {
+                return Err(ShellError::UnsupportedInput(
+                    "Expected binary from pipeline".to_string(),
+                    "value originates from here".into(),
+                    span,
+                    other.expect_span(),
+                ))
+            }
+        }
+    }
+
+    Ok(bytes)
+}
+
+fn from_parquet(
+    input: PipelineData,
+    head: Span,
+    columns: Option<Vec<String>>,
+) -> Result<PipelineData, ShellError> {
+    let span = input.span().unwrap_or(head);
+    let bytes = collect_binary(input, head)?;
+    let mut reader = Cursor::new(bytes);
+
+    let metadata = read::read_metadata(&mut reader).map_err(|e| {
+        ShellError::UnsupportedInput(
+            format!("Could not read parquet metadata: {e}"),
+            "value originates from here".into(),
+            head,
+            span,
+        )
+    })?;
+
+    let schema = read::infer_schema(&metadata).map_err(|e| {
+        ShellError::UnsupportedInput(
+            format!("Could not infer parquet schema: {e}"),
+            "value originates from here".into(),
+            head,
+            span,
+        )
+    })?;
+
+    let field_names: Vec<String> = schema.fields.iter().map(|f| f.name.clone()).collect();
+    let wanted: Vec<usize> = match &columns {
+        Some(columns) => field_names
+            .iter()
+            .enumerate()
+            .filter(|(_, name)| columns.contains(name))
+            .map(|(i, _)| i)
+            .collect(),
+        None => (0..field_names.len()).collect(),
+    };
+
+    let reader = read::FileReader::new(reader, metadata.row_groups, schema, None, None, None);
+
+    let mut rows: Vec<Value> = vec![];
+
+    for chunk in reader {
+        let chunk = chunk.map_err(|e| {
+            ShellError::UnsupportedInput(
+                format!("Could not read parquet row group: {e}"),
+                "value originates from here".into(),
+                head,
+                span,
+            )
+        })?;
+
+        let num_rows = chunk.len();
+        for row_idx in 0..num_rows {
+            let mut cols = vec![];
+            let mut vals = vec![];
+
+            for &col_idx in &wanted {
+                cols.push(field_names[col_idx].clone());
+                vals.push(array_value_to_nu(
+                    chunk.arrays()[col_idx].as_ref(),
+                    row_idx,
+                    head,
+                ));
+            }
+
+            rows.push(Value::Record {
+                cols,
+                vals,
+                span: head,
+            });
+        }
+    }
+
+    Ok(Value::List {
+        vals: rows,
+        span: head,
+    }
+    .into_pipeline_data())
+}
+
+fn array_value_to_nu(array: &dyn Array, index: usize, span: Span) -> Value {
+    use arrow2::array::*;
+
+    if array.is_null(index) {
+        return Value::nothing(span);
+    }
+
+    match array.data_type().to_physical_type() {
+        PhysicalType::Boolean => {
+            let array = array
+                .as_any()
+                .downcast_ref::<BooleanArray>()
+                .expect("boolean array");
+            Value::boolean(array.value(index), span)
+        }
+        PhysicalType::Primitive(_) => match array.data_type() {
+            ArrowDataType::Int64 => {
+                let array = array
+                    .as_any()
+                    .downcast_ref::<Int64Array>()
+                    .expect("int64 array");
+                Value::int(array.value(index), span)
+            }
+            ArrowDataType::Int32 => {
+                let array = array
+                    .as_any()
+                    .downcast_ref::<Int32Array>()
+                    .expect("int32 array");
+                Value::int(array.value(index) as i64, span)
+            }
+            ArrowDataType::Float64 => {
+                let array = array
+                    .as_any()
+                    .downcast_ref::<Float64Array>()
+                    .expect("float64 array");
+                Value::float(array.value(index), span)
+            }
+            ArrowDataType::Float32 => {
+                let array = array
+                    .as_any()
+                    .downcast_ref::<Float32Array>()
+                    .expect("float32 array");
+                Value::float(array.value(index) as f64, span)
+            }
+            _ => Value::string(format!("{:?}", array.data_type()), span),
+        },
+        PhysicalType::Utf8 | PhysicalType::LargeUtf8 => {
+            let array = array
+                .as_any()
+                .downcast_ref::<Utf8Array<i64>>()
+                .map(|a| a.value(index).to_string())
+                .or_else(|| {
+                    array
+                        .as_any()
+                        .downcast_ref::<Utf8Array<i32>>()
+                        .map(|a| a.value(index).to_string())
+                });
+            Value::string(array.unwrap_or_default(), span)
+        }
+        PhysicalType::Binary | PhysicalType::LargeBinary => {
+            let array = array
+                .as_any()
+                .downcast_ref::<BinaryArray<i64>>()
+                .map(|a| a.value(index).to_vec())
+                .or_else(|| {
+                    array
+                        .as_any()
+                        .downcast_ref::<BinaryArray<i32>>()
+                        .map(|a| a.value(index).to_vec())
+                });
+            Value::binary(array.unwrap_or_default(), span)
+        }
+        _ => Value::string(format!("{:?}", array.data_type()), span),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(FromParquet {})
+    }
+}
@@ -0,0 +1,300 @@
+use byteorder::{BigEndian, ByteOrder, LittleEndian};
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
+    SyntaxShape, Type, Value,
+};
+
+const GLOBAL_HEADER_LEN: usize = 24;
+const PACKET_HEADER_LEN: usize = 16;
+// The magic number as read big-endian: a big-endian capture reads back 0xa1b2c3d4, a
+// little-endian one the byte-swapped 0xd4c3b2a1.
+const MAGIC_LE: u32 = 0xd4c3b2a1;
+const MAGIC_BE: u32 = 0xa1b2c3d4;
+
+#[derive(Clone)]
+pub struct FromPcap;
+
+impl Command for FromPcap {
+    fn name(&self) -> &str {
+        "from pcap"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("from pcap")
+            .input_output_types(vec![(Type::Binary, Type::Any)])
+            .named(
+                "filter",
+                SyntaxShape::String,
+                "only keep packets whose protocol matches exactly, e.g. \"tcp\" or \"udp\"",
+                Some('f'),
+            )
+            .category(Category::Formats)
+    }
+
+    fn usage(&self) -> &str {
+        "Parse binary data as a libpcap capture file."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Decodes classic libpcap captures (the format Wireshark exports as \"pcap\", not \
+\"pcapng\") whose link layer is Ethernet. Each packet becomes a record with timestamp, src, \
+dst, protocol, src_port, dst_port, length, and the undecoded payload as binary.
+
+--filter is a plain exact match against the decoded protocol name, not a BPF or Wireshark \
+display-filter expression; for anything richer, pipe the output into `where` instead."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let filter: Option<String> = call.get_flag(engine_state, stack, "filter")?;
+        let bytes = collect_binary(input, head)?;
+        from_pcap(&bytes, head, filter.as_deref(), engine_state.ctrlc.clone())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Read packets out of a pcap file",
+            example: "open capture.pcap --raw | from pcap",
+            result: None,
+        }]
+    }
+}
+
+fn collect_binary(input: PipelineData, span: Span) -> Result<Vec<u8>, ShellError> {
+    let mut bytes = vec![];
+
+    for value in input {
+        match value {
+            Value::Binary { val, .. } => bytes.extend_from_slice(&val),
+            other => {
+                return Err(ShellError::UnsupportedInput(
+                    "Expected binary from pipeline".to_string(),
+                    "value originates from here".into(),
+                    span,
+                    other.expect_span(),
+                ))
+            }
+        }
+    }
+
+    Ok(bytes)
+}
+
+fn truncated(head: Span) -> ShellError {
+    ShellError::UnsupportedInput(
+        "Truncated pcap data".to_string(),
+        "input ends here".to_string(),
+        head,
+        head,
+    )
+}
+
+fn from_pcap(
+    bytes: &[u8],
+    head: Span,
+    filter: Option<&str>,
+    ctrlc: Option<std::sync::Arc<std::sync::atomic::AtomicBool>>,
+) -> Result<PipelineData, ShellError> {
+    if bytes.len() < GLOBAL_HEADER_LEN {
+        return Err(truncated(head));
+    }
+
+    let magic = BigEndian::read_u32(&bytes[0..4]);
+    let big_endian = match magic {
+        MAGIC_BE => true,
+        MAGIC_LE => false,
+        _ => {
+            return Err(ShellError::IncorrectValue {
+                msg: "not a libpcap capture: bad magic number".to_string(),
+                span: head,
+            })
+        }
+    };
+    let read_u32: fn(&[u8]) -> u32 = if big_endian {
+        BigEndian::read_u32
+    } else {
+        LittleEndian::read_u32
+    };
+
+    let mut records = vec![];
+    let mut offset = GLOBAL_HEADER_LEN;
+
+    while offset < bytes.len() {
+        if offset + PACKET_HEADER_LEN > bytes.len() {
+            return Err(truncated(head));
+        }
+
+        let ts_sec = read_u32(&bytes[offset..offset + 4]);
+        let ts_usec = read_u32(&bytes[offset + 4..offset + 8]);
+        let incl_len = read_u32(&bytes[offset + 8..offset + 12]) as usize;
+        let orig_len = read_u32(&bytes[offset + 12..offset + 16]) as usize;
+        offset += PACKET_HEADER_LEN;
+
+        if offset + incl_len > bytes.len() {
+            return Err(truncated(head));
+        }
+        let packet = &bytes[offset..offset + incl_len];
+        offset += incl_len;
+
+        let decoded = decode_ethernet(packet);
+        let protocol = decoded
+            .as_ref()
+            .map(|d| d.protocol.as_str())
+            .unwrap_or("unknown");
+        if let Some(wanted) = filter {
+            if protocol != wanted {
+                continue;
+            }
+        }
+
+        records.push(packet_to_value(
+            decoded, packet, orig_len, ts_sec, ts_usec, head,
+        ));
+    }
+
+    Ok(records.into_iter().into_pipeline_data(ctrlc))
+}
+
+struct DecodedPacket<'a> {
+    src: String,
+    dst: String,
+    protocol: String,
+    src_port: Option<u16>,
+    dst_port: Option<u16>,
+    payload: &'a [u8],
+}
+
+fn packet_to_value(
+    decoded: Option<DecodedPacket>,
+    packet: &[u8],
+    orig_len: usize,
+    ts_sec: u32,
+    ts_usec: u32,
+    head: Span,
+) -> Value {
+    let naive = chrono::NaiveDateTime::from_timestamp_opt(ts_sec as i64, ts_usec * 1000)
+        .unwrap_or_default();
+    let timestamp = chrono::DateTime::<chrono::Utc>::from_utc(naive, chrono::Utc).into();
+
+    let (src, dst, protocol, src_port, dst_port, payload) = match decoded {
+        Some(d) => (d.src, d.dst, d.protocol, d.src_port, d.dst_port, d.payload),
+        None => (
+            String::new(),
+            String::new(),
+            "unknown".to_string(),
+            None,
+            None,
+            packet,
+        ),
+    };
+
+    Value::Record {
+        cols: vec![
+            "timestamp".to_string(),
+            "src".to_string(),
+            "dst".to_string(),
+            "protocol".to_string(),
+            "src_port".to_string(),
+            "dst_port".to_string(),
+            "length".to_string(),
+            "payload".to_string(),
+        ],
+        vals: vec![
+            Value::Date {
+                val: timestamp,
+                span: head,
+            },
+            Value::string(src, head),
+            Value::string(dst, head),
+            Value::string(protocol, head),
+            port_to_value(src_port, head),
+            port_to_value(dst_port, head),
+            Value::int(orig_len as i64, head),
+            Value::binary(payload.to_vec(), head),
+        ],
+        span: head,
+    }
+}
+
+fn port_to_value(port: Option<u16>, head: Span) -> Value {
+    match port {
+        Some(p) => Value::int(p as i64, head),
+        None => Value::nothing(head),
+    }
+}
+
+const ETHERTYPE_IPV4: u16 = 0x0800;
+const IPPROTO_TCP: u8 = 6;
+const IPPROTO_UDP: u8 = 17;
+
+// Packet contents are in network byte order, whatever the capture file's own byte order.
+fn decode_ethernet(packet: &[u8]) -> Option<DecodedPacket> {
+    if packet.len() < 14 {
+        return None;
+    }
+    let ethertype = BigEndian::read_u16(&packet[12..14]);
+    if ethertype != ETHERTYPE_IPV4 {
+        return None;
+    }
+
+    let ip = &packet[14..];
+    if ip.len() < 20 {
+        return None;
+    }
+    let ihl = (ip[0] & 0x0f) as usize * 4;
+    if ip.len() < ihl {
+        return None;
+    }
+    let src = format!("{}.{}.{}.{}", ip[12], ip[13], ip[14], ip[15]);
+    let dst = format!("{}.{}.{}.{}", ip[16], ip[17], ip[18], ip[19]);
+    let proto = ip[9];
+    let transport = &ip[ihl..];
+
+    let (protocol, src_port, dst_port, payload_offset) = match proto {
+        IPPROTO_TCP if transport.len() >= 20 => {
+            let data_offset = ((transport[12] >> 4) as usize) * 4;
+            (
+                "tcp",
+                Some(BigEndian::read_u16(&transport[0..2])),
+                Some(BigEndian::read_u16(&transport[2..4])),
+                data_offset.min(transport.len()),
+            )
+        }
+        IPPROTO_UDP if transport.len() >= 8 => (
+            "udp",
+            Some(BigEndian::read_u16(&transport[0..2])),
+            Some(BigEndian::read_u16(&transport[2..4])),
+            8,
+        ),
+        _ => ("ip", None, None, 0),
+    };
+
+    Some(DecodedPacket {
+        src,
+        dst,
+        protocol: protocol.to_string(),
+        src_port,
+        dst_port,
+        payload: &transport[payload_offset..],
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(FromPcap {})
+    }
+}
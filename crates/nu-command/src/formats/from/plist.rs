@@ -0,0 +1,125 @@
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Type, Value,
+};
+use std::io::Cursor;
+
+#[derive(Clone)]
+pub struct FromPlist;
+
+impl Command for FromPlist {
+    fn name(&self) -> &str {
+        "from plist"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("from plist")
+            .input_output_types(vec![(Type::Binary, Type::Any)])
+            .category(Category::Formats)
+    }
+
+    fn usage(&self) -> &str {
+        "Parse text as .plist and create table."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Accepts both the binary (bplist00) and XML property list encodings; the format is detected automatically. macOS defaults exports and LaunchAgents are typically XML, while most preference files on disk are binary."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            example: "open ~/Library/Preferences/com.apple.finder.plist | from plist",
+            description: "Convert a binary plist file into a table",
+            result: None,
+        }]
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let bytes = collect_binary(input, head)?;
+
+        let plist_value =
+            plist::Value::from_reader(Cursor::new(bytes)).map_err(|e| ShellError::CantConvert {
+                to_type: "structured plist data".into(),
+                from_type: "binary".into(),
+                span: head,
+                help: Some(e.to_string()),
+            })?;
+
+        Ok(convert_plist_value(&plist_value, head).into_pipeline_data())
+    }
+}
+
+fn collect_binary(input: PipelineData, span: Span) -> Result<Vec<u8>, ShellError> {
+    let mut bytes = vec![];
+
+    for value in input {
+        match value {
+            Value::Binary { val, .. } => bytes.extend_from_slice(&val),
+            Value::String { val, .. } => bytes.extend_from_slice(val.as_bytes()),
+            other => {
+                return Err(ShellError::UnsupportedInput(
+                    "Expected binary or string from pipeline".to_string(),
+                    "value originates from here".into(),
+                    span,
+                    other.expect_span(),
+                ))
+            }
+        }
+    }
+
+    Ok(bytes)
+}
+
+fn convert_plist_value(value: &plist::Value, span: Span) -> Value {
+    match value {
+        plist::Value::Boolean(b) => Value::bool(*b, span),
+        plist::Value::Integer(i) => Value::int(i.as_signed().unwrap_or_default(), span),
+        plist::Value::Real(f) => Value::float(*f, span),
+        plist::Value::String(s) => Value::string(s.clone(), span),
+        plist::Value::Data(data) => Value::binary(data.clone(), span),
+        plist::Value::Date(date) => {
+            let system_time: std::time::SystemTime = (*date).into();
+            let chrono_date: chrono::DateTime<chrono::Utc> = system_time.into();
+            Value::Date {
+                val: chrono_date.into(),
+                span,
+            }
+        }
+        plist::Value::Array(array) => {
+            let vals = array.iter().map(|v| convert_plist_value(v, span)).collect();
+            Value::List { vals, span }
+        }
+        plist::Value::Dictionary(dict) => {
+            let mut cols = vec![];
+            let mut vals = vec![];
+            for (k, v) in dict {
+                cols.push(k.clone());
+                vals.push(convert_plist_value(v, span));
+            }
+            Value::Record { cols, vals, span }
+        }
+        // Uid is only meaningful inside NSKeyedArchiver payloads; surface it as its raw number.
+        plist::Value::Uid(uid) => Value::int(uid.get() as i64, span),
+        _ => Value::nothing(span),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(FromPlist {})
+    }
+}
@@ -0,0 +1,264 @@
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
+    Type, Value,
+};
+
+#[derive(Clone)]
+pub struct FromPrometheus;
+
+impl Command for FromPrometheus {
+    fn name(&self) -> &str {
+        "from prometheus"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("from prometheus")
+            .input_output_types(vec![(Type::String, Type::Table(vec![]))])
+            .category(Category::Formats)
+    }
+
+    fn usage(&self) -> &str {
+        "Parse the Prometheus text exposition format into a table of samples."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Each row is one sample line: `name`, `labels` (a record, empty if the
+sample has none), `value` (a float; `NaN`/`+Inf`/`-Inf` round-trip as such),
+and `timestamp` (the optional trailing Unix milliseconds, or `null`).
+`# HELP`/`# TYPE` comment lines and blank lines are skipped rather than
+surfaced as rows."#
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        from_prometheus(input, head, engine_state.ctrlc.clone())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Parse scraped metrics into a table",
+            example: r#"'http_requests_total{method="get",code="200"} 1027 1395066363000' | from prometheus"#,
+            result: Some(Value::List {
+                vals: vec![Value::Record {
+                    cols: vec![
+                        "name".to_string(),
+                        "labels".to_string(),
+                        "value".to_string(),
+                        "timestamp".to_string(),
+                    ],
+                    vals: vec![
+                        Value::test_string("http_requests_total"),
+                        Value::Record {
+                            cols: vec!["method".to_string(), "code".to_string()],
+                            vals: vec![Value::test_string("get"), Value::test_string("200")],
+                            span: Span::test_data(),
+                        },
+                        Value::test_float(1027.0),
+                        Value::test_int(1395066363000),
+                    ],
+                    span: Span::test_data(),
+                }],
+                span: Span::test_data(),
+            }),
+        }]
+    }
+}
+
+fn collect_string(input: PipelineData, span: Span) -> Result<String, ShellError> {
+    let value = input.into_value(span);
+    match value {
+        Value::String { val, .. } => Ok(val),
+        other => Err(ShellError::UnsupportedInput(
+            "Expected string from pipeline".to_string(),
+            "value originates from here".into(),
+            span,
+            other.expect_span(),
+        )),
+    }
+}
+
+fn from_prometheus(
+    input: PipelineData,
+    head: Span,
+    ctrlc: Option<std::sync::Arc<std::sync::atomic::AtomicBool>>,
+) -> Result<PipelineData, ShellError> {
+    let span = input.span().unwrap_or(head);
+    let text = collect_string(input, head)?;
+
+    let mut rows = vec![];
+    for line in text.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        rows.push(parse_sample_line(trimmed, head, span)?);
+    }
+
+    Ok(rows.into_iter().into_pipeline_data(ctrlc))
+}
+
+fn parse_sample_line(line: &str, head: Span, span: Span) -> Result<Value, ShellError> {
+    let bytes: Vec<char> = line.chars().collect();
+    let mut i = 0;
+
+    while i < bytes.len() && bytes[i] != '{' && !bytes[i].is_whitespace() {
+        i += 1;
+    }
+    let name: String = bytes[..i].iter().collect();
+
+    let mut labels_cols = vec![];
+    let mut labels_vals = vec![];
+
+    if i < bytes.len() && bytes[i] == '{' {
+        let start = i + 1;
+        let mut end = start;
+        let mut in_quotes = false;
+        let mut escaped = false;
+
+        while end < bytes.len() {
+            let c = bytes[end];
+            if escaped {
+                escaped = false;
+            } else if c == '\\' && in_quotes {
+                escaped = true;
+            } else if c == '"' {
+                in_quotes = !in_quotes;
+            } else if c == '}' && !in_quotes {
+                break;
+            }
+            end += 1;
+        }
+
+        let labels_text: String = bytes[start..end.min(bytes.len())].iter().collect();
+        parse_labels(&labels_text, span, &mut labels_cols, &mut labels_vals)?;
+
+        i = end + 1;
+    }
+
+    let rest: String = bytes[i.min(bytes.len())..].iter().collect();
+    let mut parts = rest.split_whitespace();
+
+    let value_str = parts.next().ok_or_else(|| {
+        ShellError::UnsupportedInput(
+            "sample line is missing its value".into(),
+            "value originates from here".into(),
+            head,
+            span,
+        )
+    })?;
+    let value = value_str.parse::<f64>().map_err(|e| {
+        ShellError::UnsupportedInput(
+            format!("could not parse sample value '{value_str}': {e}"),
+            "value originates from here".into(),
+            head,
+            span,
+        )
+    })?;
+
+    let timestamp = match parts.next() {
+        Some(ts) => ts
+            .parse::<i64>()
+            .map(|ts| Value::int(ts, span))
+            .unwrap_or_else(|_| Value::nothing(span)),
+        None => Value::nothing(span),
+    };
+
+    Ok(Value::Record {
+        cols: vec![
+            "name".to_string(),
+            "labels".to_string(),
+            "value".to_string(),
+            "timestamp".to_string(),
+        ],
+        vals: vec![
+            Value::string(name, span),
+            Value::Record {
+                cols: labels_cols,
+                vals: labels_vals,
+                span,
+            },
+            Value::float(value, span),
+            timestamp,
+        ],
+        span,
+    })
+}
+
+fn parse_labels(
+    text: &str,
+    span: Span,
+    cols: &mut Vec<String>,
+    vals: &mut Vec<Value>,
+) -> Result<(), ShellError> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut i = 0;
+
+    while i < chars.len() {
+        while i < chars.len() && (chars[i] == ',' || chars[i].is_whitespace()) {
+            i += 1;
+        }
+        if i >= chars.len() {
+            break;
+        }
+
+        let key_start = i;
+        while i < chars.len() && chars[i] != '=' {
+            i += 1;
+        }
+        let key: String = chars[key_start..i].iter().collect();
+        i += 1; // skip '='
+
+        if i >= chars.len() || chars[i] != '"' {
+            break;
+        }
+        i += 1; // skip opening quote
+
+        let mut value = String::new();
+        let mut escaped = false;
+        while i < chars.len() {
+            let c = chars[i];
+            if escaped {
+                match c {
+                    'n' => value.push('\n'),
+                    '"' => value.push('"'),
+                    '\\' => value.push('\\'),
+                    other => value.push(other),
+                }
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == '"' {
+                i += 1;
+                break;
+            } else {
+                value.push(c);
+            }
+            i += 1;
+        }
+
+        cols.push(key.trim().to_string());
+        vals.push(Value::string(value, span));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(FromPrometheus {})
+    }
+}
@@ -0,0 +1,305 @@
+use nu_engine::env::current_dir;
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Spanned,
+    SyntaxShape, Type, Value,
+};
+use protobuf::reflect::{FileDescriptor, MessageDescriptor, ReflectValueRef};
+use protobuf::MessageDyn;
+
+#[derive(Clone)]
+pub struct FromProtobuf;
+
+impl Command for FromProtobuf {
+    fn name(&self) -> &str {
+        "from protobuf"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("from protobuf")
+            .input_output_types(vec![(Type::Binary, Type::Any)])
+            .required_named(
+                "schema",
+                SyntaxShape::String,
+                "path to the .proto file describing the message",
+                None,
+            )
+            .required(
+                "message",
+                SyntaxShape::String,
+                "fully-qualified name of the message type to decode as (e.g. mypackage.MyMessage)",
+            )
+            .category(Category::Formats)
+    }
+
+    fn usage(&self) -> &str {
+        "Decode binary protobuf data into a record, using a .proto schema."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Repeated fields become lists, nested messages become nested records, and
+enum fields are rendered as their variant name. A `--schema` ending in
+`.proto` is parsed directly as a textual schema (no protoc installation is
+required), looking up its imports only relative to the schema file's own
+directory. Any other extension is treated as a compiled descriptor set,
+i.e. the binary output of `protoc --descriptor_set_out=...`, of which only
+the last file is used. Either way the message is decoded against that one
+file alone: types it imports from other files are not resolved."#
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let schema: Spanned<String> = call
+            .get_flag(engine_state, stack, "schema")?
+            .expect("required value");
+        let message: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let cwd = current_dir(engine_state, stack)?;
+
+        from_protobuf(input, head, schema, message, cwd)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Decode a binary protobuf message into a record",
+                example: "open --raw payload.bin | from protobuf --schema schema.proto mypackage.MyMessage",
+                result: None,
+            },
+            Example {
+                description: "Decode using a compiled descriptor set instead of a .proto file",
+                example: "open --raw payload.bin | from protobuf --schema schema.desc mypackage.MyMessage",
+                result: None,
+            },
+        ]
+    }
+}
+
+fn collect_binary(input: PipelineData, span: Span) -> Result<Vec<u8>, ShellError> {
+    let mut bytes = vec![];
+
+    for value in input {
+        match value {
+            Value::Binary { val, .. } => bytes.extend_from_slice(&val),
+            other => {
+                return Err(ShellError::UnsupportedInput(
+                    "Expected binary from pipeline".to_string(),
+                    "value originates from here".into(),
+                    span,
+                    other.expect_span(),
+                ))
+            }
+        }
+    }
+
+    Ok(bytes)
+}
+
+fn from_protobuf(
+    input: PipelineData,
+    head: Span,
+    schema: Spanned<String>,
+    message: Spanned<String>,
+    cwd: std::path::PathBuf,
+) -> Result<PipelineData, ShellError> {
+    let span = input.span().unwrap_or(head);
+    let bytes = collect_binary(input, head)?;
+
+    let schema_path = cwd.join(&schema.item);
+
+    let is_text_schema = schema_path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| ext.eq_ignore_ascii_case("proto"))
+        .unwrap_or(false);
+
+    let file_descriptor_proto = if is_text_schema {
+        parse_text_schema(&schema_path, &schema, cwd)?
+    } else {
+        parse_descriptor_set(&schema_path, &schema)?
+    };
+
+    let file_descriptor = FileDescriptor::new_dynamic(file_descriptor_proto, &[]).map_err(|e| {
+        ShellError::GenericError(
+            format!("Could not build descriptor from schema: {e}"),
+            "schema originates from here".into(),
+            Some(schema.span),
+            None,
+            Vec::new(),
+        )
+    })?;
+
+    let message_descriptor = file_descriptor
+        .message_by_full_name(&message.item)
+        .ok_or_else(|| {
+            ShellError::GenericError(
+                format!("No message named '{}' found in schema", message.item),
+                "message name originates from here".into(),
+                Some(message.span),
+                None,
+                Vec::new(),
+            )
+        })?;
+
+    let decoded = message_descriptor.parse_from_bytes(&bytes).map_err(|e| {
+        ShellError::UnsupportedInput(
+            format!("Could not decode protobuf message: {e}"),
+            "value originates from here".into(),
+            head,
+            span,
+        )
+    })?;
+
+    Ok(message_to_value(&message_descriptor, decoded.as_ref(), head).into_pipeline_data())
+}
+
+fn parse_text_schema(
+    schema_path: &std::path::Path,
+    schema: &Spanned<String>,
+    cwd: std::path::PathBuf,
+) -> Result<protobuf::descriptor::FileDescriptorProto, ShellError> {
+    let schema_dir = schema_path.parent().map(|p| p.to_path_buf()).unwrap_or(cwd);
+
+    let parsed = protobuf_parse::Parser::new()
+        .pure()
+        .include(&schema_dir)
+        .input(schema_path)
+        .parse_and_typecheck()
+        .map_err(|e| {
+            ShellError::GenericError(
+                format!("Could not parse protobuf schema: {e}"),
+                "schema originates from here".into(),
+                Some(schema.span),
+                None,
+                Vec::new(),
+            )
+        })?;
+
+    parsed
+        .file_descriptors
+        .into_iter()
+        .find(|fd| {
+            fd.name()
+                == schema_path
+                    .file_name()
+                    .and_then(|n| n.to_str())
+                    .unwrap_or("")
+        })
+        .or_else(|| parsed.file_descriptors.last().cloned())
+        .ok_or_else(|| {
+            ShellError::GenericError(
+                "Could not find the schema file among the parsed descriptors".into(),
+                "schema originates from here".into(),
+                Some(schema.span),
+                None,
+                Vec::new(),
+            )
+        })
+}
+
+/// Reads a compiled `FileDescriptorSet` (the output of `protoc
+/// --descriptor_set_out=...`) and returns its last file, matching the
+/// "most specific file wins" behavior used for textual schemas. Files
+/// that depend on other files in the same set are not resolved; see
+/// `extra_usage`.
+fn parse_descriptor_set(
+    schema_path: &std::path::Path,
+    schema: &Spanned<String>,
+) -> Result<protobuf::descriptor::FileDescriptorProto, ShellError> {
+    use protobuf::Message;
+
+    let bytes = std::fs::read(schema_path).map_err(|e| {
+        ShellError::GenericError(
+            format!("Could not read descriptor set: {e}"),
+            "schema originates from here".into(),
+            Some(schema.span),
+            None,
+            Vec::new(),
+        )
+    })?;
+
+    let mut descriptor_set = protobuf::descriptor::FileDescriptorSet::parse_from_bytes(&bytes)
+        .map_err(|e| {
+            ShellError::GenericError(
+                format!("Could not parse descriptor set: {e}"),
+                "schema originates from here".into(),
+                Some(schema.span),
+                None,
+                Vec::new(),
+            )
+        })?;
+
+    descriptor_set.file.pop().ok_or_else(|| {
+        ShellError::GenericError(
+            "Descriptor set does not contain any files".into(),
+            "schema originates from here".into(),
+            Some(schema.span),
+            None,
+            Vec::new(),
+        )
+    })
+}
+
+fn message_to_value(descriptor: &MessageDescriptor, message: &dyn MessageDyn, span: Span) -> Value {
+    let mut cols = vec![];
+    let mut vals = vec![];
+
+    for field in descriptor.fields() {
+        cols.push(field.name().to_string());
+
+        if field.is_repeated() {
+            let repeated = field.get_repeated(message);
+            let list = (0..repeated.len())
+                .map(|i| reflect_value_to_value(repeated.get(i), span))
+                .collect();
+            vals.push(Value::List { vals: list, span });
+        } else {
+            match field.get_singular(message) {
+                Some(value) => vals.push(reflect_value_to_value(value, span)),
+                None => vals.push(Value::nothing(span)),
+            }
+        }
+    }
+
+    Value::Record { cols, vals, span }
+}
+
+fn reflect_value_to_value(value: ReflectValueRef, span: Span) -> Value {
+    match value {
+        ReflectValueRef::U32(v) => Value::int(v as i64, span),
+        ReflectValueRef::U64(v) => Value::int(v as i64, span),
+        ReflectValueRef::I32(v) => Value::int(v as i64, span),
+        ReflectValueRef::I64(v) => Value::int(v, span),
+        ReflectValueRef::F32(v) => Value::float(v as f64, span),
+        ReflectValueRef::F64(v) => Value::float(v, span),
+        ReflectValueRef::Bool(v) => Value::boolean(v, span),
+        ReflectValueRef::String(v) => Value::string(v.to_string(), span),
+        ReflectValueRef::Bytes(v) => Value::binary(v.to_vec(), span),
+        ReflectValueRef::Enum(descriptor, number) => match descriptor.value_by_number(number) {
+            Some(enum_value) => Value::string(enum_value.name().to_string(), span),
+            None => Value::int(number as i64, span),
+        },
+        ReflectValueRef::Message(message) => {
+            message_to_value(&message.descriptor_dyn(), message.as_ref(), span)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(FromProtobuf {})
+    }
+}
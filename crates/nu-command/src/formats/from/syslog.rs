@@ -0,0 +1,478 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
+    SyntaxShape, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct FromSyslog;
+
+const FACILITY_NAMES: [&str; 24] = [
+    "kern", "user", "mail", "daemon", "auth", "syslog", "lpr", "news", "uucp", "cron", "authpriv",
+    "ftp", "ntp", "audit", "alert", "clock", "local0", "local1", "local2", "local3", "local4",
+    "local5", "local6", "local7",
+];
+
+const SEVERITY_NAMES: [&str; 8] = [
+    "emerg", "alert", "crit", "err", "warning", "notice", "info", "debug",
+];
+
+impl Command for FromSyslog {
+    fn name(&self) -> &str {
+        "from syslog"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("from syslog")
+            .input_output_types(vec![(Type::String, Type::Table(vec![]))])
+            .named(
+                "max-severity",
+                SyntaxShape::Int,
+                "only keep rows at least this severe (0 = emerg ... 7 = debug); for triage, drop the noisy tail",
+                Some('s'),
+            )
+            .category(Category::Formats)
+    }
+
+    fn usage(&self) -> &str {
+        "Parse RFC 3164/5424 syslog lines into a table of records."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Each row is one line: `timestamp`, `hostname`, `app_name`, `proc_id`,
+`msg_id`, `structured_data` (a record keyed by SD-ID, RFC 5424 only),
+`facility`/`facility_name`, `severity`/`severity_name`, and `message`. RFC
+3164 lines have no year in their timestamp, so the current year is assumed,
+and they carry no `msg_id`/`structured_data` (left `null`). Lines that
+don't start with a `<priority>` tag are passed through with everything but
+`message` left `null`, rather than erroring out a whole log stream over one
+malformed line.
+
+`--max-severity` filters the stream down to rows whose `severity` is
+numerically at or below the given threshold (lower is more severe), so
+`from syslog --max-severity 3` keeps only emerg/alert/crit/err rows for a
+quick triage pass."#
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let max_severity: Option<i64> = call.get_flag(engine_state, stack, "max-severity")?;
+        from_syslog(input, head, max_severity, engine_state.ctrlc.clone())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Parse an RFC 5424 syslog line",
+                example: r#"'<34>1 2003-10-11T22:14:15.003Z mymachine.example.com su - ID47 - failed' | from syslog"#,
+                result: None,
+            },
+            Example {
+                description: "Parse an RFC 3164 (BSD) syslog line",
+                example: r#"'<34>Oct 11 22:14:15 mymachine su: failed' | from syslog"#,
+                result: None,
+            },
+            Example {
+                description: "Triage a log file for just errors and worse",
+                example: r#"open /var/log/syslog | from syslog --max-severity 3"#,
+                result: None,
+            },
+        ]
+    }
+}
+
+fn collect_string(input: PipelineData, span: Span) -> Result<String, ShellError> {
+    let value = input.into_value(span);
+    match value {
+        Value::String { val, .. } => Ok(val),
+        other => Err(ShellError::UnsupportedInput(
+            "Expected string from pipeline".to_string(),
+            "value originates from here".into(),
+            span,
+            other.expect_span(),
+        )),
+    }
+}
+
+fn from_syslog(
+    input: PipelineData,
+    head: Span,
+    max_severity: Option<i64>,
+    ctrlc: Option<std::sync::Arc<std::sync::atomic::AtomicBool>>,
+) -> Result<PipelineData, ShellError> {
+    let span = input.span().unwrap_or(head);
+    let text = collect_string(input, head)?;
+
+    let rows = text
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| parse_syslog_line(line, span))
+        .filter(|row| passes_severity_filter(row, max_severity))
+        .collect::<Vec<_>>();
+
+    Ok(rows.into_iter().into_pipeline_data(ctrlc))
+}
+
+fn passes_severity_filter(row: &Value, max_severity: Option<i64>) -> bool {
+    let Some(max_severity) = max_severity else {
+        return true;
+    };
+    let Value::Record { cols, vals, .. } = row else {
+        return true;
+    };
+    match cols.iter().position(|c| c == "severity").map(|i| &vals[i]) {
+        Some(Value::Int { val, .. }) => *val <= max_severity,
+        _ => true,
+    }
+}
+
+fn empty_row(message: &str, span: Span) -> Value {
+    record(
+        Value::nothing(span),
+        Value::nothing(span),
+        Value::nothing(span),
+        Value::nothing(span),
+        Value::nothing(span),
+        Value::nothing(span),
+        Value::nothing(span),
+        Value::nothing(span),
+        Value::nothing(span),
+        Value::nothing(span),
+        Value::string(message, span),
+        span,
+    )
+}
+
+fn parse_syslog_line(line: &str, span: Span) -> Value {
+    let Some((priority, rest)) = parse_priority(line) else {
+        return empty_row(line, span);
+    };
+
+    let facility = priority / 8;
+    let severity = priority % 8;
+    let facility_val = Value::int(facility as i64, span);
+    let facility_name = facility_name_value(facility, span);
+    let severity_val = Value::int(severity as i64, span);
+    let severity_name = Value::string(SEVERITY_NAMES[severity as usize % 8], span);
+
+    if let Some(parsed) = parse_rfc5424(rest, span) {
+        return record(
+            parsed.timestamp,
+            parsed.hostname,
+            parsed.app_name,
+            parsed.proc_id,
+            parsed.msg_id,
+            parsed.structured_data,
+            facility_val,
+            facility_name,
+            severity_val,
+            severity_name,
+            parsed.message,
+            span,
+        );
+    }
+
+    let parsed = parse_rfc3164(rest, span);
+    record(
+        parsed.timestamp,
+        parsed.hostname,
+        parsed.app_name,
+        parsed.proc_id,
+        Value::nothing(span),
+        Value::nothing(span),
+        facility_val,
+        facility_name,
+        severity_val,
+        severity_name,
+        parsed.message,
+        span,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+fn record(
+    timestamp: Value,
+    hostname: Value,
+    app_name: Value,
+    proc_id: Value,
+    msg_id: Value,
+    structured_data: Value,
+    facility: Value,
+    facility_name: Value,
+    severity: Value,
+    severity_name: Value,
+    message: Value,
+    span: Span,
+) -> Value {
+    Value::Record {
+        cols: vec![
+            "timestamp".to_string(),
+            "hostname".to_string(),
+            "app_name".to_string(),
+            "proc_id".to_string(),
+            "msg_id".to_string(),
+            "structured_data".to_string(),
+            "facility".to_string(),
+            "facility_name".to_string(),
+            "severity".to_string(),
+            "severity_name".to_string(),
+            "message".to_string(),
+        ],
+        vals: vec![
+            timestamp,
+            hostname,
+            app_name,
+            proc_id,
+            msg_id,
+            structured_data,
+            facility,
+            facility_name,
+            severity,
+            severity_name,
+            message,
+        ],
+        span,
+    }
+}
+
+fn facility_name_value(facility: u8, span: Span) -> Value {
+    match FACILITY_NAMES.get(facility as usize) {
+        Some(name) => Value::string(*name, span),
+        None => Value::nothing(span),
+    }
+}
+
+/// Splits a leading `<priority>` tag off the front of a line, returning the
+/// numeric priority and the remainder of the line.
+fn parse_priority(line: &str) -> Option<(u8, &str)> {
+    let line = line.strip_prefix('<')?;
+    let end = line.find('>')?;
+    let priority: u8 = line[..end].parse().ok()?;
+    Some((priority, &line[end + 1..]))
+}
+
+struct Rfc5424 {
+    timestamp: Value,
+    hostname: Value,
+    app_name: Value,
+    proc_id: Value,
+    msg_id: Value,
+    structured_data: Value,
+    message: Value,
+}
+
+fn parse_rfc5424(rest: &str, span: Span) -> Option<Rfc5424> {
+    let mut fields = rest.splitn(2, ' ');
+    let version = fields.next()?;
+    if version.parse::<u32>().is_err() {
+        return None;
+    }
+    let rest = fields.next().unwrap_or("");
+
+    let mut fields = rest.splitn(6, ' ');
+    let timestamp_str = fields.next()?;
+    let hostname = fields.next()?;
+    let app_name = fields.next()?;
+    let proc_id = fields.next()?;
+    let msg_id = fields.next()?;
+    let rest = fields.next().unwrap_or("");
+
+    let timestamp = match chrono::DateTime::parse_from_rfc3339(timestamp_str) {
+        Ok(date) => Value::Date { val: date, span },
+        Err(_) if timestamp_str == "-" => Value::nothing(span),
+        Err(_) => return None,
+    };
+
+    let (structured_data, message) = parse_structured_data(rest, span);
+
+    Some(Rfc5424 {
+        timestamp,
+        hostname: nil_or_string(hostname, span),
+        app_name: nil_or_string(app_name, span),
+        proc_id: nil_or_string(proc_id, span),
+        msg_id: nil_or_string(msg_id, span),
+        structured_data,
+        message: Value::string(message.trim_start(), span),
+    })
+}
+
+fn nil_or_string(field: &str, span: Span) -> Value {
+    if field == "-" {
+        Value::nothing(span)
+    } else {
+        Value::string(field, span)
+    }
+}
+
+/// Parses a run of `[id key="val" ...]` structured-data elements, returning
+/// a record keyed by SD-ID and whatever text follows as the message.
+fn parse_structured_data(text: &str, span: Span) -> (Value, String) {
+    if !text.starts_with('[') {
+        return (Value::nothing(span), text.to_string());
+    }
+
+    let mut cols = vec![];
+    let mut vals = vec![];
+    let chars: Vec<char> = text.chars().collect();
+    let mut i = 0;
+
+    while i < chars.len() && chars[i] == '[' {
+        let start = i + 1;
+        let mut end = start;
+        let mut in_quotes = false;
+        let mut escaped = false;
+
+        while end < chars.len() {
+            let c = chars[end];
+            if escaped {
+                escaped = false;
+            } else if c == '\\' && in_quotes {
+                escaped = true;
+            } else if c == '"' {
+                in_quotes = !in_quotes;
+            } else if c == ']' && !in_quotes {
+                break;
+            }
+            end += 1;
+        }
+
+        let element: String = chars[start..end.min(chars.len())].iter().collect();
+        let mut parts = element.splitn(2, ' ');
+        let id = parts.next().unwrap_or("").to_string();
+        let params_text = parts.next().unwrap_or("");
+
+        let mut param_cols = vec![];
+        let mut param_vals = vec![];
+        let mut param_chars: Vec<char> = params_text.chars().collect();
+        let mut j = 0;
+        while j < param_chars.len() {
+            while j < param_chars.len() && param_chars[j].is_whitespace() {
+                j += 1;
+            }
+            let key_start = j;
+            while j < param_chars.len() && param_chars[j] != '=' {
+                j += 1;
+            }
+            let key: String = param_chars[key_start..j].iter().collect();
+            if key.is_empty() || j >= param_chars.len() {
+                break;
+            }
+            j += 1; // skip '='
+            if j >= param_chars.len() || param_chars[j] != '"' {
+                break;
+            }
+            j += 1; // skip opening quote
+            let mut value = String::new();
+            let mut escaped = false;
+            while j < param_chars.len() {
+                let c = param_chars[j];
+                if escaped {
+                    value.push(c);
+                    escaped = false;
+                } else if c == '\\' {
+                    escaped = true;
+                } else if c == '"' {
+                    j += 1;
+                    break;
+                } else {
+                    value.push(c);
+                }
+                j += 1;
+            }
+            param_cols.push(key);
+            param_vals.push(Value::string(value, span));
+        }
+        param_chars.clear();
+
+        cols.push(id);
+        vals.push(Value::Record {
+            cols: param_cols,
+            vals: param_vals,
+            span,
+        });
+
+        i = end + 1;
+    }
+
+    let message = chars[i..].iter().collect::<String>();
+
+    (Value::Record { cols, vals, span }, message)
+}
+
+struct Rfc3164 {
+    timestamp: Value,
+    hostname: Value,
+    app_name: Value,
+    proc_id: Value,
+    message: Value,
+}
+
+fn parse_rfc3164(rest: &str, span: Span) -> Rfc3164 {
+    // "Mmm dd hh:mm:ss hostname tag[pid]: message"
+    let rest = rest.trim_start();
+    if rest.len() < 15 {
+        return Rfc3164 {
+            timestamp: Value::nothing(span),
+            hostname: Value::nothing(span),
+            app_name: Value::nothing(span),
+            proc_id: Value::nothing(span),
+            message: Value::string(rest, span),
+        };
+    }
+
+    let (stamp_str, rest) = rest.split_at(15);
+    let year = chrono::Utc::now().format("%Y").to_string();
+    let timestamp =
+        chrono::NaiveDateTime::parse_from_str(&format!("{year} {stamp_str}"), "%Y %b %e %H:%M:%S")
+            .ok()
+            .map(|naive| Value::Date {
+                val: chrono::DateTime::<chrono::Utc>::from_utc(naive, chrono::Utc).into(),
+                span,
+            })
+            .unwrap_or_else(|| Value::nothing(span));
+
+    let rest = rest.trim_start();
+    let mut fields = rest.splitn(2, ' ');
+    let hostname = fields.next().unwrap_or("");
+    let rest = fields.next().unwrap_or("");
+
+    let (tag_part, message) = match rest.find(": ") {
+        Some(idx) => (&rest[..idx], &rest[idx + 2..]),
+        None => ("", rest),
+    };
+
+    let (app_name, proc_id) = match tag_part.find('[') {
+        Some(idx) if tag_part.ends_with(']') => {
+            (&tag_part[..idx], &tag_part[idx + 1..tag_part.len() - 1])
+        }
+        _ => (tag_part, ""),
+    };
+
+    Rfc3164 {
+        timestamp,
+        hostname: nil_or_string(hostname, span),
+        app_name: nil_or_string(app_name, span),
+        proc_id: nil_or_string(proc_id, span),
+        message: Value::string(message, span),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(FromSyslog {})
+    }
+}
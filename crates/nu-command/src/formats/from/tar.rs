@@ -0,0 +1,172 @@
+use std::io::{Cursor, Read};
+
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
+    Type, Value,
+};
+
+#[derive(Clone)]
+pub struct FromTar;
+
+impl Command for FromTar {
+    fn name(&self) -> &str {
+        "from tar"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("from tar")
+            .input_output_types(vec![(Type::Binary, Type::Table(vec![]))])
+            .category(Category::Formats)
+    }
+
+    fn usage(&self) -> &str {
+        "Read a tar archive's entries into a table."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Each row is one entry: `path`, `size`, `mtime`, `mode`, `type` (`file`,
+`directory`, `symlink`, or `other`), and `data` (the entry's raw content,
+empty for anything but a regular file). Pipe the result through `where`
+to select entries, then into `archive extract` to write them to disk."#
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        from_tar(input, head, engine_state.ctrlc.clone())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "List the contents of a tar archive",
+            example: "open --raw project.tar | from tar | select path size type",
+            result: None,
+        }]
+    }
+}
+
+fn collect_binary(input: PipelineData, span: Span) -> Result<Vec<u8>, ShellError> {
+    let mut bytes = vec![];
+
+    for value in input {
+        match value {
+            Value::Binary { val, .. } => bytes.extend_from_slice(&val),
+            other => {
+                return Err(ShellError::UnsupportedInput(
+                    "Expected binary from pipeline".to_string(),
+                    "value originates from here".into(),
+                    span,
+                    other.expect_span(),
+                ))
+            }
+        }
+    }
+
+    Ok(bytes)
+}
+
+fn from_tar(
+    input: PipelineData,
+    head: Span,
+    ctrlc: Option<std::sync::Arc<std::sync::atomic::AtomicBool>>,
+) -> Result<PipelineData, ShellError> {
+    let span = input.span().unwrap_or(head);
+    let bytes = collect_binary(input, head)?;
+
+    let reader_err = |e: std::io::Error| {
+        ShellError::UnsupportedInput(
+            format!("Could not read tar archive: {e}"),
+            "value originates from here".into(),
+            head,
+            span,
+        )
+    };
+
+    let mut archive = tar::Archive::new(Cursor::new(bytes));
+    let mut rows = vec![];
+
+    for entry in archive.entries().map_err(reader_err)? {
+        let mut entry = entry.map_err(reader_err)?;
+
+        let path = entry
+            .path()
+            .map_err(reader_err)?
+            .to_string_lossy()
+            .into_owned();
+        let size = entry.header().size().map_err(reader_err)?;
+        let mtime = entry.header().mtime().map_err(reader_err)?;
+        let mode = entry.header().mode().map_err(reader_err)?;
+        let entry_type = entry_type_name(entry.header().entry_type());
+
+        let mut data = vec![];
+        if entry.header().entry_type().is_file() {
+            entry.read_to_end(&mut data).map_err(reader_err)?;
+        }
+
+        rows.push(Value::Record {
+            cols: vec![
+                "path".to_string(),
+                "size".to_string(),
+                "mtime".to_string(),
+                "mode".to_string(),
+                "type".to_string(),
+                "data".to_string(),
+            ],
+            vals: vec![
+                Value::string(path, head),
+                Value::Filesize {
+                    val: size as i64,
+                    span: head,
+                },
+                unix_time_to_date(mtime, head),
+                Value::int(mode as i64, head),
+                Value::string(entry_type, head),
+                Value::binary(data, head),
+            ],
+            span: head,
+        });
+    }
+
+    Ok(rows.into_iter().into_pipeline_data(ctrlc))
+}
+
+fn entry_type_name(entry_type: tar::EntryType) -> &'static str {
+    if entry_type.is_dir() {
+        "directory"
+    } else if entry_type.is_symlink() {
+        "symlink"
+    } else if entry_type.is_file() {
+        "file"
+    } else {
+        "other"
+    }
+}
+
+fn unix_time_to_date(seconds: u64, span: Span) -> Value {
+    match chrono::NaiveDateTime::from_timestamp_opt(seconds as i64, 0) {
+        Some(naive) => Value::Date {
+            val: chrono::DateTime::<chrono::Utc>::from_utc(naive, chrono::Utc).into(),
+            span,
+        },
+        None => Value::nothing(span),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(FromTar {})
+    }
+}
@@ -0,0 +1,216 @@
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
+    Type, Value,
+};
+
+#[derive(Clone)]
+pub struct FromTerraformState;
+
+impl Command for FromTerraformState {
+    fn name(&self) -> &str {
+        "from terraform-state"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("from terraform-state")
+            .input_output_types(vec![(Type::String, Type::Table(vec![]))])
+            .category(Category::Formats)
+    }
+
+    fn usage(&self) -> &str {
+        "Convert a Terraform state file (terraform.tfstate) into a table of resource instances."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Each row is one entry from `resources[].instances[]`: `type`, `name`, `mode`
+(`managed` or `data`), `provider`, `index` (the `count`/`for_each` index, or `null`
+for resources without one), an `attributes` record with that instance's attributes
+as reported by the provider, and a `dependencies` list of the other resource
+addresses it depends on."#
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        from_terraform_state(input, head, engine_state.ctrlc.clone())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Inventory the resources tracked in a Terraform state file",
+            example:
+                "open terraform.tfstate --raw | from terraform-state | select type name attributes.id",
+            result: None,
+        }]
+    }
+}
+
+fn collect_string(input: PipelineData, span: Span) -> Result<String, ShellError> {
+    let value = input.into_value(span);
+    match value {
+        Value::String { val, .. } => Ok(val),
+        other => Err(ShellError::UnsupportedInput(
+            "Expected string from pipeline".to_string(),
+            "value originates from here".into(),
+            span,
+            other.expect_span(),
+        )),
+    }
+}
+
+fn from_terraform_state(
+    input: PipelineData,
+    head: Span,
+    ctrlc: Option<std::sync::Arc<std::sync::atomic::AtomicBool>>,
+) -> Result<PipelineData, ShellError> {
+    let span = input.span().unwrap_or(head);
+    let text = collect_string(input, head)?;
+
+    let root: nu_json::Value = nu_json::from_str(&text).map_err(|e| {
+        ShellError::UnsupportedInput(
+            format!("Could not parse Terraform state: {e}"),
+            "value originates from here".into(),
+            head,
+            span,
+        )
+    })?;
+
+    let resources = object_field(&root, "resources")
+        .and_then(|r| r.as_array())
+        .cloned()
+        .unwrap_or_default();
+
+    let rows = resources
+        .iter()
+        .flat_map(|resource| resource_to_rows(resource, head))
+        .collect::<Vec<_>>();
+
+    Ok(rows.into_iter().into_pipeline_data(ctrlc))
+}
+
+fn object_field<'a>(value: &'a nu_json::Value, name: &str) -> Option<&'a nu_json::Value> {
+    match value {
+        nu_json::Value::Object(map) => map.get(name),
+        _ => None,
+    }
+}
+
+fn as_str<'a>(value: Option<&'a nu_json::Value>) -> Option<&'a str> {
+    value.and_then(|v| v.as_str())
+}
+
+fn resource_to_rows(resource: &nu_json::Value, span: Span) -> Vec<Value> {
+    let mode = as_str(object_field(resource, "mode"))
+        .unwrap_or("")
+        .to_string();
+    let resource_type = as_str(object_field(resource, "type"))
+        .unwrap_or("")
+        .to_string();
+    let name = as_str(object_field(resource, "name"))
+        .unwrap_or("")
+        .to_string();
+    let provider = as_str(object_field(resource, "provider"))
+        .unwrap_or("")
+        .to_string();
+
+    let instances = object_field(resource, "instances")
+        .and_then(|i| i.as_array())
+        .cloned()
+        .unwrap_or_default();
+
+    instances
+        .iter()
+        .map(|instance| {
+            let index = match object_field(instance, "index_key") {
+                Some(nu_json::Value::String(s)) => Value::string(s.clone(), span),
+                Some(nu_json::Value::I64(i)) => Value::int(*i, span),
+                Some(nu_json::Value::U64(u)) => Value::int(*u as i64, span),
+                _ => Value::nothing(span),
+            };
+
+            let attributes = object_field(instance, "attributes")
+                .map(|attrs| json_to_value(attrs, span))
+                .unwrap_or_else(|| Value::nothing(span));
+
+            let dependencies = object_field(instance, "dependencies")
+                .and_then(|d| d.as_array())
+                .map(|deps| {
+                    deps.iter()
+                        .filter_map(|d| d.as_str())
+                        .map(|d| Value::string(d, span))
+                        .collect()
+                })
+                .unwrap_or_default();
+
+            Value::Record {
+                cols: vec![
+                    "type".to_string(),
+                    "name".to_string(),
+                    "mode".to_string(),
+                    "provider".to_string(),
+                    "index".to_string(),
+                    "attributes".to_string(),
+                    "dependencies".to_string(),
+                ],
+                vals: vec![
+                    Value::string(resource_type.clone(), span),
+                    Value::string(name.clone(), span),
+                    Value::string(mode.clone(), span),
+                    Value::string(provider.clone(), span),
+                    index,
+                    attributes,
+                    Value::List {
+                        vals: dependencies,
+                        span,
+                    },
+                ],
+                span,
+            }
+        })
+        .collect()
+}
+
+/// A general `nu_json::Value -> Value` conversion for the free-form `attributes` map, whose
+/// shape varies per resource type/provider and isn't worth modelling field-by-field.
+fn json_to_value(value: &nu_json::Value, span: Span) -> Value {
+    match value {
+        nu_json::Value::Null => Value::nothing(span),
+        nu_json::Value::Bool(b) => Value::bool(*b, span),
+        nu_json::Value::I64(i) => Value::int(*i, span),
+        nu_json::Value::U64(u) => Value::int(*u as i64, span),
+        nu_json::Value::F64(f) => Value::float(*f, span),
+        nu_json::Value::String(s) => Value::string(s.clone(), span),
+        nu_json::Value::Array(vals) => Value::List {
+            vals: vals.iter().map(|v| json_to_value(v, span)).collect(),
+            span,
+        },
+        nu_json::Value::Object(map) => {
+            let mut cols = Vec::with_capacity(map.len());
+            let mut vals = Vec::with_capacity(map.len());
+            for (k, v) in map {
+                cols.push(k.clone());
+                vals.push(json_to_value(v, span));
+            }
+            Value::Record { cols, vals, span }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(FromTerraformState {})
+    }
+}
@@ -1,3 +1,4 @@
+use crate::date::parse_date_from_string;
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
@@ -94,9 +95,9 @@ fn convert_toml_to_value(value: &toml::Value, span: Span) -> Value {
             val: s.clone(),
             span,
         },
-        toml::Value::Datetime(d) => Value::String {
-            val: d.to_string(),
-            span,
+        toml::Value::Datetime(d) => match parse_date_from_string(&d.to_string(), span) {
+            Ok(val) => Value::Date { val, span },
+            Err(val) => val,
         },
     }
 }
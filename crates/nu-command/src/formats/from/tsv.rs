@@ -1,4 +1,6 @@
-use super::delimited::{from_delimited_data, trim_from_str, DelimitedReaderConfig};
+use super::delimited::{
+    from_delimited_data, on_error_from_str, schema_from_value, trim_from_str, DelimitedReaderConfig,
+};
 
 use nu_engine::CallExt;
 use nu_protocol::ast::Call;
@@ -53,6 +55,20 @@ impl Command for FromTsv {
                 "drop leading and trailing whitespaces around headers names and/or field values",
                 Some('t'),
             )
+            .named(
+                "schema",
+                SyntaxShape::Record,
+                "a record mapping column names to types (int, float, bool, datetime, string), \
+                 parsed instead of inferred",
+                None,
+            )
+            .named(
+                "on-error",
+                SyntaxShape::String,
+                "what to do with a --schema field that fails to parse: 'fail' (default), \
+                 'null', or 'skip' the whole row",
+                None,
+            )
             .category(Category::Formats)
     }
 
@@ -141,6 +157,8 @@ fn from_tsv(
     let noheaders = call.has_flag("noheaders");
     let flexible = call.has_flag("flexible");
     let trim = trim_from_str(call.get_flag(engine_state, stack, "trim")?)?;
+    let schema = schema_from_value(call.get_flag(engine_state, stack, "schema")?)?;
+    let on_error = on_error_from_str(call.get_flag(engine_state, stack, "on-error")?)?;
 
     let config = DelimitedReaderConfig {
         separator: '\t',
@@ -151,6 +169,8 @@ fn from_tsv(
         flexible,
         no_infer,
         trim,
+        schema,
+        on_error,
     };
 
     from_delimited_data(config, input, name)
@@ -4,10 +4,17 @@ use nu_engine::CallExt;
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
-    Category, Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value,
+    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
+    SyntaxShape, Type, Value,
 };
 use std::io::Cursor;
 
+/// Selects a single sheet by its name or its zero-based position in the workbook.
+enum SheetSelector {
+    Name(String),
+    Index(usize),
+}
+
 #[derive(Clone)]
 pub struct FromXlsx;
 
@@ -26,6 +33,12 @@ impl Command for FromXlsx {
                 "Only convert specified sheets",
                 Some('s'),
             )
+            .named(
+                "sheet",
+                SyntaxShape::Any,
+                "Stream the rows of a single sheet, given by name or zero-based index",
+                None,
+            )
             .category(Category::Formats)
     }
 
@@ -50,7 +63,24 @@ impl Command for FromXlsx {
             vec![]
         };
 
-        from_xlsx(input, head, sel_sheets)
+        let sheet = match call.get_flag(engine_state, stack, "sheet")? {
+            Some(Value::String { val, .. }) => Some(SheetSelector::Name(val)),
+            Some(Value::Int { val, .. }) => Some(SheetSelector::Index(val as usize)),
+            Some(value) => {
+                return Err(ShellError::UnsupportedInput(
+                    "--sheet expects a sheet name or index".into(),
+                    "value originates from here".into(),
+                    head,
+                    value.expect_span(),
+                ))
+            }
+            None => None,
+        };
+
+        match sheet {
+            Some(sheet) => from_xlsx_sheet(input, head, sheet, engine_state.ctrlc.clone()),
+            None => from_xlsx(input, head, sel_sheets),
+        }
     }
 
     fn examples(&self) -> Vec<Example> {
@@ -65,6 +95,11 @@ impl Command for FromXlsx {
                 example: "open --raw test.xlsx | from xlsx -s [Spreadsheet1]",
                 result: None,
             },
+            Example {
+                description: "Stream the rows of a single sheet by name",
+                example: "open --raw test.xlsx | from xlsx --sheet Spreadsheet1",
+                result: None,
+            },
         ]
     }
 }
@@ -108,6 +143,99 @@ fn collect_binary(input: PipelineData, span: Span) -> Result<Vec<u8>, ShellError
     Ok(bytes)
 }
 
+fn row_to_record(row: &[DataType], head: Span) -> Value {
+    let mut row_output = IndexMap::new();
+    for (i, cell) in row.iter().enumerate() {
+        let value = match cell {
+            DataType::Empty => Value::nothing(head),
+            DataType::String(s) => Value::string(s, head),
+            DataType::Float(f) => Value::float(*f, head),
+            DataType::Int(i) => Value::int(*i, head),
+            DataType::Bool(b) => Value::boolean(*b, head),
+            _ => Value::nothing(head),
+        };
+
+        row_output.insert(format!("column{i}"), value);
+    }
+
+    let (cols, vals) = row_output
+        .into_iter()
+        .fold((vec![], vec![]), |mut acc, (k, v)| {
+            acc.0.push(k);
+            acc.1.push(v);
+            acc
+        });
+
+    Value::Record {
+        cols,
+        vals,
+        span: head,
+    }
+}
+
+fn load_xlsx(input: PipelineData, head: Span) -> Result<(Xlsx<Cursor<Vec<u8>>>, Span), ShellError> {
+    let span = input.span().unwrap_or(head);
+    let bytes = collect_binary(input, head)?;
+    let buf: Cursor<Vec<u8>> = Cursor::new(bytes);
+    let xlsx = Xlsx::<_>::new(buf).map_err(|_| {
+        ShellError::UnsupportedInput(
+            "Could not load XLSX file".to_string(),
+            "value originates from here".into(),
+            head,
+            span,
+        )
+    })?;
+
+    Ok((xlsx, span))
+}
+
+fn from_xlsx_sheet(
+    input: PipelineData,
+    head: Span,
+    sheet: SheetSelector,
+    ctrlc: Option<std::sync::Arc<std::sync::atomic::AtomicBool>>,
+) -> Result<PipelineData, ShellError> {
+    let (mut xlsx, span) = load_xlsx(input, head)?;
+
+    let sheet_name = match sheet {
+        SheetSelector::Name(name) => name,
+        SheetSelector::Index(index) => xlsx.sheet_names().get(index).cloned().ok_or_else(|| {
+            ShellError::UnsupportedInput(
+                format!("Sheet index {index} is out of range"),
+                "value originates from here".into(),
+                head,
+                span,
+            )
+        })?,
+    };
+
+    let current_sheet = xlsx
+        .worksheet_range(&sheet_name)
+        .ok_or_else(|| {
+            ShellError::UnsupportedInput(
+                format!("Sheet '{sheet_name}' not found"),
+                "value originates from here".into(),
+                head,
+                span,
+            )
+        })?
+        .map_err(|_| {
+            ShellError::UnsupportedInput(
+                format!("Could not load sheet '{sheet_name}'"),
+                "value originates from here".into(),
+                head,
+                span,
+            )
+        })?;
+
+    let rows: Vec<Value> = current_sheet
+        .rows()
+        .map(|row| row_to_record(row, head))
+        .collect();
+
+    Ok(rows.into_iter().into_pipeline_data(ctrlc))
+}
+
 fn from_xlsx(
     input: PipelineData,
     head: Span,
@@ -137,36 +265,7 @@ fn from_xlsx(
 
         if let Some(Ok(current_sheet)) = xlsx.worksheet_range(sheet_name) {
             for row in current_sheet.rows() {
-                let mut row_output = IndexMap::new();
-                for (i, cell) in row.iter().enumerate() {
-                    let value = match cell {
-                        DataType::Empty => Value::nothing(head),
-                        DataType::String(s) => Value::string(s, head),
-                        DataType::Float(f) => Value::float(*f, head),
-                        DataType::Int(i) => Value::int(*i, head),
-                        DataType::Bool(b) => Value::boolean(*b, head),
-                        _ => Value::nothing(head),
-                    };
-
-                    row_output.insert(format!("column{i}"), value);
-                }
-
-                let (cols, vals) =
-                    row_output
-                        .into_iter()
-                        .fold((vec![], vec![]), |mut acc, (k, v)| {
-                            acc.0.push(k);
-                            acc.1.push(v);
-                            acc
-                        });
-
-                let record = Value::Record {
-                    cols,
-                    vals,
-                    span: head,
-                };
-
-                sheet_output.push(record);
+                sheet_output.push(row_to_record(row, head));
             }
 
             dict.insert(
@@ -1,3 +1,5 @@
+use crate::date::parse_date_from_string;
+use base64::{engine::general_purpose::STANDARD, Engine};
 use itertools::Itertools;
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
@@ -26,6 +28,10 @@ impl Command for FromYaml {
         "Parse text as .yaml/.yml and create table."
     }
 
+    fn extra_usage(&self) -> &str {
+        "A `---`-separated multi-document input (as produced by tools like kubectl or helm) is parsed into a list with one entry per document."
+    }
+
     fn examples(&self) -> Vec<Example> {
         get_examples()
     }
@@ -175,6 +181,34 @@ fn convert_yaml_value_to_nu_value(
             Value::from(collected)
         }
         serde_yaml::Value::Null => Value::nothing(span),
+        serde_yaml::Value::Tagged(tagged) if tagged.tag == "!!binary" => {
+            let encoded = tagged
+                .value
+                .as_str()
+                .ok_or_else(|| err_not_compatible_number.clone())?;
+            let bytes = STANDARD.decode(encoded).map_err(|e| {
+                ShellError::UnsupportedInput(
+                    format!("Invalid base64 in !!binary tag: {e}"),
+                    "value originates from here".into(),
+                    span,
+                    val_span,
+                )
+            })?;
+            Value::Binary { val: bytes, span }
+        }
+        serde_yaml::Value::Tagged(tagged) if tagged.tag == "!!timestamp" => {
+            let text = tagged
+                .value
+                .as_str()
+                .ok_or_else(|| err_not_compatible_number.clone())?;
+            match parse_date_from_string(text, span) {
+                Ok(val) => Value::Date { val, span },
+                Err(val) => val,
+            }
+        }
+        serde_yaml::Value::Tagged(tagged) => {
+            convert_yaml_value_to_nu_value(&tagged.value, span, val_span)?
+        }
         x => unimplemented!("Unsupported YAML case: {:?}", x),
     })
 }
@@ -0,0 +1,170 @@
+use std::io::{Cursor, Read};
+
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
+    Type, Value,
+};
+
+#[derive(Clone)]
+pub struct FromZip;
+
+impl Command for FromZip {
+    fn name(&self) -> &str {
+        "from zip"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("from zip")
+            .input_output_types(vec![(Type::Binary, Type::Table(vec![]))])
+            .category(Category::Formats)
+    }
+
+    fn usage(&self) -> &str {
+        "Read a zip archive's entries into a table."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Each row is one entry: `path`, `size`, `mtime`, `mode` (the stored Unix
+permission bits, or 0 if the archive doesn't carry them), `type` (`file` or
+`directory`), and `data` (the entry's decompressed content, empty for
+directories). Pipe the result through `where` to select entries, then into
+`archive extract` to write them to disk."#
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        from_zip(input, head)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "List the contents of a zip archive",
+            example: "open --raw project.zip | from zip | select path size type",
+            result: None,
+        }]
+    }
+}
+
+fn collect_binary(input: PipelineData, span: Span) -> Result<Vec<u8>, ShellError> {
+    let mut bytes = vec![];
+
+    for value in input {
+        match value {
+            Value::Binary { val, .. } => bytes.extend_from_slice(&val),
+            other => {
+                return Err(ShellError::UnsupportedInput(
+                    "Expected binary from pipeline".to_string(),
+                    "value originates from here".into(),
+                    span,
+                    other.expect_span(),
+                ))
+            }
+        }
+    }
+
+    Ok(bytes)
+}
+
+fn from_zip(input: PipelineData, head: Span) -> Result<PipelineData, ShellError> {
+    let span = input.span().unwrap_or(head);
+    let bytes = collect_binary(input, head)?;
+
+    let reader_err = |e: zip::result::ZipError| {
+        ShellError::UnsupportedInput(
+            format!("Could not read zip archive: {e}"),
+            "value originates from here".into(),
+            head,
+            span,
+        )
+    };
+
+    let mut archive = zip::ZipArchive::new(Cursor::new(bytes)).map_err(reader_err)?;
+    let mut rows = vec![];
+
+    for i in 0..archive.len() {
+        let mut file = archive.by_index(i).map_err(reader_err)?;
+
+        let path = file.name().to_string();
+        let size = file.size();
+        let mtime = zip_time_to_date(file.last_modified(), head);
+        let mode = file.unix_mode().unwrap_or(0);
+        let is_dir = file.is_dir();
+
+        let mut data = vec![];
+        if !is_dir {
+            file.read_to_end(&mut data).map_err(|e| {
+                ShellError::UnsupportedInput(
+                    format!("Could not read zip entry '{path}': {e}"),
+                    "value originates from here".into(),
+                    head,
+                    span,
+                )
+            })?;
+        }
+
+        rows.push(Value::Record {
+            cols: vec![
+                "path".to_string(),
+                "size".to_string(),
+                "mtime".to_string(),
+                "mode".to_string(),
+                "type".to_string(),
+                "data".to_string(),
+            ],
+            vals: vec![
+                Value::string(path, head),
+                Value::Filesize {
+                    val: size as i64,
+                    span: head,
+                },
+                mtime,
+                Value::int(mode as i64, head),
+                Value::string(if is_dir { "directory" } else { "file" }, head),
+                Value::binary(data, head),
+            ],
+            span: head,
+        });
+    }
+
+    Ok(rows.into_iter().into_pipeline_data(None))
+}
+
+fn zip_time_to_date(time: zip::DateTime, span: Span) -> Value {
+    let date =
+        chrono::NaiveDate::from_ymd_opt(time.year() as i32, time.month() as u32, time.day() as u32)
+            .and_then(|date| {
+                date.and_hms_opt(
+                    time.hour() as u32,
+                    time.minute() as u32,
+                    time.second() as u32,
+                )
+            });
+
+    match date {
+        Some(naive) => Value::Date {
+            val: chrono::DateTime::<chrono::Utc>::from_utc(naive, chrono::Utc).into(),
+            span,
+        },
+        None => Value::nothing(span),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(FromZip {})
+    }
+}
@@ -0,0 +1,37 @@
+use nu_protocol::Config;
+
+/// Built-in MIME-subtype-or-extension to `from <name>` suffix mappings,
+/// used for the handful of types whose MIME subtype doesn't already match a
+/// `from` command name one-for-one (`json`, `csv`, `toml`, and so on do, and
+/// never need an entry here).
+const BUILTIN_CONVERSIONS: &[(&str, &str)] = &[
+    ("x-yaml", "yaml"),
+    ("x-msgpack", "msgpack"),
+    ("x-nuon", "nuon"),
+    ("vnd.api+json", "json"),
+    ("markdown", "md"),
+];
+
+/// Resolves the `from <name>` command that should handle a MIME subtype or
+/// bare file extension, for use by anything that produces tagged
+/// binary/string data (`open`, `http get`, and similar).
+///
+/// `$env.config.content_type_conversions` is consulted first so users can
+/// register their own mappings (e.g. `{geo: geojson}` to send `.geo` files
+/// through `from geojson`); the built-in table above is the fallback for
+/// the handful of MIME subtypes that don't already spell the format name.
+/// When nothing matches either, the subtype/extension is returned unchanged,
+/// which preserves the historical behavior of just trying `from <ext>`.
+pub fn decl_name_for_content_type(subtype_or_ext: &str, config: &Config) -> String {
+    if let Some(name) = config.content_type_conversions.get(subtype_or_ext) {
+        if let Ok(name) = name.as_string() {
+            return name;
+        }
+    }
+
+    BUILTIN_CONVERSIONS
+        .iter()
+        .find(|(key, _)| *key == subtype_or_ext)
+        .map(|(_, name)| name.to_string())
+        .unwrap_or_else(|| subtype_or_ext.to_string())
+}
@@ -1,5 +1,7 @@
 mod from;
+mod mime_dispatch;
 mod to;
 
 pub use from::*;
+pub use mime_dispatch::decl_name_for_content_type;
 pub use to::*;
@@ -0,0 +1,237 @@
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{Category, Example, PipelineData, ShellError, Signature, Span, Type, Value};
+
+#[derive(Clone)]
+pub struct ToBson;
+
+impl Command for ToBson {
+    fn name(&self) -> &str {
+        "to bson"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("to bson")
+            .input_output_types(vec![(Type::Any, Type::Binary)])
+            .category(Category::Formats)
+    }
+
+    fn usage(&self) -> &str {
+        "Convert structured data into .bson data."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Input must be a record (written as a single document) or a list of records (written as concatenated documents, the layout mongodump produces). Records with the special `$`-prefixed keys produced by `from bson` (`$object_id`, `$binary_subtype`/`$binary`, `$timestamp`/`$increment`, `$regex`/`$options`, `$javascript`/`$scope`) round-trip back into their original BSON type."
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        to_bson(input, head)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Convert a record to BSON binary data",
+            example: "{a: 1} | to bson",
+            result: None,
+        }]
+    }
+}
+
+fn to_bson(input: PipelineData, head: Span) -> Result<PipelineData, ShellError> {
+    let value = input.into_value(head);
+
+    let documents = match &value {
+        Value::List { vals, .. } => vals
+            .iter()
+            .map(|v| value_to_document(v, head))
+            .collect::<Result<Vec<_>, _>>()?,
+        Value::Record { .. } => vec![value_to_document(&value, head)?],
+        other => {
+            return Err(ShellError::UnsupportedInput(
+                "to bson expects a record or a list of records".into(),
+                "value originates from here".into(),
+                head,
+                other.expect_span(),
+            ))
+        }
+    };
+
+    let mut buffer = vec![];
+    for doc in documents {
+        doc.to_writer(&mut buffer)
+            .map_err(|e| encode_err(e, head))?;
+    }
+
+    Ok(PipelineData::Value(Value::binary(buffer, head), None))
+}
+
+fn value_to_document(value: &Value, span: Span) -> Result<bson::Document, ShellError> {
+    match value {
+        Value::Record { cols, vals, .. } => match value_to_bson_document(cols, vals, span)? {
+            bson::Bson::Document(doc) => Ok(doc),
+            _ => unreachable!("a record always converts to a BSON document"),
+        },
+        Value::LazyRecord { val, .. } => value_to_document(&val.collect()?, span),
+        other => Err(ShellError::UnsupportedInput(
+            "to bson expects a record or a list of records".into(),
+            "value originates from here".into(),
+            span,
+            other.expect_span(),
+        )),
+    }
+}
+
+fn value_to_bson(value: &Value, span: Span) -> Result<bson::Bson, ShellError> {
+    Ok(match value {
+        Value::Bool { val, .. } => bson::Bson::Boolean(*val),
+        Value::Int { val, .. } => bson::Bson::Int64(*val),
+        Value::Filesize { val, .. } => bson::Bson::Int64(*val),
+        Value::Duration { val, .. } => bson::Bson::Int64(*val),
+        Value::Float { val, .. } => bson::Bson::Double(*val),
+        Value::String { val, .. } => bson::Bson::String(val.clone()),
+        Value::Binary { val, .. } => bson::Bson::Binary(bson::Binary {
+            subtype: bson::spec::BinarySubtype::Generic,
+            bytes: val.clone(),
+        }),
+        Value::Date { val, .. } => {
+            bson::Bson::DateTime(bson::DateTime::from_chrono(val.with_timezone(&chrono::Utc)))
+        }
+        Value::Nothing { .. } => bson::Bson::Null,
+        Value::List { vals, .. } => bson::Bson::Array(
+            vals.iter()
+                .map(|v| value_to_bson(v, span))
+                .collect::<Result<Vec<_>, _>>()?,
+        ),
+        Value::Record { cols, vals, .. } => value_to_bson_document(cols, vals, span)?,
+        Value::LazyRecord { val, .. } => value_to_bson(&val.collect()?, span)?,
+        Value::Error { error } => return Err(error.clone()),
+        other => bson::Bson::String(other.into_string(",", &Default::default())),
+    })
+}
+
+/// Reconstructs the special BSON types that `from bson` represents as `$`-prefixed records,
+/// falling back to an ordinary document for everything else.
+fn value_to_bson_document(
+    cols: &[String],
+    vals: &[Value],
+    span: Span,
+) -> Result<bson::Bson, ShellError> {
+    if let ([col], [Value::String { val, .. }]) = (cols, vals) {
+        if col == "$object_id" {
+            return bson::oid::ObjectId::parse_str(val)
+                .map(bson::Bson::ObjectId)
+                .map_err(|e| ShellError::CantConvert {
+                    to_type: "BSON ObjectId".into(),
+                    from_type: "string".into(),
+                    span,
+                    help: Some(e.to_string()),
+                });
+        }
+    }
+
+    if let (Some(subtype_idx), Some(bytes_idx)) = (
+        cols.iter().position(|c| c == "$binary_subtype"),
+        cols.iter().position(|c| c == "$binary"),
+    ) {
+        if let (Value::String { val: subtype, .. }, Value::Binary { val: bytes, .. }) =
+            (&vals[subtype_idx], &vals[bytes_idx])
+        {
+            return Ok(bson::Bson::Binary(bson::Binary {
+                subtype: binary_subtype_from_name(subtype),
+                bytes: bytes.clone(),
+            }));
+        }
+    }
+
+    if let (Some(time_idx), Some(inc_idx)) = (
+        cols.iter().position(|c| c == "$timestamp"),
+        cols.iter().position(|c| c == "$increment"),
+    ) {
+        if let (Value::Int { val: time, .. }, Value::Int { val: increment, .. }) =
+            (&vals[time_idx], &vals[inc_idx])
+        {
+            return Ok(bson::Bson::Timestamp(bson::Timestamp {
+                time: *time as u32,
+                increment: *increment as u32,
+            }));
+        }
+    }
+
+    if let (Some(pattern_idx), Some(options_idx)) = (
+        cols.iter().position(|c| c == "$regex"),
+        cols.iter().position(|c| c == "$options"),
+    ) {
+        if let (Value::String { val: pattern, .. }, Value::String { val: options, .. }) =
+            (&vals[pattern_idx], &vals[options_idx])
+        {
+            return Ok(bson::Bson::RegularExpression(bson::Regex {
+                pattern: pattern.clone(),
+                options: options.clone(),
+            }));
+        }
+    }
+
+    if let Some(code_idx) = cols.iter().position(|c| c == "$javascript") {
+        if let Value::String { val: code, .. } = &vals[code_idx] {
+            return Ok(match cols.iter().position(|c| c == "$scope") {
+                Some(scope_idx) => match value_to_bson(&vals[scope_idx], span)? {
+                    bson::Bson::Document(scope) => {
+                        bson::Bson::JavaScriptCodeWithScope(bson::JavaScriptCodeWithScope {
+                            code: code.clone(),
+                            scope,
+                        })
+                    }
+                    _ => bson::Bson::JavaScriptCode(code.clone()),
+                },
+                None => bson::Bson::JavaScriptCode(code.clone()),
+            });
+        }
+    }
+
+    let mut doc = bson::Document::new();
+    for (col, val) in cols.iter().zip(vals.iter()) {
+        doc.insert(col.clone(), value_to_bson(val, span)?);
+    }
+    Ok(bson::Bson::Document(doc))
+}
+
+fn binary_subtype_from_name(name: &str) -> bson::spec::BinarySubtype {
+    match name {
+        "function" => bson::spec::BinarySubtype::Function,
+        "binary_old" => bson::spec::BinarySubtype::BinaryOld,
+        "uuid_old" => bson::spec::BinarySubtype::UuidOld,
+        "uuid" => bson::spec::BinarySubtype::Uuid,
+        "md5" => bson::spec::BinarySubtype::Md5,
+        "encrypted" => bson::spec::BinarySubtype::Encrypted,
+        _ => bson::spec::BinarySubtype::Generic,
+    }
+}
+
+fn encode_err(error: bson::ser::Error, head: Span) -> ShellError {
+    ShellError::GenericError(
+        format!("Could not encode BSON data: {error}"),
+        "could not convert to bson".into(),
+        Some(head),
+        None,
+        Vec::new(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(ToBson {})
+    }
+}
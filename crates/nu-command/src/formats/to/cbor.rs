@@ -0,0 +1,170 @@
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{Category, Example, PipelineData, ShellError, Signature, Span, Type, Value};
+
+#[derive(Clone)]
+pub struct ToCbor;
+
+impl Command for ToCbor {
+    fn name(&self) -> &str {
+        "to cbor"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("to cbor")
+            .input_output_types(vec![(Type::Any, Type::Binary)])
+            .switch(
+                "seq",
+                "encode each element of a list as its own top-level CBOR item, concatenated together (a CBOR sequence, RFC 8742)",
+                Some('s'),
+            )
+            .category(Category::Formats)
+    }
+
+    fn usage(&self) -> &str {
+        "Convert structured data into CBOR binary data."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Dates are encoded using tag 0 (RFC 3339 date/time string) so they round-trip through `from cbor` exactly. A record with a single `$bignum` key (as produced by `from cbor` for tag 2/3 values) is encoded back as a bignum."
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let seq = call.has_flag("seq");
+        to_cbor(input, head, seq)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Convert a record to CBOR binary data",
+                example: "{a: 1} | to cbor",
+                result: None,
+            },
+            Example {
+                description: "Encode a list as a CBOR sequence",
+                example: "[{a: 1} {a: 2}] | to cbor --seq",
+                result: None,
+            },
+        ]
+    }
+}
+
+fn value_to_cbor(value: &Value, span: Span) -> Result<ciborium::value::Value, ShellError> {
+    use ciborium::value::Value as Cbor;
+
+    Ok(match value {
+        Value::Bool { val, .. } => Cbor::Bool(*val),
+        Value::Int { val, .. } => Cbor::Integer((*val).into()),
+        Value::Filesize { val, .. } => Cbor::Integer((*val).into()),
+        Value::Duration { val, .. } => Cbor::Integer((*val).into()),
+        Value::Float { val, .. } => Cbor::Float(*val),
+        Value::String { val, .. } => Cbor::Text(val.clone()),
+        Value::Binary { val, .. } => Cbor::Bytes(val.clone()),
+        Value::Nothing { .. } => Cbor::Null,
+        Value::Date { val, .. } => Cbor::Tag(0, Box::new(Cbor::Text(val.to_rfc3339()))),
+        Value::List { vals, .. } => {
+            let items = vals
+                .iter()
+                .map(|v| value_to_cbor(v, span))
+                .collect::<Result<Vec<_>, _>>()?;
+            Cbor::Array(items)
+        }
+        Value::Record { cols, vals, .. } => {
+            if let ([col], [Value::String { val: hex, .. }]) = (cols.as_slice(), vals.as_slice()) {
+                if col == "$bignum" {
+                    return bignum_to_cbor(hex, span);
+                }
+            }
+            let entries = cols
+                .iter()
+                .zip(vals.iter())
+                .map(|(k, v)| Ok((Cbor::Text(k.clone()), value_to_cbor(v, span)?)))
+                .collect::<Result<Vec<_>, ShellError>>()?;
+            Cbor::Map(entries)
+        }
+        Value::LazyRecord { val, .. } => value_to_cbor(&val.collect()?, span)?,
+        Value::Error { error } => return Err(error.clone()),
+        other => Cbor::Text(other.into_string(",", &Default::default())),
+    })
+}
+
+fn bignum_to_cbor(hex: &str, span: Span) -> Result<ciborium::value::Value, ShellError> {
+    let (negative, digits) = match hex.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, hex),
+    };
+    let digits = digits.strip_prefix("0x").unwrap_or(digits);
+
+    let bytes = (0..digits.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&digits[i..(i + 2).min(digits.len())], 16))
+        .collect::<Result<Vec<u8>, _>>()
+        .map_err(|e| ShellError::CantConvert {
+            to_type: "CBOR bignum".into(),
+            from_type: "string".into(),
+            span,
+            help: Some(e.to_string()),
+        })?;
+
+    Ok(ciborium::value::Value::Tag(
+        if negative { 3 } else { 2 },
+        Box::new(ciborium::value::Value::Bytes(bytes)),
+    ))
+}
+
+fn to_cbor(input: PipelineData, head: Span, seq: bool) -> Result<PipelineData, ShellError> {
+    let value = input.into_value(head);
+
+    let mut buffer = vec![];
+
+    if seq {
+        let Value::List { vals, .. } = &value else {
+            return Err(ShellError::UnsupportedInput(
+                "to cbor --seq expects a list input".into(),
+                "value originates from here".into(),
+                head,
+                value.expect_span(),
+            ));
+        };
+        for v in vals {
+            let cbor_value = value_to_cbor(v, head)?;
+            ciborium::ser::into_writer(&cbor_value, &mut buffer)
+                .map_err(|e| encode_err(e, head))?;
+        }
+    } else {
+        let cbor_value = value_to_cbor(&value, head)?;
+        ciborium::ser::into_writer(&cbor_value, &mut buffer).map_err(|e| encode_err(e, head))?;
+    }
+
+    Ok(PipelineData::Value(Value::binary(buffer, head), None))
+}
+
+fn encode_err(error: ciborium::ser::Error<std::io::Error>, head: Span) -> ShellError {
+    ShellError::GenericError(
+        format!("Could not encode CBOR data: {error}"),
+        "could not convert to cbor".into(),
+        Some(head),
+        None,
+        Vec::new(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(ToCbor {})
+    }
+}
@@ -1,10 +1,11 @@
-use crate::formats::to::delimited::to_delimited_data;
+use crate::formats::to::delimited::{merge_descriptors, to_delimited_data};
+use csv::WriterBuilder;
 use nu_engine::CallExt;
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
-    Category, Config, Example, PipelineData, ShellError, Signature, Span, Spanned, SyntaxShape,
-    Type, Value,
+    Category, Config, Example, ListStream, PipelineData, RawStream, ShellError, Signature, Span,
+    Spanned, SyntaxShape, Type, Value,
 };
 
 #[derive(Clone)]
@@ -27,6 +28,12 @@ impl Command for ToCsv {
                 "a character to separate columns, defaults to ','",
                 Some('s'),
             )
+            .named(
+                "columns",
+                SyntaxShape::List(Box::new(SyntaxShape::String)),
+                "the columns to write, and their order; defaults to the columns of the first row",
+                None,
+            )
             .switch(
                 "noheaders",
                 "do not output the columns names as the first row",
@@ -52,6 +59,11 @@ impl Command for ToCsv {
                 example: "{a: 1 b: 2} | to csv",
                 result: Some(Value::test_string("a,b\n1,2\n")),
             },
+            Example {
+                description: "Fix the column order instead of inferring it from the first row",
+                example: "[[foo bar]; [1 2]] | to csv --columns [bar foo]",
+                result: Some(Value::test_string("bar,foo\n2,1\n")),
+            },
         ]
     }
 
@@ -59,6 +71,16 @@ impl Command for ToCsv {
         "Convert table into .csv text ."
     }
 
+    fn extra_usage(&self) -> &str {
+        r#"When the input is a list stream (e.g. the output of `generate`), rows are
+written out one at a time as they arrive instead of being collected into
+memory first, so `generate ... | to csv | save big.csv` runs in constant
+memory. The header row is taken from `--columns` if given, otherwise from
+the columns of the first row; later rows are matched into that fixed
+column set by name, with missing fields left empty and extra fields
+dropped."#
+    }
+
     fn run(
         &self,
         engine_state: &EngineState,
@@ -69,22 +91,17 @@ impl Command for ToCsv {
         let head = call.head;
         let noheaders = call.has_flag("noheaders");
         let separator: Option<Spanned<String>> = call.get_flag(engine_state, stack, "separator")?;
+        let columns: Option<Vec<String>> = call.get_flag(engine_state, stack, "columns")?;
         let config = engine_state.get_config();
-        to_csv(input, noheaders, separator, head, config)
+        to_csv(input, noheaders, separator, columns, head, config)
     }
 }
 
-fn to_csv(
-    input: PipelineData,
-    noheaders: bool,
-    separator: Option<Spanned<String>>,
-    head: Span,
-    config: &Config,
-) -> Result<PipelineData, ShellError> {
-    let sep = match separator {
+fn parse_separator(separator: Option<Spanned<String>>) -> Result<char, ShellError> {
+    match separator {
         Some(Spanned { item: s, span, .. }) => {
             if s == r"\t" {
-                '\t'
+                Ok('\t')
             } else {
                 let vec_s: Vec<char> = s.chars().collect();
                 if vec_s.len() != 1 {
@@ -94,13 +111,170 @@ fn to_csv(
                         span,
                     });
                 };
-                vec_s[0]
+                Ok(vec_s[0])
             }
         }
-        _ => ',',
-    };
+        None => Ok(','),
+    }
+}
 
-    to_delimited_data(noheaders, sep, "CSV", input, head, config)
+fn to_csv(
+    input: PipelineData,
+    noheaders: bool,
+    separator: Option<Spanned<String>>,
+    columns: Option<Vec<String>>,
+    head: Span,
+    config: &Config,
+) -> Result<PipelineData, ShellError> {
+    let sep = parse_separator(separator)?;
+
+    match input {
+        PipelineData::ListStream(stream, ..) => {
+            to_csv_stream(stream, noheaders, sep, columns, head, config.clone())
+        }
+        // `--columns` fixes the header for collected input too, so it goes through the row writer.
+        PipelineData::Value(value, ..) if columns.is_some() => {
+            let rows = match value {
+                Value::List { vals, .. } => vals,
+                other => vec![other],
+            };
+            let stream = ListStream::from_stream(rows.into_iter(), None);
+            to_csv_stream(stream, noheaders, sep, columns, head, config.clone())
+        }
+        input => to_delimited_data(noheaders, sep, "CSV", input, head, config),
+    }
+}
+
+/// Serializes a [`ListStream`] of records into CSV one row at a time,
+/// instead of `to_delimited_data`'s collect-everything-then-write approach.
+/// The header row is fixed from `columns` (if given) or from the first
+/// row's columns, so later rows are matched into that set by name rather
+/// than merged, which would require seeing every row up front.
+fn to_csv_stream(
+    stream: ListStream,
+    noheaders: bool,
+    separator: char,
+    columns: Option<Vec<String>>,
+    head: Span,
+    config: Config,
+) -> Result<PipelineData, ShellError> {
+    let ctrlc = stream.ctrlc.clone();
+    Ok(PipelineData::ExternalStream {
+        stdout: Some(RawStream::new(
+            Box::new(CsvRowStream {
+                stream,
+                separator,
+                noheaders,
+                columns,
+                head,
+                config,
+                wrote_header: false,
+            }),
+            ctrlc,
+            head,
+            None,
+        )),
+        stderr: None,
+        exit_code: None,
+        span: head,
+        metadata: None,
+        trim_end_newline: false,
+    })
+}
+
+struct CsvRowStream {
+    stream: ListStream,
+    separator: char,
+    noheaders: bool,
+    columns: Option<Vec<String>>,
+    head: Span,
+    config: Config,
+    wrote_header: bool,
+}
+
+impl Iterator for CsvRowStream {
+    type Item = Result<Vec<u8>, ShellError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = self.stream.next()?;
+
+        if self.columns.is_none() {
+            self.columns = Some(match &value {
+                Value::Record { cols, .. } => cols.clone(),
+                _ => merge_descriptors(std::slice::from_ref(&value)),
+            });
+        }
+        let columns = self.columns.clone().unwrap_or_default();
+
+        let mut wtr = WriterBuilder::new()
+            .delimiter(self.separator as u8)
+            .from_writer(vec![]);
+
+        if !self.wrote_header && !self.noheaders && !columns.is_empty() {
+            if let Err(err) = wtr.write_record(columns.iter().map(|c| &c[..])) {
+                return Some(Err(make_conversion_error(&err, self.head)));
+            }
+        }
+        self.wrote_header = true;
+
+        let row = match &value {
+            Value::Record { .. } => columns
+                .iter()
+                .map(|col| match value.to_owned().get_data_by_key(col) {
+                    Some(v) => to_field_string(&v, &self.config, self.head),
+                    None => Ok(String::new()),
+                })
+                .collect::<Result<Vec<_>, _>>(),
+            Value::Error { error } => Err(error.clone()),
+            other => to_field_string(other, &self.config, self.head).map(|s| vec![s]),
+        };
+
+        let row = match row {
+            Ok(row) => row,
+            Err(err) => return Some(Err(err)),
+        };
+
+        if let Err(err) = wtr.write_record(&row) {
+            return Some(Err(make_conversion_error(&err, self.head)));
+        }
+
+        match wtr.into_inner() {
+            Ok(bytes) => Some(Ok(bytes)),
+            Err(err) => Some(Err(make_conversion_error(&err.into_error(), self.head))),
+        }
+    }
+}
+
+fn to_field_string(value: &Value, config: &Config, head: Span) -> Result<String, ShellError> {
+    match value {
+        Value::String { .. }
+        | Value::Bool { .. }
+        | Value::Int { .. }
+        | Value::Duration { .. }
+        | Value::Binary { .. }
+        | Value::CustomValue { .. }
+        | Value::Filesize { .. }
+        | Value::CellPath { .. }
+        | Value::Float { .. } => Ok(value.clone().into_abbreviated_string(config)),
+        Value::Date { val, .. } => Ok(val.to_string()),
+        Value::Nothing { .. } => Ok(String::new()),
+        Value::Error { error } => Err(error.clone()),
+        _ => Err(ShellError::UnsupportedInput(
+            "Unexpected type".to_string(),
+            format!("input type: {:?}", value.get_type()),
+            head,
+            value.expect_span(),
+        )),
+    }
+}
+
+fn make_conversion_error(err: &impl std::error::Error, span: Span) -> ShellError {
+    ShellError::CantConvert {
+        to_type: "CSV".into(),
+        from_type: "row".into(),
+        span,
+        help: Some(err.to_string()),
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,266 @@
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct ToDot;
+
+impl Command for ToDot {
+    fn name(&self) -> &str {
+        "to dot"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("to dot")
+            .input_output_types(vec![(Type::Record(vec![]), Type::String)])
+            .category(Category::Formats)
+    }
+
+    fn usage(&self) -> &str {
+        "Convert a record of nodes and edges into Graphviz DOT text."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Expects the shape produced by `from dot`: `directed` and `strict` booleans,
+a `name` string, an `attributes` record of graph-level attributes, and
+`nodes`/`edges` tables whose non-`id`/`from`/`to` columns become that row's
+attribute list. Missing fields fall back to an empty/false default rather
+than erroring, so a hand-built record only needs the fields it cares
+about."#
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        to_dot(input, head)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Render a record of nodes and edges as a digraph",
+            example:
+                "{directed: true, nodes: [[id]; [a] [b]], edges: [[from, to]; [a, b]]} | to dot",
+            result: Some(Value::test_string("digraph {\n  a;\n  b;\n  a -> b;\n}\n")),
+        }]
+    }
+}
+
+fn to_dot(input: PipelineData, head: Span) -> Result<PipelineData, ShellError> {
+    let value = input.into_value(head);
+
+    let Value::Record { cols, vals, .. } = &value else {
+        return Err(ShellError::UnsupportedInput(
+            format!("{:?} is not valid top-level DOT input", value.get_type()),
+            "value originates from here".into(),
+            head,
+            value.expect_span(),
+        ));
+    };
+
+    let field = |name: &str| cols.iter().position(|c| c == name).map(|i| &vals[i]);
+
+    let directed = matches!(field("directed"), Some(Value::Bool { val: true, .. }));
+    let strict = matches!(field("strict"), Some(Value::Bool { val: true, .. }));
+    let name = match field("name") {
+        Some(Value::String { val, .. }) if !val.is_empty() => format!("{val} "),
+        _ => String::new(),
+    };
+
+    let mut output = String::new();
+    if strict {
+        output.push_str("strict ");
+    }
+    output.push_str(if directed { "digraph " } else { "graph " });
+    output.push_str(&name);
+    output.push_str("{\n");
+
+    if let Some(Value::Record {
+        cols: attr_cols,
+        vals: attr_vals,
+        ..
+    }) = field("attributes")
+    {
+        for (key, val) in attr_cols.iter().zip(attr_vals.iter()) {
+            output.push_str("  ");
+            output.push_str(key);
+            output.push('=');
+            output.push_str(&dot_quote(&dot_value_to_string(val, head)?));
+            output.push_str(";\n");
+        }
+    }
+
+    if let Some(Value::List { vals, .. }) = field("nodes") {
+        for node in vals {
+            output.push_str("  ");
+            output.push_str(&render_row(node, "id", &[], head)?);
+            output.push_str(";\n");
+        }
+    }
+
+    if let Some(Value::List { vals, .. }) = field("edges") {
+        let op = if directed { "->" } else { "--" };
+        for edge in vals {
+            output.push_str("  ");
+            output.push_str(&render_edge(edge, op, head)?);
+            output.push_str(";\n");
+        }
+    }
+
+    output.push_str("}\n");
+
+    Ok(Value::string(output, head).into_pipeline_data())
+}
+
+fn render_row(
+    value: &Value,
+    id_col: &str,
+    skip: &[&str],
+    head: Span,
+) -> Result<String, ShellError> {
+    let Value::Record { cols, vals, .. } = value else {
+        return Err(ShellError::UnsupportedInput(
+            format!("{:?} is not a valid DOT node", value.get_type()),
+            "value originates from here".into(),
+            head,
+            value.expect_span(),
+        ));
+    };
+
+    let id = cols
+        .iter()
+        .position(|c| c == id_col)
+        .map(|i| &vals[i])
+        .ok_or_else(|| {
+            ShellError::UnsupportedInput(
+                format!("node is missing its `{id_col}` column"),
+                "value originates from here".into(),
+                head,
+                value.expect_span(),
+            )
+        })?;
+
+    let mut out = dot_quote(&dot_value_to_string(id, head)?);
+
+    let attrs = render_attrs(cols, vals, id_col, skip, head)?;
+    if !attrs.is_empty() {
+        out.push(' ');
+        out.push_str(&attrs);
+    }
+
+    Ok(out)
+}
+
+fn render_edge(value: &Value, op: &str, head: Span) -> Result<String, ShellError> {
+    let Value::Record { cols, vals, .. } = value else {
+        return Err(ShellError::UnsupportedInput(
+            format!("{:?} is not a valid DOT edge", value.get_type()),
+            "value originates from here".into(),
+            head,
+            value.expect_span(),
+        ));
+    };
+
+    let endpoint = |name: &str| {
+        cols.iter()
+            .position(|c| c == name)
+            .map(|i| &vals[i])
+            .ok_or_else(|| {
+                ShellError::UnsupportedInput(
+                    format!("edge is missing its `{name}` column"),
+                    "value originates from here".into(),
+                    head,
+                    value.expect_span(),
+                )
+            })
+    };
+
+    let from = dot_quote(&dot_value_to_string(endpoint("from")?, head)?);
+    let to = dot_quote(&dot_value_to_string(endpoint("to")?, head)?);
+
+    let mut out = format!("{from} {op} {to}");
+    let attrs = render_attrs(cols, vals, "from", &["to"], head)?;
+    if !attrs.is_empty() {
+        out.push(' ');
+        out.push_str(&attrs);
+    }
+
+    Ok(out)
+}
+
+fn render_attrs(
+    cols: &[String],
+    vals: &[Value],
+    id_col: &str,
+    skip: &[&str],
+    head: Span,
+) -> Result<String, ShellError> {
+    let mut entries = vec![];
+
+    for (col, val) in cols.iter().zip(vals.iter()) {
+        if col == id_col || skip.contains(&col.as_str()) {
+            continue;
+        }
+        entries.push(format!(
+            "{col}={}",
+            dot_quote(&dot_value_to_string(val, head)?)
+        ));
+    }
+
+    if entries.is_empty() {
+        return Ok(String::new());
+    }
+
+    Ok(format!("[{}]", entries.join(", ")))
+}
+
+fn dot_value_to_string(value: &Value, head: Span) -> Result<String, ShellError> {
+    match value {
+        Value::String { val, .. } => Ok(val.clone()),
+        Value::Int { val, .. } => Ok(val.to_string()),
+        Value::Float { val, .. } => Ok(val.to_string()),
+        Value::Bool { val, .. } => Ok(val.to_string()),
+        Value::Nothing { .. } => Ok(String::new()),
+        Value::Error { error } => Err(error.clone()),
+        other => Err(ShellError::UnsupportedInput(
+            format!("{:?} cannot be written as a DOT value", other.get_type()),
+            "value originates from here".into(),
+            head,
+            other.expect_span(),
+        )),
+    }
+}
+
+/// Quotes a value if it isn't already a bare identifier, so labels containing
+/// spaces or punctuation still round-trip through `from dot`.
+fn dot_quote(s: &str) -> String {
+    let mut chars = s.chars();
+    let is_bare = chars
+        .next()
+        .is_some_and(|first| first.is_ascii_alphabetic() || first == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+
+    if is_bare {
+        s.to_string()
+    } else {
+        format!("\"{}\"", s.replace('"', "\\\""))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(ToDot {})
+    }
+}
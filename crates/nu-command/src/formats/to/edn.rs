@@ -0,0 +1,161 @@
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct ToEdn;
+
+impl Command for ToEdn {
+    fn name(&self) -> &str {
+        "to edn"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("to edn")
+            .input_output_types(vec![(Type::Any, Type::String)])
+            .category(Category::Formats)
+    }
+
+    fn usage(&self) -> &str {
+        "Convert structured data into EDN (extensible data notation)."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"A string starting with `:` is written out as a keyword rather than a quoted
+string, mirroring `from edn`. A record with exactly the columns `tag` and
+`value` (in that order) is written as a tagged literal (`#tag value`), and a
+record with the single column `edn-set` is written as a set (`#{...}`). Any
+other record is written as a map with its column names turned into
+keywords."#
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let value = input.into_value(head);
+        let mut output = String::new();
+        write_value(&mut output, &value, head)?;
+        Ok(Value::string(output, head).into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Converts a record into an EDN map",
+                example: "{a: 1 b: 2} | to edn",
+                result: Some(Value::test_string("{:a 1 :b 2}")),
+            },
+            Example {
+                description: "A leading colon marks a string as a keyword",
+                example: "':foo' | to edn",
+                result: Some(Value::test_string(":foo")),
+            },
+        ]
+    }
+}
+
+fn write_value(output: &mut String, value: &Value, head: Span) -> Result<(), ShellError> {
+    match value {
+        Value::Nothing { .. } => output.push_str("nil"),
+        Value::Bool { val, .. } => output.push_str(if *val { "true" } else { "false" }),
+        Value::Int { val, .. } => output.push_str(&val.to_string()),
+        Value::Float { val, .. } => output.push_str(&format_float(*val)),
+        Value::String { val, .. } if val.starts_with(':') => output.push_str(val),
+        Value::String { val, .. } => write_string(output, val),
+        Value::List { vals, .. } => {
+            output.push('[');
+            for (i, val) in vals.iter().enumerate() {
+                if i > 0 {
+                    output.push(' ');
+                }
+                write_value(output, val, head)?;
+            }
+            output.push(']');
+        }
+        Value::Record { cols, vals, .. } if cols.as_slice() == ["edn-set"] => {
+            output.push_str("#{");
+            if let Value::List { vals, .. } = &vals[0] {
+                for (i, val) in vals.iter().enumerate() {
+                    if i > 0 {
+                        output.push(' ');
+                    }
+                    write_value(output, val, head)?;
+                }
+            }
+            output.push('}');
+        }
+        Value::Record { cols, vals, .. } if cols.as_slice() == ["tag", "value"] => {
+            let tag = vals[0].as_string().unwrap_or_default();
+            output.push('#');
+            output.push_str(&tag);
+            output.push(' ');
+            write_value(output, &vals[1], head)?;
+        }
+        Value::Record { cols, vals, .. } => {
+            output.push('{');
+            for (i, (col, val)) in cols.iter().zip(vals.iter()).enumerate() {
+                if i > 0 {
+                    output.push(' ');
+                }
+                output.push(':');
+                output.push_str(col);
+                output.push(' ');
+                write_value(output, val, head)?;
+            }
+            output.push('}');
+        }
+        Value::Error { error } => return Err(error.clone()),
+        other => {
+            return Err(ShellError::UnsupportedInput(
+                format!("{:?} cannot be written as EDN", other.get_type()),
+                "value originates from here".into(),
+                head,
+                other.expect_span(),
+            ))
+        }
+    }
+
+    Ok(())
+}
+
+fn write_string(output: &mut String, val: &str) {
+    output.push('"');
+    for c in val.chars() {
+        match c {
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            '\n' => output.push_str("\\n"),
+            '\t' => output.push_str("\\t"),
+            '\r' => output.push_str("\\r"),
+            c => output.push(c),
+        }
+    }
+    output.push('"');
+}
+
+fn format_float(val: f64) -> String {
+    if val.fract() == 0.0 && val.is_finite() {
+        format!("{val:.1}")
+    } else {
+        val.to_string()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(ToEdn {})
+    }
+}
@@ -5,7 +5,7 @@ use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
     Category, Config, DataSource, Example, IntoPipelineData, PipelineData, PipelineMetadata,
-    ShellError, Signature, Spanned, SyntaxShape, Type, Value,
+    ShellError, Signature, Span, Spanned, SyntaxShape, Type, Value,
 };
 use rust_embed::RustEmbed;
 use serde::{Deserialize, Serialize};
@@ -114,6 +114,17 @@ impl Command for ToHtml {
                 "produce a color table of all available themes",
                 Some('l'),
             )
+            .named(
+                "template",
+                SyntaxShape::Filepath,
+                "render through a handlebars-style template file instead of the built-in layout",
+                None,
+            )
+            .switch(
+                "theme-record",
+                "return the resolved theme as a record instead of rendering any HTML",
+                None,
+            )
             .category(Category::Formats)
     }
 
@@ -140,6 +151,11 @@ impl Command for ToHtml {
                     r#"<html><style>body { background-color:black;color:white; }</style><body><table><thead><tr><th>foo</th><th>bar</th></tr></thead><tbody><tr><td>1</td><td>2</td></tr></tbody></table></body></html>"#,
                 )),
             },
+            Example {
+                description: "Render a report page from a template file, once per input row",
+                example: "open report.tpl.html | save -f /dev/null; ls | to html --template report.tpl.html",
+                result: None,
+            },
         ]
     }
 
@@ -148,7 +164,16 @@ impl Command for ToHtml {
     }
 
     fn extra_usage(&self) -> &str {
-        "Screenshots of the themes can be browsed here: https://github.com/mbadolato/iTerm2-Color-Schemes."
+        r#"Screenshots of the themes can be browsed here: https://github.com/mbadolato/iTerm2-Color-Schemes.
+
+With `--template`, the input rows become the data context for a small
+handlebars-style template instead of the built-in table layout: `{{column}}`
+substitutes a column from the current row, `{{#each rows}}...{{/each}}`
+repeats its body once per input row, and `{{theme.foreground}}` (or any
+other column of `to html --theme-record`'s output) reaches into the resolved theme.
+Nested `{{#each}}` blocks and helpers/conditionals beyond that are not
+supported. `--theme-record` sidesteps HTML entirely and returns the
+resolved theme as a plain record, for scripts that just want the colors."#
     }
 
     fn run(
@@ -243,10 +268,52 @@ fn to_html(
     let dark = call.has_flag("dark");
     let partial = call.has_flag("partial");
     let list = call.has_flag("list");
+    let theme_record = call.has_flag("theme-record");
     let theme: Option<Spanned<String>> = call.get_flag(engine_state, stack, "theme")?;
+    let template: Option<Spanned<String>> = call.get_flag(engine_state, stack, "template")?;
     let config = engine_state.get_config();
 
+    if theme_record {
+        let color_hm = get_theme_from_asset_file(dark, &theme).map_err(|_| {
+            ShellError::GenericError(
+                "Error finding theme name".to_string(),
+                "Error finding theme name".to_string(),
+                theme.as_ref().map(|t| t.span),
+                None,
+                Vec::new(),
+            )
+        })?;
+        return Ok(theme_to_record(&color_hm, head).into_pipeline_data());
+    }
+
     let vec_of_values = input.into_iter().collect::<Vec<Value>>();
+
+    if let Some(template) = template {
+        let color_hm = get_theme_from_asset_file(dark, &theme).map_err(|_| {
+            ShellError::GenericError(
+                "Error finding theme name".to_string(),
+                "Error finding theme name".to_string(),
+                theme.as_ref().map(|t| t.span),
+                None,
+                Vec::new(),
+            )
+        })?;
+
+        let template_text = std::fs::read_to_string(&template.item).map_err(|e| {
+            ShellError::GenericError(
+                format!("Could not read template file: {e}"),
+                "template originates from here".into(),
+                Some(template.span),
+                None,
+                Vec::new(),
+            )
+        })?;
+
+        let rendered =
+            render_html_template(&template_text, &vec_of_values, &color_hm, config, head)?;
+        return Ok(Value::string(rendered, head).into_pipeline_data());
+    }
+
     let headers = merge_descriptors(&vec_of_values);
     let headers = Some(headers)
         .filter(|headers| !headers.is_empty() && (headers.len() > 1 || !headers[0].is_empty()));
@@ -720,6 +787,148 @@ fn run_regexes(hash: &HashMap<u32, (&'static str, String)>, contents: &str) -> S
     working_string
 }
 
+fn theme_to_record(color_hm: &HashMap<&'static str, String>, span: Span) -> Value {
+    let mut cols = vec![];
+    let mut vals = vec![];
+
+    for key in [
+        "black",
+        "red",
+        "green",
+        "yellow",
+        "blue",
+        "magenta",
+        "cyan",
+        "white",
+        "bold_black",
+        "bold_red",
+        "bold_green",
+        "bold_yellow",
+        "bold_blue",
+        "bold_magenta",
+        "bold_cyan",
+        "bold_white",
+        "background",
+        "foreground",
+    ] {
+        cols.push(key.to_string());
+        vals.push(Value::string(
+            color_hm.get(key).cloned().unwrap_or_default(),
+            span,
+        ));
+    }
+
+    Value::Record { cols, vals, span }
+}
+
+/// Renders a minimal handlebars-style template: `{{column}}` substitutes a
+/// column from the current row context, `{{#each rows}}...{{/each}}` repeats
+/// its body once per row in `rows`, and `{{theme.key}}` looks up a theme
+/// color. Nesting `{{#each}}` blocks, helpers and conditionals are not
+/// supported; see `extra_usage`.
+fn render_html_template(
+    template: &str,
+    rows: &[Value],
+    theme: &HashMap<&'static str, String>,
+    config: &Config,
+    head: Span,
+) -> Result<String, ShellError> {
+    const EACH_OPEN: &str = "{{#each rows}}";
+    const EACH_CLOSE: &str = "{{/each}}";
+
+    let mut output = String::new();
+    let mut rest = template;
+
+    loop {
+        match rest.find(EACH_OPEN) {
+            Some(start) => {
+                output.push_str(&substitute_template(
+                    &rest[..start],
+                    rows.first(),
+                    theme,
+                    config,
+                ));
+
+                let after_open = &rest[start + EACH_OPEN.len()..];
+                let close = after_open.find(EACH_CLOSE).ok_or_else(|| {
+                    ShellError::GenericError(
+                        "Unclosed {{#each rows}} block in template".into(),
+                        "template originates from here".into(),
+                        Some(head),
+                        None,
+                        Vec::new(),
+                    )
+                })?;
+
+                let body = &after_open[..close];
+                for row in rows {
+                    output.push_str(&substitute_template(body, Some(row), theme, config));
+                }
+
+                rest = &after_open[close + EACH_CLOSE.len()..];
+            }
+            None => {
+                output.push_str(&substitute_template(rest, rows.first(), theme, config));
+                break;
+            }
+        }
+    }
+
+    Ok(output)
+}
+
+fn substitute_template(
+    text: &str,
+    row: Option<&Value>,
+    theme: &HashMap<&'static str, String>,
+    config: &Config,
+) -> String {
+    let mut output = String::new();
+    let mut rest = text;
+
+    while let Some(start) = rest.find("{{") {
+        output.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+
+        match after.find("}}") {
+            Some(end) => {
+                let key = after[..end].trim();
+                output.push_str(&resolve_template_key(key, row, theme, config));
+                rest = &after[end + 2..];
+            }
+            None => {
+                output.push_str("{{");
+                rest = after;
+                break;
+            }
+        }
+    }
+
+    output.push_str(rest);
+    output
+}
+
+fn resolve_template_key(
+    key: &str,
+    row: Option<&Value>,
+    theme: &HashMap<&'static str, String>,
+    config: &Config,
+) -> String {
+    if let Some(color) = key.strip_prefix("theme.") {
+        return theme.get(color).cloned().unwrap_or_default();
+    }
+
+    match row {
+        Some(Value::Record { cols, vals, .. }) => cols
+            .iter()
+            .position(|c| c == key)
+            .map(|i| vals[i].into_string(", ", config))
+            .unwrap_or_default(),
+        Some(other) if key == "this" => other.into_string(", ", config),
+        _ => String::new(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
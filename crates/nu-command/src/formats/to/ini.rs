@@ -0,0 +1,142 @@
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct ToIni;
+
+impl Command for ToIni {
+    fn name(&self) -> &str {
+        "to ini"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("to ini")
+            .input_output_types(vec![(Type::Record(vec![]), Type::String)])
+            .category(Category::Formats)
+    }
+
+    fn usage(&self) -> &str {
+        "Convert record into .ini text."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Top-level keys whose value is a record become `[section]` headers, written
+in the order they appear in the record (round-tripping `from ini` doesn't
+reorder sections). Nested records under a section become dotted section
+names (`[a.b]`), and lists become a repeated `key=value` line per element."#
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Outputs an INI string representing the contents of this record",
+            example: "{foo: {a: 1 b: 2}} | to ini",
+            result: Some(Value::test_string("[foo]\na=1\nb=2\n")),
+        }]
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        to_ini(input, head)
+    }
+}
+
+fn to_ini(input: PipelineData, head: Span) -> Result<PipelineData, ShellError> {
+    let value = input.into_value(head);
+
+    let Value::Record { cols, vals, .. } = &value else {
+        return Err(ShellError::UnsupportedInput(
+            format!("{:?} is not valid top-level INI", value.get_type()),
+            "value originates from here".into(),
+            head,
+            value.expect_span(),
+        ));
+    };
+
+    let mut output = String::new();
+    write_section(&mut output, &[], cols, vals, head)?;
+
+    Ok(Value::string(output, head).into_pipeline_data())
+}
+
+/// Writes this section's own `key=value` lines, then recurses into any
+/// record-valued keys as further `[section]` (or `[section.sub]`) blocks.
+fn write_section(
+    output: &mut String,
+    path: &[String],
+    cols: &[String],
+    vals: &[Value],
+    head: Span,
+) -> Result<(), ShellError> {
+    let mut subsections = vec![];
+
+    for (col, val) in cols.iter().zip(vals.iter()) {
+        match val {
+            Value::Record { cols, vals, .. } => subsections.push((col, cols, vals)),
+            Value::List { vals, .. } => {
+                for val in vals {
+                    output.push_str(col);
+                    output.push('=');
+                    output.push_str(&ini_value_to_string(val, head)?);
+                    output.push('\n');
+                }
+            }
+            _ => {
+                output.push_str(col);
+                output.push('=');
+                output.push_str(&ini_value_to_string(val, head)?);
+                output.push('\n');
+            }
+        }
+    }
+
+    for (name, cols, vals) in subsections {
+        let mut section_path = path.to_vec();
+        section_path.push(name.clone());
+
+        output.push('[');
+        output.push_str(&section_path.join("."));
+        output.push_str("]\n");
+
+        write_section(output, &section_path, cols, vals, head)?;
+    }
+
+    Ok(())
+}
+
+fn ini_value_to_string(value: &Value, head: Span) -> Result<String, ShellError> {
+    match value {
+        Value::String { val, .. } => Ok(val.clone()),
+        Value::Int { val, .. } => Ok(val.to_string()),
+        Value::Float { val, .. } => Ok(val.to_string()),
+        Value::Bool { val, .. } => Ok(val.to_string()),
+        Value::Nothing { .. } => Ok(String::new()),
+        Value::Error { error } => Err(error.clone()),
+        other => Err(ShellError::UnsupportedInput(
+            format!("{:?} cannot be written as an INI value", other.get_type()),
+            "value originates from here".into(),
+            head,
+            other.expect_span(),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(ToIni {})
+    }
+}
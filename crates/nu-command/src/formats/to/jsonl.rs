@@ -0,0 +1,94 @@
+use super::value_to_json_value;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoInterruptiblePipelineData, IntoPipelineData, PipelineData, ShellError,
+    Signature, Span, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct ToJsonl;
+
+impl Command for ToJsonl {
+    fn name(&self) -> &str {
+        "to jsonl"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("to jsonl")
+            .input_output_types(vec![(Type::Any, Type::String)])
+            .category(Category::Formats)
+    }
+
+    fn usage(&self) -> &str {
+        "Converts table data into a stream of newline-delimited JSON lines (NDJSON)."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Unlike `to json`, each row is serialized and emitted on its own as soon as
+it's available, instead of collecting the whole table before writing
+anything out, so this can sit in a long-running streaming pipeline."#
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let input = input.try_expand_range()?;
+
+        match input {
+            PipelineData::ListStream(stream, ..) => {
+                let ctrlc = engine_state.ctrlc.clone();
+                let lines = stream.map(move |value| row_to_line(&value, span));
+                Ok(lines.into_pipeline_data(ctrlc))
+            }
+            input => {
+                let value = input.into_value(span);
+                Ok(row_to_line(&value, span).into_pipeline_data())
+            }
+        }
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Converts a record into a line of JSON",
+            example: "{a: 1} | to jsonl",
+            result: Some(Value::test_string("{\"a\":1}\n")),
+        }]
+    }
+}
+
+fn row_to_line(value: &Value, span: Span) -> Value {
+    let json_value = match value_to_json_value(value) {
+        Ok(json_value) => json_value,
+        Err(error) => return Value::Error { error },
+    };
+
+    match nu_json::to_string_raw(&json_value) {
+        Ok(line) => Value::string(format!("{line}\n"), span),
+        _ => Value::Error {
+            error: ShellError::CantConvert {
+                to_type: "JSON".into(),
+                from_type: value.get_type().to_string(),
+                span,
+                help: None,
+            },
+        },
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(ToJsonl {})
+    }
+}
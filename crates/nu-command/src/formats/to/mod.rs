@@ -1,26 +1,52 @@
+#[cfg(feature = "bson")]
+mod bson;
+mod cbor;
 mod command;
 mod csv;
 mod delimited;
+mod dot;
+mod edn;
 mod html;
+mod ini;
 mod json;
+mod jsonl;
 mod md;
+mod msgpack;
 mod nuon;
+mod parquet;
+mod plist;
+mod prometheus;
+mod qr;
 mod text;
 mod toml;
 mod tsv;
+mod xlsx;
 mod xml;
 mod yaml;
 
 pub use self::csv::ToCsv;
 pub use self::toml::ToToml;
+#[cfg(feature = "bson")]
+pub use bson::ToBson;
+pub use cbor::ToCbor;
 pub use command::To;
+pub use dot::ToDot;
+pub use edn::ToEdn;
 pub use html::ToHtml;
+pub use ini::ToIni;
 pub use json::ToJson;
+pub use jsonl::ToJsonl;
 pub use md::ToMd;
+pub use msgpack::ToMsgpack;
 pub use nuon::value_to_string;
 pub use nuon::ToNuon;
+pub use parquet::ToParquet;
+pub use plist::ToPlist;
+pub use prometheus::ToPrometheus;
+pub use qr::ToQr;
 pub use text::ToText;
 pub use tsv::ToTsv;
+pub use xlsx::ToXlsx;
 pub use xml::ToXml;
 pub use yaml::ToYaml;
 
@@ -0,0 +1,151 @@
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{Category, Example, PipelineData, ShellError, Signature, Span, Type, Value};
+
+#[derive(Clone)]
+pub struct ToMsgpack;
+
+impl Command for ToMsgpack {
+    fn name(&self) -> &str {
+        "to msgpack"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("to msgpack")
+            .input_output_types(vec![(Type::Any, Type::Binary)])
+            .switch(
+                "objects",
+                "encode each element of a list as its own MessagePack value, concatenated together",
+                Some('o'),
+            )
+            .category(Category::Formats)
+    }
+
+    fn usage(&self) -> &str {
+        "Convert structured data into MessagePack binary data."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Dates are encoded using the MessagePack timestamp extension (type -1) rather than as strings, so they round-trip through `from msgpack` exactly."
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let objects = call.has_flag("objects");
+        to_msgpack(input, head, objects)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Convert a record to MessagePack binary data",
+                example: "{a: 1} | to msgpack",
+                result: None,
+            },
+            Example {
+                description: "Encode a list as concatenated MessagePack values",
+                example: "[{a: 1} {a: 2}] | to msgpack --objects",
+                result: None,
+            },
+        ]
+    }
+}
+
+fn value_to_rmpv(value: &Value) -> Result<rmpv::Value, ShellError> {
+    Ok(match value {
+        Value::Bool { val, .. } => rmpv::Value::Boolean(*val),
+        Value::Int { val, .. } => rmpv::Value::from(*val),
+        Value::Filesize { val, .. } => rmpv::Value::from(*val),
+        Value::Duration { val, .. } => rmpv::Value::from(*val),
+        Value::Float { val, .. } => rmpv::Value::from(*val),
+        Value::String { val, .. } => rmpv::Value::from(val.clone()),
+        Value::Binary { val, .. } => rmpv::Value::Binary(val.clone()),
+        Value::Nothing { .. } => rmpv::Value::Nil,
+        Value::Date { val, .. } => rmpv::Value::Ext(-1, encode_timestamp_ext(val)),
+        Value::List { vals, .. } => {
+            let items = vals
+                .iter()
+                .map(value_to_rmpv)
+                .collect::<Result<Vec<_>, _>>()?;
+            rmpv::Value::Array(items)
+        }
+        Value::Record { cols, vals, .. } => {
+            let entries = cols
+                .iter()
+                .zip(vals.iter())
+                .map(|(k, v)| Ok((rmpv::Value::from(k.clone()), value_to_rmpv(v)?)))
+                .collect::<Result<Vec<_>, ShellError>>()?;
+            rmpv::Value::Map(entries)
+        }
+        Value::LazyRecord { val, .. } => value_to_rmpv(&val.collect()?)?,
+        Value::Error { error } => return Err(error.clone()),
+        other => rmpv::Value::from(other.into_string(",", &Default::default())),
+    })
+}
+
+/// Encodes a `chrono::DateTime` using the MessagePack timestamp 96 format (32-bit
+/// nanoseconds followed by a signed 64-bit seconds count), the only variant that can
+/// represent the full range and precision of a `DateTime<FixedOffset>`.
+fn encode_timestamp_ext(date: &chrono::DateTime<chrono::FixedOffset>) -> Vec<u8> {
+    let seconds = date.timestamp();
+    let nanos = date.timestamp_subsec_nanos();
+
+    let mut bytes = Vec::with_capacity(12);
+    bytes.extend_from_slice(&nanos.to_be_bytes());
+    bytes.extend_from_slice(&seconds.to_be_bytes());
+    bytes
+}
+
+fn to_msgpack(input: PipelineData, head: Span, objects: bool) -> Result<PipelineData, ShellError> {
+    let value = input.into_value(head);
+
+    let mut buffer = vec![];
+
+    if objects {
+        let Value::List { vals, .. } = &value else {
+            return Err(ShellError::UnsupportedInput(
+                "to msgpack --objects expects a list input".into(),
+                "value originates from here".into(),
+                head,
+                value.expect_span(),
+            ));
+        };
+        for v in vals {
+            let rmpv_value = value_to_rmpv(v)?;
+            rmpv::encode::write_value(&mut buffer, &rmpv_value).map_err(|e| encode_err(e, head))?;
+        }
+    } else {
+        let rmpv_value = value_to_rmpv(&value)?;
+        rmpv::encode::write_value(&mut buffer, &rmpv_value).map_err(|e| encode_err(e, head))?;
+    }
+
+    Ok(PipelineData::Value(Value::binary(buffer, head), None))
+}
+
+fn encode_err(error: rmpv::encode::Error, head: Span) -> ShellError {
+    ShellError::GenericError(
+        format!("Could not encode MessagePack data: {error}"),
+        "could not convert to msgpack".into(),
+        Some(head),
+        None,
+        Vec::new(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(ToMsgpack {})
+    }
+}
@@ -0,0 +1,231 @@
+use arrow2::array::{Array, BooleanArray, Float64Array, Int64Array, Utf8Array};
+use arrow2::chunk::Chunk;
+use arrow2::datatypes::{DataType as ArrowDataType, Field, Schema};
+use arrow2::io::parquet::write::{
+    CompressionOptions, Encoding, FileWriter, RowGroupIterator, Version, WriteOptions,
+};
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{Category, Example, PipelineData, ShellError, Signature, Span, Type, Value};
+
+#[derive(Clone)]
+pub struct ToParquet;
+
+impl Command for ToParquet {
+    fn name(&self) -> &str {
+        "to parquet"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("to parquet")
+            .input_output_types(vec![(Type::Table(vec![]), Type::Binary)])
+            .category(Category::Formats)
+    }
+
+    fn usage(&self) -> &str {
+        "Convert a table into binary Parquet data."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "The whole table is written as a single row group, with each column's arrow type picked from the first non-null value seen in that column. This works without building nushell with the `dataframe` feature."
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        to_parquet(input, head)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Convert a table to binary parquet data",
+            example: "[[a b]; [1 2] [3 4]] | to parquet | save out.parquet",
+            result: None,
+        }]
+    }
+}
+
+fn to_parquet(input: PipelineData, head: Span) -> Result<PipelineData, ShellError> {
+    let value = input.into_value(head);
+    let (cols, rows) = match value {
+        Value::List { vals, .. } => {
+            let mut cols = vec![];
+            let mut rows = vec![];
+            for row in vals {
+                match row {
+                    Value::Record {
+                        cols: c, vals: v, ..
+                    } => {
+                        if cols.is_empty() {
+                            cols = c;
+                        }
+                        rows.push(v);
+                    }
+                    _ => {
+                        return Err(ShellError::UnsupportedInput(
+                            "Expected a table of records".into(),
+                            "value originates from here".into(),
+                            head,
+                            head,
+                        ))
+                    }
+                }
+            }
+            (cols, rows)
+        }
+        _ => {
+            return Err(ShellError::UnsupportedInput(
+                "to parquet only converts tables".into(),
+                "value originates from here".into(),
+                head,
+                head,
+            ))
+        }
+    };
+
+    let mut fields = vec![];
+    let mut arrays: Vec<Box<dyn Array>> = vec![];
+
+    for (i, name) in cols.iter().enumerate() {
+        let column: Vec<Option<Value>> = rows.iter().map(|r| r.get(i).cloned()).collect();
+
+        let (field, array) = column_to_array(name, &column, head)?;
+        fields.push(field);
+        arrays.push(array);
+    }
+
+    let schema = Schema::from(fields);
+    let chunk = Chunk::new(arrays);
+
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: CompressionOptions::Snappy,
+        version: Version::V2,
+        data_pagesize_limit: None,
+    };
+
+    let encodings: Vec<Vec<Encoding>> = schema
+        .fields
+        .iter()
+        .map(|_| vec![Encoding::Plain])
+        .collect();
+
+    let row_groups =
+        RowGroupIterator::try_new(vec![Ok(chunk)].into_iter(), &schema, options, encodings)
+            .map_err(|e| {
+                ShellError::GenericError(
+                    format!("Could not encode parquet row group: {e}"),
+                    "could not convert to parquet".into(),
+                    Some(head),
+                    None,
+                    Vec::new(),
+                )
+            })?;
+
+    let mut buffer = vec![];
+    let mut writer = FileWriter::try_new(&mut buffer, schema, options).map_err(|e| {
+        ShellError::GenericError(
+            format!("Could not start parquet writer: {e}"),
+            "could not convert to parquet".into(),
+            Some(head),
+            None,
+            Vec::new(),
+        )
+    })?;
+
+    for group in row_groups {
+        let group = group.map_err(|e| {
+            ShellError::GenericError(
+                format!("Could not write parquet row group: {e}"),
+                "could not convert to parquet".into(),
+                Some(head),
+                None,
+                Vec::new(),
+            )
+        })?;
+        writer.write(group).map_err(|e| {
+            ShellError::GenericError(
+                format!("Could not write parquet row group: {e}"),
+                "could not convert to parquet".into(),
+                Some(head),
+                None,
+                Vec::new(),
+            )
+        })?;
+    }
+
+    writer.end(None).map_err(|e| {
+        ShellError::GenericError(
+            format!("Could not finalize parquet file: {e}"),
+            "could not convert to parquet".into(),
+            Some(head),
+            None,
+            Vec::new(),
+        )
+    })?;
+
+    Ok(PipelineData::Value(Value::binary(buffer, head), None))
+}
+
+fn column_to_array(
+    name: &str,
+    values: &[Option<Value>],
+    span: Span,
+) -> Result<(Field, Box<dyn Array>), ShellError> {
+    let data_type = values
+        .iter()
+        .flatten()
+        .find_map(|v| match v {
+            Value::Nothing { .. } => None,
+            Value::Int { .. } | Value::Filesize { .. } => Some(ArrowDataType::Int64),
+            Value::Float { .. } => Some(ArrowDataType::Float64),
+            Value::Bool { .. } => Some(ArrowDataType::Boolean),
+            _ => Some(ArrowDataType::Utf8),
+        })
+        .unwrap_or(ArrowDataType::Utf8);
+
+    let array: Box<dyn Array> = match data_type {
+        ArrowDataType::Int64 => Box::new(Int64Array::from_iter(values.iter().map(|v| match v {
+            Some(Value::Int { val, .. }) => Some(*val),
+            Some(Value::Filesize { val, .. }) => Some(*val),
+            _ => None,
+        }))),
+        ArrowDataType::Float64 => {
+            Box::new(Float64Array::from_iter(values.iter().map(|v| match v {
+                Some(Value::Float { val, .. }) => Some(*val),
+                _ => None,
+            })))
+        }
+        ArrowDataType::Boolean => {
+            Box::new(BooleanArray::from_iter(values.iter().map(|v| match v {
+                Some(Value::Bool { val, .. }) => Some(*val),
+                _ => None,
+            })))
+        }
+        _ => {
+            Box::new(Utf8Array::<i32>::from_iter(values.iter().map(|v| {
+                v.as_ref().map(|v| v.into_string(",", &Default::default()))
+            })))
+        }
+    };
+
+    let _ = span;
+    Ok((Field::new(name, data_type, true), array))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(ToParquet {})
+    }
+}
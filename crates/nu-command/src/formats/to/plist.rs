@@ -0,0 +1,145 @@
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{Category, Example, PipelineData, ShellError, Signature, Span, Type, Value};
+
+#[derive(Clone)]
+pub struct ToPlist;
+
+impl Command for ToPlist {
+    fn name(&self) -> &str {
+        "to plist"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("to plist")
+            .input_output_types(vec![(Type::Any, Type::Binary), (Type::Any, Type::String)])
+            .switch(
+                "binary",
+                "write the binary (bplist00) encoding instead of XML",
+                Some('b'),
+            )
+            .category(Category::Formats)
+    }
+
+    fn usage(&self) -> &str {
+        "Convert structured data into .plist data."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "XML is written by default, matching the format macOS itself uses for human-edited plists such as LaunchAgents; pass --binary for the compact bplist00 encoding most preference files on disk actually use."
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let binary = call.has_flag("binary");
+        to_plist(input, head, binary)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Convert a record to XML plist data",
+                example: "{a: 1} | to plist",
+                result: None,
+            },
+            Example {
+                description: "Convert a record to binary plist data",
+                example: "{a: 1} | to plist --binary",
+                result: None,
+            },
+        ]
+    }
+}
+
+fn value_to_plist(value: &Value, span: Span) -> Result<plist::Value, ShellError> {
+    Ok(match value {
+        Value::Bool { val, .. } => plist::Value::Boolean(*val),
+        Value::Int { val, .. } => plist::Value::Integer((*val).into()),
+        Value::Filesize { val, .. } => plist::Value::Integer((*val).into()),
+        Value::Duration { val, .. } => plist::Value::Integer((*val).into()),
+        Value::Float { val, .. } => plist::Value::Real(*val),
+        Value::String { val, .. } => plist::Value::String(val.clone()),
+        Value::Binary { val, .. } => plist::Value::Data(val.clone()),
+        Value::Date { val, .. } => {
+            let system_time: std::time::SystemTime = (*val).into();
+            plist::Value::Date(system_time.into())
+        }
+        Value::List { vals, .. } => {
+            let items = vals
+                .iter()
+                .map(|v| value_to_plist(v, span))
+                .collect::<Result<Vec<_>, _>>()?;
+            plist::Value::Array(items)
+        }
+        Value::Record { cols, vals, .. } => {
+            let mut dict = plist::Dictionary::new();
+            for (k, v) in cols.iter().zip(vals.iter()) {
+                dict.insert(k.clone(), value_to_plist(v, span)?);
+            }
+            plist::Value::Dictionary(dict)
+        }
+        Value::LazyRecord { val, .. } => value_to_plist(&val.collect()?, span)?,
+        Value::Nothing { .. } => {
+            return Err(ShellError::UnsupportedInput(
+                "plist has no representation for null values".into(),
+                "value originates from here".into(),
+                span,
+                value.expect_span(),
+            ))
+        }
+        Value::Error { error } => return Err(error.clone()),
+        other => plist::Value::String(other.into_string(",", &Default::default())),
+    })
+}
+
+fn to_plist(input: PipelineData, head: Span, binary: bool) -> Result<PipelineData, ShellError> {
+    let value = input.into_value(head);
+    let plist_value = value_to_plist(&value, head)?;
+
+    if binary {
+        let mut buffer = vec![];
+        plist::to_writer_binary(&mut buffer, &plist_value).map_err(|e| encode_err(e, head))?;
+        Ok(PipelineData::Value(Value::binary(buffer, head), None))
+    } else {
+        let mut buffer = vec![];
+        plist::to_writer_xml(&mut buffer, &plist_value).map_err(|e| encode_err(e, head))?;
+        let text = String::from_utf8(buffer).map_err(|e| {
+            ShellError::GenericError(
+                format!("Could not convert plist XML to UTF-8: {e}"),
+                "could not convert to plist".into(),
+                Some(head),
+                None,
+                Vec::new(),
+            )
+        })?;
+        Ok(PipelineData::Value(Value::string(text, head), None))
+    }
+}
+
+fn encode_err(error: plist::Error, head: Span) -> ShellError {
+    ShellError::GenericError(
+        format!("Could not encode plist data: {error}"),
+        "could not convert to plist".into(),
+        Some(head),
+        None,
+        Vec::new(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(ToPlist {})
+    }
+}
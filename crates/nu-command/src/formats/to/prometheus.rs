@@ -0,0 +1,159 @@
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct ToPrometheus;
+
+impl Command for ToPrometheus {
+    fn name(&self) -> &str {
+        "to prometheus"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("to prometheus")
+            .input_output_types(vec![(Type::Table(vec![]), Type::String)])
+            .category(Category::Formats)
+    }
+
+    fn usage(&self) -> &str {
+        "Serialize a table of samples back into the Prometheus text exposition format."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Expects the shape produced by `from prometheus`: `name`, an optional
+`labels` record, `value`, and an optional `timestamp`. Rows missing `labels`
+or `timestamp` just omit the braces or trailing field rather than erroring."#
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        to_prometheus(input, head)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Render a sample back into exposition format",
+            example: r#"[[name, labels, value]; [http_requests_total, {method: get, code: 200}, 1027]] | to prometheus"#,
+            result: Some(Value::test_string(
+                "http_requests_total{method=\"get\",code=\"200\"} 1027\n",
+            )),
+        }]
+    }
+}
+
+fn to_prometheus(input: PipelineData, head: Span) -> Result<PipelineData, ShellError> {
+    let mut output = String::new();
+
+    for value in input {
+        output.push_str(&render_sample(&value, head)?);
+        output.push('\n');
+    }
+
+    Ok(Value::string(output, head).into_pipeline_data())
+}
+
+fn render_sample(value: &Value, head: Span) -> Result<String, ShellError> {
+    let Value::Record { cols, vals, .. } = value else {
+        return Err(ShellError::UnsupportedInput(
+            format!("{:?} is not a valid Prometheus sample", value.get_type()),
+            "value originates from here".into(),
+            head,
+            value.expect_span(),
+        ));
+    };
+
+    let field = |name: &str| cols.iter().position(|c| c == name).map(|i| &vals[i]);
+
+    let name = match field("name") {
+        Some(Value::String { val, .. }) => val.clone(),
+        _ => {
+            return Err(ShellError::UnsupportedInput(
+                "sample is missing its `name` column".into(),
+                "value originates from here".into(),
+                head,
+                value.expect_span(),
+            ))
+        }
+    };
+
+    let mut out = name;
+
+    if let Some(Value::Record {
+        cols: label_cols,
+        vals: label_vals,
+        ..
+    }) = field("labels")
+    {
+        if !label_cols.is_empty() {
+            let rendered = label_cols
+                .iter()
+                .zip(label_vals.iter())
+                .map(|(key, val)| {
+                    let escaped = prometheus_value_to_string(val)
+                        .replace('\\', "\\\\")
+                        .replace('"', "\\\"")
+                        .replace('\n', "\\n");
+                    format!("{key}=\"{escaped}\"")
+                })
+                .collect::<Vec<_>>()
+                .join(",");
+            out.push('{');
+            out.push_str(&rendered);
+            out.push('}');
+        }
+    }
+
+    let value_str = match field("value") {
+        Some(Value::Float { val, .. }) => val.to_string(),
+        Some(Value::Int { val, .. }) => val.to_string(),
+        _ => {
+            return Err(ShellError::UnsupportedInput(
+                "sample is missing its `value` column".into(),
+                "value originates from here".into(),
+                head,
+                value.expect_span(),
+            ))
+        }
+    };
+
+    out.push(' ');
+    out.push_str(&value_str);
+
+    if let Some(Value::Int { val, .. }) = field("timestamp") {
+        out.push(' ');
+        out.push_str(&val.to_string());
+    }
+
+    Ok(out)
+}
+
+fn prometheus_value_to_string(value: &Value) -> String {
+    match value {
+        Value::String { val, .. } => val.clone(),
+        Value::Int { val, .. } => val.to_string(),
+        Value::Float { val, .. } => val.to_string(),
+        Value::Bool { val, .. } => val.to_string(),
+        other => other.into_string(", ", &nu_protocol::Config::default()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(ToPrometheus {})
+    }
+}
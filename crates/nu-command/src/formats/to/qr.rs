@@ -0,0 +1,133 @@
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{Category, Example, PipelineData, ShellError, Signature, Span, Type, Value};
+
+#[derive(Clone)]
+pub struct ToQr;
+
+impl Command for ToQr {
+    fn name(&self) -> &str {
+        "to qr"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("to qr")
+            .input_output_types(vec![
+                (Type::String, Type::String),
+                (Type::String, Type::Binary),
+            ])
+            .switch(
+                "png",
+                "render as PNG binary data instead of unicode blocks",
+                Some('p'),
+            )
+            .category(Category::Formats)
+    }
+
+    fn usage(&self) -> &str {
+        "Encode a string into a QR code."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "By default, renders the code as unicode block characters suitable for printing straight to a terminal. With --png, returns PNG-encoded binary data instead, which can be piped into `save` to write an image file."
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let png = call.has_flag("png");
+        to_qr(input, head, png)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Render a URL as a QR code made of unicode blocks",
+                example: "'https://www.nushell.sh' | to qr",
+                result: None,
+            },
+            Example {
+                description: "Save a QR code for some wifi credentials as a PNG file",
+                example: "'WIFI:S:mynet;P:mypass;;' | to qr --png | save wifi-qr.png",
+                result: None,
+            },
+        ]
+    }
+}
+
+fn to_qr(input: PipelineData, head: Span, png: bool) -> Result<PipelineData, ShellError> {
+    let value = input.into_value(head);
+    let text = match &value {
+        Value::String { val, .. } => val.clone(),
+        other => {
+            return Err(ShellError::UnsupportedInput(
+                "to qr expects string input".into(),
+                "value originates from here".into(),
+                head,
+                other.expect_span(),
+            ))
+        }
+    };
+
+    let code = qrcode::QrCode::new(text.as_bytes()).map_err(|e| encode_err(e, head))?;
+
+    if png {
+        let image = code
+            .render::<image::Luma<u8>>()
+            .max_dimensions(512, 512)
+            .build();
+
+        let mut buffer = std::io::Cursor::new(Vec::new());
+        image::DynamicImage::ImageLuma8(image)
+            .write_to(&mut buffer, image::ImageOutputFormat::Png)
+            .map_err(|e| {
+                ShellError::GenericError(
+                    format!("Could not encode QR code as PNG: {e}"),
+                    "could not convert to qr --png".into(),
+                    Some(head),
+                    None,
+                    Vec::new(),
+                )
+            })?;
+
+        Ok(PipelineData::Value(
+            Value::binary(buffer.into_inner(), head),
+            None,
+        ))
+    } else {
+        let rendered = code
+            .render::<char>()
+            .quiet_zone(true)
+            .module_dimensions(2, 1)
+            .build();
+
+        Ok(PipelineData::Value(Value::string(rendered, head), None))
+    }
+}
+
+fn encode_err(error: qrcode::types::QrError, head: Span) -> ShellError {
+    ShellError::GenericError(
+        format!("Could not encode QR code: {error}"),
+        "could not convert to qr".into(),
+        Some(head),
+        None,
+        Vec::new(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(ToQr {})
+    }
+}
@@ -1,9 +1,29 @@
+use chrono::{DateTime, Datelike, FixedOffset, Timelike};
 use nu_protocol::ast::{Call, PathMember};
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
     Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Type, Value,
 };
 
+fn chrono_to_toml_datetime(dt: &DateTime<FixedOffset>) -> toml::value::Datetime {
+    toml::value::Datetime {
+        date: Some(toml::value::Date {
+            year: dt.year() as u16,
+            month: dt.month() as u8,
+            day: dt.day() as u8,
+        }),
+        time: Some(toml::value::Time {
+            hour: dt.hour() as u8,
+            minute: dt.minute() as u8,
+            second: dt.second() as u8,
+            nanosecond: dt.timestamp_subsec_nanos(),
+        }),
+        offset: Some(toml::value::Offset::Custom {
+            minutes: (dt.offset().local_minus_utc() / 60) as i16,
+        }),
+    }
+}
+
 #[derive(Clone)]
 pub struct ToToml;
 
@@ -15,6 +35,11 @@ impl Command for ToToml {
     fn signature(&self) -> Signature {
         Signature::build("to toml")
             .input_output_types(vec![(Type::Record(vec![]), Type::String)])
+            .switch(
+                "preserve",
+                "keep the record's own key order instead of the alphabetical order the plain TOML writer uses",
+                Some('p'),
+            )
             .category(Category::Formats)
     }
 
@@ -22,12 +47,23 @@ impl Command for ToToml {
         "Convert record into .toml text."
     }
 
+    fn extra_usage(&self) -> &str {
+        "With --preserve, keys are written in the order they appear in the record, which keeps small edits (e.g. `update package.version ...`) from reordering the rest of the file. This does not restore comments lost when the file was originally parsed."
+    }
+
     fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            description: "Outputs an TOML string representing the contents of this record",
-            example: r#"{foo: 1 bar: 'qwe'} | to toml"#,
-            result: Some(Value::test_string("bar = \"qwe\"\nfoo = 1\n")),
-        }]
+        vec![
+            Example {
+                description: "Outputs an TOML string representing the contents of this record",
+                example: r#"{foo: 1 bar: 'qwe'} | to toml"#,
+                result: Some(Value::test_string("bar = \"qwe\"\nfoo = 1\n")),
+            },
+            Example {
+                description: "Round-trip a record through TOML without reordering its keys",
+                example: r#"open Cargo.toml | update package.version '1.2.3' | to toml --preserve"#,
+                result: None,
+            },
+        ]
     }
 
     fn run(
@@ -38,8 +74,95 @@ impl Command for ToToml {
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
         let head = call.head;
-        to_toml(engine_state, input, head)
+        if call.has_flag("preserve") {
+            to_toml_preserving_order(input, head)
+        } else {
+            to_toml(engine_state, input, head)
+        }
+    }
+}
+
+fn value_to_toml_edit_item(v: &Value, head: Span) -> Result<toml_edit::Item, ShellError> {
+    use toml_edit::{value, Array, Item, Table};
+
+    Ok(match v {
+        Value::Bool { val, .. } => value(*val),
+        Value::Int { val, .. } => value(*val),
+        Value::Filesize { val, .. } => value(*val),
+        Value::Duration { val, .. } => value(val.to_string()),
+        Value::Date { val, .. } => value(chrono_to_toml_datetime(val)),
+        Value::Range { .. } => value("<Range>"),
+        Value::Float { val, .. } => value(*val),
+        Value::String { val, .. } => value(val),
+        Value::Record { cols, vals, .. } => {
+            let mut table = Table::new();
+            for (k, v) in cols.iter().zip(vals.iter()) {
+                table.insert(k, value_to_toml_edit_item(v, head)?);
+            }
+            Item::Table(table)
+        }
+        Value::LazyRecord { val, .. } => value_to_toml_edit_item(&val.collect()?, head)?,
+        Value::List { vals, .. } => {
+            let mut array = Array::new();
+            for v in vals {
+                match value_to_toml_edit_item(v, head)?.into_value() {
+                    Ok(toml_value) => array.push(toml_value),
+                    Err(_) => {
+                        return Err(ShellError::UnsupportedInput(
+                            "TOML arrays cannot contain tables".into(),
+                            "value originates from here".into(),
+                            head,
+                            v.expect_span(),
+                        ))
+                    }
+                }
+            }
+            value(array)
+        }
+        Value::Nothing { .. } => value("<Nothing>"),
+        Value::Error { error } => return Err(error.clone()),
+        Value::Binary { val, .. } => {
+            let mut array = Array::new();
+            for byte in val {
+                array.push(*byte as i64);
+            }
+            value(array)
+        }
+        Value::CellPath { val, .. } => {
+            let mut array = Array::new();
+            for member in &val.members {
+                match member {
+                    PathMember::String { val, .. } => array.push(val.as_str()),
+                    PathMember::Int { val, .. } => array.push(*val as i64),
+                }
+            }
+            value(array)
+        }
+        Value::Block { .. } | Value::Closure { .. } | Value::CustomValue { .. } => {
+            value(format!("<{}>", v.get_type()))
+        }
+    })
+}
+
+fn to_toml_preserving_order(input: PipelineData, head: Span) -> Result<PipelineData, ShellError> {
+    let value = input.into_value(head);
+
+    let Value::Record { .. } = &value else {
+        return Err(ShellError::UnsupportedInput(
+            format!("{:?} is not valid top-level TOML", value.get_type()),
+            "value originates from here".into(),
+            head,
+            value.expect_span(),
+        ));
+    };
+
+    let item = value_to_toml_edit_item(&value, head)?;
+    let mut document = toml_edit::Document::new();
+    if let toml_edit::Item::Table(table) = item {
+        *document.as_table_mut() = table;
     }
+
+    Ok(Value::string(document.to_string(), head).into_pipeline_data())
 }
 
 // Helper method to recursively convert nu_protocol::Value -> toml::Value
@@ -50,7 +173,7 @@ fn helper(engine_state: &EngineState, v: &Value) -> Result<toml::Value, ShellErr
         Value::Int { val, .. } => toml::Value::Integer(*val),
         Value::Filesize { val, .. } => toml::Value::Integer(*val),
         Value::Duration { val, .. } => toml::Value::String(val.to_string()),
-        Value::Date { val, .. } => toml::Value::String(val.to_string()),
+        Value::Date { val, .. } => toml::Value::Datetime(chrono_to_toml_datetime(val)),
         Value::Range { .. } => toml::Value::String("<Range>".to_string()),
         Value::Float { val, .. } => toml::Value::Float(*val),
         Value::String { val, .. } => toml::Value::String(val.clone()),
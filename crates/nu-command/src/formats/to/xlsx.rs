@@ -0,0 +1,208 @@
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{Category, Example, PipelineData, ShellError, Signature, Span, Type, Value};
+use rust_xlsxwriter::{Format, Workbook};
+
+#[derive(Clone)]
+pub struct ToXlsx;
+
+impl Command for ToXlsx {
+    fn name(&self) -> &str {
+        "to xlsx"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("to xlsx")
+            .input_output_types(vec![
+                (Type::Table(vec![]), Type::Binary),
+                (Type::Record(vec![]), Type::Binary),
+            ])
+            .category(Category::Formats)
+    }
+
+    fn usage(&self) -> &str {
+        "Convert a table, or a record of tables, into binary .xlsx data."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "A record input is written as one sheet per column, named after that column. A table input is written as a single sheet named Sheet1."
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        to_xlsx(input, head)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Convert a table to binary .xlsx data",
+                example: "[[a b]; [1 2] [3 4]] | to xlsx | save report.xlsx",
+                result: None,
+            },
+            Example {
+                description: "Write multiple sheets by passing a record of tables",
+                example:
+                    "{sheet1: [[a b]; [1 2]], sheet2: [[a b]; [3 4]]} | to xlsx | save report.xlsx",
+                result: None,
+            },
+        ]
+    }
+}
+
+fn write_sheet(
+    workbook: &mut Workbook,
+    name: &str,
+    table: &Value,
+    head: Span,
+) -> Result<(), ShellError> {
+    let Value::List { vals: rows, .. } = table else {
+        return Err(ShellError::UnsupportedInput(
+            "to xlsx only converts tables, or records of tables".into(),
+            "value originates from here".into(),
+            head,
+            table.expect_span(),
+        ));
+    };
+
+    let mut cols: Vec<String> = vec![];
+    for row in rows {
+        if let Value::Record { cols: c, .. } = row {
+            if c.len() > cols.len() {
+                cols = c.clone();
+            }
+        }
+    }
+
+    let sheet = workbook.add_worksheet().set_name(name).map_err(|e| {
+        ShellError::GenericError(
+            format!("Could not create sheet '{name}': {e}"),
+            "could not convert to xlsx".into(),
+            Some(head),
+            None,
+            Vec::new(),
+        )
+    })?;
+
+    for (col_idx, col_name) in cols.iter().enumerate() {
+        sheet
+            .write_string(0, col_idx as u16, col_name)
+            .map_err(|e| write_err(e, head))?;
+    }
+
+    let date_format = Format::new().set_num_format("yyyy-mm-dd hh:mm:ss");
+
+    for (row_idx, row) in rows.iter().enumerate() {
+        let row_num = (row_idx + 1) as u32;
+        if let Value::Record { cols: c, vals, .. } = row {
+            for (col_idx, col_name) in cols.iter().enumerate() {
+                let Some(value) = c.iter().position(|name| name == col_name).map(|i| &vals[i])
+                else {
+                    continue;
+                };
+
+                match value {
+                    Value::Int { val, .. } => {
+                        sheet
+                            .write_number(row_num, col_idx as u16, *val as f64)
+                            .map_err(|e| write_err(e, head))?;
+                    }
+                    Value::Float { val, .. } => {
+                        sheet
+                            .write_number(row_num, col_idx as u16, *val)
+                            .map_err(|e| write_err(e, head))?;
+                    }
+                    Value::Bool { val, .. } => {
+                        sheet
+                            .write_boolean(row_num, col_idx as u16, *val)
+                            .map_err(|e| write_err(e, head))?;
+                    }
+                    Value::Date { val, .. } => {
+                        sheet
+                            .write_string_with_format(
+                                row_num,
+                                col_idx as u16,
+                                &val.to_rfc3339(),
+                                &date_format,
+                            )
+                            .map_err(|e| write_err(e, head))?;
+                    }
+                    Value::Nothing { .. } => {}
+                    other => {
+                        sheet
+                            .write_string(
+                                row_num,
+                                col_idx as u16,
+                                other.into_string(",", &Default::default()),
+                            )
+                            .map_err(|e| write_err(e, head))?;
+                    }
+                };
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn write_err(error: rust_xlsxwriter::XlsxError, head: Span) -> ShellError {
+    ShellError::GenericError(
+        format!("Could not write xlsx cell: {error}"),
+        "could not convert to xlsx".into(),
+        Some(head),
+        None,
+        Vec::new(),
+    )
+}
+
+fn to_xlsx(input: PipelineData, head: Span) -> Result<PipelineData, ShellError> {
+    let value = input.into_value(head);
+    let mut workbook = Workbook::new();
+
+    match &value {
+        Value::List { .. } => write_sheet(&mut workbook, "Sheet1", &value, head)?,
+        Value::Record { cols, vals, .. } => {
+            for (name, table) in cols.iter().zip(vals.iter()) {
+                write_sheet(&mut workbook, name, table, head)?;
+            }
+        }
+        _ => {
+            return Err(ShellError::UnsupportedInput(
+                "to xlsx only converts tables, or records of tables".into(),
+                "value originates from here".into(),
+                head,
+                value.expect_span(),
+            ))
+        }
+    }
+
+    let buffer = workbook.save_to_buffer().map_err(|e| {
+        ShellError::GenericError(
+            format!("Could not finalize xlsx file: {e}"),
+            "could not convert to xlsx".into(),
+            Some(head),
+            None,
+            Vec::new(),
+        )
+    })?;
+
+    Ok(PipelineData::Value(Value::binary(buffer, head), None))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(ToXlsx {})
+    }
+}
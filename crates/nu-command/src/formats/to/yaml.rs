@@ -1,8 +1,10 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
 use nu_protocol::ast::{Call, PathMember};
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
     Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Type, Value,
 };
+use serde_yaml::value::{Tag, TaggedValue};
 
 #[derive(Clone)]
 pub struct ToYaml;
@@ -15,6 +17,11 @@ impl Command for ToYaml {
     fn signature(&self) -> Signature {
         Signature::build("to yaml")
             .input_output_types(vec![(Type::Any, Type::String)])
+            .switch(
+                "multi-doc",
+                "treat a list input as separate YAML documents, joined by `---` markers",
+                Some('m'),
+            )
             .category(Category::Formats)
     }
 
@@ -22,12 +29,23 @@ impl Command for ToYaml {
         "Convert table into .yaml/.yml text."
     }
 
+    fn extra_usage(&self) -> &str {
+        "With --multi-doc, each element of a list input is written as its own document, separated by `---`, matching how tools like kubectl emit multi-document manifests."
+    }
+
     fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            description: "Outputs an YAML string representing the contents of this table",
-            example: r#"[[foo bar]; ["1" "2"]] | to yaml"#,
-            result: Some(Value::test_string("- foo: '1'\n  bar: '2'\n")),
-        }]
+        vec![
+            Example {
+                description: "Outputs an YAML string representing the contents of this table",
+                example: r#"[[foo bar]; ["1" "2"]] | to yaml"#,
+                result: Some(Value::test_string("- foo: '1'\n  bar: '2'\n")),
+            },
+            Example {
+                description: "Outputs a multi-document YAML string, one document per list item",
+                example: r#"[{a: 1} {b: 2}] | to yaml --multi-doc"#,
+                result: Some(Value::test_string("---\na: 1\n---\nb: 2\n")),
+            },
+        ]
     }
 
     fn run(
@@ -39,7 +57,11 @@ impl Command for ToYaml {
     ) -> Result<PipelineData, ShellError> {
         let head = call.head;
         let input = input.try_expand_range()?;
-        to_yaml(input, head)
+        if call.has_flag("multi-doc") {
+            to_yaml_multi_doc(input, head)
+        } else {
+            to_yaml(input, head)
+        }
     }
 }
 
@@ -49,7 +71,10 @@ pub fn value_to_yaml_value(v: &Value) -> Result<serde_yaml::Value, ShellError> {
         Value::Int { val, .. } => serde_yaml::Value::Number(serde_yaml::Number::from(*val)),
         Value::Filesize { val, .. } => serde_yaml::Value::Number(serde_yaml::Number::from(*val)),
         Value::Duration { val, .. } => serde_yaml::Value::String(val.to_string()),
-        Value::Date { val, .. } => serde_yaml::Value::String(val.to_string()),
+        Value::Date { val, .. } => serde_yaml::Value::Tagged(Box::new(TaggedValue {
+            tag: Tag::new("!!timestamp"),
+            value: serde_yaml::Value::String(val.to_rfc3339()),
+        })),
         Value::Range { .. } => serde_yaml::Value::Null,
         Value::Float { val, .. } => serde_yaml::Value::Number(serde_yaml::Number::from(*val)),
         Value::String { val, .. } => serde_yaml::Value::String(val.clone()),
@@ -80,11 +105,10 @@ pub fn value_to_yaml_value(v: &Value) -> Result<serde_yaml::Value, ShellError> {
         Value::Closure { .. } => serde_yaml::Value::Null,
         Value::Nothing { .. } => serde_yaml::Value::Null,
         Value::Error { error } => return Err(error.clone()),
-        Value::Binary { val, .. } => serde_yaml::Value::Sequence(
-            val.iter()
-                .map(|x| serde_yaml::Value::Number(serde_yaml::Number::from(*x)))
-                .collect(),
-        ),
+        Value::Binary { val, .. } => serde_yaml::Value::Tagged(Box::new(TaggedValue {
+            tag: Tag::new("!!binary"),
+            value: serde_yaml::Value::String(STANDARD.encode(val)),
+        })),
         Value::CellPath { val, .. } => serde_yaml::Value::Sequence(
             val.members
                 .iter()
@@ -122,6 +146,34 @@ fn to_yaml(input: PipelineData, head: Span) -> Result<PipelineData, ShellError>
     }
 }
 
+fn to_yaml_multi_doc(input: PipelineData, head: Span) -> Result<PipelineData, ShellError> {
+    let value = input.into_value(head);
+
+    let Value::List { vals, .. } = &value else {
+        return to_yaml(value.into_pipeline_data(), head);
+    };
+
+    let mut docs = String::new();
+    for val in vals {
+        let yaml_value = value_to_yaml_value(val)?;
+        let serde_yaml_string =
+            serde_yaml::to_string(&yaml_value).map_err(|_| ShellError::CantConvert {
+                to_type: "YAML".into(),
+                from_type: val.get_type().to_string(),
+                span: head,
+                help: None,
+            })?;
+        docs.push_str("---\n");
+        docs.push_str(&serde_yaml_string);
+    }
+
+    Ok(Value::String {
+        val: docs,
+        span: head,
+    }
+    .into_pipeline_data())
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
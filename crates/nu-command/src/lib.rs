@@ -26,6 +26,7 @@ mod shells;
 mod sort_utils;
 mod strings;
 mod system;
+mod time;
 mod viewers;
 
 pub use bits::*;
@@ -55,6 +56,7 @@ pub use shells::*;
 pub use sort_utils::*;
 pub use strings::*;
 pub use system::*;
+pub use time::*;
 pub use viewers::*;
 
 #[cfg(feature = "dataframe")]
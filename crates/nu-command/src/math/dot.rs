@@ -0,0 +1,84 @@
+use super::linalg::as_vector;
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, SyntaxShape, Type,
+    Value,
+};
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "math dot"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("math dot")
+            .input_output_types(vec![(Type::List(Box::new(Type::Number)), Type::Float)])
+            .required("other", SyntaxShape::Any, "the other vector")
+            .category(Category::Math)
+    }
+
+    fn usage(&self) -> &str {
+        "Compute the dot product of two numeric vectors."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["vector", "linear algebra", "matrix"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let a = as_vector(&input.into_value(head), head)?;
+        let other: Value = call.req(engine_state, stack, 0)?;
+        let b = as_vector(&other, head)?;
+
+        if a.len() != b.len() {
+            return Err(ShellError::IncompatibleParametersSingle {
+                msg: format!(
+                    "vectors must have the same length, got {} and {}",
+                    a.len(),
+                    b.len()
+                ),
+                span: other.expect_span(),
+            });
+        }
+
+        let dot: f64 = a.iter().zip(b.iter()).map(|(x, y)| x * y).sum();
+
+        Ok(Value::Float {
+            val: dot,
+            span: head,
+        }
+        .into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Compute the dot product of two vectors",
+            example: "[1 2 3] | math dot [4 5 6]",
+            result: Some(Value::test_float(32.0)),
+        }]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
@@ -0,0 +1,384 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, SyntaxShape,
+    Type, Value,
+};
+use std::collections::HashMap;
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "math eval"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("math eval")
+            .input_output_types(vec![
+                (Type::Nothing, Type::Float),
+                (Type::Record(vec![]), Type::Float),
+            ])
+            .required(
+                "expression",
+                SyntaxShape::String,
+                "the math expression to evaluate",
+            )
+            .category(Category::Math)
+    }
+
+    fn usage(&self) -> &str {
+        "Evaluate a math expression, with support for trig/log functions and variables."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Supports the usual arithmetic operators (+ - * / % ^), parentheses, the constants
+`pi`, `e`, and `tau`, and the functions `sqrt`, `abs`, `floor`, `ceil`, `round`, `ln`, `log`
+(base 10), `sin`, `cos`, `tan`, `asin`, `acos`, and `atan`. Piping in a record binds its
+fields as variables for the expression to reference by name."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["expression", "calculator", "arithmetic"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let expression: String = call.req(engine_state, stack, 0)?;
+
+        let vars = match input.into_value(head) {
+            Value::Record { cols, vals, .. } => {
+                let mut vars = HashMap::new();
+                for (col, val) in cols.into_iter().zip(vals.into_iter()) {
+                    let n = match val {
+                        Value::Int { val, .. } => val as f64,
+                        Value::Float { val, .. } => val,
+                        other => {
+                            return Err(ShellError::UnsupportedInput(
+                                "variable bindings must be numbers".into(),
+                                "value originates from here".into(),
+                                head,
+                                other.expect_span(),
+                            ))
+                        }
+                    };
+                    vars.insert(col, n);
+                }
+                vars
+            }
+            Value::Nothing { .. } => HashMap::new(),
+            other => {
+                return Err(ShellError::UnsupportedInput(
+                    "math eval expects a record of variables, or nothing".into(),
+                    "value originates from here".into(),
+                    head,
+                    other.expect_span(),
+                ))
+            }
+        };
+
+        let result = eval(&expression, &vars, head)?;
+
+        Ok(Value::Float {
+            val: result,
+            span: head,
+        }
+        .into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Evaluate an arithmetic expression",
+                example: "math eval '2 + 3 * 4'",
+                result: Some(Value::test_float(14.0)),
+            },
+            Example {
+                description: "Evaluate a trig expression using a constant",
+                example: "math eval 'sin(pi / 2)'",
+                result: Some(Value::test_float(1.0)),
+            },
+            Example {
+                description: "Evaluate an expression with variables bound from a record",
+                example: "{x: 2, y: 3} | math eval 'x ^ y'",
+                result: Some(Value::test_float(8.0)),
+            },
+        ]
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Number(f64),
+    Ident(String),
+    Plus,
+    Minus,
+    Star,
+    Slash,
+    Percent,
+    Caret,
+    LParen,
+    RParen,
+    Comma,
+}
+
+fn tokenize(input: &str, head: Span) -> Result<Vec<Token>, ShellError> {
+    let mut tokens = Vec::new();
+    let chars: Vec<char> = input.chars().collect();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        match c {
+            ' ' | '\t' => i += 1,
+            '+' => {
+                tokens.push(Token::Plus);
+                i += 1;
+            }
+            '-' => {
+                tokens.push(Token::Minus);
+                i += 1;
+            }
+            '*' => {
+                tokens.push(Token::Star);
+                i += 1;
+            }
+            '/' => {
+                tokens.push(Token::Slash);
+                i += 1;
+            }
+            '%' => {
+                tokens.push(Token::Percent);
+                i += 1;
+            }
+            '^' => {
+                tokens.push(Token::Caret);
+                i += 1;
+            }
+            '(' => {
+                tokens.push(Token::LParen);
+                i += 1;
+            }
+            ')' => {
+                tokens.push(Token::RParen);
+                i += 1;
+            }
+            ',' => {
+                tokens.push(Token::Comma);
+                i += 1;
+            }
+            c if c.is_ascii_digit() || c == '.' => {
+                let start = i;
+                while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                    i += 1;
+                }
+                let text: String = chars[start..i].iter().collect();
+                let n = text
+                    .parse::<f64>()
+                    .map_err(|_| bad_expression(input, head))?;
+                tokens.push(Token::Number(n));
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                let start = i;
+                while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                    i += 1;
+                }
+                let text: String = chars[start..i].iter().collect();
+                tokens.push(Token::Ident(text));
+            }
+            _ => return Err(bad_expression(input, head)),
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// A small recursive-descent expression evaluator, since `math eval` needs to parse and
+/// compute arbitrary arithmetic without pulling in the parser engine or a third-party crate.
+struct Parser<'a> {
+    tokens: &'a [Token],
+    pos: usize,
+    vars: &'a HashMap<String, f64>,
+    input: &'a str,
+    head: Span,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<&Token> {
+        let tok = self.tokens.get(self.pos);
+        self.pos += 1;
+        tok
+    }
+
+    fn error(&self) -> ShellError {
+        bad_expression(self.input, self.head)
+    }
+
+    // expr := term (('+' | '-') term)*
+    fn expr(&mut self) -> Result<f64, ShellError> {
+        let mut val = self.term()?;
+        loop {
+            match self.peek() {
+                Some(Token::Plus) => {
+                    self.pos += 1;
+                    val += self.term()?;
+                }
+                Some(Token::Minus) => {
+                    self.pos += 1;
+                    val -= self.term()?;
+                }
+                _ => break,
+            }
+        }
+        Ok(val)
+    }
+
+    // term := power (('*' | '/' | '%') power)*
+    fn term(&mut self) -> Result<f64, ShellError> {
+        let mut val = self.power()?;
+        loop {
+            match self.peek() {
+                Some(Token::Star) => {
+                    self.pos += 1;
+                    val *= self.power()?;
+                }
+                Some(Token::Slash) => {
+                    self.pos += 1;
+                    val /= self.power()?;
+                }
+                Some(Token::Percent) => {
+                    self.pos += 1;
+                    val %= self.power()?;
+                }
+                _ => break,
+            }
+        }
+        Ok(val)
+    }
+
+    // power := unary ('^' power)?     (right-associative)
+    fn power(&mut self) -> Result<f64, ShellError> {
+        let base = self.unary()?;
+        if let Some(Token::Caret) = self.peek() {
+            self.pos += 1;
+            let exp = self.power()?;
+            Ok(base.powf(exp))
+        } else {
+            Ok(base)
+        }
+    }
+
+    // unary := '-' unary | atom
+    fn unary(&mut self) -> Result<f64, ShellError> {
+        if let Some(Token::Minus) = self.peek() {
+            self.pos += 1;
+            return Ok(-self.unary()?);
+        }
+        self.atom()
+    }
+
+    // atom := number | ident ('(' expr ')')? | '(' expr ')'
+    fn atom(&mut self) -> Result<f64, ShellError> {
+        match self.next().cloned() {
+            Some(Token::Number(n)) => Ok(n),
+            Some(Token::LParen) => {
+                let val = self.expr()?;
+                match self.next() {
+                    Some(Token::RParen) => Ok(val),
+                    _ => Err(self.error()),
+                }
+            }
+            Some(Token::Ident(name)) => {
+                if let Some(Token::LParen) = self.peek() {
+                    self.pos += 1;
+                    let arg = self.expr()?;
+                    match self.next() {
+                        Some(Token::RParen) => apply_function(&name, arg, self),
+                        _ => Err(self.error()),
+                    }
+                } else {
+                    lookup(&name, self.vars).ok_or_else(|| self.error())
+                }
+            }
+            _ => Err(self.error()),
+        }
+    }
+}
+
+fn apply_function(name: &str, arg: f64, parser: &Parser<'_>) -> Result<f64, ShellError> {
+    match name {
+        "sqrt" => Ok(arg.sqrt()),
+        "abs" => Ok(arg.abs()),
+        "floor" => Ok(arg.floor()),
+        "ceil" => Ok(arg.ceil()),
+        "round" => Ok(arg.round()),
+        "ln" => Ok(arg.ln()),
+        "log" => Ok(arg.log10()),
+        "sin" => Ok(arg.sin()),
+        "cos" => Ok(arg.cos()),
+        "tan" => Ok(arg.tan()),
+        "asin" => Ok(arg.asin()),
+        "acos" => Ok(arg.acos()),
+        "atan" => Ok(arg.atan()),
+        _ => Err(parser.error()),
+    }
+}
+
+fn lookup(name: &str, vars: &HashMap<String, f64>) -> Option<f64> {
+    match name {
+        "pi" => Some(std::f64::consts::PI),
+        "e" => Some(std::f64::consts::E),
+        "tau" => Some(std::f64::consts::TAU),
+        _ => vars.get(name).copied(),
+    }
+}
+
+fn eval(input: &str, vars: &HashMap<String, f64>, head: Span) -> Result<f64, ShellError> {
+    let tokens = tokenize(input, head)?;
+    let mut parser = Parser {
+        tokens: &tokens,
+        pos: 0,
+        vars,
+        input,
+        head,
+    };
+    let result = parser.expr()?;
+    if parser.pos != tokens.len() {
+        return Err(bad_expression(input, head));
+    }
+    Ok(result)
+}
+
+fn bad_expression(text: &str, head: Span) -> ShellError {
+    ShellError::GenericError(
+        format!("'{text}' is not a valid math expression"),
+        "could not parse this expression".into(),
+        Some(head),
+        None,
+        Vec::new(),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
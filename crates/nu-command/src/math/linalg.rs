@@ -0,0 +1,59 @@
+use nu_protocol::{ShellError, Span, Value};
+
+/// Reads a `Value::List` of numbers into a plain `Vec<f64>`, shared by `math dot`, `math
+/// matmul`, and `math transpose`.
+pub(super) fn as_vector(value: &Value, head: Span) -> Result<Vec<f64>, ShellError> {
+    let Value::List { vals, .. } = value else {
+        return Err(not_numeric_list(head, value.expect_span()));
+    };
+
+    vals.iter()
+        .map(|v| match v {
+            Value::Int { val, .. } => Ok(*val as f64),
+            Value::Float { val, .. } => Ok(*val),
+            other => Err(not_numeric_list(head, other.expect_span())),
+        })
+        .collect()
+}
+
+/// Reads a `Value::List` of `Value::List`s of numbers into a `Vec<Vec<f64>>`, requiring every
+/// row to have the same length.
+pub(super) fn as_matrix(value: &Value, head: Span) -> Result<Vec<Vec<f64>>, ShellError> {
+    let Value::List { vals, .. } = value else {
+        return Err(not_numeric_matrix(head, value.expect_span()));
+    };
+
+    let rows: Vec<Vec<f64>> = vals
+        .iter()
+        .map(|row| as_vector(row, head))
+        .collect::<Result<_, _>>()?;
+
+    if let Some(width) = rows.first().map(Vec::len) {
+        if rows.iter().any(|row| row.len() != width) {
+            return Err(ShellError::IncompatibleParametersSingle {
+                msg: "every row of a matrix must have the same number of columns".into(),
+                span: value.expect_span(),
+            });
+        }
+    }
+
+    Ok(rows)
+}
+
+fn not_numeric_list(head: Span, span: Span) -> ShellError {
+    ShellError::UnsupportedInput(
+        "expected a list of numbers".into(),
+        "value originates from here".into(),
+        head,
+        span,
+    )
+}
+
+fn not_numeric_matrix(head: Span, span: Span) -> ShellError {
+    ShellError::UnsupportedInput(
+        "expected a list of lists of numbers".into(),
+        "value originates from here".into(),
+        head,
+        span,
+    )
+}
@@ -0,0 +1,116 @@
+use super::linalg::as_matrix;
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, SyntaxShape, Type,
+    Value,
+};
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "math matmul"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("math matmul")
+            .input_output_types(vec![(
+                Type::List(Box::new(Type::List(Box::new(Type::Number)))),
+                Type::List(Box::new(Type::List(Box::new(Type::Number)))),
+            )])
+            .required("other", SyntaxShape::Any, "the matrix to multiply by")
+            .category(Category::Math)
+    }
+
+    fn usage(&self) -> &str {
+        "Multiply two matrices, each given as a list of equal-length rows."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["vector", "linear algebra", "matrix"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let a = as_matrix(&input.into_value(head), head)?;
+        let other: Value = call.req(engine_state, stack, 0)?;
+        let b = as_matrix(&other, head)?;
+
+        let a_cols = a.first().map(Vec::len).unwrap_or(0);
+        let b_rows = b.len();
+
+        if a_cols != b_rows {
+            return Err(ShellError::IncompatibleParametersSingle {
+                msg: format!(
+                    "cannot multiply a matrix with {a_cols} columns by one with {b_rows} rows"
+                ),
+                span: other.expect_span(),
+            });
+        }
+
+        let b_cols = b.first().map(Vec::len).unwrap_or(0);
+
+        let product: Vec<Value> = a
+            .iter()
+            .map(|row| {
+                let result_row: Vec<Value> = (0..b_cols)
+                    .map(|j| {
+                        let sum: f64 = row.iter().enumerate().map(|(k, x)| x * b[k][j]).sum();
+                        Value::float(sum, head)
+                    })
+                    .collect();
+                Value::List {
+                    vals: result_row,
+                    span: head,
+                }
+            })
+            .collect();
+
+        Ok(Value::List {
+            vals: product,
+            span: head,
+        }
+        .into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Multiply a 2x2 matrix by another 2x2 matrix",
+            example: "[[1 2] [3 4]] | math matmul [[5 6] [7 8]]",
+            result: Some(Value::List {
+                vals: vec![
+                    Value::List {
+                        vals: vec![Value::test_float(19.0), Value::test_float(22.0)],
+                        span: nu_protocol::Span::test_data(),
+                    },
+                    Value::List {
+                        vals: vec![Value::test_float(43.0), Value::test_float(50.0)],
+                        span: nu_protocol::Span::test_data(),
+                    },
+                ],
+                span: nu_protocol::Span::test_data(),
+            }),
+        }]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
@@ -9,11 +9,15 @@ mod avg;
 mod ceil;
 mod cos;
 mod cosh;
+mod dot;
 mod euler;
+mod eval;
 mod floor;
+mod linalg;
 mod ln;
 mod log;
 pub mod math_;
+mod matmul;
 mod max;
 mod median;
 mod min;
@@ -25,19 +29,24 @@ mod round;
 mod sin;
 mod sinh;
 mod sqrt;
+mod stats;
 mod stddev;
 mod sum;
 mod tan;
 mod tanh;
 mod tau;
+mod transpose;
 mod utils;
 mod variance;
 
 pub use abs::SubCommand as MathAbs;
 pub use avg::SubCommand as MathAvg;
 pub use ceil::SubCommand as MathCeil;
+pub use dot::SubCommand as MathDot;
+pub use eval::SubCommand as MathEval;
 pub use floor::SubCommand as MathFloor;
 pub use math_::MathCommand as Math;
+pub use matmul::SubCommand as MathMatmul;
 pub use max::SubCommand as MathMax;
 pub use median::SubCommand as MathMedian;
 pub use min::SubCommand as MathMin;
@@ -45,8 +54,10 @@ pub use mode::SubCommand as MathMode;
 pub use product::SubCommand as MathProduct;
 pub use round::SubCommand as MathRound;
 pub use sqrt::SubCommand as MathSqrt;
+pub use stats::SubCommand as MathStats;
 pub use stddev::SubCommand as MathStddev;
 pub use sum::SubCommand as MathSum;
+pub use transpose::SubCommand as MathTranspose;
 pub use variance::SubCommand as MathVariance;
 
 pub use cos::SubCommand as MathCos;
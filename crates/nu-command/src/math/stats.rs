@@ -0,0 +1,224 @@
+use super::median::median;
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value,
+};
+
+use crate::math::utils::run_with_function;
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "math stats"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("math stats")
+            .input_output_types(vec![
+                (Type::List(Box::new(Type::Number)), Type::Record(vec![])),
+                (Type::Table(vec![]), Type::Record(vec![])),
+            ])
+            .switch(
+                "sample",
+                "calculate sample variance and standard deviation (i.e. using N-1 as the denominator)",
+                Some('s'),
+            )
+            .named(
+                "percentiles",
+                SyntaxShape::List(Box::new(SyntaxShape::Number)),
+                "additional percentiles (0-100) to include in the result, in order",
+                Some('p'),
+            )
+            .category(Category::Math)
+    }
+
+    fn usage(&self) -> &str {
+        "Compute count, mean, standard deviation, variance, min, max, and median for a list of numbers, or for each column in a table."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "This covers the same ground as chaining math avg, math stddev, math variance, math min, \
+math max and math median. Count, mean, variance, min and max come from one walk over the input; \
+the median and each requested percentile are then computed from their own sorted copy of it."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec![
+            "statistics",
+            "summary",
+            "describe",
+            "percentile",
+            "quantile",
+        ]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let sample = call.has_flag("sample");
+        let percentiles: Vec<f64> = call
+            .get_flag::<Vec<Value>>(engine_state, stack, "percentiles")?
+            .unwrap_or_default()
+            .iter()
+            .map(|v| v.as_float())
+            .collect::<Result<Vec<f64>, ShellError>>()?;
+
+        run_with_function(call, input, compute_stats(sample, percentiles))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Compute descriptive statistics for a list of numbers",
+                example: "[1 2 3 4 5] | math stats",
+                result: None,
+            },
+            Example {
+                description: "Also include the 90th percentile",
+                example: "[1 2 3 4 5 6 7 8 9 10] | math stats --percentiles [90]",
+                result: None,
+            },
+        ]
+    }
+}
+
+pub fn compute_stats(
+    sample: bool,
+    percentiles: Vec<f64>,
+) -> impl Fn(&[Value], Span, &Span) -> Result<Value, ShellError> {
+    move |values: &[Value], span: Span, head: &Span| {
+        if values.is_empty() {
+            return Err(ShellError::UnsupportedInput(
+                "Unable to give a result with this input".to_string(),
+                "value originates from here".into(),
+                *head,
+                span,
+            ));
+        }
+
+        // Welford's online algorithm: mean and the sum of squared differences from the
+        // running mean are both updated in a single pass, so variance/stddev don't need
+        // a second walk over the data once count and mean are known.
+        let mut count = 0u64;
+        let mut mean = 0.0;
+        let mut m2 = 0.0;
+        let mut min = f64::INFINITY;
+        let mut max = f64::NEG_INFINITY;
+
+        for value in values {
+            let x = value.as_float()?;
+            count += 1;
+            let delta = x - mean;
+            mean += delta / count as f64;
+            let delta2 = x - mean;
+            m2 += delta * delta2;
+            min = min.min(x);
+            max = max.max(x);
+        }
+
+        let denominator = if sample {
+            count.saturating_sub(1)
+        } else {
+            count
+        };
+        let variance = if denominator == 0 {
+            0.0
+        } else {
+            m2 / denominator as f64
+        };
+
+        let mut cols = vec![
+            "count".to_string(),
+            "mean".to_string(),
+            "stddev".to_string(),
+            "variance".to_string(),
+            "min".to_string(),
+            "max".to_string(),
+            "median".to_string(),
+        ];
+        let mut vals = vec![
+            Value::Int {
+                val: count as i64,
+                span: *head,
+            },
+            Value::Float {
+                val: mean,
+                span: *head,
+            },
+            Value::Float {
+                val: variance.sqrt(),
+                span: *head,
+            },
+            Value::Float {
+                val: variance,
+                span: *head,
+            },
+            Value::Float {
+                val: min,
+                span: *head,
+            },
+            Value::Float {
+                val: max,
+                span: *head,
+            },
+            median(values, span, head)?,
+        ];
+
+        for p in &percentiles {
+            cols.push(format!("p{}", p));
+            vals.push(Value::Float {
+                val: percentile(values, *p, head)?,
+                span: *head,
+            });
+        }
+
+        Ok(Value::Record {
+            cols,
+            vals,
+            span: *head,
+        })
+    }
+}
+
+fn percentile(values: &[Value], p: f64, head: &Span) -> Result<f64, ShellError> {
+    let mut sorted: Vec<f64> = values
+        .iter()
+        .map(|v| v.as_float())
+        .collect::<Result<Vec<f64>, ShellError>>()?;
+    sorted.sort_by(|a, b| a.total_cmp(b));
+
+    let rank = (p / 100.0) * (sorted.len() - 1) as f64;
+    let lower = rank.floor() as usize;
+    let upper = rank.ceil() as usize;
+    let Some(&lower_val) = sorted.get(lower) else {
+        return Err(ShellError::UnsupportedInput(
+            "Empty input".to_string(),
+            "value originates from here".into(),
+            *head,
+            *head,
+        ));
+    };
+    let upper_val = sorted.get(upper).copied().unwrap_or(lower_val);
+
+    Ok(lower_val + (upper_val - lower_val) * (rank - lower as f64))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
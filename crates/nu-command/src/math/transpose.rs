@@ -0,0 +1,96 @@
+use super::linalg::as_matrix;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "math transpose"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("math transpose")
+            .input_output_types(vec![(
+                Type::List(Box::new(Type::List(Box::new(Type::Number)))),
+                Type::List(Box::new(Type::List(Box::new(Type::Number)))),
+            )])
+            .category(Category::Math)
+    }
+
+    fn usage(&self) -> &str {
+        "Transpose a matrix given as a list of equal-length rows."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["vector", "linear algebra", "matrix"]
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let rows = as_matrix(&input.into_value(head), head)?;
+
+        let width = rows.first().map(Vec::len).unwrap_or(0);
+        let transposed: Vec<Value> = (0..width)
+            .map(|col| Value::List {
+                vals: rows
+                    .iter()
+                    .map(|row| Value::float(row[col], head))
+                    .collect(),
+                span: head,
+            })
+            .collect();
+
+        Ok(Value::List {
+            vals: transposed,
+            span: head,
+        }
+        .into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Transpose a 2x3 matrix into a 3x2 matrix",
+            example: "[[1 2 3] [4 5 6]] | math transpose",
+            result: Some(Value::List {
+                vals: vec![
+                    Value::List {
+                        vals: vec![Value::test_float(1.0), Value::test_float(4.0)],
+                        span: nu_protocol::Span::test_data(),
+                    },
+                    Value::List {
+                        vals: vec![Value::test_float(2.0), Value::test_float(5.0)],
+                        span: nu_protocol::Span::test_data(),
+                    },
+                    Value::List {
+                        vals: vec![Value::test_float(3.0), Value::test_float(6.0)],
+                        span: nu_protocol::Span::test_data(),
+                    },
+                ],
+                span: nu_protocol::Span::test_data(),
+            }),
+        }]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
@@ -1,4 +1,4 @@
-use crate::formats::value_to_json_value;
+use crate::formats::{decl_name_for_content_type, value_to_json_value};
 use base64::engine::general_purpose::PAD;
 use base64::engine::GeneralPurpose;
 use base64::{alphabet, Engine};
@@ -360,7 +360,8 @@ pub fn request_handle_response(
                         path_extension
                     }
                     _ => Some(content_type.subtype().to_string()),
-                };
+                }
+                .map(|ext| decl_name_for_content_type(&ext, engine_state.get_config()));
 
                 let output = response_to_buffer(resp, engine_state, span);
 
@@ -1,8 +1,16 @@
 mod http;
+mod ping;
 mod port;
+#[cfg(feature = "s3")]
+mod s3;
+mod send;
 mod url;
 
 pub use self::http::*;
 pub use self::url::*;
 
+pub use ping::Ping;
 pub use port::SubCommand as Port;
+#[cfg(feature = "s3")]
+pub use s3::{S3Get, S3Ls, S3Put, S3};
+pub use send::{Send, SendEmail};
@@ -0,0 +1,248 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, SyntaxShape,
+    Type, Value,
+};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::{
+    thread,
+    time::{Duration, Instant},
+};
+
+const CTRL_C_CHECK_INTERVAL: Duration = Duration::from_millis(100);
+const DEFAULT_COUNT: i64 = 4;
+const DEFAULT_INTERVAL: Duration = Duration::from_secs(1);
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(1);
+const DEFAULT_PORT: i64 = 80;
+
+#[derive(Clone)]
+pub struct Ping;
+
+impl Command for Ping {
+    fn name(&self) -> &str {
+        "ping"
+    }
+
+    fn usage(&self) -> &str {
+        "Probe a host and report latency records instead of scraped text."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "This sends TCP connect probes rather than raw ICMP echo requests, since ICMP needs a \
+privileged raw socket that isn't available to an unprivileged Nu process; `--port` picks the \
+port to connect to (default: 80). There is no hop-by-hop traceroute here for the same reason."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["icmp", "network", "latency", "traceroute"]
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("ping")
+            .input_output_types(vec![(Type::Nothing, Type::Table(vec![]))])
+            .allow_variants_without_examples(true)
+            .required("target", SyntaxShape::String, "hostname or IP to probe")
+            .named(
+                "port",
+                SyntaxShape::Int,
+                "TCP port to probe (default: 80)",
+                Some('P'),
+            )
+            .named(
+                "count",
+                SyntaxShape::Int,
+                "number of probes to send, or 0 for unlimited (default: 4)",
+                Some('c'),
+            )
+            .named(
+                "interval",
+                SyntaxShape::Duration,
+                "time to wait between probes (default: 1sec)",
+                Some('i'),
+            )
+            .named(
+                "timeout",
+                SyntaxShape::Duration,
+                "per-probe connect timeout (default: 1sec)",
+                Some('t'),
+            )
+            .switch(
+                "stream",
+                "emit each probe record as it completes instead of waiting for all of them",
+                Some('s'),
+            )
+            .category(Category::Network)
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let target: String = call.req(engine_state, stack, 0)?;
+        let port: Option<i64> = call.get_flag(engine_state, stack, "port")?;
+        let count: Option<i64> = call.get_flag(engine_state, stack, "count")?;
+        let interval: Option<i64> = call.get_flag(engine_state, stack, "interval")?;
+        let timeout: Option<i64> = call.get_flag(engine_state, stack, "timeout")?;
+        let stream = call.has_flag("stream");
+
+        let probe_iter = PingIterator {
+            target,
+            port: port.unwrap_or(DEFAULT_PORT) as u16,
+            remaining: match count.unwrap_or(DEFAULT_COUNT) {
+                0 => u64::MAX,
+                n => n.max(0) as u64,
+            },
+            interval: interval
+                .map(|i| Duration::from_nanos(i.max(0) as u64))
+                .unwrap_or(DEFAULT_INTERVAL),
+            timeout: timeout
+                .map(|t| Duration::from_nanos(t.max(0) as u64))
+                .unwrap_or(DEFAULT_TIMEOUT),
+            probe_number: 0,
+            first_probe: true,
+            span: call.head,
+            ctrlc: engine_state.ctrlc.clone(),
+        };
+
+        if stream {
+            Ok(PipelineData::ListStream(
+                nu_protocol::ListStream {
+                    stream: Box::new(probe_iter),
+                    ctrlc: engine_state.ctrlc.clone(),
+                },
+                None,
+            ))
+        } else {
+            Ok(Value::List {
+                vals: probe_iter.collect(),
+                span: call.head,
+            }
+            .into_pipeline_data())
+        }
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Send 4 TCP probes to example.com on port 443",
+                example: "ping example.com --port 443",
+                result: None,
+            },
+            Example {
+                description: "Stream probes to a host once a second, forever",
+                example: "ping 10.0.0.1 --count 0 --stream",
+                result: None,
+            },
+        ]
+    }
+}
+
+struct PingIterator {
+    target: String,
+    port: u16,
+    remaining: u64,
+    interval: Duration,
+    timeout: Duration,
+    probe_number: u64,
+    first_probe: bool,
+    span: Span,
+    ctrlc: Option<std::sync::Arc<std::sync::atomic::AtomicBool>>,
+}
+
+impl PingIterator {
+    fn ctrlc_pressed(&self) -> bool {
+        nu_utils::ctrl_c::was_pressed(&self.ctrlc)
+    }
+}
+
+impl Iterator for PingIterator {
+    type Item = Value;
+
+    fn next(&mut self) -> Option<Value> {
+        if self.remaining == 0 || self.ctrlc_pressed() {
+            return None;
+        }
+
+        if self.first_probe {
+            self.first_probe = false;
+        } else {
+            let mut waited = Duration::ZERO;
+            while waited < self.interval {
+                if self.ctrlc_pressed() {
+                    return None;
+                }
+                let chunk = CTRL_C_CHECK_INTERVAL.min(self.interval - waited);
+                thread::sleep(chunk);
+                waited += chunk;
+            }
+        }
+
+        self.probe_number += 1;
+        if self.remaining != u64::MAX {
+            self.remaining -= 1;
+        }
+
+        let target = format!("{}:{}", self.target, self.port);
+        let cols = vec![
+            "probe".to_string(),
+            "target".to_string(),
+            "port".to_string(),
+            "reachable".to_string(),
+            "latency_ms".to_string(),
+            "error".to_string(),
+        ];
+
+        let vals = match target.to_socket_addrs() {
+            Ok(mut addrs) => match addrs.next() {
+                Some(addr) => {
+                    let start = Instant::now();
+                    match TcpStream::connect_timeout(&addr, self.timeout) {
+                        Ok(_) => vec![
+                            Value::int(self.probe_number as i64, self.span),
+                            Value::string(self.target.clone(), self.span),
+                            Value::int(self.port as i64, self.span),
+                            Value::boolean(true, self.span),
+                            Value::float(start.elapsed().as_secs_f64() * 1000.0, self.span),
+                            Value::nothing(self.span),
+                        ],
+                        Err(e) => vec![
+                            Value::int(self.probe_number as i64, self.span),
+                            Value::string(self.target.clone(), self.span),
+                            Value::int(self.port as i64, self.span),
+                            Value::boolean(false, self.span),
+                            Value::nothing(self.span),
+                            Value::string(e.to_string(), self.span),
+                        ],
+                    }
+                }
+                None => vec![
+                    Value::int(self.probe_number as i64, self.span),
+                    Value::string(self.target.clone(), self.span),
+                    Value::int(self.port as i64, self.span),
+                    Value::boolean(false, self.span),
+                    Value::nothing(self.span),
+                    Value::string("could not resolve host", self.span),
+                ],
+            },
+            Err(e) => vec![
+                Value::int(self.probe_number as i64, self.span),
+                Value::string(self.target.clone(), self.span),
+                Value::int(self.port as i64, self.span),
+                Value::boolean(false, self.span),
+                Value::nothing(self.span),
+                Value::string(e.to_string(), self.span),
+            ],
+        };
+
+        Some(Value::Record {
+            cols,
+            vals,
+            span: self.span,
+        })
+    }
+}
@@ -0,0 +1,99 @@
+use nu_protocol::{BufferedReader, PipelineData, RawStream, ShellError, Span};
+use std::io::BufReader;
+use std::sync::{atomic::AtomicBool, Arc};
+use ureq::{Error, Response};
+
+pub const DEFAULT_ENDPOINT: &str = "https://s3.amazonaws.com";
+
+/// Build a path-style object URL: `{endpoint}/{bucket}/{key}`.
+pub fn object_url(endpoint: &str, bucket: &str, key: &str) -> String {
+    format!(
+        "{}/{}/{}",
+        endpoint.trim_end_matches('/'),
+        bucket.trim_matches('/'),
+        key.trim_start_matches('/')
+    )
+}
+
+/// Build the URL for a `GET ?list-type=2` bucket listing, optionally scoped to a prefix.
+pub fn list_url(endpoint: &str, bucket: &str, prefix: Option<&str>) -> String {
+    let base = format!(
+        "{}/{}?list-type=2",
+        endpoint.trim_end_matches('/'),
+        bucket.trim_matches('/')
+    );
+    match prefix {
+        Some(prefix) if !prefix.is_empty() => {
+            format!("{base}&prefix={}", urlencoding_encode(prefix))
+        }
+        _ => base,
+    }
+}
+
+// A minimal query-string encoder; S3 prefixes are usually plain object-key-safe text, but
+// this keeps anything exotic from corrupting the query string.
+fn urlencoding_encode(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                out.push(byte as char)
+            }
+            _ => out.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    out
+}
+
+pub fn handle_response_error(span: Span, url: &str, error: Error) -> ShellError {
+    match error {
+        Error::Status(code, response) => {
+            let body = response.into_string().unwrap_or_default();
+            ShellError::GenericError(
+                format!("S3 request to {url} failed with status {code}"),
+                body,
+                Some(span),
+                None,
+                Vec::new(),
+            )
+        }
+        Error::Transport(transport) => ShellError::GenericError(
+            format!("S3 request to {url} failed"),
+            transport.to_string(),
+            Some(span),
+            None,
+            Vec::new(),
+        ),
+    }
+}
+
+/// Stream an S3 response body into pipeline data, the same way `http get` streams its
+/// responses, without requiring `network::http::client`'s private helpers.
+pub fn response_to_stream(
+    response: Response,
+    ctrlc: Option<Arc<AtomicBool>>,
+    span: Span,
+) -> PipelineData {
+    let buffer_size = response
+        .header("content-length")
+        .and_then(|len| len.parse::<u64>().ok())
+        .filter(|len| *len > 0);
+
+    let buffered_input = BufReader::new(response.into_reader());
+
+    PipelineData::ExternalStream {
+        stdout: Some(RawStream::new(
+            Box::new(BufferedReader {
+                input: buffered_input,
+            }),
+            ctrlc,
+            span,
+            buffer_size,
+        )),
+        stderr: None,
+        exit_code: None,
+        span,
+        metadata: None,
+        trim_end_newline: false,
+    }
+}
@@ -0,0 +1,113 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{Category, Example, PipelineData, ShellError, Signature, SyntaxShape, Type};
+
+use super::client::{self, DEFAULT_ENDPOINT};
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "s3 get"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("s3 get")
+            .input_output_types(vec![(Type::Nothing, Type::Any)])
+            .optional(
+                "bucket",
+                SyntaxShape::String,
+                "the bucket to fetch from (not needed with --presigned)",
+            )
+            .optional(
+                "key",
+                SyntaxShape::String,
+                "the object key to fetch (not needed with --presigned)",
+            )
+            .named(
+                "endpoint",
+                SyntaxShape::String,
+                "the S3-compatible endpoint to use, or a full presigned URL via --presigned",
+                None,
+            )
+            .named(
+                "presigned",
+                SyntaxShape::String,
+                "fetch this presigned URL directly, ignoring bucket/key/endpoint",
+                None,
+            )
+            .category(Category::Network)
+    }
+
+    fn usage(&self) -> &str {
+        "Download an object from S3."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Only works against public or anonymously-readable objects, or a --presigned URL you \
+already have: this build does not vendor an HMAC/crypto-signing crate, so it cannot compute \
+AWS SigV4 signatures for private objects."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["aws", "bucket", "object storage", "cloud", "download"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let presigned: Option<String> = call.get_flag(engine_state, stack, "presigned")?;
+
+        let url = match presigned {
+            Some(url) => url,
+            None => {
+                let bucket: Option<String> = call.opt(engine_state, stack, 0)?;
+                let key: Option<String> = call.opt(engine_state, stack, 1)?;
+                let (bucket, key) = match (bucket, key) {
+                    (Some(bucket), Some(key)) => (bucket, key),
+                    _ => {
+                        return Err(ShellError::MissingParameter {
+                            param_name: "bucket and key (or --presigned)".into(),
+                            span: head,
+                        })
+                    }
+                };
+                let endpoint: Option<String> = call.get_flag(engine_state, stack, "endpoint")?;
+                let endpoint = endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_string());
+                client::object_url(&endpoint, &bucket, &key)
+            }
+        };
+
+        let response = ureq::get(&url)
+            .call()
+            .map_err(|e| client::handle_response_error(head, &url, e))?;
+
+        Ok(client::response_to_stream(
+            response,
+            engine_state.ctrlc.clone(),
+            head,
+        ))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Download a public object",
+                example: "s3 get my-public-bucket path/to/file.json",
+                result: None,
+            },
+            Example {
+                description: "Download through a presigned URL",
+                example: "s3 get --presigned $url | save file.json",
+                result: None,
+            },
+        ]
+    }
+}
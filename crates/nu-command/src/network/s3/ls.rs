@@ -0,0 +1,148 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, SyntaxShape,
+    Type, Value,
+};
+
+use super::client::{self, DEFAULT_ENDPOINT};
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "s3 ls"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("s3 ls")
+            .input_output_types(vec![(Type::Nothing, Type::Table(vec![]))])
+            .required("bucket", SyntaxShape::String, "the bucket to list")
+            .optional(
+                "prefix",
+                SyntaxShape::String,
+                "only list keys starting with this prefix",
+            )
+            .named(
+                "endpoint",
+                SyntaxShape::String,
+                "the S3-compatible endpoint to use (default: AWS)",
+                None,
+            )
+            .category(Category::Network)
+    }
+
+    fn usage(&self) -> &str {
+        "List the objects in an S3 bucket."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Only works against public or anonymously-readable buckets: this build does not \
+vendor an HMAC/crypto-signing crate, so it cannot compute AWS SigV4 signatures, which rules \
+out private-bucket listing with AWS credentials."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["aws", "bucket", "object storage", "cloud"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let bucket: String = call.req(engine_state, stack, 0)?;
+        let prefix: Option<String> = call.opt(engine_state, stack, 1)?;
+        let endpoint: Option<String> = call.get_flag(engine_state, stack, "endpoint")?;
+        let endpoint = endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_string());
+
+        let url = client::list_url(&endpoint, &bucket, prefix.as_deref());
+        let body = ureq::get(&url)
+            .call()
+            .map_err(|e| client::handle_response_error(head, &url, e))?
+            .into_string()
+            .map_err(|e| {
+                ShellError::GenericError(
+                    format!("could not read the response from {url}"),
+                    e.to_string(),
+                    Some(head),
+                    None,
+                    Vec::new(),
+                )
+            })?;
+
+        let entries = parse_list_bucket_result(&body, head)?;
+
+        Ok(Value::List {
+            vals: entries,
+            span: head,
+        }
+        .into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "List the objects in a public bucket",
+                example: "s3 ls my-public-bucket",
+                result: None,
+            },
+            Example {
+                description: "List objects under a prefix, against a non-AWS endpoint",
+                example: "s3 ls my-bucket logs/2023/ --endpoint https://s3.example.com",
+                result: None,
+            },
+        ]
+    }
+}
+
+/// Pull `key`/`size`/`last_modified` out of each `<Contents>` element of a
+/// `ListBucketResult` document; everything else in the schema (owner, storage class,
+/// continuation tokens, ...) is left unparsed since none of the other subcommands need it.
+fn parse_list_bucket_result(body: &str, span: Span) -> Result<Vec<Value>, ShellError> {
+    let doc = roxmltree::Document::parse(body).map_err(|e| {
+        ShellError::GenericError(
+            format!("could not parse the bucket listing as XML: {e}"),
+            "while listing this bucket".into(),
+            Some(span),
+            None,
+            Vec::new(),
+        )
+    })?;
+
+    let mut entries = Vec::new();
+    for contents in doc.descendants().filter(|n| n.has_tag_name("Contents")) {
+        let key = child_text(contents, "Key").unwrap_or_default();
+        let size = child_text(contents, "Size")
+            .and_then(|s| s.parse::<i64>().ok())
+            .unwrap_or(0);
+        let last_modified = child_text(contents, "LastModified").unwrap_or_default();
+
+        entries.push(Value::Record {
+            cols: vec!["key".into(), "size".into(), "last_modified".into()],
+            vals: vec![
+                Value::String { val: key, span },
+                Value::Filesize { val: size, span },
+                Value::String {
+                    val: last_modified,
+                    span,
+                },
+            ],
+            span,
+        });
+    }
+
+    Ok(entries)
+}
+
+fn child_text(node: roxmltree::Node, tag: &str) -> Option<String> {
+    node.children()
+        .find(|n| n.has_tag_name(tag))
+        .and_then(|n| n.text())
+        .map(|t| t.to_string())
+}
@@ -0,0 +1,10 @@
+mod client;
+mod get;
+mod ls;
+mod put;
+mod s3_;
+
+pub use get::SubCommand as S3Get;
+pub use ls::SubCommand as S3Ls;
+pub use put::SubCommand as S3Put;
+pub use s3_::S3;
@@ -0,0 +1,117 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, PipelineData, ShellError, Signature, SyntaxShape, Type, Value,
+};
+
+use super::client::{self, DEFAULT_ENDPOINT};
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "s3 put"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("s3 put")
+            .input_output_types(vec![(Type::Any, Type::Nothing)])
+            .optional(
+                "bucket",
+                SyntaxShape::String,
+                "the bucket to upload to (not needed with --presigned)",
+            )
+            .optional(
+                "key",
+                SyntaxShape::String,
+                "the object key to write (not needed with --presigned)",
+            )
+            .named(
+                "endpoint",
+                SyntaxShape::String,
+                "the S3-compatible endpoint to use, or a full presigned URL via --presigned",
+                None,
+            )
+            .named(
+                "presigned",
+                SyntaxShape::String,
+                "upload to this presigned URL directly, ignoring bucket/key/endpoint",
+                None,
+            )
+            .category(Category::Network)
+    }
+
+    fn usage(&self) -> &str {
+        "Upload input to an object in S3."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Only works against a bucket with public-write access, or a --presigned URL you already \
+have: this build does not vendor an HMAC/crypto-signing crate, so it cannot compute AWS SigV4 \
+signatures for authenticated uploads."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["aws", "bucket", "object storage", "cloud", "upload"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let presigned: Option<String> = call.get_flag(engine_state, stack, "presigned")?;
+
+        let url = match presigned {
+            Some(url) => url,
+            None => {
+                let bucket: Option<String> = call.opt(engine_state, stack, 0)?;
+                let key: Option<String> = call.opt(engine_state, stack, 1)?;
+                let (bucket, key) = match (bucket, key) {
+                    (Some(bucket), Some(key)) => (bucket, key),
+                    _ => {
+                        return Err(ShellError::MissingParameter {
+                            param_name: "bucket and key (or --presigned)".into(),
+                            span: head,
+                        })
+                    }
+                };
+                let endpoint: Option<String> = call.get_flag(engine_state, stack, "endpoint")?;
+                let endpoint = endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_string());
+                client::object_url(&endpoint, &bucket, &key)
+            }
+        };
+
+        let value = input.into_value(head);
+        let body = match &value {
+            Value::Binary { val, .. } => val.clone(),
+            other => other.as_string()?.into_bytes(),
+        };
+
+        ureq::put(&url)
+            .send_bytes(&body)
+            .map_err(|e| client::handle_response_error(head, &url, e))?;
+
+        Ok(PipelineData::Empty)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Upload a string to a publicly-writable bucket",
+                example: r#""hello" | s3 put my-bucket greeting.txt"#,
+                result: None,
+            },
+            Example {
+                description: "Upload a file's contents through a presigned URL",
+                example: "open --raw report.csv | s3 put --presigned $url",
+                result: None,
+            },
+        ]
+    }
+}
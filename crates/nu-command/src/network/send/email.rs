@@ -0,0 +1,376 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value,
+};
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpStream;
+use std::time::Duration;
+
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "send email"
+    }
+
+    fn usage(&self) -> &str {
+        "Send an email over SMTP."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Expects a `message` record with `to` (a string or list of strings), `from`, `subject`, \
+`body`, and an optional `attachments` list of records shaped like `{filename: ..., data: ...}` \
+where `data` is a binary value. There is no `--tls` support in this build, since no TLS crate \
+is vendored in this workspace; rather than silently sending your message in plaintext while \
+claiming it was encrypted, this command only ever speaks plaintext SMTP and says so."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["mail", "smtp", "notify"]
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("send email")
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .allow_variants_without_examples(true)
+            .required(
+                "message",
+                SyntaxShape::Record,
+                "a record describing the email to send",
+            )
+            .required_named(
+                "smtp-server",
+                SyntaxShape::String,
+                "the SMTP server to send through, as `host` or `host:port` (default port 25)",
+                Some('s'),
+            )
+            .named(
+                "user",
+                SyntaxShape::String,
+                "username to authenticate with via AUTH LOGIN",
+                Some('u'),
+            )
+            .named(
+                "password",
+                SyntaxShape::String,
+                "password to authenticate with via AUTH LOGIN",
+                Some('p'),
+            )
+            .category(Category::Network)
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let message: Value = call.req(engine_state, stack, 0)?;
+        let smtp_server: String = call
+            .get_flag(engine_state, stack, "smtp-server")?
+            .expect("required value");
+        let user: Option<String> = call.get_flag(engine_state, stack, "user")?;
+        let password: Option<String> = call.get_flag(engine_state, stack, "password")?;
+
+        let to = required_string_list(&message, "to", head)?;
+        let from = required_string(&message, "from", head)?;
+        let subject = required_string(&message, "subject", head)?;
+        let body = required_string(&message, "body", head)?;
+
+        reject_crlf(&from, "from", head)?;
+        for recipient in &to {
+            reject_crlf(recipient, "to", head)?;
+        }
+        reject_crlf(&subject, "subject", head)?;
+        let attachments = match message.get_data_by_key("attachments") {
+            Some(value) => collect_attachments(&value, head)?,
+            None => Vec::new(),
+        };
+
+        let (host, port) = match smtp_server.split_once(':') {
+            Some((host, port)) => (
+                host.to_string(),
+                port.parse::<u16>().map_err(|_| {
+                    ShellError::GenericError(
+                        format!("'{port}' is not a valid port number"),
+                        "invalid --smtp-server".into(),
+                        Some(head),
+                        None,
+                        Vec::new(),
+                    )
+                })?,
+            ),
+            None => (smtp_server.clone(), 25),
+        };
+
+        send_smtp(
+            &host,
+            port,
+            user.as_deref(),
+            password.as_deref(),
+            &from,
+            &to,
+            &subject,
+            &body,
+            &attachments,
+            head,
+        )?;
+
+        Ok(PipelineData::Empty)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Send a plain report email through a local relay",
+                example: r#"send email {to: "ops@example.com", from: "nu@example.com", subject: "nightly report", body: "all green"} --smtp-server localhost:25"#,
+                result: None,
+            },
+            Example {
+                description: "Send an email with a log file attached, authenticating first",
+                example: r#"send email {to: ["a@example.com", "b@example.com"], from: "nu@example.com", subject: "logs", body: "see attached", attachments: [{filename: "run.log", data: (open --raw run.log)}]} --smtp-server mail.example.com:587 --user nu --password $env.SMTP_PASSWORD"#,
+                result: None,
+            },
+        ]
+    }
+}
+
+struct Attachment {
+    filename: String,
+    data: Vec<u8>,
+}
+
+fn required_string(message: &Value, key: &str, span: Span) -> Result<String, ShellError> {
+    match message.get_data_by_key(key) {
+        Some(value) => value.as_string(),
+        None => Err(ShellError::CantFindColumn {
+            col_name: key.to_string(),
+            span,
+            src_span: message.span()?,
+        }),
+    }
+}
+
+fn required_string_list(message: &Value, key: &str, span: Span) -> Result<Vec<String>, ShellError> {
+    let value = message
+        .get_data_by_key(key)
+        .ok_or(ShellError::CantFindColumn {
+            col_name: key.to_string(),
+            span,
+            src_span: message.span()?,
+        })?;
+
+    match value.as_list() {
+        Ok(list) => list.iter().map(|item| item.as_string()).collect(),
+        Err(_) => Ok(vec![value.as_string()?]),
+    }
+}
+
+/// `from`, `to`, and `subject` are written verbatim into SMTP command lines (`MAIL
+/// FROM:<...>`, `RCPT TO:<...>`) and message headers. A value containing a CR or LF
+/// would inject extra SMTP commands or extra headers/recipients, so reject those
+/// values outright rather than trying to sanitize them.
+fn reject_crlf(value: &str, field: &str, span: Span) -> Result<(), ShellError> {
+    if value.contains('\r') || value.contains('\n') {
+        return Err(ShellError::GenericError(
+            format!("'{field}' cannot contain a carriage return or line feed"),
+            "invalid email field".into(),
+            Some(span),
+            None,
+            Vec::new(),
+        ));
+    }
+    Ok(())
+}
+
+fn collect_attachments(value: &Value, span: Span) -> Result<Vec<Attachment>, ShellError> {
+    let list = value.as_list()?;
+    list.iter()
+        .map(|item| {
+            let filename = required_string(item, "filename", span)?;
+            let data_value = item
+                .get_data_by_key("data")
+                .ok_or(ShellError::CantFindColumn {
+                    col_name: "data".to_string(),
+                    span,
+                    src_span: item.span()?,
+                })?;
+            let data = data_value.as_binary()?.to_vec();
+            Ok(Attachment { filename, data })
+        })
+        .collect()
+}
+
+fn smtp_error(context: &str, detail: impl std::fmt::Display, span: Span) -> ShellError {
+    ShellError::GenericError(
+        format!("SMTP error during {context}: {detail}"),
+        "while sending this email".into(),
+        Some(span),
+        None,
+        Vec::new(),
+    )
+}
+
+fn read_response(reader: &mut BufReader<TcpStream>, span: Span) -> Result<String, ShellError> {
+    let mut full = String::new();
+    loop {
+        let mut line = String::new();
+        reader
+            .read_line(&mut line)
+            .map_err(|e| smtp_error("reading server response", e, span))?;
+        if line.is_empty() {
+            return Err(smtp_error(
+                "reading server response",
+                "connection closed",
+                span,
+            ));
+        }
+        full.push_str(&line);
+        // A multi-line reply continues with "CODE-", the final line has "CODE ".
+        let continues = line.len() > 3 && line.as_bytes()[3] == b'-';
+        if !continues {
+            break;
+        }
+    }
+    if !full.starts_with(|c: char| c.is_ascii_digit())
+        || full.starts_with('4')
+        || full.starts_with('5')
+    {
+        return Err(smtp_error("talking to the server", full.trim_end(), span));
+    }
+    Ok(full)
+}
+
+fn send_command(
+    stream: &mut TcpStream,
+    reader: &mut BufReader<TcpStream>,
+    command: &str,
+    span: Span,
+) -> Result<String, ShellError> {
+    stream
+        .write_all(format!("{command}\r\n").as_bytes())
+        .map_err(|e| smtp_error("sending a command", e, span))?;
+    read_response(reader, span)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn send_smtp(
+    host: &str,
+    port: u16,
+    user: Option<&str>,
+    password: Option<&str>,
+    from: &str,
+    to: &[String],
+    subject: &str,
+    body: &str,
+    attachments: &[Attachment],
+    span: Span,
+) -> Result<(), ShellError> {
+    let stream = TcpStream::connect((host, port))
+        .map_err(|e| smtp_error(&format!("connecting to {host}:{port}"), e, span))?;
+    stream
+        .set_read_timeout(Some(CONNECT_TIMEOUT))
+        .map_err(|e| smtp_error("configuring the connection", e, span))?;
+    let mut write_stream = stream
+        .try_clone()
+        .map_err(|e| smtp_error("configuring the connection", e, span))?;
+    let mut reader = BufReader::new(stream);
+
+    read_response(&mut reader, span)?; // greeting
+    send_command(&mut write_stream, &mut reader, "EHLO localhost", span)?;
+
+    if let (Some(user), Some(password)) = (user, password) {
+        send_command(&mut write_stream, &mut reader, "AUTH LOGIN", span)?;
+        send_command(&mut write_stream, &mut reader, &STANDARD.encode(user), span)?;
+        send_command(
+            &mut write_stream,
+            &mut reader,
+            &STANDARD.encode(password),
+            span,
+        )?;
+    }
+
+    send_command(
+        &mut write_stream,
+        &mut reader,
+        &format!("MAIL FROM:<{from}>"),
+        span,
+    )?;
+    for recipient in to {
+        send_command(
+            &mut write_stream,
+            &mut reader,
+            &format!("RCPT TO:<{recipient}>"),
+            span,
+        )?;
+    }
+    send_command(&mut write_stream, &mut reader, "DATA", span)?;
+
+    let message = build_message(from, to, subject, body, attachments);
+    write_stream
+        .write_all(message.as_bytes())
+        .map_err(|e| smtp_error("sending the message body", e, span))?;
+    read_response(&mut reader, span)?;
+
+    send_command(&mut write_stream, &mut reader, "QUIT", span)?;
+    Ok(())
+}
+
+fn build_message(
+    from: &str,
+    to: &[String],
+    subject: &str,
+    body: &str,
+    attachments: &[Attachment],
+) -> String {
+    let boundary = "nu-send-email-boundary";
+    let mut message = String::new();
+    message.push_str(&format!("From: {from}\r\n"));
+    message.push_str(&format!("To: {}\r\n", to.join(", ")));
+    message.push_str(&format!("Subject: {subject}\r\n"));
+    message.push_str("MIME-Version: 1.0\r\n");
+
+    if attachments.is_empty() {
+        message.push_str("Content-Type: text/plain; charset=utf-8\r\n\r\n");
+        message.push_str(body);
+        message.push_str("\r\n");
+    } else {
+        message.push_str(&format!(
+            "Content-Type: multipart/mixed; boundary=\"{boundary}\"\r\n\r\n"
+        ));
+        message.push_str(&format!("--{boundary}\r\n"));
+        message.push_str("Content-Type: text/plain; charset=utf-8\r\n\r\n");
+        message.push_str(body);
+        message.push_str("\r\n");
+        for attachment in attachments {
+            message.push_str(&format!("--{boundary}\r\n"));
+            message.push_str("Content-Type: application/octet-stream\r\n");
+            message.push_str(&format!(
+                "Content-Disposition: attachment; filename=\"{}\"\r\n",
+                attachment.filename
+            ));
+            message.push_str("Content-Transfer-Encoding: base64\r\n\r\n");
+            message.push_str(&STANDARD.encode(&attachment.data));
+            message.push_str("\r\n");
+        }
+        message.push_str(&format!("--{boundary}--\r\n"));
+    }
+
+    // Lines consisting of a single "." must be escaped per RFC 5321 before the
+    // terminating "\r\n.\r\n" is appended.
+    let escaped: String = message
+        .split("\r\n")
+        .map(|line| if line == "." { ".." } else { line })
+        .collect::<Vec<_>>()
+        .join("\r\n");
+
+    format!("{escaped}\r\n.\r\n")
+}
@@ -0,0 +1,5 @@
+mod email;
+mod send_;
+
+pub use email::SubCommand as SendEmail;
+pub use send_::Send;
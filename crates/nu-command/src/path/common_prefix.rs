@@ -0,0 +1,118 @@
+use std::path::PathBuf;
+
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{Example, PipelineData, ShellError, Signature, Type, Value};
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "path common-prefix"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("path common-prefix")
+            .input_output_types(vec![(Type::List(Box::new(Type::String)), Type::String)])
+    }
+
+    fn usage(&self) -> &str {
+        "Find the longest common path prefix shared by a list of paths."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Compares paths component by component, so it never produces a prefix that
+splits a path in the middle of a file or directory name. This is purely
+lexical and does not require the paths to exist."#
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let (values, span) = match input {
+            PipelineData::Value(Value::List { vals, span }, ..) => (vals, span),
+            PipelineData::Empty => return Err(ShellError::PipelineEmpty { dst_span: head }),
+            other => {
+                return Err(ShellError::OnlySupportsThisInputType {
+                    exp_input_type: "list of string".into(),
+                    wrong_type: other.get_type().to_string(),
+                    dst_span: head,
+                    src_span: other.span().unwrap_or(head),
+                })
+            }
+        };
+
+        let paths = values
+            .iter()
+            .map(|v| v.as_string().map(PathBuf::from))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(PipelineData::Value(
+            Value::string(common_prefix(&paths).to_string_lossy(), span),
+            None,
+        ))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Find the common prefix of a list of paths",
+            example: r#"[ /home/viking/spam /home/viking/eggs /home/viking/bacon/spam ] | path common-prefix"#,
+            result: Some(Value::test_string("/home/viking")),
+        }]
+    }
+}
+
+fn common_prefix(paths: &[PathBuf]) -> PathBuf {
+    let mut iter = paths.iter();
+    let Some(first) = iter.next() else {
+        return PathBuf::new();
+    };
+
+    let mut prefix: Vec<_> = first.components().collect();
+
+    for path in iter {
+        let components: Vec<_> = path.components().collect();
+        let len = prefix
+            .iter()
+            .zip(components.iter())
+            .take_while(|(a, b)| a == b)
+            .count();
+        prefix.truncate(len);
+    }
+
+    prefix.into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn finds_common_prefix() {
+        let paths = vec![
+            PathBuf::from("/home/viking/spam"),
+            PathBuf::from("/home/viking/eggs"),
+            PathBuf::from("/home/viking/bacon/spam"),
+        ];
+        assert_eq!(common_prefix(&paths), PathBuf::from("/home/viking"));
+    }
+
+    #[test]
+    fn no_common_prefix() {
+        let paths = vec![PathBuf::from("/home/viking"), PathBuf::from("relative")];
+        assert_eq!(common_prefix(&paths), PathBuf::new());
+    }
+}
@@ -0,0 +1,102 @@
+use std::path::Path;
+
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value};
+
+use super::PathSubcommandArguments;
+
+struct Arguments {
+    columns: Option<Vec<String>>,
+}
+
+impl PathSubcommandArguments for Arguments {
+    fn get_columns(&self) -> Option<Vec<String>> {
+        self.columns.clone()
+    }
+}
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "path escape-glob"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("path escape-glob")
+            .input_output_types(vec![
+                (Type::String, Type::String),
+                (Type::Table(vec![]), Type::Table(vec![])),
+            ])
+            .named(
+                "columns",
+                SyntaxShape::Table,
+                "For a record or table input, escape strings at the given columns",
+                Some('c'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Escape glob-special characters in a path so it matches itself literally."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Backslash-escapes the characters *, ?, [, ], and the backslash itself, so that the result can be safely passed back into a glob-aware command such as `ls` or `glob`."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let args = Arguments {
+            columns: call.get_flag(engine_state, stack, "columns")?,
+        };
+
+        if matches!(input, PipelineData::Empty) {
+            return Err(ShellError::PipelineEmpty { dst_span: head });
+        }
+        input.map(
+            move |value| super::operate(&escape_glob, &args, value, head),
+            engine_state.ctrlc.clone(),
+        )
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Escape a path containing glob-special characters",
+            example: "'file[1].txt' | path escape-glob",
+            result: Some(Value::test_string("file\\[1\\].txt")),
+        }]
+    }
+}
+
+fn escape_glob(path: &Path, span: Span, _args: &Arguments) -> Value {
+    let mut escaped = String::new();
+    for c in path.to_string_lossy().chars() {
+        if matches!(c, '*' | '?' | '[' | ']' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+
+    Value::string(escaped, span)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
@@ -1,26 +1,36 @@
 mod basename;
+mod common_prefix;
 mod dirname;
+mod escape_glob;
 mod exists;
 mod expand;
 mod join;
+mod normalize;
 mod parse;
 pub mod path_;
 mod relative_to;
 mod split;
+mod to_unix;
+mod to_windows;
 mod r#type;
 
 use std::path::Path as StdPath;
 
 pub use basename::SubCommand as PathBasename;
+pub use common_prefix::SubCommand as PathCommonPrefix;
 pub use dirname::SubCommand as PathDirname;
+pub use escape_glob::SubCommand as PathEscapeGlob;
 pub use exists::SubCommand as PathExists;
 pub use expand::SubCommand as PathExpand;
 pub use join::SubCommand as PathJoin;
+pub use normalize::SubCommand as PathNormalize;
 pub use parse::SubCommand as PathParse;
 pub use path_::PathCommand as Path;
 pub use r#type::SubCommand as PathType;
 pub use relative_to::SubCommand as PathRelativeTo;
 pub use split::SubCommand as PathSplit;
+pub use to_unix::SubCommand as PathToUnix;
+pub use to_windows::SubCommand as PathToWindows;
 
 use nu_protocol::{ShellError, Span, Value};
 
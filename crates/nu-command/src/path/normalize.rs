@@ -0,0 +1,139 @@
+use std::path::{Component, Path, PathBuf};
+
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{EngineState, Stack};
+use nu_protocol::{
+    engine::Command, Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value,
+};
+
+use super::PathSubcommandArguments;
+
+struct Arguments {
+    columns: Option<Vec<String>>,
+}
+
+impl PathSubcommandArguments for Arguments {
+    fn get_columns(&self) -> Option<Vec<String>> {
+        self.columns.clone()
+    }
+}
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "path normalize"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("path normalize")
+            .input_output_types(vec![
+                (Type::String, Type::String),
+                (
+                    Type::List(Box::new(Type::String)),
+                    Type::List(Box::new(Type::String)),
+                ),
+            ])
+            .named(
+                "columns",
+                SyntaxShape::Table,
+                "For a record or table input, normalize strings at the given columns",
+                Some('c'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Normalize a path without accessing the filesystem."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Collapses repeated separators and resolves "." and ".." components lexically,
+keeping leading ".." components for relative paths that climb above their
+starting point. Unlike `path expand`, it never looks at the filesystem, so it
+can be used on paths that don't exist yet."#
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let args = Arguments {
+            columns: call.get_flag(engine_state, stack, "columns")?,
+        };
+
+        if matches!(input, PipelineData::Empty) {
+            return Err(ShellError::PipelineEmpty { dst_span: head });
+        }
+        input.map(
+            move |value| super::operate(&normalize, &args, value, head),
+            engine_state.ctrlc.clone(),
+        )
+    }
+
+    #[cfg(windows)]
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Normalize a path that has redundant components",
+            example: r"'C:\Users\..\Users\viking\.\spam.txt' | path normalize",
+            result: Some(Value::test_string(r"C:\Users\viking\spam.txt")),
+        }]
+    }
+
+    #[cfg(not(windows))]
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Normalize a path that has redundant components",
+                example: "'/home/viking/../viking/./spam.txt' | path normalize",
+                result: Some(Value::test_string("/home/viking/spam.txt")),
+            },
+            Example {
+                description: "Normalize a relative path that climbs above its start",
+                example: "'foo/../../bar' | path normalize",
+                result: Some(Value::test_string("../bar")),
+            },
+        ]
+    }
+}
+
+fn normalize(path: &Path, span: Span, _args: &Arguments) -> Value {
+    let mut result = PathBuf::new();
+
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => match result.components().next_back() {
+                Some(Component::Normal(_)) => {
+                    result.pop();
+                }
+                Some(Component::ParentDir) | None => result.push(".."),
+                _ => {}
+            },
+            other => result.push(other.as_os_str()),
+        }
+    }
+
+    if result.as_os_str().is_empty() {
+        result.push(".");
+    }
+
+    Value::string(result.to_string_lossy(), span)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
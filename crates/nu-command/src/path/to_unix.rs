@@ -0,0 +1,102 @@
+use std::path::Path;
+
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value};
+
+use super::PathSubcommandArguments;
+
+struct Arguments {
+    columns: Option<Vec<String>>,
+}
+
+impl PathSubcommandArguments for Arguments {
+    fn get_columns(&self) -> Option<Vec<String>> {
+        self.columns.clone()
+    }
+}
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "path to-unix"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("path to-unix")
+            .input_output_types(vec![
+                (Type::String, Type::String),
+                (Type::Table(vec![]), Type::Table(vec![])),
+            ])
+            .named(
+                "columns",
+                SyntaxShape::Table,
+                "For a record or table input, convert strings at the given columns",
+                Some('c'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Convert a path to use Unix-style forward-slash separators."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Every backslash becomes a forward slash, so a leading `\\\\host\\share` UNC path comes out as `//host/share`."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let args = Arguments {
+            columns: call.get_flag(engine_state, stack, "columns")?,
+        };
+
+        if matches!(input, PipelineData::Empty) {
+            return Err(ShellError::PipelineEmpty { dst_span: head });
+        }
+        input.map(
+            move |value| super::operate(&to_unix, &args, value, head),
+            engine_state.ctrlc.clone(),
+        )
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Convert a Windows-style path to Unix style",
+                example: "'C:\\Users\\joe\\test.txt' | path to-unix",
+                result: Some(Value::test_string("C:/Users/joe/test.txt")),
+            },
+            Example {
+                description: "Convert a UNC-style network path",
+                example: "'\\\\server\\share\\file.txt' | path to-unix",
+                result: Some(Value::test_string("//server/share/file.txt")),
+            },
+        ]
+    }
+}
+
+fn to_unix(path: &Path, span: Span, _args: &Arguments) -> Value {
+    let path = path.to_string_lossy();
+    Value::string(path.replace('\\', "/"), span)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
@@ -0,0 +1,91 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, SyntaxShape,
+    Type, Value,
+};
+
+#[derive(Clone)]
+pub struct DirsApp;
+
+impl Command for DirsApp {
+    fn name(&self) -> &str {
+        "dirs app"
+    }
+
+    fn usage(&self) -> &str {
+        "Return the platform-appropriate config, cache, data, and runtime directories for a named application."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Follows the XDG base directory spec on Linux and the platform conventions `dirs-next`
+already uses for the base directories elsewhere in the binary (e.g. `~/Library/...` on macOS,
+`%APPDATA%`/`%LOCALAPPDATA%` on Windows). `runtime` is only ever populated from `$XDG_RUNTIME_DIR`,
+which isn't set on macOS or Windows, so it's `null` there instead of guessing at a substitute."
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("dirs app")
+            .input_output_types(vec![(Type::Nothing, Type::Record(vec![]))])
+            .required(
+                "name",
+                SyntaxShape::String,
+                "the name of the application, used as the final path component",
+            )
+            .category(Category::Platform)
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let name: String = call.req(engine_state, stack, 0)?;
+
+        let config = nu_path::config_dir().map(|dir| dir.join(&name));
+        let cache = nu_path::cache_dir().map(|dir| dir.join(&name));
+        let data = nu_path::data_dir().map(|dir| dir.join(&name));
+        let runtime = std::env::var_os("XDG_RUNTIME_DIR")
+            .map(std::path::PathBuf::from)
+            .map(|dir| dir.join(&name));
+
+        Ok(Value::Record {
+            cols: vec![
+                "config".into(),
+                "cache".into(),
+                "data".into(),
+                "runtime".into(),
+            ],
+            vals: vec![
+                path_or_nothing(config, span),
+                path_or_nothing(cache, span),
+                path_or_nothing(data, span),
+                path_or_nothing(runtime, span),
+            ],
+            span,
+        }
+        .into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Get the preferred directories for an application named 'my-tool'",
+            example: "dirs app my-tool",
+            result: None,
+        }]
+    }
+}
+
+fn path_or_nothing(path: Option<std::path::PathBuf>, span: Span) -> Value {
+    match path {
+        Some(path) => Value::String {
+            val: path.to_string_lossy().into_owned(),
+            span,
+        },
+        None => Value::nothing(span),
+    }
+}
@@ -0,0 +1,143 @@
+use dialoguer::{Input, Select};
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, SyntaxShape,
+    Type, Value,
+};
+
+#[derive(Clone)]
+pub struct Form;
+
+impl Command for Form {
+    fn name(&self) -> &str {
+        "form"
+    }
+
+    fn usage(&self) -> &str {
+        "Render an interactive form from a record schema and return the filled-in record."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Each field in the schema record is either a type name (`string`, `int`, `float`, `bool`)
+for a free-text prompt, or a list of strings offered as a single-choice menu. Text entered for
+`int`/`float`/`bool` fields is validated and reprompted on parse failure; menu choices can't be
+invalid by construction."
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("form")
+            .input_output_types(vec![(Type::Nothing, Type::Record(vec![]))])
+            .required(
+                "schema",
+                SyntaxShape::Record,
+                "a record mapping field names to a type name or a list of choices",
+            )
+            .category(Category::Platform)
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let schema: Value = call.req(engine_state, stack, 0)?;
+        let (cols, vals) = match schema {
+            Value::Record { cols, vals, .. } => (cols, vals),
+            other => {
+                return Err(ShellError::UnsupportedInput(
+                    "form's schema must be a record".into(),
+                    "value originates from here".into(),
+                    span,
+                    other.expect_span(),
+                ))
+            }
+        };
+
+        let mut out_cols = vec![];
+        let mut out_vals = vec![];
+        for (name, field) in cols.into_iter().zip(vals) {
+            let value = prompt_field(&name, &field, span)?;
+            out_cols.push(name);
+            out_vals.push(value);
+        }
+
+        Ok(Value::Record {
+            cols: out_cols,
+            vals: out_vals,
+            span,
+        }
+        .into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Prompt for a name, an age, and a size chosen from a fixed list",
+            example: "form {name: string, age: int, size: [small medium large]}",
+            result: None,
+        }]
+    }
+}
+
+fn prompt_field(name: &str, field: &Value, span: Span) -> Result<Value, ShellError> {
+    match field {
+        Value::List { vals: choices, .. } => {
+            let labels: Vec<String> = choices
+                .iter()
+                .map(|choice| choice.into_string(", ", &nu_protocol::Config::default()))
+                .collect();
+            let selection = Select::new()
+                .with_prompt(name)
+                .items(&labels)
+                .default(0)
+                .interact()
+                .map_err(|e| io_err(e, span))?;
+            Ok(Value::String {
+                val: labels[selection].clone(),
+                span,
+            })
+        }
+        Value::String { val: type_name, .. } => match type_name.as_str() {
+            "string" => Input::<String>::new()
+                .with_prompt(name)
+                .interact_text()
+                .map(|val| Value::String { val, span })
+                .map_err(|e| io_err(e, span)),
+            "int" => Input::<i64>::new()
+                .with_prompt(name)
+                .interact_text()
+                .map(|val| Value::Int { val, span })
+                .map_err(|e| io_err(e, span)),
+            "float" => Input::<f64>::new()
+                .with_prompt(name)
+                .interact_text()
+                .map(|val| Value::Float { val, span })
+                .map_err(|e| io_err(e, span)),
+            "bool" => Input::<bool>::new()
+                .with_prompt(name)
+                .interact_text()
+                .map(|val| Value::Bool { val, span })
+                .map_err(|e| io_err(e, span)),
+            other => Err(ShellError::UnsupportedInput(
+                format!("unknown form field type '{other}', expected string, int, float, or bool"),
+                "value originates from here".into(),
+                span,
+                field.expect_span(),
+            )),
+        },
+        other => Err(ShellError::UnsupportedInput(
+            "each form field must be a type name or a list of choices".into(),
+            "value originates from here".into(),
+            span,
+            other.expect_span(),
+        )),
+    }
+}
+
+fn io_err(error: std::io::Error, span: Span) -> ShellError {
+    ShellError::IOErrorSpanned(format!("failed to read form input: {error}"), span)
+}
@@ -1,19 +1,35 @@
 mod ansi;
 mod clear;
 mod dir_info;
+mod dirs_app;
 mod du;
+mod form;
 mod input;
 mod kill;
+mod poll;
 mod reedline_commands;
+mod say;
+#[cfg(all(target_os = "linux", feature = "service"))]
+mod service;
 mod sleep;
+mod term_bell;
 mod term_size;
+mod ulimit;
 
 pub use ansi::{Ansi, AnsiGradient, AnsiLink, AnsiStrip};
 pub use clear::Clear;
 pub use dir_info::{DirBuilder, DirInfo, FileInfo};
+pub use dirs_app::DirsApp;
 pub use du::Du;
+pub use form::Form;
 pub use input::Input;
 pub use kill::Kill;
+pub use poll::Poll;
 pub use reedline_commands::{Keybindings, KeybindingsDefault, KeybindingsList, KeybindingsListen};
+pub use say::Say;
+#[cfg(all(target_os = "linux", feature = "service"))]
+pub use service::{Service, ServiceList, ServiceStatus};
 pub use sleep::Sleep;
+pub use term_bell::TermBell;
 pub use term_size::TermSize;
+pub use ulimit::Ulimit;
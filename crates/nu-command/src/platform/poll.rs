@@ -0,0 +1,221 @@
+use nu_engine::{eval_block_with_early_return, CallExt};
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Closure, Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value,
+};
+use std::{thread, time::Duration, time::Instant};
+
+const CTRL_C_CHECK_INTERVAL: Duration = Duration::from_millis(100);
+const DEFAULT_INTERVAL: Duration = Duration::from_secs(1);
+
+#[derive(Clone)]
+pub struct Poll;
+
+impl Command for Poll {
+    fn name(&self) -> &str {
+        "poll"
+    }
+
+    fn usage(&self) -> &str {
+        "Repeatedly run a closure at an interval, streaming each sample, until a predicate passes or a timeout elapses."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "The standard \"wait for service healthy\" primitive: run `closure` once per tick, emit its result as a stream item, and keep going until `--until` returns true for the latest sample (or `--timeout` runs out). Responds to ctrl-c immediately."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["watch", "wait", "retry", "healthcheck"]
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("poll")
+            .input_output_types(vec![(Type::Nothing, Type::Table(vec![]))])
+            .allow_variants_without_examples(true)
+            .required(
+                "closure",
+                SyntaxShape::Closure(Some(vec![])),
+                "the closure to run on each tick, producing one sample",
+            )
+            .named(
+                "interval",
+                SyntaxShape::Duration,
+                "how long to wait between samples (default: 1sec)",
+                Some('i'),
+            )
+            .named(
+                "until",
+                SyntaxShape::Closure(Some(vec![SyntaxShape::Any])),
+                "a predicate, given the latest sample, that stops polling once it returns true",
+                Some('u'),
+            )
+            .named(
+                "timeout",
+                SyntaxShape::Duration,
+                "stop polling after this much total time has elapsed, even if `--until` never passes",
+                Some('t'),
+            )
+            .category(Category::Platform)
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let closure: Closure = call.req(engine_state, stack, 0)?;
+        let until: Option<Closure> = call.get_flag(engine_state, stack, "until")?;
+        let interval: Option<i64> = call.get_flag(engine_state, stack, "interval")?;
+        let timeout: Option<i64> = call.get_flag(engine_state, stack, "timeout")?;
+
+        let interval = interval
+            .map(|i| Duration::from_nanos(i.max(0) as u64))
+            .unwrap_or(DEFAULT_INTERVAL);
+        let timeout = timeout.map(|t| Duration::from_nanos(t.max(0) as u64));
+
+        let ctrlc = engine_state.ctrlc.clone();
+        let engine_state = engine_state.clone();
+        let block = engine_state.get_block(closure.block_id).clone();
+        let body_stack = stack.captures_to_stack(&closure.captures);
+
+        let until = until.map(|until| {
+            let until_block = engine_state.get_block(until.block_id).clone();
+            let until_stack = stack.captures_to_stack(&until.captures);
+            (until_block, until_stack)
+        });
+
+        let poll_iter = PollIterator {
+            engine_state,
+            body_stack,
+            block,
+            until,
+            interval,
+            timeout,
+            start: Instant::now(),
+            first_tick: true,
+            done: false,
+            span: call.head,
+            redirect_stdout: call.redirect_stdout,
+            redirect_stderr: call.redirect_stderr,
+        };
+
+        Ok(PipelineData::ListStream(
+            nu_protocol::ListStream {
+                stream: Box::new(poll_iter),
+                ctrlc,
+            },
+            None,
+        ))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Poll a health-check closure every 5 seconds for up to a minute",
+                example: "poll --interval 5sec --timeout 60sec --until {|x| $x.status == \"healthy\"} { check-service }",
+                result: None,
+            },
+            Example {
+                description: "Sample the current time once a second, forever",
+                example: "poll { date now }",
+                result: None,
+            },
+        ]
+    }
+}
+
+struct PollIterator {
+    engine_state: EngineState,
+    body_stack: Stack,
+    block: nu_protocol::engine::Block,
+    until: Option<(nu_protocol::engine::Block, Stack)>,
+    interval: Duration,
+    timeout: Option<Duration>,
+    start: Instant,
+    first_tick: bool,
+    done: bool,
+    span: Span,
+    redirect_stdout: bool,
+    redirect_stderr: bool,
+}
+
+impl PollIterator {
+    fn ctrlc_pressed(&self) -> bool {
+        nu_utils::ctrl_c::was_pressed(&self.engine_state.ctrlc)
+    }
+
+    fn timed_out(&self) -> bool {
+        self.timeout
+            .map(|timeout| self.start.elapsed() >= timeout)
+            .unwrap_or(false)
+    }
+}
+
+impl Iterator for PollIterator {
+    type Item = Value;
+
+    fn next(&mut self) -> Option<Value> {
+        if self.done || self.timed_out() {
+            return None;
+        }
+
+        if self.first_tick {
+            self.first_tick = false;
+        } else {
+            let mut waited = Duration::ZERO;
+            while waited < self.interval {
+                if self.timed_out() || self.ctrlc_pressed() {
+                    return None;
+                }
+                let chunk = CTRL_C_CHECK_INTERVAL.min(self.interval - waited);
+                thread::sleep(chunk);
+                waited += chunk;
+            }
+        }
+
+        if self.ctrlc_pressed() {
+            return None;
+        }
+
+        let sample = match eval_block_with_early_return(
+            &self.engine_state,
+            &mut self.body_stack,
+            &self.block,
+            PipelineData::Empty,
+            self.redirect_stdout,
+            self.redirect_stderr,
+        ) {
+            Ok(data) => data.into_value(self.span),
+            Err(error) => Value::Error { error },
+        };
+
+        if let Some((until_block, until_stack)) = &mut self.until {
+            if let Some(var) = until_block.signature.get_positional(0) {
+                if let Some(var_id) = &var.var_id {
+                    until_stack.add_var(*var_id, sample.clone());
+                }
+            }
+
+            let predicate_passed = matches!(
+                eval_block_with_early_return(
+                    &self.engine_state,
+                    until_stack,
+                    until_block,
+                    PipelineData::Empty,
+                    self.redirect_stdout,
+                    self.redirect_stderr,
+                ),
+                Ok(data) if data.into_value(self.span).is_true()
+            );
+
+            if predicate_passed {
+                self.done = true;
+            }
+        }
+
+        Some(sample)
+    }
+}
@@ -0,0 +1,85 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, SyntaxShape, Type,
+    Value,
+};
+use std::process::Command as CommandSys;
+
+#[derive(Clone)]
+pub struct Say;
+
+impl Command for Say {
+    fn name(&self) -> &str {
+        "say"
+    }
+
+    fn usage(&self) -> &str {
+        "Speak text aloud using the operating system's text-to-speech engine."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Shells out to a platform-native TTS tool rather than bundling an audio/speech
+synthesis library: `say` on macOS, `spd-say` on Linux (part of speech-dispatcher, often
+not installed by default), and a PowerShell `System.Speech` call on Windows. If the
+underlying tool is missing, this command fails with an I/O error."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["speak", "tts", "speech", "notify"]
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("say")
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .required("text", SyntaxShape::String, "the text to speak")
+            .category(Category::Platform)
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let text: String = call.req(engine_state, stack, 0)?;
+
+        let status = if cfg!(target_os = "macos") {
+            CommandSys::new("say").arg(&text).status()
+        } else if cfg!(windows) {
+            let script = format!(
+                "Add-Type -AssemblyName System.Speech; \
+                 (New-Object System.Speech.Synthesis.SpeechSynthesizer).Speak('{}')",
+                text.replace('\'', "''")
+            );
+            CommandSys::new("powershell")
+                .args(["-NoProfile", "-Command", &script])
+                .status()
+        } else {
+            CommandSys::new("spd-say").arg(&text).status()
+        }
+        .map_err(|e| {
+            ShellError::IOErrorSpanned(format!("failed to run text-to-speech: {e}"), span)
+        })?;
+
+        if !status.success() {
+            return Err(ShellError::IOErrorSpanned(
+                format!("text-to-speech command exited with {status}"),
+                span,
+            ));
+        }
+
+        Ok(Value::Nothing { span }.into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Announce that a long-running pipeline has finished",
+            example: "sleep 10sec; say 'done'",
+            result: None,
+        }]
+    }
+}
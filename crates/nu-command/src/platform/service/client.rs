@@ -0,0 +1,57 @@
+use nu_protocol::{ShellError, Span, Value};
+use std::process::Command as CommandSys;
+
+/// Run `systemctl <args> --output=json` and parse the result into a list of `nu_json::Value`
+/// objects. `systemctl` has supported `--output=json` since systemd 247; this is what lets
+/// `service` avoid scraping `systemctl`'s column-aligned table output.
+pub(crate) fn run_systemctl_json(
+    args: &[&str],
+    span: Span,
+) -> Result<Vec<nu_json::Value>, ShellError> {
+    let mut command_args = args.to_vec();
+    command_args.push("--output=json");
+
+    let output = CommandSys::new("systemctl")
+        .args(&command_args)
+        .output()
+        .map_err(|e| ShellError::IOErrorSpanned(format!("failed to run systemctl: {e}"), span))?;
+
+    if !output.status.success() {
+        return Err(ShellError::IOErrorSpanned(
+            format!(
+                "systemctl exited with {}: {}",
+                output.status,
+                String::from_utf8_lossy(&output.stderr)
+            ),
+            span,
+        ));
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let parsed: nu_json::Value = nu_json::from_str(&stdout).map_err(|e| {
+        ShellError::IOErrorSpanned(format!("could not parse systemctl output: {e}"), span)
+    })?;
+
+    match parsed {
+        nu_json::Value::Array(units) => Ok(units),
+        // `systemctl show --output=json` returns a single object, not an array.
+        other => Ok(vec![other]),
+    }
+}
+
+pub(crate) fn object_field<'a>(
+    value: &'a nu_json::Value,
+    name: &str,
+) -> Option<&'a nu_json::Value> {
+    match value {
+        nu_json::Value::Object(map) => map.get(name),
+        _ => None,
+    }
+}
+
+pub(crate) fn as_string(value: Option<&nu_json::Value>, span: Span) -> Value {
+    match value.and_then(|v| v.as_str()) {
+        Some(s) => Value::string(s, span),
+        None => Value::nothing(span),
+    }
+}
@@ -0,0 +1,68 @@
+use super::client::{as_string, object_field, run_systemctl_json};
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Type,
+    Value,
+};
+
+#[derive(Clone)]
+pub struct ServiceList;
+
+impl Command for ServiceList {
+    fn name(&self) -> &str {
+        "service list"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("service list")
+            .input_output_types(vec![(Type::Nothing, Type::Table(vec![]))])
+            .category(Category::Platform)
+    }
+
+    fn usage(&self) -> &str {
+        "List systemd units with their load, active, and sub state."
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let units = run_systemctl_json(&["list-units", "--all"], span)?;
+
+        let rows = units
+            .iter()
+            .map(|unit| Value::Record {
+                cols: vec![
+                    "unit".to_string(),
+                    "load".to_string(),
+                    "active".to_string(),
+                    "sub".to_string(),
+                    "description".to_string(),
+                ],
+                vals: vec![
+                    as_string(object_field(unit, "unit"), span),
+                    as_string(object_field(unit, "load"), span),
+                    as_string(object_field(unit, "active"), span),
+                    as_string(object_field(unit, "sub"), span),
+                    as_string(object_field(unit, "description"), span),
+                ],
+                span,
+            })
+            .collect::<Vec<_>>();
+
+        Ok(rows.into_iter().into_pipeline_data(None))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "List all systemd units and their current state",
+            example: "service list | where active == failed",
+            result: None,
+        }]
+    }
+}
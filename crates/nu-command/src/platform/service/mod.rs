@@ -0,0 +1,8 @@
+mod client;
+mod list;
+mod service_;
+mod status;
+
+pub use list::ServiceList;
+pub use service_::Service;
+pub use status::ServiceStatus;
@@ -0,0 +1,54 @@
+use nu_engine::get_full_help;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, IntoPipelineData, PipelineData, ShellError, Signature, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct Service;
+
+impl Command for Service {
+    fn name(&self) -> &str {
+        "service"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("service")
+            .category(Category::Platform)
+            .input_output_types(vec![(Type::Nothing, Type::String)])
+    }
+
+    fn usage(&self) -> &str {
+        "Query systemd units as structured data."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "You must use one of the following subcommands. Using this command as-is will only produce this help message.
+
+Linux only. Shells out to `systemctl --output=json`, which is systemd's own structured
+output mode, rather than talking to D-Bus directly: no D-Bus client crate is vendored in
+this workspace, and `systemctl`'s JSON output is already the non-brittle alternative to
+parsing its column output."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        Ok(Value::String {
+            val: get_full_help(
+                &Service.signature(),
+                &Service.examples(),
+                engine_state,
+                stack,
+                self.is_parser_keyword(),
+            ),
+            span: call.head,
+        }
+        .into_pipeline_data())
+    }
+}
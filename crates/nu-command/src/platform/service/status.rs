@@ -0,0 +1,84 @@
+use super::client::{as_string, object_field, run_systemctl_json};
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, SyntaxShape, Type,
+    Value,
+};
+
+#[derive(Clone)]
+pub struct ServiceStatus;
+
+impl Command for ServiceStatus {
+    fn name(&self) -> &str {
+        "service status"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("service status")
+            .input_output_types(vec![(Type::Nothing, Type::Record(vec![]))])
+            .required("name", SyntaxShape::String, "the unit to query")
+            .category(Category::Platform)
+    }
+
+    fn usage(&self) -> &str {
+        "Show the load/active/sub state and last exit code of a single systemd unit."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let name: String = call.req(engine_state, stack, 0)?;
+
+        let properties = [
+            "LoadState",
+            "ActiveState",
+            "SubState",
+            "Description",
+            "ExecMainStatus",
+            "ExecMainCode",
+        ]
+        .join(",");
+
+        let units = run_systemctl_json(&["show", &name, "--property", &properties], span)?;
+
+        let unit = units
+            .first()
+            .ok_or_else(|| ShellError::IOErrorSpanned(format!("no such unit: {name}"), span))?;
+
+        Ok(Value::Record {
+            cols: vec![
+                "unit".to_string(),
+                "load".to_string(),
+                "active".to_string(),
+                "sub".to_string(),
+                "description".to_string(),
+                "exit_code".to_string(),
+            ],
+            vals: vec![
+                Value::string(name, span),
+                as_string(object_field(unit, "LoadState"), span),
+                as_string(object_field(unit, "ActiveState"), span),
+                as_string(object_field(unit, "SubState"), span),
+                as_string(object_field(unit, "Description"), span),
+                as_string(object_field(unit, "ExecMainStatus"), span),
+            ],
+            span,
+        }
+        .into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Check whether sshd is running and its last exit code",
+            example: "service status sshd",
+            result: None,
+        }]
+    }
+}
@@ -1,3 +1,4 @@
+use crate::date::parse_date_from_string;
 use nu_engine::CallExt;
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
@@ -11,6 +12,7 @@ use std::{
 };
 
 const CTRL_C_CHECK_INTERVAL: Duration = Duration::from_millis(100);
+const PROGRESS_TICK: Duration = Duration::from_secs(1);
 
 #[derive(Clone)]
 pub struct Sleep;
@@ -24,11 +26,30 @@ impl Command for Sleep {
         "Delay for a specified amount of time."
     }
 
+    fn extra_usage(&self) -> &str {
+        "Responds to ctrl-c immediately, even for a very long sleep. With --until, sleeps until an absolute date/time is reached instead of for a fixed duration. With --progress, streams a countdown record once per second instead of returning nothing."
+    }
+
     fn signature(&self) -> Signature {
         Signature::build("sleep")
-            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
-            .required("duration", SyntaxShape::Duration, "time to sleep")
+            .input_output_types(vec![
+                (Type::Nothing, Type::Nothing),
+                (Type::Nothing, Type::Table(vec![])),
+            ])
+            .allow_variants_without_examples(true)
+            .optional("duration", SyntaxShape::Duration, "time to sleep")
             .rest("rest", SyntaxShape::Duration, "additional time")
+            .named(
+                "until",
+                SyntaxShape::String,
+                "sleep until this absolute date/time, instead of a fixed duration",
+                None,
+            )
+            .switch(
+                "progress",
+                "stream a countdown record once per second while sleeping",
+                Some('p'),
+            )
             .category(Category::Platform)
     }
 
@@ -47,11 +68,54 @@ impl Command for Sleep {
             Duration::from_nanos(if val < 0 { 0 } else { val as u64 })
         }
 
-        let duration: i64 = call.req(engine_state, stack, 0)?;
+        let duration: Option<i64> = call.opt(engine_state, stack, 0)?;
         let rest: Vec<i64> = call.rest(engine_state, stack, 1)?;
+        let until: Option<String> = call.get_flag(engine_state, stack, "until")?;
+        let progress = call.has_flag("progress");
 
-        let total_dur =
-            duration_from_i64(duration) + rest.into_iter().map(duration_from_i64).sum::<Duration>();
+        let total_dur = match (duration, until) {
+            (Some(duration), None) => {
+                duration_from_i64(duration)
+                    + rest.into_iter().map(duration_from_i64).sum::<Duration>()
+            }
+            (None, Some(until)) => {
+                let wake_at = match parse_date_from_string(&until, call.head) {
+                    Ok(wake_at) => wake_at,
+                    Err(Value::Error { error }) => return Err(error),
+                    Err(_) => unreachable!("parse_date_from_string only errs with Value::Error"),
+                };
+                let now = chrono::Local::now().with_timezone(wake_at.offset());
+                (wake_at - now).to_std().unwrap_or(Duration::ZERO)
+            }
+            (Some(_), Some(_)) => {
+                return Err(ShellError::GenericError(
+                    "`sleep` takes either a duration or `--until`, not both".into(),
+                    "remove one of these".into(),
+                    Some(call.head),
+                    None,
+                    Vec::new(),
+                ))
+            }
+            (None, None) => {
+                return Err(ShellError::MissingParameter {
+                    param_name: "duration".into(),
+                    span: call.head,
+                })
+            }
+        };
+
+        if progress {
+            return Ok(PipelineData::ListStream(
+                nu_protocol::ListStream {
+                    stream: Box::new(SleepProgress {
+                        remaining: total_dur,
+                        span: call.head,
+                    }),
+                    ctrlc: engine_state.ctrlc.clone(),
+                },
+                None,
+            ));
+        }
 
         let ctrlc_ref = &engine_state.ctrlc.clone();
         let start = Instant::now();
@@ -78,6 +142,16 @@ impl Command for Sleep {
                     span: Span::test_data(),
                 }),
             },
+            Example {
+                description: "Sleep until a specific time",
+                example: "sleep --until '2030-01-01 00:00:00'",
+                result: None,
+            },
+            Example {
+                description: "Sleep for 5sec, printing a countdown",
+                example: "sleep 5sec --progress",
+                result: None,
+            },
             // Example {
             //     description: "Sleep for 3sec",
             //     example: "sleep 1sec 1sec 1sec",
@@ -92,6 +166,36 @@ impl Command for Sleep {
     }
 }
 
+/// Yields one countdown record per second while a `sleep --progress` sleep is in progress,
+/// checking for ctrl-c between ticks so long sleeps stay responsive.
+struct SleepProgress {
+    remaining: Duration,
+    span: Span,
+}
+
+impl Iterator for SleepProgress {
+    type Item = Value;
+
+    fn next(&mut self) -> Option<Value> {
+        if self.remaining == Duration::ZERO {
+            return None;
+        }
+
+        let tick = PROGRESS_TICK.min(self.remaining);
+        thread::sleep(tick);
+        self.remaining -= tick;
+
+        Some(Value::Record {
+            cols: vec!["remaining".to_string()],
+            vals: vec![Value::Duration {
+                val: self.remaining.as_nanos() as i64,
+                span: self.span,
+            }],
+            span: self.span,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::Sleep;
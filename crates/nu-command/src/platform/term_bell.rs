@@ -0,0 +1,55 @@
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{Category, Example, PipelineData, ShellError, Signature, Type};
+use std::io::Write;
+
+#[derive(Clone)]
+pub struct TermBell;
+
+impl Command for TermBell {
+    fn name(&self) -> &str {
+        "term bell"
+    }
+
+    fn usage(&self) -> &str {
+        "Ring the terminal bell."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Writes the bell control character (0x07) to stdout. What that produces - an audible
+beep, a screen flash, or nothing at all - is entirely up to the terminal emulator's settings."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["beep", "alert", "notify"]
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("term bell")
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .category(Category::Platform)
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        print!("\x07");
+        std::io::stdout().flush().map_err(|e| {
+            ShellError::IOErrorSpanned(format!("failed to write to stdout: {e}"), span)
+        })?;
+        Ok(PipelineData::Empty)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Ring the bell once a long-running pipeline finishes",
+            example: "sleep 10sec; term bell",
+            result: None,
+        }]
+    }
+}
@@ -0,0 +1,240 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Spanned,
+    SyntaxShape, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct Ulimit;
+
+impl Command for Ulimit {
+    fn name(&self) -> &str {
+        "ulimit"
+    }
+
+    fn usage(&self) -> &str {
+        "View or set a process resource limit (nofile, core, or cpu)."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"With no arguments, reports the soft and hard limit for every known
+resource. With just `resource`, reports that one. Given `resource` and a
+new soft limit (and optionally a hard limit), sets it for the current
+process and anything it spawns afterward - not for processes already
+running. Pass -1 for a limit to mean "unlimited". Unsupported on Windows,
+which has no POSIX rlimit concept."#
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("ulimit")
+            .input_output_types(vec![(Type::Nothing, Type::Table(vec![]))])
+            .allow_variants_without_examples(true)
+            .optional(
+                "resource",
+                SyntaxShape::String,
+                "the resource to inspect or set: nofile, core, or cpu",
+            )
+            .optional(
+                "soft",
+                SyntaxShape::Int,
+                "new soft limit to set, or -1 for unlimited",
+            )
+            .optional(
+                "hard",
+                SyntaxShape::Int,
+                "new hard limit to set, or -1 for unlimited (defaults to the current hard limit)",
+            )
+            .category(Category::Platform)
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["rlimit", "nofile", "resource"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        ulimit(engine_state, stack, call)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Show every known resource limit",
+                example: "ulimit",
+                result: None,
+            },
+            Example {
+                description: "Show the open file descriptor limit",
+                example: "ulimit nofile",
+                result: None,
+            },
+            Example {
+                description: "Raise the open file descriptor soft limit to 4096",
+                example: "ulimit nofile 4096",
+                result: None,
+            },
+        ]
+    }
+}
+
+#[cfg(unix)]
+const RESOURCES: &[(&str, libc::c_int)] = &[
+    ("nofile", libc::RLIMIT_NOFILE),
+    ("core", libc::RLIMIT_CORE),
+    ("cpu", libc::RLIMIT_CPU),
+];
+
+#[cfg(unix)]
+fn resource_id(name: &str) -> Option<(&'static str, libc::c_int)> {
+    RESOURCES.iter().find(|(n, _)| *n == name).copied()
+}
+
+#[cfg(unix)]
+fn ulimit(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+) -> Result<PipelineData, ShellError> {
+    let head = call.head;
+    let resource: Option<Spanned<String>> = call.opt(engine_state, stack, 0)?;
+    let soft: Option<i64> = call.opt(engine_state, stack, 1)?;
+    let hard: Option<i64> = call.opt(engine_state, stack, 2)?;
+
+    let resources = match &resource {
+        Some(r) => {
+            let found = resource_id(&r.item).ok_or_else(|| ShellError::IncorrectValue {
+                msg: format!(
+                    "unknown resource `{}`, expected one of: nofile, core, cpu",
+                    r.item
+                ),
+                span: r.span,
+            })?;
+            vec![found]
+        }
+        None => RESOURCES.to_vec(),
+    };
+
+    if let Some(soft) = soft {
+        let (_, id) = resources[0];
+        set_limit(id, soft, hard, head)?;
+    }
+
+    let mut rows = Vec::with_capacity(resources.len());
+    for (name, id) in resources {
+        let limit = get_limit(id, head)?;
+        rows.push(Value::Record {
+            cols: vec![
+                "resource".to_string(),
+                "soft".to_string(),
+                "hard".to_string(),
+            ],
+            vals: vec![
+                Value::string(name, head),
+                limit_value(limit.rlim_cur, head),
+                limit_value(limit.rlim_max, head),
+            ],
+            span: head,
+        });
+    }
+
+    Ok(Value::List {
+        vals: rows,
+        span: head,
+    }
+    .into_pipeline_data())
+}
+
+#[cfg(unix)]
+fn get_limit(id: libc::c_int, span: Span) -> Result<libc::rlimit, ShellError> {
+    let mut limit = libc::rlimit {
+        rlim_cur: 0,
+        rlim_max: 0,
+    };
+
+    let result = unsafe { libc::getrlimit(id, &mut limit) };
+    if result != 0 {
+        return Err(ShellError::GenericError(
+            "failed to read resource limit".into(),
+            std::io::Error::last_os_error().to_string(),
+            Some(span),
+            None,
+            Vec::new(),
+        ));
+    }
+
+    Ok(limit)
+}
+
+#[cfg(unix)]
+fn set_limit(id: libc::c_int, soft: i64, hard: Option<i64>, span: Span) -> Result<(), ShellError> {
+    let current = get_limit(id, span)?;
+    let limit = libc::rlimit {
+        rlim_cur: to_rlim(soft),
+        rlim_max: hard.map(to_rlim).unwrap_or(current.rlim_max),
+    };
+
+    let result = unsafe { libc::setrlimit(id, &limit) };
+    if result != 0 {
+        return Err(ShellError::GenericError(
+            "failed to set resource limit".into(),
+            std::io::Error::last_os_error().to_string(),
+            Some(span),
+            None,
+            Vec::new(),
+        ));
+    }
+
+    Ok(())
+}
+
+#[cfg(unix)]
+fn to_rlim(value: i64) -> libc::rlim_t {
+    if value < 0 {
+        libc::RLIM_INFINITY
+    } else {
+        value as libc::rlim_t
+    }
+}
+
+#[cfg(unix)]
+fn limit_value(value: libc::rlim_t, span: Span) -> Value {
+    if value == libc::RLIM_INFINITY {
+        Value::string("unlimited", span)
+    } else {
+        Value::int(value as i64, span)
+    }
+}
+
+#[cfg(not(unix))]
+fn ulimit(
+    _engine_state: &EngineState,
+    _stack: &mut Stack,
+    call: &Call,
+) -> Result<PipelineData, ShellError> {
+    Err(ShellError::GenericError(
+        "ulimit is not supported on this platform".into(),
+        "POSIX resource limits don't exist outside unix".into(),
+        Some(call.head),
+        None,
+        Vec::new(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(Ulimit {})
+    }
+}
@@ -0,0 +1,100 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, PipelineData, ShellError, Signature, Spanned, SyntaxShape, Type, Value,
+};
+use rand::{rngs::OsRng, thread_rng, RngCore};
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "random binary"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("random binary")
+            .input_output_types(vec![(Type::Nothing, Type::Binary)])
+            .allow_variants_without_examples(true)
+            .required("length", SyntaxShape::Int, "Length of the binary data")
+            .switch(
+                "secure",
+                "draw from the operating system's CSPRNG instead of the default fast RNG",
+                Some('s'),
+            )
+            .category(Category::Random)
+    }
+
+    fn usage(&self) -> &str {
+        "Generate random bytes."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["generate", "random", "bytes", "token", "salt"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        binary(engine_state, stack, call)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Generate 16 random bytes",
+                example: "random binary 16",
+                result: None,
+            },
+            Example {
+                description: "Generate a cryptographically secure 32 byte token",
+                example: "random binary 32 --secure",
+                result: None,
+            },
+        ]
+    }
+}
+
+fn binary(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+) -> Result<PipelineData, ShellError> {
+    let span = call.head;
+    let length: Spanned<i64> = call.req(engine_state, stack, 0)?;
+    let secure = call.has_flag("secure");
+
+    if length.item < 0 {
+        return Err(ShellError::NeedsPositiveValue(length.span));
+    }
+
+    let mut bytes = vec![0u8; length.item as usize];
+    if secure {
+        OsRng.fill_bytes(&mut bytes);
+    } else {
+        thread_rng().fill_bytes(&mut bytes);
+    }
+
+    Ok(PipelineData::Value(
+        Value::Binary { val: bytes, span },
+        None,
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
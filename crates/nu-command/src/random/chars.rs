@@ -2,14 +2,18 @@ use nu_engine::CallExt;
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
-    Category, Example, PipelineData, ShellError, Signature, SyntaxShape, Type, Value,
+    Category, Example, PipelineData, ShellError, Signature, Spanned, SyntaxShape, Type, Value,
 };
 use rand::{
-    distributions::{Alphanumeric, Distribution},
-    thread_rng,
+    distributions::{Alphanumeric, Distribution, Slice},
+    rngs::OsRng,
+    thread_rng, RngCore,
 };
 
 const DEFAULT_CHARS_LENGTH: usize = 25;
+const ALPHA_CHARSET: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+const DIGIT_CHARSET: &str = "0123456789";
+const HEX_CHARSET: &str = "0123456789abcdef";
 
 #[derive(Clone)]
 pub struct SubCommand;
@@ -24,6 +28,17 @@ impl Command for SubCommand {
             .input_output_types(vec![(Type::Nothing, Type::String)])
             .allow_variants_without_examples(true)
             .named("length", SyntaxShape::Int, "Number of chars", Some('l'))
+            .named(
+                "charset",
+                SyntaxShape::String,
+                "Character set to draw from: alnum (default), alpha, digit, hex, or a literal string of characters to use as a custom set",
+                Some('c'),
+            )
+            .switch(
+                "secure",
+                "draw from the operating system's CSPRNG instead of the default fast RNG",
+                Some('s'),
+            )
             .category(Category::Random)
     }
 
@@ -57,10 +72,43 @@ impl Command for SubCommand {
                 example: "random chars -l 20",
                 result: None,
             },
+            Example {
+                description: "Generate a random hex string, e.g. for a salt",
+                example: "random chars -l 32 -c hex",
+                result: None,
+            },
+            Example {
+                description: "Generate random chars from a custom character set",
+                example: "random chars -l 10 -c 'abc123'",
+                result: None,
+            },
         ]
     }
 }
 
+fn resolve_charset(charset: Option<Spanned<String>>) -> Result<Vec<char>, ShellError> {
+    let Some(charset) = charset else {
+        return Ok(Vec::new()); // empty means "use Alphanumeric directly"
+    };
+
+    let set = match charset.item.as_str() {
+        "alnum" => format!("{ALPHA_CHARSET}{DIGIT_CHARSET}"),
+        "alpha" => ALPHA_CHARSET.to_string(),
+        "digit" => DIGIT_CHARSET.to_string(),
+        "hex" => HEX_CHARSET.to_string(),
+        custom if !custom.is_empty() => custom.to_string(),
+        _ => {
+            return Err(ShellError::IncorrectValue {
+                msg: "charset must be alnum, alpha, digit, hex, or a non-empty custom string"
+                    .into(),
+                span: charset.span,
+            })
+        }
+    };
+
+    Ok(set.chars().collect())
+}
+
 fn chars(
     engine_state: &EngineState,
     stack: &mut Stack,
@@ -68,15 +116,29 @@ fn chars(
 ) -> Result<PipelineData, ShellError> {
     let span = call.head;
     let length: Option<usize> = call.get_flag(engine_state, stack, "length")?;
+    let charset: Option<Spanned<String>> = call.get_flag(engine_state, stack, "charset")?;
+    let secure = call.has_flag("secure");
 
     let chars_length = length.unwrap_or(DEFAULT_CHARS_LENGTH);
-    let mut rng = thread_rng();
+    let charset = resolve_charset(charset)?;
 
-    let random_string = Alphanumeric
-        .sample_iter(&mut rng)
-        .take(chars_length)
-        .map(char::from)
-        .collect::<String>();
+    let random_string = if charset.is_empty() {
+        sample_string(
+            Alphanumeric
+                .sample_iter(PickRng::new(secure))
+                .map(char::from),
+            chars_length,
+        )
+    } else {
+        let dist = Slice::new(&charset).map_err(|_| ShellError::IncorrectValue {
+            msg: "charset must contain at least one character".into(),
+            span,
+        })?;
+        sample_string(
+            dist.sample_iter(PickRng::new(secure)).copied(),
+            chars_length,
+        )
+    };
 
     Ok(PipelineData::Value(
         Value::String {
@@ -87,6 +149,57 @@ fn chars(
     ))
 }
 
+fn sample_string(iter: impl Iterator<Item = char>, length: usize) -> String {
+    iter.take(length).collect()
+}
+
+// `Alphanumeric`/`Slice` need a single concrete `Rng` type to sample from, so the `--secure`
+// switch is threaded through as an enum rather than a trait object.
+enum PickRng {
+    Fast(rand::rngs::ThreadRng),
+    Secure(OsRng),
+}
+
+impl PickRng {
+    fn new(secure: bool) -> Self {
+        if secure {
+            PickRng::Secure(OsRng)
+        } else {
+            PickRng::Fast(thread_rng())
+        }
+    }
+}
+
+impl rand::RngCore for PickRng {
+    fn next_u32(&mut self) -> u32 {
+        match self {
+            PickRng::Fast(rng) => rng.next_u32(),
+            PickRng::Secure(rng) => rng.next_u32(),
+        }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        match self {
+            PickRng::Fast(rng) => rng.next_u64(),
+            PickRng::Secure(rng) => rng.next_u64(),
+        }
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        match self {
+            PickRng::Fast(rng) => rng.fill_bytes(dest),
+            PickRng::Secure(rng) => rng.fill_bytes(dest),
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        match self {
+            PickRng::Fast(rng) => rng.try_fill_bytes(dest),
+            PickRng::Secure(rng) => rng.try_fill_bytes(dest),
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -19,6 +19,15 @@ impl Command for Parse {
         "Parse columns from string data using a simple pattern."
     }
 
+    fn extra_usage(&self) -> &str {
+        r#"With `--regex`, a named capture group's name may carry a `:type`
+suffix (e.g. `(?P<size:filesize>...)` or `(?<size:filesize>...)`) to coerce
+that column from a string into `int`, `filesize`, or `date` once captured.
+A column whose captured text doesn't parse as its declared type is left as
+a string rather than erroring out the whole parse. Unknown suffixes are
+ignored and the column is left untouched."#
+    }
+
     fn search_terms(&self) -> Vec<&str> {
         vec!["pattern", "match"]
     }
@@ -115,6 +124,18 @@ impl Command for Parse {
                     span: Span::test_data(),
                 }),
             },
+            Example {
+                description: "Parse a string, coercing a named capture group into a typed column",
+                example: "\"file.txt 1024\" | parse -r '(?P<name>\\S+) (?P<size:filesize>\\d+)'",
+                result: Some(Value::List {
+                    vals: vec![Value::Record {
+                        cols: vec!["name".to_string(), "size".to_string()],
+                        vals: vec![Value::test_string("file.txt"), Value::test_filesize(1024)],
+                        span: Span::test_data(),
+                    }],
+                    span: Span::test_data(),
+                }),
+            },
         ]
     }
 
@@ -149,6 +170,8 @@ fn operate(
         build_regex(&pattern_item, pattern_span)?
     };
 
+    let (item_to_parse, column_types) = strip_capture_types(&item_to_parse);
+
     let regex_pattern = Regex::new(&item_to_parse).map_err(|err| {
         ShellError::GenericError(
             "Error with regular expression".into(),
@@ -186,14 +209,18 @@ fn operate(
                                 }
                             };
                             let mut vals = Vec::with_capacity(captures.len());
+                            let cap_span = v.span()?;
 
-                            for (column_name, cap) in columns.iter().zip(captures.iter().skip(1)) {
-                                let cap_string = cap.map(|v| v.as_str()).unwrap_or("").to_string();
+                            for (i, (column_name, cap)) in
+                                columns.iter().zip(captures.iter().skip(1)).enumerate()
+                            {
+                                let cap_string = cap.map(|v| v.as_str()).unwrap_or("");
                                 cols.push(column_name.clone());
-                                vals.push(Value::String {
-                                    val: cap_string,
-                                    span: v.span()?,
-                                });
+                                vals.push(convert_capture(
+                                    cap_string,
+                                    column_types.get(i).and_then(|t| *t),
+                                    cap_span,
+                                ));
                             }
 
                             parsed.push(Value::Record {
@@ -225,6 +252,7 @@ fn operate(
                     excess: Vec::new(),
                     regex: regex_pattern,
                     columns,
+                    column_types,
                     stream: stream.stream,
                 },
                 ctrlc,
@@ -244,6 +272,7 @@ fn operate(
                     excess: Vec::new(),
                     regex: regex_pattern,
                     columns,
+                    column_types,
                     stream: stream.stream,
                 },
                 ctrlc,
@@ -319,11 +348,132 @@ fn column_names(regex: &Regex) -> Vec<String> {
         .collect()
 }
 
+#[derive(Clone, Copy)]
+enum CaptureType {
+    Int,
+    Filesize,
+    Date,
+}
+
+impl CaptureType {
+    fn from_suffix(suffix: &str) -> Option<CaptureType> {
+        match suffix {
+            "int" => Some(CaptureType::Int),
+            "filesize" => Some(CaptureType::Filesize),
+            "date" => Some(CaptureType::Date),
+            _ => None,
+        }
+    }
+}
+
+/// Strips a `:type` suffix off named capture groups (`(?P<name:type>...)` or
+/// `(?<name:type>...)`) so the pattern is valid to compile, returning the
+/// cleaned pattern alongside the declared type for each capturing group in
+/// left-to-right declaration order (`None` for untyped or unnamed groups).
+/// This has to stay aligned with `column_names()`'s output order.
+fn strip_capture_types(pattern: &str) -> (String, Vec<Option<CaptureType>>) {
+    let chars: Vec<char> = pattern.chars().collect();
+    let mut output = String::with_capacity(pattern.len());
+    let mut types = Vec::new();
+    let mut in_class = false;
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+
+        if c == '\\' && i + 1 < chars.len() {
+            output.push(c);
+            output.push(chars[i + 1]);
+            i += 2;
+            continue;
+        }
+
+        if c == '[' {
+            in_class = true;
+        } else if c == ']' {
+            in_class = false;
+        }
+
+        if c == '(' && !in_class {
+            if i + 1 < chars.len() && chars[i + 1] == '?' {
+                let is_named = matches_at(&chars, i, "(?P<")
+                    || (matches_at(&chars, i, "(?<")
+                        && !matches_at(&chars, i, "(?<=")
+                        && !matches_at(&chars, i, "(?<!"));
+
+                if is_named {
+                    let prefix_len = if matches_at(&chars, i, "(?P<") { 4 } else { 3 };
+                    let name_start = i + prefix_len;
+                    let end = chars[name_start..]
+                        .iter()
+                        .position(|&c| c == '>')
+                        .map(|p| name_start + p);
+
+                    if let Some(end) = end {
+                        let full_name: String = chars[name_start..end].iter().collect();
+                        let (name, kind) = match full_name.split_once(':') {
+                            Some((name, kind)) => {
+                                (name.to_string(), CaptureType::from_suffix(kind))
+                            }
+                            None => (full_name, None),
+                        };
+
+                        output.extend(&chars[i..i + prefix_len]);
+                        output.push_str(&name);
+                        output.push('>');
+                        types.push(kind);
+
+                        i = end + 1;
+                        continue;
+                    }
+                }
+            } else {
+                types.push(None);
+            }
+        }
+
+        output.push(c);
+        i += 1;
+    }
+
+    (output, types)
+}
+
+fn matches_at(chars: &[char], i: usize, needle: &str) -> bool {
+    let needle: Vec<char> = needle.chars().collect();
+    i + needle.len() <= chars.len() && chars[i..i + needle.len()] == needle[..]
+}
+
+/// Coerces a captured string into its declared column type, falling back to
+/// a plain string column (rather than erroring the whole parse) whenever the
+/// text doesn't actually parse as that type.
+fn convert_capture(capture: &str, capture_type: Option<CaptureType>, span: Span) -> Value {
+    match capture_type {
+        Some(CaptureType::Int) => match capture.trim().parse::<i64>() {
+            Ok(val) => Value::Int { val, span },
+            Err(_) => Value::string(capture, span),
+        },
+        Some(CaptureType::Filesize) => match capture.trim().parse::<bytesize::ByteSize>() {
+            Ok(size) => Value::Filesize {
+                val: size.0 as i64,
+                span,
+            },
+            Err(_) => Value::string(capture, span),
+        },
+        Some(CaptureType::Date) => match crate::parse_date_from_string(capture.trim(), span) {
+            Ok(val) => Value::Date { val, span },
+            Err(_) => Value::string(capture, span),
+        },
+        None => Value::string(capture, span),
+    }
+}
+
 pub struct ParseStreamer {
     span: Span,
     excess: Vec<Value>,
     regex: Regex,
     columns: Vec<String>,
+    column_types: Vec<Option<CaptureType>>,
     stream: Box<dyn Iterator<Item = Value> + Send + 'static>,
 }
 
@@ -343,6 +493,7 @@ impl Iterator for ParseStreamer {
                     v.span().unwrap_or(self.span),
                     s,
                     self.columns.clone(),
+                    &self.column_types,
                     &mut self.excess,
                 ),
                 Err(_) => Some(Value::Error {
@@ -364,6 +515,7 @@ pub struct ParseStreamerExternal {
     excess: Vec<Value>,
     regex: Regex,
     columns: Vec<String>,
+    column_types: Vec<Option<CaptureType>>,
     stream: Box<dyn Iterator<Item = Result<Vec<u8>, ShellError>> + Send + 'static>,
 }
 
@@ -383,6 +535,7 @@ impl Iterator for ParseStreamerExternal {
                     self.span,
                     s,
                     self.columns.clone(),
+                    &self.column_types,
                     &mut self.excess,
                 ),
                 Err(_) => Some(Value::Error {
@@ -406,6 +559,7 @@ fn stream_helper(
     span: Span,
     s: String,
     columns: Vec<String>,
+    column_types: &[Option<CaptureType>],
     excess: &mut Vec<Value>,
 ) -> Option<Value> {
     let results = regex.captures_iter(&s);
@@ -428,13 +582,14 @@ fn stream_helper(
         };
         let mut vals = Vec::with_capacity(captures.len());
 
-        for (column_name, cap) in columns.iter().zip(captures.iter().skip(1)) {
-            let cap_string = cap.map(|v| v.as_str()).unwrap_or("").to_string();
+        for (i, (column_name, cap)) in columns.iter().zip(captures.iter().skip(1)).enumerate() {
+            let cap_string = cap.map(|v| v.as_str()).unwrap_or("");
             cols.push(column_name.clone());
-            vals.push(Value::String {
-                val: cap_string,
+            vals.push(convert_capture(
+                cap_string,
+                column_types.get(i).and_then(|t| *t),
                 span,
-            });
+            ));
         }
 
         excess.push(Value::Record { cols, vals, span });
@@ -2,6 +2,8 @@ mod complete;
 #[cfg(unix)]
 mod exec;
 mod nu_check;
+#[cfg(target_os = "macos")]
+mod prefs;
 #[cfg(any(
     target_os = "android",
     target_os = "linux",
@@ -19,6 +21,8 @@ pub use complete::Complete;
 #[cfg(unix)]
 pub use exec::Exec;
 pub use nu_check::NuCheck;
+#[cfg(target_os = "macos")]
+pub use prefs::{Prefs, PrefsGet, PrefsSet};
 #[cfg(any(
     target_os = "android",
     target_os = "linux",
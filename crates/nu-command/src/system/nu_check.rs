@@ -340,6 +340,7 @@ fn parse_script(
     span: Span,
 ) -> Result<PipelineData, ShellError> {
     let (_, err) = parse(working_set, filename, contents, false, &[]);
+    let err = err.into_iter().next();
     if err.is_some() {
         let msg = format!(r#"Found : {}"#, err.expect("Unable to parse content"));
         if is_debug {
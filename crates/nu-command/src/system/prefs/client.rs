@@ -0,0 +1,110 @@
+use nu_protocol::{ShellError, Span, Value};
+use std::io::Cursor;
+use std::process::Command as CommandSys;
+
+/// Read an entire preferences domain via `defaults export <domain> -`, which prints the
+/// domain as an XML property list to stdout. `defaults read` is not used here because its
+/// output is the old NeXTSTEP ASCII plist dialect, not something the `plist` crate parses.
+pub(crate) fn export_domain(domain: &str, span: Span) -> Result<Value, ShellError> {
+    let output = CommandSys::new("defaults")
+        .args(["export", domain, "-"])
+        .output()
+        .map_err(|e| ShellError::IOErrorSpanned(format!("failed to run defaults: {e}"), span))?;
+
+    if !output.status.success() {
+        return Err(ShellError::IOErrorSpanned(
+            format!(
+                "defaults export failed: {}",
+                String::from_utf8_lossy(&output.stderr)
+            ),
+            span,
+        ));
+    }
+
+    let plist_value = plist::Value::from_reader(Cursor::new(output.stdout)).map_err(|e| {
+        ShellError::CantConvert {
+            to_type: "structured plist data".into(),
+            from_type: "binary".into(),
+            span,
+            help: Some(e.to_string()),
+        }
+    })?;
+
+    Ok(convert_plist_value(&plist_value, span))
+}
+
+/// Write a single scalar value into a domain with `defaults write <domain> <key> -<type> <value>`.
+/// `defaults write` also supports `-array`/`-dict` for nested values, but those take a
+/// multi-argument shell syntax that isn't worth replicating here; `prefs set` only covers
+/// the scalar types `defaults write` accepts as a single trailing argument.
+pub(crate) fn write_scalar(
+    domain: &str,
+    key: &str,
+    value: &Value,
+    span: Span,
+) -> Result<(), ShellError> {
+    let (flag, text) = match value {
+        Value::Bool { val, .. } => ("-bool", val.to_string()),
+        Value::Int { val, .. } => ("-int", val.to_string()),
+        Value::Float { val, .. } => ("-float", val.to_string()),
+        Value::String { val, .. } => ("-string", val.clone()),
+        other => {
+            return Err(ShellError::UnsupportedInput(
+                "prefs set only supports bool, int, float, and string values".to_string(),
+                "value originates from here".into(),
+                span,
+                other.expect_span(),
+            ))
+        }
+    };
+
+    let output = CommandSys::new("defaults")
+        .args(["write", domain, key, flag, &text])
+        .output()
+        .map_err(|e| ShellError::IOErrorSpanned(format!("failed to run defaults: {e}"), span))?;
+
+    if !output.status.success() {
+        return Err(ShellError::IOErrorSpanned(
+            format!(
+                "defaults write failed: {}",
+                String::from_utf8_lossy(&output.stderr)
+            ),
+            span,
+        ));
+    }
+
+    Ok(())
+}
+
+fn convert_plist_value(value: &plist::Value, span: Span) -> Value {
+    match value {
+        plist::Value::Boolean(b) => Value::bool(*b, span),
+        plist::Value::Integer(i) => Value::int(i.as_signed().unwrap_or_default(), span),
+        plist::Value::Real(f) => Value::float(*f, span),
+        plist::Value::String(s) => Value::string(s.clone(), span),
+        plist::Value::Data(data) => Value::binary(data.clone(), span),
+        plist::Value::Date(date) => {
+            let system_time: std::time::SystemTime = (*date).into();
+            let chrono_date: chrono::DateTime<chrono::Utc> = system_time.into();
+            Value::Date {
+                val: chrono_date.into(),
+                span,
+            }
+        }
+        plist::Value::Array(array) => {
+            let vals = array.iter().map(|v| convert_plist_value(v, span)).collect();
+            Value::List { vals, span }
+        }
+        plist::Value::Dictionary(dict) => {
+            let mut cols = vec![];
+            let mut vals = vec![];
+            for (k, v) in dict {
+                cols.push(k.clone());
+                vals.push(convert_plist_value(v, span));
+            }
+            Value::Record { cols, vals, span }
+        }
+        plist::Value::Uid(uid) => Value::int(uid.get() as i64, span),
+        _ => Value::nothing(span),
+    }
+}
@@ -0,0 +1,75 @@
+use super::client::export_domain;
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, SyntaxShape, Type,
+    Value,
+};
+
+#[derive(Clone)]
+pub struct PrefsGet;
+
+impl Command for PrefsGet {
+    fn name(&self) -> &str {
+        "prefs get"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("prefs get")
+            .input_output_types(vec![(Type::Nothing, Type::Any)])
+            .required(
+                "domain",
+                SyntaxShape::String,
+                "the defaults domain to read, e.g. 'com.apple.finder' or 'NSGlobalDomain'",
+            )
+            .optional(
+                "key",
+                SyntaxShape::String,
+                "a single key within the domain to read",
+            )
+            .category(Category::Platform)
+    }
+
+    fn usage(&self) -> &str {
+        "Read a macOS `defaults` preference domain, or a single key within it."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let domain: String = call.req(engine_state, stack, 0)?;
+        let key: Option<String> = call.opt(engine_state, stack, 1)?;
+
+        let value = export_domain(&domain, span)?;
+
+        let value = match key {
+            Some(key) => value
+                .get_data_by_key(&key)
+                .unwrap_or_else(|| Value::nothing(span)),
+            None => value,
+        };
+
+        Ok(value.into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Read every key in the Finder preferences domain",
+                example: "prefs get com.apple.finder",
+                result: None,
+            },
+            Example {
+                description: "Read a single key from the global domain",
+                example: "prefs get NSGlobalDomain AppleInterfaceStyle",
+                result: None,
+            },
+        ]
+    }
+}
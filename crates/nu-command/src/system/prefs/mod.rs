@@ -0,0 +1,8 @@
+mod client;
+mod get;
+mod prefs_;
+mod set;
+
+pub use get::PrefsGet;
+pub use prefs_::Prefs;
+pub use set::PrefsSet;
@@ -0,0 +1,72 @@
+use super::client::write_scalar;
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, SyntaxShape, Type,
+    Value,
+};
+
+#[derive(Clone)]
+pub struct PrefsSet;
+
+impl Command for PrefsSet {
+    fn name(&self) -> &str {
+        "prefs set"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("prefs set")
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .required(
+                "domain",
+                SyntaxShape::String,
+                "the defaults domain to write, e.g. 'com.apple.finder' or 'NSGlobalDomain'",
+            )
+            .required(
+                "key",
+                SyntaxShape::String,
+                "the key to write within the domain",
+            )
+            .required(
+                "value",
+                SyntaxShape::Any,
+                "the new value; must be a bool, int, float, or string",
+            )
+            .category(Category::Platform)
+    }
+
+    fn usage(&self) -> &str {
+        "Write a single key in a macOS `defaults` preference domain."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Only bool, int, float, and string values are supported; `defaults write` takes \
+arrays and dictionaries through a different, multi-argument syntax that isn't covered here."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let domain: String = call.req(engine_state, stack, 0)?;
+        let key: String = call.req(engine_state, stack, 1)?;
+        let value: Value = call.req(engine_state, stack, 2)?;
+
+        write_scalar(&domain, &key, &value, span)?;
+
+        Ok(Value::nothing(span).into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Turn on dark mode",
+            example: "prefs set NSGlobalDomain AppleInterfaceStyle 'Dark'",
+            result: None,
+        }]
+    }
+}
@@ -0,0 +1,64 @@
+use crate::time::utils::monotonic_now_nanos;
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, SyntaxShape, Type,
+    Value,
+};
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "time elapsed-since"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("time elapsed-since")
+            .input_output_types(vec![(Type::Nothing, Type::Duration)])
+            .allow_variants_without_examples(true)
+            .required(
+                "token",
+                SyntaxShape::Duration,
+                "a token previously returned by `time now`",
+            )
+            .category(Category::Date)
+    }
+
+    fn usage(&self) -> &str {
+        "Get the monotonic-clock duration elapsed since a `time now` token."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["monotonic", "benchmark", "stopwatch"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let token: i64 = call.req(engine_state, stack, 0)?;
+
+        let elapsed = monotonic_now_nanos().saturating_sub(token);
+
+        Ok(Value::Duration {
+            val: elapsed,
+            span: head,
+        }
+        .into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Time how long a block takes using the monotonic clock",
+            example: "let start = (time now); sleep 100ms; time elapsed-since $start",
+            result: None,
+        }]
+    }
+}
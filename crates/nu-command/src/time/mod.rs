@@ -0,0 +1,8 @@
+mod elapsed_since;
+mod now;
+mod time_;
+mod utils;
+
+pub use elapsed_since::SubCommand as TimeElapsedSince;
+pub use now::SubCommand as TimeNow;
+pub use time_::Time;
@@ -0,0 +1,61 @@
+use crate::time::utils::monotonic_now_nanos;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "time now"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("time now")
+            .input_output_types(vec![(Type::Nothing, Type::Duration)])
+            .allow_variants_without_examples(true)
+            .category(Category::Date)
+    }
+
+    fn usage(&self) -> &str {
+        "Get a monotonic-clock timestamp, as a token for `time elapsed-since`."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Unlike `date now`, the returned value isn't a wall-clock date - it's
+a duration from an arbitrary, fixed point captured the first time a `time`
+subcommand runs in this process, backed by the OS monotonic clock. It can only be compared against
+other `time now` tokens from this same process, but doing so is immune to
+system clock adjustments, making it the right choice for benchmarking."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["monotonic", "benchmark", "stopwatch"]
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        Ok(Value::Duration {
+            val: monotonic_now_nanos(),
+            span: head,
+        }
+        .into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Time how long a block takes using the monotonic clock",
+            example: "let start = (time now); sleep 100ms; time elapsed-since $start",
+            result: None,
+        }]
+    }
+}
@@ -0,0 +1,55 @@
+use nu_engine::get_full_help;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, IntoPipelineData, PipelineData, ShellError, Signature, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct Time;
+
+impl Command for Time {
+    fn name(&self) -> &str {
+        "time"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("time")
+            .category(Category::Date)
+            .input_output_types(vec![(Type::Nothing, Type::String)])
+    }
+
+    fn usage(&self) -> &str {
+        "Monotonic-clock timing commands, for benchmarking."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "You must use one of the following subcommands. Using this command as-is will only produce this help message."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["monotonic", "elapsed", "benchmark", "stopwatch"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+
+        Ok(Value::String {
+            val: get_full_help(
+                &Time.signature(),
+                &Time.examples(),
+                engine_state,
+                stack,
+                false,
+            ),
+            span: head,
+        }
+        .into_pipeline_data())
+    }
+}
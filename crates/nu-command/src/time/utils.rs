@@ -0,0 +1,14 @@
+use once_cell::sync::Lazy;
+use std::time::Instant;
+
+/// A fixed reference point captured the first time any `time` subcommand
+/// runs. Monotonic tokens are durations measured from this point rather than
+/// from the Unix epoch, so they're immune to wall-clock adjustments (NTP
+/// jumps, DST, manual clock changes) - unlike `date now`.
+static EPOCH: Lazy<Instant> = Lazy::new(Instant::now);
+
+/// Nanoseconds elapsed since `EPOCH`, suitable for storing in a
+/// `Value::Duration` and diffing later with `time elapsed-since`.
+pub(crate) fn monotonic_now_nanos() -> i64 {
+    Instant::now().duration_since(*EPOCH).as_nanos() as i64
+}
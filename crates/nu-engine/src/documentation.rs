@@ -59,6 +59,15 @@ fn get_documentation(
         long_desc.push_str("\n\n");
     }
 
+    if sig.is_deprecated() {
+        match sig.deprecation_message() {
+            Some(message) => {
+                let _ = writeln!(long_desc, "Deprecated: {message}\n");
+            }
+            None => long_desc.push_str("Deprecated.\n\n"),
+        }
+    }
+
     let extra_usage = if config.brief { "" } else { &sig.extra_usage };
     if !extra_usage.is_empty() {
         long_desc.push_str(extra_usage);
@@ -171,78 +180,120 @@ fn get_documentation(
         }
     }
 
-    if !examples.is_empty() {
+    let attribute_example_sources: Vec<&str> = sig.example_attributes().collect();
+
+    if !examples.is_empty() || !attribute_example_sources.is_empty() {
         let _ = write!(long_desc, "\n{G}Examples{RESET}:");
     }
 
     for example in examples {
-        long_desc.push('\n');
+        render_example(
+            &mut long_desc,
+            engine_state,
+            stack,
+            config,
+            example.description,
+            example.example,
+            example.result.as_ref(),
+        );
+    }
+
+    // `@example` attributes only carry source text, no description or expected result -
+    // they exist for quick copy-paste snippets, not the richer `Example`s a command
+    // returns from code.
+    for source in &attribute_example_sources {
+        render_example(
+            &mut long_desc,
+            engine_state,
+            stack,
+            config,
+            "",
+            source,
+            None,
+        );
+    }
+
+    long_desc.push('\n');
+
+    if config.no_color {
+        nu_utils::strip_ansi_string_likely(long_desc)
+    } else {
+        long_desc
+    }
+}
+
+/// Renders one example - its description (if any), its highlighted source, and its
+/// expected result table (if any) - onto `long_desc`. Shared by `Example`s returned from
+/// code and `@example` attribute sources, which only ever supply the source text.
+fn render_example(
+    long_desc: &mut String,
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    config: &DocumentationConfig,
+    description: &str,
+    example: &str,
+    result: Option<&Value>,
+) {
+    long_desc.push('\n');
+    if !description.is_empty() {
         long_desc.push_str("  ");
-        long_desc.push_str(example.description);
+        long_desc.push_str(description);
+    }
 
-        if config.no_color {
-            let _ = write!(long_desc, "\n  > {}\n", example.example);
-        } else if let Some(highlighter) = engine_state.find_decl(b"nu-highlight", &[]) {
-            let decl = engine_state.get_decl(highlighter);
+    if config.no_color {
+        let _ = write!(long_desc, "\n  > {example}\n");
+    } else if let Some(highlighter) = engine_state.find_decl(b"nu-highlight", &[]) {
+        let decl = engine_state.get_decl(highlighter);
 
-            match decl.run(
-                engine_state,
-                stack,
-                &Call::new(Span::unknown()),
-                Value::string(example.example, Span::unknown()).into_pipeline_data(),
-            ) {
-                Ok(output) => {
-                    let result = output.into_value(Span::unknown());
-                    match result.as_string() {
-                        Ok(s) => {
-                            let _ = write!(long_desc, "\n  > {s}\n");
-                        }
-                        _ => {
-                            let _ = write!(long_desc, "\n  > {}\n", example.example);
-                        }
+        match decl.run(
+            engine_state,
+            stack,
+            &Call::new(Span::unknown()),
+            Value::string(example, Span::unknown()).into_pipeline_data(),
+        ) {
+            Ok(output) => {
+                let result = output.into_value(Span::unknown());
+                match result.as_string() {
+                    Ok(s) => {
+                        let _ = write!(long_desc, "\n  > {s}\n");
+                    }
+                    _ => {
+                        let _ = write!(long_desc, "\n  > {example}\n");
                     }
-                }
-                Err(_) => {
-                    let _ = write!(long_desc, "\n  > {}\n", example.example);
                 }
             }
-        } else {
-            let _ = write!(long_desc, "\n  > {}\n", example.example);
-        }
-
-        if let Some(result) = &example.result {
-            let table = engine_state
-                .find_decl("table".as_bytes(), &[])
-                .and_then(|decl_id| {
-                    engine_state
-                        .get_decl(decl_id)
-                        .run(
-                            engine_state,
-                            stack,
-                            &Call::new(Span::new(0, 0)),
-                            PipelineData::Value(result.clone(), None),
-                        )
-                        .ok()
-                });
-
-            for item in table.into_iter().flatten() {
-                let _ = writeln!(
-                    long_desc,
-                    "  {}",
-                    item.into_string("", engine_state.get_config())
-                        .replace('\n', "\n  ")
-                        .trim()
-                );
+            Err(_) => {
+                let _ = write!(long_desc, "\n  > {example}\n");
             }
         }
+    } else {
+        let _ = write!(long_desc, "\n  > {example}\n");
     }
 
-    long_desc.push('\n');
+    if let Some(result) = result {
+        let table = engine_state
+            .find_decl("table".as_bytes(), &[])
+            .and_then(|decl_id| {
+                engine_state
+                    .get_decl(decl_id)
+                    .run(
+                        engine_state,
+                        stack,
+                        &Call::new(Span::new(0, 0)),
+                        PipelineData::Value(result.clone(), None),
+                    )
+                    .ok()
+            });
 
-    if config.no_color {
-        nu_utils::strip_ansi_string_likely(long_desc)
-    } else {
-        long_desc
+        for item in table.into_iter().flatten() {
+            let _ = writeln!(
+                long_desc,
+                "  {}",
+                item.into_string("", engine_state.get_config())
+                    .replace('\n', "\n  ")
+                    .trim()
+            );
+        }
     }
 }
 
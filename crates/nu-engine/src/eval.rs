@@ -55,6 +55,12 @@ pub fn eval_call(
         }
         .into_pipeline_data())
     } else if let Some(block_id) = decl.get_block_id() {
+        if let Some(message) = decl.signature().deprecation_message() {
+            eprintln!("warning: `{}` is deprecated: {message}", decl.name());
+        } else if decl.signature().is_deprecated() {
+            eprintln!("warning: `{}` is deprecated", decl.name());
+        }
+
         let block = engine_state.get_block(block_id);
 
         let mut callee_stack = caller_stack.gather_captures(&block.captures);
@@ -143,6 +149,8 @@ pub fn eval_call(
             }
         }
 
+        let start = engine_state.call_stats.is_enabled().then(Instant::now);
+
         let result = eval_block_with_early_return(
             engine_state,
             &mut callee_stack,
@@ -152,6 +160,12 @@ pub fn eval_call(
             call.redirect_stderr,
         );
 
+        if let Some(start) = start {
+            engine_state
+                .call_stats
+                .record(call.decl_id, start.elapsed().as_nanos() as u64);
+        }
+
         if block.redirect_env {
             redirect_env(engine_state, caller_stack, &callee_stack);
         }
@@ -161,7 +175,17 @@ pub fn eval_call(
         // We pass caller_stack here with the knowledge that internal commands
         // are going to be specifically looking for global state in the stack
         // rather than any local state.
-        decl.run(engine_state, caller_stack, call, input)
+        let start = engine_state.call_stats.is_enabled().then(Instant::now);
+
+        let result = decl.run(engine_state, caller_stack, call, input);
+
+        if let Some(start) = start {
+            engine_state
+                .call_stats
+                .record(call.decl_id, start.elapsed().as_nanos() as u64);
+        }
+
+        result
     }
 }
 
@@ -424,6 +448,14 @@ pub fn eval_expression(
                         Bits::ShiftRight => lhs.bit_shr(op_span, &rhs, expr.span),
                     }
                 }
+                Operator::Coalesce => {
+                    let lhs = eval_expression(engine_state, stack, lhs)?;
+                    if matches!(lhs, Value::Nothing { .. }) {
+                        eval_expression(engine_state, stack, rhs)
+                    } else {
+                        Ok(lhs)
+                    }
+                }
                 Operator::Assignment(assignment) => {
                     let rhs = eval_expression(engine_state, stack, rhs)?;
 
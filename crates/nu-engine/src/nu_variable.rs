@@ -41,6 +41,10 @@ impl LazyRecord for NuVariable {
         cols.push("is-interactive");
         cols.push("is-login");
 
+        cols.push("argv");
+        cols.push("cache-path");
+        cols.push("data-path");
+
         cols
     }
 
@@ -193,6 +197,34 @@ impl LazyRecord for NuVariable {
                 val: self.engine_state.get_startup_time(),
                 span: self.span(),
             }),
+            "argv" => Ok(Value::List {
+                vals: std::env::args()
+                    .map(|arg| Value::string(arg, self.span()))
+                    .collect(),
+                span: self.span(),
+            }),
+            "cache-path" => {
+                if let Some(mut path) = nu_path::cache_dir() {
+                    path.push("nushell");
+                    Ok(Value::String {
+                        val: path.to_string_lossy().to_string(),
+                        span: self.span(),
+                    })
+                } else {
+                    err("Could not get cache directory")
+                }
+            }
+            "data-path" => {
+                if let Some(mut path) = nu_path::data_dir() {
+                    path.push("nushell");
+                    Ok(Value::String {
+                        val: path.to_string_lossy().to_string(),
+                        span: self.span(),
+                    })
+                } else {
+                    err("Could not get data directory")
+                }
+            }
             _ => err(&format!("Could not find column '{column}'")),
         }
     }
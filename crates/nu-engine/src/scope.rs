@@ -245,6 +245,26 @@ impl<'e, 's> ScopeData<'e, 's> {
                     span,
                 });
 
+                cols.push("attributes".to_string());
+                vals.push(Value::List {
+                    vals: signature
+                        .attributes
+                        .iter()
+                        .map(|attr| Value::Record {
+                            cols: vec!["name".into(), "value".into()],
+                            vals: vec![
+                                Value::string(&attr.name, span),
+                                match &attr.value {
+                                    Some(value) => Value::string(value, span),
+                                    None => Value::nothing(span),
+                                },
+                            ],
+                            span,
+                        })
+                        .collect(),
+                    span,
+                });
+
                 commands.push(Value::Record { cols, vals, span })
             }
         }
@@ -556,12 +576,16 @@ impl<'e, 's> ScopeData<'e, 's> {
                 },
             );
 
-            let module_usage = self
+            let (module_usage, module_extra_usage) = self
                 .engine_state
                 .build_module_usage(**module_id)
-                .map(|(usage, _)| usage)
                 .unwrap_or_default();
 
+            let file = module
+                .span
+                .and_then(|module_span| file_for_span(self.engine_state, module_span))
+                .map_or_else(|| Value::nothing(span), |file| Value::string(file, span));
+
             modules.push(Value::Record {
                 cols: vec![
                     "name".into(),
@@ -569,6 +593,8 @@ impl<'e, 's> ScopeData<'e, 's> {
                     "aliases".into(),
                     "env_block".into(),
                     "usage".into(),
+                    "extra_usage".into(),
+                    "file".into(),
                 ],
                 vals: vec![
                     Value::string(String::from_utf8_lossy(module_name), span),
@@ -582,6 +608,8 @@ impl<'e, 's> ScopeData<'e, 's> {
                     },
                     export_env_block,
                     Value::string(module_usage, span),
+                    Value::string(module_extra_usage, span),
+                    file,
                 ],
                 span,
             });
@@ -625,6 +653,15 @@ impl<'e, 's> ScopeData<'e, 's> {
     }
 }
 
+/// Finds the path of the file a span's contents were parsed from, if any
+/// (e.g. not the case for code entered directly at the REPL).
+fn file_for_span(engine_state: &EngineState, span: Span) -> Option<String> {
+    engine_state
+        .files()
+        .find(|(_, start, end)| span.start >= *start && span.start < *end)
+        .map(|(name, _, _)| name.clone())
+}
+
 fn extract_custom_completion_from_arg(engine_state: &EngineState, shape: &SyntaxShape) -> String {
     return match shape {
         SyntaxShape::Custom(_, custom_completion_decl_id) => {
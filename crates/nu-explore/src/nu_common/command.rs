@@ -55,7 +55,7 @@ fn eval_source2(
 ) -> Result<PipelineData, ShellError> {
     let (mut block, delta) = {
         let mut working_set = StateWorkingSet::new(engine_state);
-        let (output, err) = parse(
+        let (output, errors) = parse(
             &mut working_set,
             Some(fname), // format!("entry #{}", entry_num)
             source,
@@ -63,7 +63,7 @@ fn eval_source2(
             &[],
         );
 
-        if let Some(err) = err {
+        if let Some(err) = errors.first() {
             return Err(ShellError::IOError(err.to_string()));
         }
 
@@ -3,10 +3,15 @@ pub fn escape_quote_string(input: &str) -> String {
     output.push('"');
 
     for c in input.chars() {
-        if c == '"' || c == '\\' {
-            output.push('\\');
+        match c {
+            '"' | '\\' => {
+                output.push('\\');
+                output.push(c);
+            }
+            '\n' => output.push_str("\\n"),
+            '\r' => output.push_str("\\r"),
+            _ => output.push(c),
         }
-        output.push(c);
     }
 
     output.push('"');
@@ -256,11 +256,8 @@ pub enum ParseError {
     DuplicateCommandDef(#[label = "defined more than once"] Span),
 
     #[error("Unknown command.")]
-    #[diagnostic(
-        code(nu::parser::unknown_command),
-        // TODO: actual suggestions like "Did you mean `foo`?"
-    )]
-    UnknownCommand(#[label = "unknown command"] Span),
+    #[diagnostic(code(nu::parser::unknown_command))]
+    UnknownCommand(#[label = "unknown command"] Span, #[help] Option<String>),
 
     #[error("Non-UTF8 string.")]
     #[diagnostic(code(nu::parser::non_utf8))]
@@ -451,7 +448,7 @@ impl ParseError {
             ParseError::CantAddOverlayHelp(_, s) => *s,
             ParseError::NotFound(s) => *s,
             ParseError::DuplicateCommandDef(s) => *s,
-            ParseError::UnknownCommand(s) => *s,
+            ParseError::UnknownCommand(s, _) => *s,
             ParseError::NonUtf8(s) => *s,
             ParseError::UnknownFlag(_, _, s, _) => *s,
             ParseError::RequiredAfterOptional(_, s) => *s,
@@ -1,6 +1,6 @@
 use crate::ParseError;
 use nu_protocol::{
-    ast::{Expr, Expression},
+    ast::{Comparison, Expr, Expression, Operator, PathMember},
     engine::StateWorkingSet,
     Span, Value,
 };
@@ -20,15 +20,31 @@ pub fn eval_constant(
             val: b.clone(),
             span: expr.span,
         }),
-        Expr::Var(var_id) => match working_set.find_constant(*var_id) {
-            Some(val) => Ok(val.clone()),
-            None => Err(ParseError::NotAConstant(expr.span)),
-        },
+        Expr::Var(var_id) => {
+            if *var_id == nu_protocol::NU_VARIABLE_ID {
+                // `$nu` itself isn't a constant (most of its fields depend on the
+                // running engine), but `$nu.os-info.name`, used for conditional
+                // compilation, is knowable at parse time, so it is special-cased
+                // below via FullCellPath rather than here.
+                return Err(ParseError::NotAConstant(expr.span));
+            }
+
+            match working_set.find_constant(*var_id) {
+                Some(val) => Ok(val.clone()),
+                None => Err(ParseError::NotAConstant(expr.span)),
+            }
+        }
         Expr::CellPath(cell_path) => Ok(Value::CellPath {
             val: cell_path.clone(),
             span: expr.span,
         }),
         Expr::FullCellPath(cell_path) => {
+            if cell_path.head.expr == Expr::Var(nu_protocol::NU_VARIABLE_ID) {
+                if let Some(val) = eval_constant_nu_variable(&cell_path.tail, expr.span) {
+                    return Ok(val);
+                }
+            }
+
             let value = eval_constant(working_set, &cell_path.head)?;
 
             match value.follow_cell_path(&cell_path.tail, false, false) {
@@ -41,6 +57,27 @@ pub fn eval_constant(
                 )),
             }
         }
+        Expr::BinaryOp(lhs, op, rhs) => {
+            let op = match &op.expr {
+                Expr::Operator(op) => op,
+                _ => return Err(ParseError::NotAConstant(op.span)),
+            };
+            let lhs = eval_constant(working_set, lhs)?;
+            let rhs = eval_constant(working_set, rhs)?;
+
+            match op {
+                Operator::Comparison(Comparison::Equal) => lhs.eq(expr.span, &rhs, expr.span),
+                Operator::Comparison(Comparison::NotEqual) => lhs.ne(expr.span, &rhs, expr.span),
+                _ => return Err(ParseError::NotAConstant(expr.span)),
+            }
+            .map_err(|shell_error| {
+                ParseError::LabeledError(
+                    "Error when evaluating constant expression".to_string(),
+                    format!("{shell_error:?}"),
+                    expr.span,
+                )
+            })
+        }
         Expr::DateTime(dt) => Ok(Value::Date {
             val: *dt,
             span: expr.span,
@@ -115,6 +152,21 @@ pub fn eval_constant(
     }
 }
 
+/// Resolve the `$nu` fields that are knowable at parse time (used for
+/// conditional compilation, e.g. `if $nu.os-info.name == "windows"`),
+/// without pulling in the engine state that builds the rest of `$nu`.
+/// Only `os-info.name` is resolved; any other path gives `None`.
+fn eval_constant_nu_variable(tail: &[PathMember], span: Span) -> Option<Value> {
+    match tail {
+        [PathMember::String { val: a, .. }, PathMember::String { val: b, .. }]
+            if a == "os-info" && b == "name" =>
+        {
+            Some(Value::string(std::env::consts::OS, span))
+        }
+        _ => None,
+    }
+}
+
 /// Get the value as a string
 pub fn value_as_string(value: Value, span: Span) -> Result<String, ParseError> {
     match value {
@@ -6,7 +6,8 @@ use nu_protocol::{
         ImportPatternMember, PathMember, Pipeline, PipelineElement,
     },
     engine::{StateWorkingSet, DEFAULT_OVERLAY_NAME},
-    span, Alias, BlockId, Exportable, Module, PositionalArg, Span, Spanned, SyntaxShape, Type,
+    span, Alias, Attribute, BlockId, Exportable, Flag, Module, PositionalArg, Signature, Span,
+    Spanned, SyntaxShape, Type, Value,
 };
 use std::collections::HashSet;
 use std::path::{Path, PathBuf};
@@ -19,11 +20,11 @@ use crate::{
     eval::{eval_constant, value_as_string},
     known_external::KnownExternal,
     lex,
-    lite_parser::{lite_parse, LiteCommand, LiteElement},
+    lite_parser::{lite_parse, LiteBlock, LiteCommand, LiteElement},
     parser::{
         check_call, check_name, garbage, garbage_pipeline, parse, parse_call, parse_import_pattern,
-        parse_internal_call, parse_multispan_value, parse_signature, parse_string, parse_value,
-        parse_var_with_opt_type, trim_quotes, ParsedInternalCall,
+        parse_internal_call, parse_math_expression, parse_multispan_value, parse_signature,
+        parse_string, parse_value, parse_var_with_opt_type, trim_quotes, ParsedInternalCall,
     },
     unescape_unquote_string, ParseError, Token, TokenContents,
 };
@@ -343,6 +344,61 @@ pub fn parse_for(
     )
 }
 
+/// Pulls `name: description` lines out of a `def`'s doc comment and attaches
+/// them to the matching parameter or flag on `signature`, so `help` can show
+/// a description for parameters that weren't documented with an inline
+/// `# description` comment inside the signature itself. Lines that don't
+/// match a known parameter/flag name are left in place, since they're just
+/// part of the command's prose description.
+///
+/// Inline signature comments win over doc comment lines: a parameter whose
+/// `desc` is already set is left untouched.
+fn apply_doc_comment_params(extra_usage: String, signature: &mut Signature) -> String {
+    let mut remaining = vec![];
+
+    for line in extra_usage.lines() {
+        let Some((name, desc)) = line.split_once(':') else {
+            remaining.push(line);
+            continue;
+        };
+
+        let name = name.trim();
+        let desc = desc.trim();
+        if name.is_empty() || desc.is_empty() || name.contains(char::is_whitespace) {
+            remaining.push(line);
+            continue;
+        }
+
+        let target = match find_positional_mut(signature, name) {
+            Some(positional) => Some(&mut positional.desc),
+            None => find_flag_mut(signature, name).map(|flag| &mut flag.desc),
+        };
+
+        match target {
+            Some(target) if target.is_empty() => *target = desc.to_string(),
+            _ => remaining.push(line),
+        }
+    }
+
+    remaining.join("\n")
+}
+
+fn find_positional_mut<'sig>(
+    signature: &'sig mut Signature,
+    name: &str,
+) -> Option<&'sig mut PositionalArg> {
+    signature
+        .required_positional
+        .iter_mut()
+        .chain(signature.optional_positional.iter_mut())
+        .chain(signature.rest_positional.iter_mut())
+        .find(|positional| positional.name == name)
+}
+
+fn find_flag_mut<'sig>(signature: &'sig mut Signature, name: &str) -> Option<&'sig mut Flag> {
+    signature.named.iter_mut().find(|flag| flag.long == name)
+}
+
 pub fn parse_def(
     working_set: &mut StateWorkingSet,
     lite_command: &LiteCommand,
@@ -491,7 +547,11 @@ pub fn parse_def(
             signature.name = name.clone();
             *signature = signature.add_help();
             signature.usage = usage;
-            signature.extra_usage = extra_usage;
+            signature.extra_usage = apply_doc_comment_params(extra_usage, &mut signature);
+            signature.attributes = working_set
+                .pending_attributes
+                .remove(&spans[0].start)
+                .unwrap_or_default();
 
             *declaration = signature.clone().into_block_command(block_id);
 
@@ -667,7 +727,24 @@ pub fn parse_extern(
                 signature.name = external_name.clone();
                 signature.usage = usage.clone();
                 signature.extra_usage = extra_usage.clone();
-                signature.allows_unknown_args = true;
+                signature.allows_unknown_args = call.has_flag("unknown-ok");
+
+                // If this extern declares a subcommand (e.g. "git push"), inherit any
+                // flags declared on the parent extern (e.g. "git") that this signature
+                // doesn't already override, so shared flags don't have to be repeated
+                // on every subcommand.
+                if let Some((parent_name, _)) = external_name.rsplit_once(' ') {
+                    if let Some(parent_id) =
+                        working_set.find_decl(parent_name.as_bytes(), &Type::Any)
+                    {
+                        let parent_signature = working_set.get_decl(parent_id).signature();
+                        for flag in parent_signature.named {
+                            if !signature.named.iter().any(|f| f.long == flag.long) {
+                                signature.named.push(flag);
+                            }
+                        }
+                    }
+                }
 
                 let decl = KnownExternal {
                     name: external_name,
@@ -1836,6 +1913,181 @@ fn collect_first_comments(tokens: &[Token]) -> Vec<Span> {
     comments
 }
 
+/// Strips top-level `@name [value]` attribute lines out of a lite-parsed
+/// block, stashing each run of them on [`StateWorkingSet::pending_attributes`]
+/// keyed by the byte offset of the declaration they precede, so that
+/// `parse_def` can claim them once it parses that declaration. A line with no
+/// following declaration (e.g. at the end of the block) is silently dropped.
+pub(crate) fn extract_attributes(working_set: &mut StateWorkingSet, block: LiteBlock) -> LiteBlock {
+    let mut pending = vec![];
+    let mut stripped = LiteBlock::new();
+
+    for pipeline in block.block {
+        if pipeline.commands.len() == 1 {
+            if let LiteElement::Command(_, command) = &pipeline.commands[0] {
+                if !command.parts.is_empty()
+                    && working_set
+                        .get_span_contents(command.parts[0])
+                        .starts_with(b"@")
+                {
+                    pending.push(parse_attribute(working_set, command));
+                    continue;
+                }
+
+                if !pending.is_empty() {
+                    working_set
+                        .pending_attributes
+                        .insert(command.parts[0].start, std::mem::take(&mut pending));
+                }
+            }
+        }
+        stripped.push(pipeline);
+    }
+
+    stripped
+}
+
+/// Parses a single `@name` or `@name value` lite command into an [`Attribute`].
+/// `value`, if present, is the raw source text following the name, with
+/// surrounding quotes trimmed.
+fn parse_attribute(working_set: &StateWorkingSet, command: &LiteCommand) -> Attribute {
+    let name =
+        String::from_utf8_lossy(&working_set.get_span_contents(command.parts[0])[1..]).to_string();
+
+    let value = command.parts.get(1).map(|first| {
+        let value_span = Span::new(first.start, command.parts[command.parts.len() - 1].end);
+        String::from_utf8_lossy(trim_quotes(working_set.get_span_contents(value_span))).to_string()
+    });
+
+    Attribute { name, value }
+}
+
+/// Resolves top-level `if <const-condition> { ... } else { ... }` statements in
+/// a module block at parse time, replacing each one with only the pipelines of
+/// the branch the condition selects (or nothing, if the condition is false and
+/// there's no `else`). This lets a module declare different `def`/`export def`
+/// for e.g. different platforms without needing separate files.
+///
+/// Only a single `if`/`else` pair is understood (no `else if` chains, which is a
+/// parse error here even though regular `if` supports them), and the condition
+/// must be something `eval_constant` can resolve without running the engine,
+/// such as a `const` value or a comparison against `$nu.os-info.name`.
+fn expand_module_conditional_compilation(
+    working_set: &mut StateWorkingSet,
+    block: LiteBlock,
+    expand_aliases_denylist: &[usize],
+) -> (LiteBlock, Option<ParseError>) {
+    let mut error = None;
+    let mut expanded = LiteBlock::new();
+
+    for pipeline in block.block {
+        if pipeline.commands.len() == 1 {
+            if let LiteElement::Command(_, command) = &pipeline.commands[0] {
+                if !command.parts.is_empty()
+                    && working_set.get_span_contents(command.parts[0]) == b"if"
+                {
+                    match parse_module_if_branch(working_set, command, expand_aliases_denylist) {
+                        Ok(Some(branch)) => {
+                            for branch_pipeline in branch.block {
+                                expanded.push(branch_pipeline);
+                            }
+                        }
+                        Ok(None) => { /* condition false and no else: drop the branch */ }
+                        Err(err) => error = error.or(Some(err)),
+                    }
+                    continue;
+                }
+            }
+        }
+
+        expanded.push(pipeline);
+    }
+
+    (expanded, error)
+}
+
+/// Parses a single top-level `if <const-condition> { .. } else { .. }` lite
+/// command, returning the lite-parsed pipelines of the selected branch (or
+/// `None` if the condition is false and there's no `else` branch).
+fn parse_module_if_branch(
+    working_set: &mut StateWorkingSet,
+    command: &LiteCommand,
+    expand_aliases_denylist: &[usize],
+) -> Result<Option<LiteBlock>, ParseError> {
+    let parts = &command.parts[1..];
+
+    let then_idx = parts
+        .iter()
+        .position(|span| working_set.get_span_contents(*span).starts_with(b"{"))
+        .ok_or_else(|| ParseError::Expected("block".into(), command.parts[0]))?;
+
+    if then_idx == 0 {
+        return Err(ParseError::Expected(
+            "condition before block".into(),
+            command.parts[0],
+        ));
+    }
+
+    let (cond_expr, err) = parse_math_expression(
+        working_set,
+        &parts[..then_idx],
+        None,
+        expand_aliases_denylist,
+    );
+    if let Some(err) = err {
+        return Err(err);
+    }
+
+    let cond = eval_constant(working_set, &cond_expr)?
+        .as_bool()
+        .map_err(|_| ParseError::Expected("boolean condition".into(), cond_expr.span))?;
+
+    let then_span = parts[then_idx];
+    let trailing = &parts[then_idx + 1..];
+
+    let else_span = match trailing {
+        [] => None,
+        [else_kw, block_span]
+            if working_set.get_span_contents(*else_kw) == b"else"
+                && working_set.get_span_contents(*block_span).starts_with(b"{") =>
+        {
+            Some(*block_span)
+        }
+        _ => {
+            // Either the `else` isn't followed by a plain `{ .. }` block (most commonly an
+            // `else if` chain, which regular `if` supports but this const-guarded form
+            // doesn't), or there's other unrecognized content after the blocks. Either way,
+            // silently dropping it would make declarations vanish with no diagnostic.
+            return Err(ParseError::Expected(
+                "'else { ... }' - 'else if' chains aren't supported here".into(),
+                span(trailing),
+            ));
+        }
+    };
+
+    let chosen_span = if cond { Some(then_span) } else { else_span };
+
+    let Some(block_span) = chosen_span else {
+        return Ok(None);
+    };
+
+    // Strip the surrounding `{` `}` before re-lexing the branch's contents.
+    let inner_span = Span::new(block_span.start + 1, block_span.end - 1);
+    let source = working_set.get_span_contents(inner_span).to_vec();
+
+    let (tokens, err) = lex(&source, inner_span.start, &[], &[], false);
+    if let Some(err) = err {
+        return Err(err);
+    }
+
+    let (branch, err) = lite_parse(&tokens);
+    if let Some(err) = err {
+        return Err(err);
+    }
+
+    Ok(Some(branch))
+}
+
 pub fn parse_module_block(
     working_set: &mut StateWorkingSet,
     span: Span,
@@ -1856,6 +2108,12 @@ pub fn parse_module_block(
     let (output, err) = lite_parse(&output);
     error = error.or(err);
 
+    let (output, err) =
+        expand_module_conditional_compilation(working_set, output, expand_aliases_denylist);
+    error = error.or(err);
+
+    let output = extract_attributes(working_set, output);
+
     for pipeline in &output.block {
         if pipeline.commands.len() == 1 {
             if let LiteElement::Command(_, command) = &pipeline.commands[0] {
@@ -3416,6 +3674,7 @@ pub fn parse_source(
                             scoped,
                             expand_aliases_denylist,
                         );
+                        let err = err.into_iter().next();
 
                         // Restore the currently parsed directory back
                         working_set.currently_parsed_cwd = prev_currently_parsed_cwd;
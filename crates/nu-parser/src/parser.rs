@@ -13,13 +13,14 @@ use nu_protocol::{
         FullCellPath, ImportPattern, ImportPatternHead, ImportPatternMember, Math, Operator,
         PathMember, Pipeline, PipelineElement, RangeInclusion, RangeOperator,
     },
+    did_you_mean_multiple,
     engine::StateWorkingSet,
-    span, BlockId, Flag, PositionalArg, Signature, Span, Spanned, SyntaxShape, Type, Unit, VarId,
-    ENV_VARIABLE_ID, IN_VARIABLE_ID,
+    format_suggestions, span, BlockId, Flag, PositionalArg, Signature, Span, Spanned, SyntaxShape,
+    Type, Unit, VarId, ENV_VARIABLE_ID, IN_VARIABLE_ID,
 };
 
 use crate::parse_keywords::{
-    is_unaliasable_parser_keyword, parse_alias, parse_def, parse_def_predecl,
+    extract_attributes, is_unaliasable_parser_keyword, parse_alias, parse_def, parse_def_predecl,
     parse_export_in_block, parse_extern, parse_for, parse_hide, parse_keyword, parse_let_or_const,
     parse_module, parse_old_alias, parse_overlay_hide, parse_overlay_new, parse_overlay_use,
     parse_source, parse_use, parse_where, parse_where_expr,
@@ -440,7 +441,7 @@ fn parse_long_flag(
                         sig.name.clone(),
                         long_name.clone(),
                         arg_span,
-                        sig.clone().formatted_flags(),
+                        suggest_flag(sig, &long_name),
                     )),
                 )
             }
@@ -506,7 +507,10 @@ fn parse_short_flags(
                                 sig.name.clone(),
                                 format!("-{}", String::from_utf8_lossy(contents)),
                                 *first,
-                                sig.clone().formatted_flags(),
+                                suggest_flag(
+                                    sig,
+                                    &format!("-{}", String::from_utf8_lossy(contents)),
+                                ),
                             ))
                         });
                     }
@@ -517,7 +521,7 @@ fn parse_short_flags(
                             sig.name.clone(),
                             format!("-{}", String::from_utf8_lossy(contents)),
                             *first,
-                            sig.clone().formatted_flags(),
+                            suggest_flag(sig, &format!("-{}", String::from_utf8_lossy(contents))),
                         ))
                     });
                 }
@@ -528,7 +532,7 @@ fn parse_short_flags(
                         sig.name.clone(),
                         format!("-{}", String::from_utf8_lossy(contents)),
                         *first,
-                        sig.clone().formatted_flags(),
+                        suggest_flag(sig, &format!("-{}", String::from_utf8_lossy(contents))),
                     ))
                 });
             }
@@ -540,7 +544,7 @@ fn parse_short_flags(
                         sig.name.clone(),
                         format!("-{}", String::from_utf8_lossy(contents)),
                         *first,
-                        sig.clone().formatted_flags(),
+                        suggest_flag(sig, &format!("-{}", String::from_utf8_lossy(contents))),
                     ))
                 });
             }
@@ -2046,6 +2050,7 @@ pub fn parse_full_cell_path(
             // the previous input type found in that block
             let (output, err) =
                 parse_block(working_set, &output, true, expand_aliases_denylist, true);
+            let err = err.into_iter().next();
             working_set
                 .type_scope
                 .add_type(working_set.type_scope.get_last_output());
@@ -2842,6 +2847,44 @@ pub fn parse_string_strict(
     }
 }
 
+/// Builds the help text for [`ParseError::UnknownFlag`], prefixing the
+/// existing flag listing with a "did you mean" hint when a close match for
+/// `flag_name` is found among `sig`'s flags.
+fn suggest_flag(sig: &Signature, flag_name: &str) -> String {
+    let candidates: Vec<String> = sig
+        .named
+        .iter()
+        .flat_map(|flag| {
+            let mut names = vec![format!("--{}", flag.long)];
+            if let Some(short) = flag.short {
+                names.push(format!("-{short}"));
+            }
+            names
+        })
+        .collect();
+
+    match format_suggestions(&did_you_mean_multiple(&candidates, flag_name)) {
+        Some(suggestion) => format!(
+            "Did you mean {suggestion}? {}",
+            sig.clone().formatted_flags()
+        ),
+        None => sig.clone().formatted_flags(),
+    }
+}
+
+/// Suggests up to three known command names close to `name`, for use in
+/// [`ParseError::UnknownCommand`]'s help text.
+fn suggest_command(working_set: &StateWorkingSet, name: &str) -> Option<String> {
+    let candidates: Vec<String> = working_set
+        .find_commands_by_predicate(|_| true)
+        .into_iter()
+        .map(|(name, _)| String::from_utf8_lossy(&name).to_string())
+        .collect();
+
+    let suggestions = did_you_mean_multiple(&candidates, name);
+    format_suggestions(&suggestions).map(|s| format!("Did you mean '{s}'?"))
+}
+
 //TODO: Handle error case for unknown shapes
 pub fn parse_shape_name(
     working_set: &StateWorkingSet,
@@ -2899,12 +2942,14 @@ pub fn parse_shape_name(
                 if let Some(decl_id) = decl_id {
                     return (SyntaxShape::Custom(Box::new(shape), decl_id), err);
                 } else {
+                    let suggestion =
+                        suggest_command(working_set, &String::from_utf8_lossy(command_name));
                     return (
                         shape,
-                        Some(ParseError::UnknownCommand(Span::new(
-                            span.start + split[0].len() + 1,
-                            span.end,
-                        ))),
+                        Some(ParseError::UnknownCommand(
+                            Span::new(span.start + split[0].len() + 1, span.end),
+                            suggestion,
+                        )),
                     );
                 }
             } else {
@@ -4227,6 +4272,7 @@ pub fn parse_block_expression(
         expand_aliases_denylist,
         false,
     );
+    let err = err.into_iter().next();
     error = error.or(err);
 
     if let Some(signature) = signature {
@@ -4388,6 +4434,7 @@ pub fn parse_closure_expression(
         expand_aliases_denylist,
         false,
     );
+    let err = err.into_iter().next();
     error = error.or(err);
 
     if let Some(signature) = signature {
@@ -4759,6 +4806,7 @@ pub fn parse_operator(
         b"or" => Operator::Boolean(Boolean::Or),
         b"xor" => Operator::Boolean(Boolean::Xor),
         b"**" => Operator::Math(Math::Pow),
+        b"??" => Operator::Coalesce,
         // WARNING: not actual operators below! Error handling only
         pow @ (b"^" | b"pow") => {
             return (
@@ -5115,9 +5163,11 @@ pub fn parse_expression(
     }
 
     if pos == spans.len() {
+        let name = String::from_utf8_lossy(working_set.get_span_contents(spans[0])).to_string();
+        let suggestion = suggest_command(working_set, &name);
         return (
             garbage(span(spans)),
-            Some(ParseError::UnknownCommand(spans[0])),
+            Some(ParseError::UnknownCommand(spans[0], suggestion)),
         );
     }
 
@@ -5492,11 +5542,13 @@ pub fn parse_block(
     scoped: bool,
     expand_aliases_denylist: &[usize],
     is_subexpression: bool,
-) -> (Block, Option<ParseError>) {
-    let mut error = None;
+) -> (Block, Vec<ParseError>) {
+    let mut errors = vec![];
 
     let (lite_block, err) = lite_parse(tokens);
-    error = error.or(err);
+    errors.extend(err);
+
+    let lite_block = extract_attributes(working_set, lite_block);
 
     trace!("parsing block: {:?}", lite_block);
 
@@ -5518,7 +5570,7 @@ pub fn parse_block(
                     if let Some(err) =
                         parse_def_predecl(working_set, &command.parts, expand_aliases_denylist)
                     {
-                        error = error.or(Some(err));
+                        errors.push(err);
                     }
                 }
             }
@@ -5545,9 +5597,7 @@ pub fn parse_block(
                             );
                             working_set.type_scope.add_type(expr.ty.clone());
 
-                            if error.is_none() {
-                                error = err;
-                            }
+                            errors.extend(err);
 
                             PipelineElement::Expression(*span, expr)
                         }
@@ -5561,9 +5611,7 @@ pub fn parse_block(
 
                             working_set.type_scope.add_type(expr.ty.clone());
 
-                            if error.is_none() {
-                                error = err;
-                            }
+                            errors.extend(err);
 
                             PipelineElement::Redirection(*span, redirection.clone(), expr)
                         }
@@ -5580,9 +5628,7 @@ pub fn parse_block(
 
                             working_set.type_scope.add_type(out_expr.ty.clone());
 
-                            if error.is_none() {
-                                error = out_err;
-                            }
+                            errors.extend(out_err);
 
                             let (err_expr, err_err) = parse_string(
                                 working_set,
@@ -5592,9 +5638,7 @@ pub fn parse_block(
 
                             working_set.type_scope.add_type(err_expr.ty.clone());
 
-                            if error.is_none() {
-                                error = err_err;
-                            }
+                            errors.extend(err_err);
 
                             PipelineElement::SeparateRedirection {
                                 out: (*out_span, out_expr),
@@ -5681,9 +5725,7 @@ pub fn parse_block(
                             }
                         }
 
-                        if error.is_none() {
-                            error = err;
-                        }
+                        errors.extend(err);
 
                         pipeline
                     }
@@ -5697,7 +5739,7 @@ pub fn parse_block(
     }
     working_set.type_scope.exit_scope();
 
-    (block, error)
+    (block, errors)
 }
 
 pub fn discover_captures_in_closure(
@@ -6121,14 +6163,20 @@ fn wrap_expr_with_collect(working_set: &mut StateWorkingSet, expr: &Expression)
 // Parses a vector of u8 to create an AST Block. If a file name is given, then
 // the name is stored in the working set. When parsing a source without a file
 // name, the source of bytes is stored as "source"
+//
+// Returns every error collected while parsing the block's top-level pipelines,
+// rather than just the first one, so a script with several unrelated mistakes
+// (e.g. two typo'd commands) gets reported in a single pass. Errors raised
+// while parsing inside a single pipeline element still stop at the first
+// problem found for that element.
 pub fn parse(
     working_set: &mut StateWorkingSet,
     fname: Option<&str>,
     contents: &[u8],
     scoped: bool,
     expand_aliases_denylist: &[usize],
-) -> (Block, Option<ParseError>) {
-    let mut error = None;
+) -> (Block, Vec<ParseError>) {
+    let mut errors = vec![];
 
     let span_offset = working_set.next_span_start();
 
@@ -6140,11 +6188,11 @@ pub fn parse(
     working_set.add_file(name, contents);
 
     let (output, err) = lex(contents, span_offset, &[], &[], false);
-    error = error.or(err);
+    errors.extend(err);
 
     let (mut output, err) =
         parse_block(working_set, &output, scoped, expand_aliases_denylist, false);
-    error = error.or(err);
+    errors.extend(err);
 
     let mut seen = vec![];
     let mut seen_blocks = HashMap::new();
@@ -6152,7 +6200,7 @@ pub fn parse(
     let captures = discover_captures_in_closure(working_set, &output, &mut seen, &mut seen_blocks);
     match captures {
         Ok(captures) => output.captures = captures.into_iter().map(|(var_id, _)| var_id).collect(),
-        Err(err) => error = Some(err),
+        Err(err) => errors.push(err),
     }
 
     // Also check other blocks that might have been imported
@@ -6166,7 +6214,7 @@ pub fn parse(
                 Ok(captures) => {
                     seen_blocks.insert(block_id, captures);
                 }
-                Err(err) => error = Some(err),
+                Err(err) => errors.push(err),
             }
         }
     }
@@ -6184,5 +6232,5 @@ pub fn parse(
         }
     }
 
-    (output, error)
+    (output, errors)
 }
@@ -565,6 +565,10 @@ pub fn math_result_type(
                     )
                 }
             },
+            Operator::Coalesce => match (&lhs.ty, &rhs.ty) {
+                (x, y) if x == y => (x.clone(), None),
+                _ => (Type::Any, None),
+            },
             Operator::Assignment(_) => match (&lhs.ty, &rhs.ty) {
                 (x, y) if x == y => (Type::Nothing, None),
                 (Type::Any, _) => (Type::Nothing, None),
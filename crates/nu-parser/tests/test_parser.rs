@@ -4,7 +4,7 @@ use nu_protocol::ast::Call;
 use nu_protocol::{
     ast::{Expr, Expression, PipelineElement},
     engine::{Command, EngineState, Stack, StateWorkingSet},
-    PipelineData, ShellError, Signature, SyntaxShape,
+    PipelineData, ShellError, Signature, Span, SyntaxShape,
 };
 
 #[cfg(test)]
@@ -42,6 +42,35 @@ impl Command for Let {
     }
 }
 
+#[cfg(test)]
+#[derive(Clone)]
+pub struct Module;
+
+#[cfg(test)]
+impl Command for Module {
+    fn name(&self) -> &str {
+        "module"
+    }
+
+    fn usage(&self) -> &str {
+        "Define a custom module."
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build("module")
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        _call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        todo!()
+    }
+}
+
 fn test_int(
     test_tag: &str,     // name of sub-test
     test: &[u8],        // input expression
@@ -53,6 +82,7 @@ fn test_int(
     let mut working_set = StateWorkingSet::new(&engine_state);
 
     let (block, err) = parse(&mut working_set, None, test, true, &[]);
+    let err = err.into_iter().next();
 
     if let Some(err_pat) = expected_err {
         if let Some(parse_err) = err {
@@ -265,6 +295,7 @@ fn test_parse_any() {
     let mut working_set = StateWorkingSet::new(&engine_state);
 
     let (block, err) = parse(&mut working_set, None, test, true, &[]);
+    let err = err.into_iter().next();
 
     match (block, err) {
         (_, Some(e)) => {
@@ -281,6 +312,7 @@ pub fn parse_int() {
     let mut working_set = StateWorkingSet::new(&engine_state);
 
     let (block, err) = parse(&mut working_set, None, b"3", true, &[]);
+    let err = err.into_iter().next();
 
     assert!(err.is_none());
     assert_eq!(block.len(), 1);
@@ -304,6 +336,7 @@ pub fn parse_int_with_underscores() {
     let mut working_set = StateWorkingSet::new(&engine_state);
 
     let (block, err) = parse(&mut working_set, None, b"420_69_2023", true, &[]);
+    let err = err.into_iter().next();
 
     assert!(err.is_none());
     assert_eq!(block.len(), 1);
@@ -327,6 +360,7 @@ pub fn parse_binary_with_hex_format() {
     let mut working_set = StateWorkingSet::new(&engine_state);
 
     let (block, err) = parse(&mut working_set, None, b"0x[13]", true, &[]);
+    let err = err.into_iter().next();
 
     assert!(err.is_none());
     assert_eq!(block.len(), 1);
@@ -345,6 +379,7 @@ pub fn parse_binary_with_incomplete_hex_format() {
     let mut working_set = StateWorkingSet::new(&engine_state);
 
     let (block, err) = parse(&mut working_set, None, b"0x[3]", true, &[]);
+    let err = err.into_iter().next();
 
     assert!(err.is_none());
     assert_eq!(block.len(), 1);
@@ -363,6 +398,7 @@ pub fn parse_binary_with_binary_format() {
     let mut working_set = StateWorkingSet::new(&engine_state);
 
     let (block, err) = parse(&mut working_set, None, b"0b[1010 1000]", true, &[]);
+    let err = err.into_iter().next();
 
     assert!(err.is_none());
     assert_eq!(block.len(), 1);
@@ -381,6 +417,7 @@ pub fn parse_binary_with_incomplete_binary_format() {
     let mut working_set = StateWorkingSet::new(&engine_state);
 
     let (block, err) = parse(&mut working_set, None, b"0b[10]", true, &[]);
+    let err = err.into_iter().next();
 
     assert!(err.is_none());
     assert_eq!(block.len(), 1);
@@ -399,6 +436,7 @@ pub fn parse_binary_with_octal_format() {
     let mut working_set = StateWorkingSet::new(&engine_state);
 
     let (block, err) = parse(&mut working_set, None, b"0o[250]", true, &[]);
+    let err = err.into_iter().next();
 
     assert!(err.is_none());
     assert_eq!(block.len(), 1);
@@ -417,6 +455,7 @@ pub fn parse_binary_with_incomplete_octal_format() {
     let mut working_set = StateWorkingSet::new(&engine_state);
 
     let (block, err) = parse(&mut working_set, None, b"0o[2]", true, &[]);
+    let err = err.into_iter().next();
 
     assert!(err.is_none());
     assert_eq!(block.len(), 1);
@@ -435,6 +474,7 @@ pub fn parse_binary_with_invalid_octal_format() {
     let mut working_set = StateWorkingSet::new(&engine_state);
 
     let (block, err) = parse(&mut working_set, None, b"0b[90]", true, &[]);
+    let err = err.into_iter().next();
 
     assert!(err.is_none());
     assert_eq!(block.len(), 1);
@@ -455,6 +495,7 @@ pub fn parse_binary_with_multi_byte_char() {
     // found using fuzzing, Rust can panic if you slice into this string
     let contents = b"0x[\xEF\xBF\xBD]";
     let (block, err) = parse(&mut working_set, None, contents, true, &[]);
+    let err = err.into_iter().next();
 
     assert!(err.is_none());
     assert_eq!(block.len(), 1);
@@ -476,6 +517,7 @@ pub fn parse_call() {
     working_set.add_decl(sig.predeclare());
 
     let (block, err) = parse(&mut working_set, None, b"foo", true, &[]);
+    let err = err.into_iter().next();
 
     assert!(err.is_none());
     assert_eq!(block.len(), 1);
@@ -504,6 +546,7 @@ pub fn parse_call_missing_flag_arg() {
     working_set.add_decl(sig.predeclare());
 
     let (_, err) = parse(&mut working_set, None, b"foo --jazz", true, &[]);
+    let err = err.into_iter().next();
     assert!(matches!(err, Some(ParseError::MissingFlagParam(..))));
 }
 
@@ -516,6 +559,7 @@ pub fn parse_call_missing_short_flag_arg() {
     working_set.add_decl(sig.predeclare());
 
     let (_, err) = parse(&mut working_set, None, b"foo -j", true, &[]);
+    let err = err.into_iter().next();
     assert!(matches!(err, Some(ParseError::MissingFlagParam(..))));
 }
 
@@ -529,6 +573,7 @@ pub fn parse_call_too_many_shortflag_args() {
         .named("--math", SyntaxShape::Int, "math!!", Some('m'));
     working_set.add_decl(sig.predeclare());
     let (_, err) = parse(&mut working_set, None, b"foo -mj", true, &[]);
+    let err = err.into_iter().next();
     assert!(matches!(
         err,
         Some(ParseError::ShortFlagBatchCantTakeArg(..))
@@ -543,6 +588,7 @@ pub fn parse_call_unknown_shorthand() {
     let sig = Signature::build("foo").switch("--jazz", "jazz!!", Some('j'));
     working_set.add_decl(sig.predeclare());
     let (_, err) = parse(&mut working_set, None, b"foo -mj", true, &[]);
+    let err = err.into_iter().next();
     assert!(matches!(err, Some(ParseError::UnknownFlag(..))));
 }
 
@@ -554,6 +600,7 @@ pub fn parse_call_extra_positional() {
     let sig = Signature::build("foo").switch("--jazz", "jazz!!", Some('j'));
     working_set.add_decl(sig.predeclare());
     let (_, err) = parse(&mut working_set, None, b"foo -j 100", true, &[]);
+    let err = err.into_iter().next();
     assert!(matches!(err, Some(ParseError::ExtraPositional(..))));
 }
 
@@ -565,6 +612,7 @@ pub fn parse_call_missing_req_positional() {
     let sig = Signature::build("foo").required("jazz", SyntaxShape::Int, "jazz!!");
     working_set.add_decl(sig.predeclare());
     let (_, err) = parse(&mut working_set, None, b"foo", true, &[]);
+    let err = err.into_iter().next();
     assert!(matches!(err, Some(ParseError::MissingPositional(..))));
 }
 
@@ -576,14 +624,37 @@ pub fn parse_call_missing_req_flag() {
     let sig = Signature::build("foo").required_named("--jazz", SyntaxShape::Int, "jazz!!", None);
     working_set.add_decl(sig.predeclare());
     let (_, err) = parse(&mut working_set, None, b"foo", true, &[]);
+    let err = err.into_iter().next();
     assert!(matches!(err, Some(ParseError::MissingRequiredFlag(..))));
 }
 
+#[test]
+fn module_level_if_rejects_else_if_chain() {
+    let engine_state = EngineState::new();
+    let mut working_set = StateWorkingSet::new(&engine_state);
+
+    working_set.add_decl(Box::new(Module));
+
+    let (_, err) = parse(
+        &mut working_set,
+        None,
+        b"module foo { if true { } else if false { } else { } }",
+        true,
+        &[],
+    );
+
+    assert!(
+        !err.is_empty(),
+        "an `else if` chain in a module-level if isn't supported and should be a parse error"
+    );
+}
+
 #[test]
 fn test_nothing_comparison_eq() {
     let engine_state = EngineState::new();
     let mut working_set = StateWorkingSet::new(&engine_state);
     let (block, err) = parse(&mut working_set, None, b"2 == null", true, &[]);
+    let err = err.into_iter().next();
 
     assert!(err.is_none());
     assert_eq!(block.len(), 1);
@@ -607,6 +678,7 @@ fn test_nothing_comparison_neq() {
     let engine_state = EngineState::new();
     let mut working_set = StateWorkingSet::new(&engine_state);
     let (block, err) = parse(&mut working_set, None, b"2 != null", true, &[]);
+    let err = err.into_iter().next();
 
     assert!(err.is_none());
     assert_eq!(block.len(), 1);
@@ -634,6 +706,7 @@ mod string {
         let mut working_set = StateWorkingSet::new(&engine_state);
 
         let (block, err) = parse(&mut working_set, None, b"\"hello nushell\"", true, &[]);
+        let err = err.into_iter().next();
 
         assert!(err.is_none());
         assert_eq!(block.len(), 1);
@@ -657,6 +730,7 @@ mod string {
             let mut working_set = StateWorkingSet::new(&engine_state);
 
             let (block, err) = parse(&mut working_set, None, b"$\"hello (39 + 3)\"", true, &[]);
+            let err = err.into_iter().next();
 
             assert!(err.is_none());
             assert_eq!(block.len(), 1);
@@ -689,6 +763,7 @@ mod string {
             let mut working_set = StateWorkingSet::new(&engine_state);
 
             let (block, err) = parse(&mut working_set, None, b"$\"hello \\(39 + 3)\"", true, &[]);
+            let err = err.into_iter().next();
 
             assert!(err.is_none());
 
@@ -726,6 +801,7 @@ mod string {
                 true,
                 &[],
             );
+            let err = err.into_iter().next();
 
             assert!(err.is_none());
 
@@ -765,6 +841,7 @@ mod string {
                 true,
                 &[],
             );
+            let err = err.into_iter().next();
 
             assert!(err.is_none());
 
@@ -810,6 +887,7 @@ mod string {
                 true,
                 &[],
             );
+            let err = err.into_iter().next();
 
             assert!(err.is_none());
         }
@@ -835,6 +913,7 @@ mod string {
                 true,
                 &[],
             );
+            let err = err.into_iter().next();
 
             assert!(err.is_none());
         }
@@ -851,6 +930,7 @@ mod range {
         let mut working_set = StateWorkingSet::new(&engine_state);
 
         let (block, err) = parse(&mut working_set, None, b"0..10", true, &[]);
+        let err = err.into_iter().next();
 
         assert!(err.is_none());
         assert_eq!(block.len(), 1);
@@ -883,6 +963,7 @@ mod range {
         let mut working_set = StateWorkingSet::new(&engine_state);
 
         let (block, err) = parse(&mut working_set, None, b"0..<10", true, &[]);
+        let err = err.into_iter().next();
 
         assert!(err.is_none());
         assert_eq!(block.len(), 1);
@@ -915,6 +996,7 @@ mod range {
         let mut working_set = StateWorkingSet::new(&engine_state);
 
         let (block, err) = parse(&mut working_set, None, b"10..0", true, &[]);
+        let err = err.into_iter().next();
 
         assert!(err.is_none());
         assert_eq!(block.len(), 1);
@@ -947,6 +1029,7 @@ mod range {
         let mut working_set = StateWorkingSet::new(&engine_state);
 
         let (block, err) = parse(&mut working_set, None, b"(3 - 3)..<(8 + 2)", true, &[]);
+        let err = err.into_iter().next();
 
         assert!(err.is_none());
         assert_eq!(block.len(), 1);
@@ -981,6 +1064,7 @@ mod range {
         working_set.add_decl(Box::new(Let));
 
         let (block, err) = parse(&mut working_set, None, b"let a = 2; $a..10", true, &[]);
+        let err = err.into_iter().next();
 
         assert!(err.is_none());
         assert_eq!(block.len(), 2);
@@ -1021,6 +1105,7 @@ mod range {
             true,
             &[],
         );
+        let err = err.into_iter().next();
 
         assert!(err.is_none());
         assert_eq!(block.len(), 2);
@@ -1053,6 +1138,7 @@ mod range {
         let mut working_set = StateWorkingSet::new(&engine_state);
 
         let (block, err) = parse(&mut working_set, None, b"0..", true, &[]);
+        let err = err.into_iter().next();
 
         assert!(err.is_none());
         assert_eq!(block.len(), 1);
@@ -1085,6 +1171,7 @@ mod range {
         let mut working_set = StateWorkingSet::new(&engine_state);
 
         let (block, err) = parse(&mut working_set, None, b"..10", true, &[]);
+        let err = err.into_iter().next();
 
         assert!(err.is_none());
         assert_eq!(block.len(), 1);
@@ -1117,6 +1204,7 @@ mod range {
         let mut working_set = StateWorkingSet::new(&engine_state);
 
         let (block, err) = parse(&mut working_set, None, b"-10..-3", true, &[]);
+        let err = err.into_iter().next();
 
         assert!(err.is_none());
         assert_eq!(block.len(), 1);
@@ -1149,6 +1237,7 @@ mod range {
         let mut working_set = StateWorkingSet::new(&engine_state);
 
         let (block, err) = parse(&mut working_set, None, b"2.0..4.0..10.0", true, &[]);
+        let err = err.into_iter().next();
 
         assert!(err.is_none());
         assert_eq!(block.len(), 1);
@@ -1181,6 +1270,7 @@ mod range {
         let mut working_set = StateWorkingSet::new(&engine_state);
 
         let (_, err) = parse(&mut working_set, None, b"(0)..\"a\"", true, &[]);
+        let err = err.into_iter().next();
 
         assert!(err.is_some());
     }
@@ -1498,6 +1588,7 @@ mod input_types {
         let input = r#"ls | to-custom | group-by name other"#;
 
         let (block, err) = parse(&mut working_set, None, input.as_bytes(), true, &[]);
+        let err = err.into_iter().next();
 
         assert!(err.is_none());
         assert_eq!(block.len(), 1);
@@ -1562,6 +1653,7 @@ mod input_types {
             r#"let a = (ls | to-custom | group-by name other); let b = (1+3); $a | agg sum"#;
 
         let (block, err) = parse(&mut working_set, None, input.as_bytes(), true, &[]);
+        let err = err.into_iter().next();
 
         assert!(err.is_none());
         assert_eq!(block.len(), 3);
@@ -1593,6 +1685,7 @@ mod input_types {
         let input = r#"let a = (ls | to-custom | group-by name other); ($a + $a) | agg sum"#;
 
         let (block, err) = parse(&mut working_set, None, input.as_bytes(), true, &[]);
+        let err = err.into_iter().next();
 
         assert!(err.is_none());
         assert_eq!(block.len(), 2);
@@ -1625,6 +1718,7 @@ mod input_types {
         let a = (ls | to-custom | group-by name other); [1 2 3] | to-custom; [1 2 3] | to-custom"#;
 
         let (block, err) = parse(&mut working_set, None, input.as_bytes(), true, &[]);
+        let err = err.into_iter().next();
 
         assert!(err.is_none());
         assert_eq!(block.len(), 3);
@@ -1669,6 +1763,7 @@ mod input_types {
         let input = r#"ls | group-by name"#;
 
         let (block, err) = parse(&mut working_set, None, input.as_bytes(), true, &[]);
+        let err = err.into_iter().next();
 
         assert!(err.is_none());
         assert_eq!(block.len(), 1);
@@ -1775,6 +1870,7 @@ mod input_types {
         let input = r#"[[a b]; [1 2] [3 4]] | to-custom | with-column [ ("a" | min) ("b" | min) ] | collect"#;
 
         let (block, err) = parse(&mut working_set, None, input.as_bytes(), true, &[]);
+        let err = err.into_iter().next();
 
         assert!(err.is_none());
         assert_eq!(block.len(), 1);
@@ -1829,6 +1925,7 @@ mod input_types {
 
         for input in inputs {
             let (block, err) = parse(&mut working_set, None, input.as_bytes(), true, &[]);
+            let err = err.into_iter().next();
 
             assert!(err.is_none(), "testing: {input}");
             assert_eq!(block.len(), 2, "testing: {input}");
@@ -1848,9 +1945,40 @@ mod input_types {
             true,
             &[],
         );
+        let err = err.into_iter().next();
 
         let err = err.unwrap();
 
         assert!(matches!(err, ParseError::VariableNotFound(_)));
     }
+
+    #[test]
+    fn parse_collects_multiple_unrelated_errors() {
+        let mut engine_state = EngineState::new();
+        add_declarations(&mut engine_state);
+
+        let mut working_set = StateWorkingSet::new(&engine_state);
+        let (_, err) = parse(&mut working_set, None, b"$foo; $bar", true, &[]);
+
+        assert_eq!(
+            err.len(),
+            2,
+            "expected both undefined variables to be reported: {err:#?}"
+        );
+        assert!(err
+            .iter()
+            .all(|e| matches!(e, ParseError::VariableNotFound(_))));
+
+        let spans: Vec<Span> = err
+            .iter()
+            .map(|e| match e {
+                ParseError::VariableNotFound(span) => *span,
+                _ => unreachable!(),
+            })
+            .collect();
+        assert_ne!(
+            spans[0], spans[1],
+            "the two errors should point at $foo and $bar respectively, not the same span"
+        );
+    }
 }
@@ -15,6 +15,7 @@ pub fn do_test(test: &[u8], expected: &str, error_contains: Option<&str>) {
     let mut working_set = StateWorkingSet::new(&engine_state);
 
     let (block, err) = parse(&mut working_set, None, test, true, &[]);
+    let err = err.into_iter().next();
 
     match err {
         None => {
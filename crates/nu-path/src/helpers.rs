@@ -10,6 +10,14 @@ pub fn config_dir() -> Option<PathBuf> {
     dirs_next::config_dir()
 }
 
+pub fn cache_dir() -> Option<PathBuf> {
+    dirs_next::cache_dir()
+}
+
+pub fn data_dir() -> Option<PathBuf> {
+    dirs_next::data_dir()
+}
+
 #[cfg(windows)]
 pub fn canonicalize(path: &std::path::Path) -> std::io::Result<std::path::PathBuf> {
     path.canonicalize()?.to_winuser_path()
@@ -56,6 +56,7 @@ impl Expression {
                     Operator::Boolean(Boolean::And) => 50,
                     Operator::Boolean(Boolean::Xor) => 45,
                     Operator::Boolean(Boolean::Or) => 40,
+                    Operator::Coalesce => 35,
                     Operator::Assignment(_) => 10,
                 }
             }
@@ -64,6 +64,7 @@ pub enum Operator {
     Boolean(Boolean),
     Bits(Bits),
     Assignment(Assignment),
+    Coalesce,
 }
 
 impl Display for Operator {
@@ -103,6 +104,7 @@ impl Display for Operator {
             Operator::Bits(Bits::BitAnd) => write!(f, "bit-and"),
             Operator::Bits(Bits::ShiftLeft) => write!(f, "bit-shl"),
             Operator::Bits(Bits::ShiftRight) => write!(f, "bit-shr"),
+            Operator::Coalesce => write!(f, "??"),
         }
     }
 }
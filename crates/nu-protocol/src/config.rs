@@ -100,6 +100,7 @@ pub struct Config {
     pub cursor_shape_vi_insert: NuCursorShape,
     pub cursor_shape_vi_normal: NuCursorShape,
     pub cursor_shape_emacs: NuCursorShape,
+    pub content_type_conversions: HashMap<String, Value>,
 }
 
 impl Default for Config {
@@ -143,6 +144,7 @@ impl Default for Config {
             cursor_shape_vi_insert: NuCursorShape::Block,
             cursor_shape_vi_normal: NuCursorShape::UnderScore,
             cursor_shape_emacs: NuCursorShape::Line,
+            content_type_conversions: HashMap::new(),
         }
     }
 }
@@ -979,6 +981,16 @@ impl Value {
                             vals[index] = Value::record_from_hashmap(&config.color_config, *span);
                         }
                     }
+                    "content_type_conversions" => {
+                        if let Ok(map) = create_map(value) {
+                            config.content_type_conversions = map;
+                        } else {
+                            invalid!(vals[index].span().ok(), "should be a record");
+                            // Reconstruct
+                            vals[index] =
+                                Value::record_from_hashmap(&config.content_type_conversions, *span);
+                        }
+                    }
                     "use_grid_icons" => {
                         try_bool!(cols, vals, index, span, use_grid_icons);
                     }
@@ -0,0 +1,77 @@
+use crate::DeclId;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// Cumulative call count and wall time for a single declaration. The counters
+/// are updated with relaxed atomics, so adding to them takes no lock; finding
+/// the entry in [`CallStats`] does take its map lock.
+#[derive(Default)]
+pub struct DeclStats {
+    pub call_count: AtomicU64,
+    pub total_nanos: AtomicU64,
+}
+
+impl DeclStats {
+    fn record(&self, elapsed_nanos: u64) {
+        self.call_count.fetch_add(1, Ordering::Relaxed);
+        self.total_nanos.fetch_add(elapsed_nanos, Ordering::Relaxed);
+    }
+}
+
+/// Opt-in per-decl call statistics, toggled by `debug stats --enable` /
+/// `--disable` and read by `debug stats`. Disabled by default, so a normal
+/// run pays nothing beyond a single relaxed atomic load per call.
+#[derive(Default)]
+pub struct CallStats {
+    enabled: AtomicBool,
+    by_decl: Mutex<HashMap<DeclId, Arc<DeclStats>>>,
+}
+
+impl CallStats {
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.load(Ordering::Relaxed)
+    }
+
+    pub fn set_enabled(&self, enabled: bool) {
+        self.enabled.store(enabled, Ordering::Relaxed);
+    }
+
+    /// Records a single call's wall time, unless stats collection is
+    /// disabled. Only takes the lock to find or create the decl's entry;
+    /// the counters themselves are updated lock-free.
+    pub fn record(&self, decl_id: DeclId, elapsed_nanos: u64) {
+        if !self.is_enabled() {
+            return;
+        }
+
+        let stats = {
+            let mut by_decl = self.by_decl.lock().expect("call stats mutex poisoned");
+            by_decl.entry(decl_id).or_default().clone()
+        };
+
+        stats.record(elapsed_nanos);
+    }
+
+    pub fn snapshot(&self) -> Vec<(DeclId, u64, u64)> {
+        self.by_decl
+            .lock()
+            .expect("call stats mutex poisoned")
+            .iter()
+            .map(|(decl_id, stats)| {
+                (
+                    *decl_id,
+                    stats.call_count.load(Ordering::Relaxed),
+                    stats.total_nanos.load(Ordering::Relaxed),
+                )
+            })
+            .collect()
+    }
+
+    pub fn clear(&self) {
+        self.by_decl
+            .lock()
+            .expect("call stats mutex poisoned")
+            .clear();
+    }
+}
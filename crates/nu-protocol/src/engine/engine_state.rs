@@ -1,11 +1,13 @@
 use fancy_regex::Regex;
 use lru::LruCache;
 
-use super::{Command, EnvVars, OverlayFrame, ScopeFrame, Stack, Visibility, DEFAULT_OVERLAY_NAME};
+use super::{
+    CallStats, Command, EnvVars, OverlayFrame, ScopeFrame, Stack, Visibility, DEFAULT_OVERLAY_NAME,
+};
 use crate::Value;
 use crate::{
-    ast::Block, AliasId, BlockId, Config, DeclId, Example, Module, ModuleId, OverlayId, ShellError,
-    Signature, Span, Type, VarId, Variable,
+    ast::Block, AliasId, Attribute, BlockId, Config, DeclId, Example, Module, ModuleId, OverlayId,
+    ShellError, Signature, Span, Type, VarId, Variable,
 };
 use core::panic;
 use std::borrow::Borrow;
@@ -148,6 +150,7 @@ pub struct EngineState {
     pub is_interactive: bool,
     pub is_login: bool,
     startup_time: i64,
+    pub call_stats: Arc<CallStats>,
 }
 
 // The max number of compiled regexes to keep around in a LRU cache, arbitrarily chosen
@@ -201,6 +204,7 @@ impl EngineState {
             is_interactive: false,
             is_login: false,
             startup_time: -1,
+            call_stats: Arc::new(CallStats::default()),
         }
     }
 
@@ -1041,6 +1045,10 @@ pub struct StateWorkingSet<'a> {
     pub currently_parsed_cwd: Option<PathBuf>,
     /// All previously parsed module files. Used to protect against circular imports.
     pub parsed_module_files: Vec<PathBuf>,
+    /// `@name [value]` attributes parsed ahead of a `def`/`export def`, keyed
+    /// by the byte offset of that declaration's `def` keyword, waiting to be
+    /// claimed by `parse_def` once it parses that declaration.
+    pub pending_attributes: HashMap<usize, Vec<Attribute>>,
 }
 
 /// A temporary placeholder for expression types. It is used to keep track of the input types
@@ -1224,6 +1232,7 @@ impl<'a> StateWorkingSet<'a> {
             type_scope: TypeScope::default(),
             currently_parsed_cwd: permanent_state.currently_parsed_cwd.clone(),
             parsed_module_files: vec![],
+            pending_attributes: HashMap::new(),
         }
     }
 
@@ -2280,65 +2289,71 @@ impl<'a> miette::SourceCode for &StateWorkingSet<'a> {
             let finding_span = "Finding span in StateWorkingSet";
             dbg!(finding_span, span);
         }
-        for (filename, start, end) in self.files() {
+        // Adjacent files sit back-to-back in the shared span arena, so a span that lands
+        // exactly on the boundary (e.g. the very first byte of a `source`d file) matches
+        // both the file that just ended and the one that's starting. Prefer a file the span
+        // is strictly inside over one it merely touches at the end, so such spans are
+        // attributed to the file they actually belong to, not the one before it.
+        let file_for_span = self
+            .files()
+            .find(|(_, start, end)| span.offset() >= *start && span.offset() + span.len() < *end)
+            .or_else(|| {
+                self.files().find(|(_, start, end)| {
+                    span.offset() >= *start && span.offset() + span.len() <= *end
+                })
+            });
+        if let Some((filename, start, end)) = file_for_span {
             if debugging {
                 dbg!(&filename, start, end);
+                let found_file = "Found matching file";
+                dbg!(found_file);
             }
-            if span.offset() >= *start && span.offset() + span.len() <= *end {
-                if debugging {
-                    let found_file = "Found matching file";
-                    dbg!(found_file);
-                }
-                let our_span = Span::new(*start, *end);
-                // We need to move to a local span because we're only reading
-                // the specific file contents via self.get_span_contents.
-                let local_span = (span.offset() - *start, span.len()).into();
-                if debugging {
-                    dbg!(&local_span);
-                }
-                let span_contents = self.get_span_contents(our_span);
+            let our_span = Span::new(*start, *end);
+            // We need to move to a local span because we're only reading
+            // the specific file contents via self.get_span_contents.
+            let local_span = (span.offset() - *start, span.len()).into();
+            if debugging {
+                dbg!(&local_span);
+            }
+            let span_contents = self.get_span_contents(our_span);
+            if debugging {
+                dbg!(String::from_utf8_lossy(span_contents));
+            }
+            let span_contents =
+                span_contents.read_span(&local_span, context_lines_before, context_lines_after)?;
+            let content_span = span_contents.span();
+            // Back to "global" indexing
+            let retranslated = (content_span.offset() + start, content_span.len()).into();
+            if debugging {
+                dbg!(&retranslated);
+            }
+
+            let data = span_contents.data();
+            if filename == "<cli>" {
                 if debugging {
-                    dbg!(String::from_utf8_lossy(span_contents));
+                    let success_cli = "Successfully read CLI span";
+                    dbg!(success_cli, String::from_utf8_lossy(data));
                 }
-                let span_contents = span_contents.read_span(
-                    &local_span,
-                    context_lines_before,
-                    context_lines_after,
-                )?;
-                let content_span = span_contents.span();
-                // Back to "global" indexing
-                let retranslated = (content_span.offset() + start, content_span.len()).into();
+                return Ok(Box::new(miette::MietteSpanContents::new(
+                    data,
+                    retranslated,
+                    span_contents.line(),
+                    span_contents.column(),
+                    span_contents.line_count(),
+                )));
+            } else {
                 if debugging {
-                    dbg!(&retranslated);
-                }
-
-                let data = span_contents.data();
-                if filename == "<cli>" {
-                    if debugging {
-                        let success_cli = "Successfully read CLI span";
-                        dbg!(success_cli, String::from_utf8_lossy(data));
-                    }
-                    return Ok(Box::new(miette::MietteSpanContents::new(
-                        data,
-                        retranslated,
-                        span_contents.line(),
-                        span_contents.column(),
-                        span_contents.line_count(),
-                    )));
-                } else {
-                    if debugging {
-                        let success_file = "Successfully read file span";
-                        dbg!(success_file);
-                    }
-                    return Ok(Box::new(miette::MietteSpanContents::new_named(
-                        filename.clone(),
-                        data,
-                        retranslated,
-                        span_contents.line(),
-                        span_contents.column(),
-                        span_contents.line_count(),
-                    )));
+                    let success_file = "Successfully read file span";
+                    dbg!(success_file);
                 }
+                return Ok(Box::new(miette::MietteSpanContents::new_named(
+                    filename.clone(),
+                    data,
+                    retranslated,
+                    span_contents.line(),
+                    span_contents.column(),
+                    span_contents.line_count(),
+                )));
             }
         }
         Err(miette::MietteError::OutOfBounds)
@@ -2442,4 +2457,27 @@ mod engine_state_tests {
 
         Ok(())
     }
+
+    #[test]
+    fn read_span_resolves_boundary_span_to_following_file() {
+        use miette::SourceCode;
+
+        let engine_state = EngineState::new();
+        let mut working_set = StateWorkingSet::new(&engine_state);
+
+        working_set.add_file("first.nu".into(), &[b'1'; 3]);
+        let second_start = working_set.next_span_start();
+        working_set.add_file("second.nu".into(), &[b'2'; 3]);
+
+        // This span covers only the first byte of "second.nu", but since that byte sits
+        // immediately after the last byte of "first.nu" in the shared span arena, a naive
+        // "span.offset() + span.len() <= end" match would also accept "first.nu".
+        let boundary_span = Span::new(second_start, second_start + 1);
+
+        let contents = (&working_set)
+            .read_span(&boundary_span.into(), 0, 0)
+            .expect("boundary span should resolve to a file");
+
+        assert_eq!(contents.name(), Some("second.nu"));
+    }
 }
@@ -1,4 +1,5 @@
 mod call_info;
+mod call_stats;
 mod capture_block;
 mod command;
 mod engine_state;
@@ -6,6 +7,7 @@ mod overlay;
 mod stack;
 
 pub use call_info::*;
+pub use call_stats::*;
 pub use capture_block::*;
 pub use command::*;
 pub use engine_state::*;
@@ -223,3 +223,22 @@ fn sort_by_words(name: &str) -> String {
 // IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
 // DEALINGS IN THE SOFTWARE.
 // Footer
+
+/// Finds up to `n` matches for `lookup` among `candidates`, ranked closest first,
+/// using the same substring-aware scoring as [`find_best_match_for_name_with_substrings`].
+pub fn find_best_n_matches_for_name<'c>(
+    candidates: &[&'c str],
+    lookup: &str,
+    n: usize,
+    dist: Option<usize>,
+) -> Vec<&'c str> {
+    let dist = dist.unwrap_or_else(|| cmp::max(lookup.len(), 3) / 3);
+
+    let mut scored: Vec<(usize, &'c str)> = candidates
+        .iter()
+        .filter_map(|c| lev_distance_with_substrings(lookup, c, dist).map(|d| (d, *c)))
+        .collect();
+
+    scored.sort_by_key(|(d, _)| *d);
+    scored.into_iter().take(n).map(|(_, c)| c).collect()
+}
@@ -1093,6 +1093,29 @@ pub fn did_you_mean<S: AsRef<str>>(possibilities: &[S], input: &str) -> Option<S
     suggestion
 }
 
+/// Like [`did_you_mean`], but returns up to three ranked matches instead of just
+/// the closest one, for messages that want to show the user several near-misses
+/// at once rather than guessing which single one they meant.
+pub fn did_you_mean_multiple<S: AsRef<str>>(possibilities: &[S], input: &str) -> Vec<String> {
+    let possibilities: Vec<&str> = possibilities.iter().map(|s| s.as_ref()).collect();
+    crate::lev_distance::find_best_n_matches_for_name(&possibilities, input, 3, None)
+        .into_iter()
+        .map(|s| s.to_string())
+        .collect()
+}
+
+/// Joins suggestion candidates into a natural-language list, e.g. `"foo"`,
+/// `"foo or bar"`, or `"foo, bar, or baz"`, suitable for substitution into a
+/// "did you mean ...?" message.
+pub fn format_suggestions(candidates: &[String]) -> Option<String> {
+    match candidates {
+        [] => None,
+        [one] => Some(one.clone()),
+        [first, second] => Some(format!("{first} or {second}")),
+        [init @ .., last] => Some(format!("{}, or {last}", init.join(", "))),
+    }
+}
+
 pub fn levenshtein_distance(a: &str, b: &str) -> usize {
     crate::lev_distance::lev_distance(a, b, usize::max_value())
         .expect("It is impossible to exceed the supplied limit since all types involved are usize.")
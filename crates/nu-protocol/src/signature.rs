@@ -27,6 +27,16 @@ pub struct Flag {
     pub default_value: Option<Expression>,
 }
 
+/// A single `@name [value]` attribute attached to a `def`/`export def`, parsed
+/// before the declaration and carried along on its [`Signature`]. `value` is
+/// the attribute's raw argument text (e.g. the message in `@deprecated "use
+/// foo instead"`), if one was given.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Attribute {
+    pub name: String,
+    pub value: Option<String>,
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PositionalArg {
     pub name: String,
@@ -123,6 +133,12 @@ pub struct Signature {
     pub allows_unknown_args: bool,
     // Signature category used to classify commands stored in the list of declarations
     pub category: Category,
+    /// `@name [value]`-style attributes parsed ahead of this declaration's
+    /// `def`/`export def`. `@deprecated` and `@example` are recognized and
+    /// acted on (see [`Signature::is_deprecated`] and
+    /// [`Signature::example_attributes`]); any other name is kept here for
+    /// scripts' own tooling but isn't otherwise interpreted.
+    pub attributes: Vec<Attribute>,
 }
 
 /// Format argument type for user readable output.
@@ -224,9 +240,33 @@ impl Signature {
             creates_scope: false,
             category: Category::Default,
             allows_unknown_args: false,
+            attributes: vec![],
         }
     }
 
+    /// True if an `@deprecated` attribute was attached to this declaration.
+    pub fn is_deprecated(&self) -> bool {
+        self.attributes.iter().any(|attr| attr.name == "deprecated")
+    }
+
+    /// The message given to `@deprecated`, if any.
+    pub fn deprecation_message(&self) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|attr| attr.name == "deprecated")
+            .and_then(|attr| attr.value.as_deref())
+    }
+
+    /// The source text of every `@example` attribute attached to this
+    /// declaration, in the order they were written. `help` renders these
+    /// alongside the `Example`s the command returns from code.
+    pub fn example_attributes(&self) -> impl Iterator<Item = &str> {
+        self.attributes
+            .iter()
+            .filter(|attr| attr.name == "example")
+            .filter_map(|attr| attr.value.as_deref())
+    }
+
     // Add a default help option to a signature
     pub fn add_help(mut self) -> Signature {
         // default help flag
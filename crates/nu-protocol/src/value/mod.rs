@@ -10,7 +10,9 @@ use crate::ast::{Bits, Boolean, CellPath, Comparison, PathMember};
 use crate::ast::{Math, Operator};
 use crate::engine::EngineState;
 use crate::ShellError;
-use crate::{did_you_mean, BlockId, Config, Span, Spanned, Type, VarId};
+use crate::{
+    did_you_mean_multiple, format_suggestions, BlockId, Config, Span, Spanned, Type, VarId,
+};
 use byte_unit::ByteUnit;
 use chrono::{DateTime, Duration, FixedOffset};
 use chrono_humanize::HumanTime;
@@ -825,7 +827,8 @@ impl Value {
                             current = found.1.clone();
                         } else {
                             if from_user_input {
-                                if let Some(suggestion) = did_you_mean(&cols, column_name) {
+                                let suggestions = did_you_mean_multiple(&cols, column_name);
+                                if let Some(suggestion) = format_suggestions(&suggestions) {
                                     err_or_null!(
                                         ShellError::DidYouMean(suggestion, *origin_span),
                                         *origin_span
@@ -849,7 +852,8 @@ impl Value {
                             current = val.get_column_value(column_name)?;
                         } else {
                             if from_user_input {
-                                if let Some(suggestion) = did_you_mean(&columns, column_name) {
+                                let suggestions = did_you_mean_multiple(&columns, column_name);
+                                if let Some(suggestion) = format_suggestions(&suggestions) {
                                     err_or_null!(
                                         ShellError::DidYouMean(suggestion, *origin_span),
                                         *origin_span
@@ -2637,6 +2641,10 @@ impl Value {
             return lhs.operation(*span, Operator::Comparison(Comparison::LessThan), op, rhs);
         }
 
+        if matches!(self, Value::Nothing { .. }) || matches!(rhs, Value::Nothing { .. }) {
+            return Ok(Value::Nothing { span });
+        }
+
         if !type_compatible(self.get_type(), rhs.get_type())
             && (self.get_type() != Type::Any)
             && (rhs.get_type() != Type::Any)
@@ -2673,6 +2681,10 @@ impl Value {
             );
         }
 
+        if matches!(self, Value::Nothing { .. }) || matches!(rhs, Value::Nothing { .. }) {
+            return Ok(Value::Nothing { span });
+        }
+
         if !type_compatible(self.get_type(), rhs.get_type())
             && (self.get_type() != Type::Any)
             && (rhs.get_type() != Type::Any)
@@ -2707,6 +2719,10 @@ impl Value {
             );
         }
 
+        if matches!(self, Value::Nothing { .. }) || matches!(rhs, Value::Nothing { .. }) {
+            return Ok(Value::Nothing { span });
+        }
+
         if !type_compatible(self.get_type(), rhs.get_type())
             && (self.get_type() != Type::Any)
             && (rhs.get_type() != Type::Any)
@@ -2741,6 +2757,10 @@ impl Value {
             );
         }
 
+        if matches!(self, Value::Nothing { .. }) || matches!(rhs, Value::Nothing { .. }) {
+            return Ok(Value::Nothing { span });
+        }
+
         if !type_compatible(self.get_type(), rhs.get_type())
             && (self.get_type() != Type::Any)
             && (rhs.get_type() != Type::Any)
@@ -2771,24 +2791,23 @@ impl Value {
             return lhs.operation(*span, Operator::Comparison(Comparison::Equal), op, rhs);
         }
 
+        if matches!(self, Value::Nothing { .. }) || matches!(rhs, Value::Nothing { .. }) {
+            return Ok(Value::Nothing { span });
+        }
+
         if let Some(ordering) = self.partial_cmp(rhs) {
             Ok(Value::Bool {
                 val: matches!(ordering, Ordering::Equal),
                 span,
             })
         } else {
-            match (self, rhs) {
-                (Value::Nothing { .. }, _) | (_, Value::Nothing { .. }) => {
-                    Ok(Value::Bool { val: false, span })
-                }
-                _ => Err(ShellError::OperatorMismatch {
-                    op_span: op,
-                    lhs_ty: self.get_type(),
-                    lhs_span: self.span()?,
-                    rhs_ty: rhs.get_type(),
-                    rhs_span: rhs.span()?,
-                }),
-            }
+            Err(ShellError::OperatorMismatch {
+                op_span: op,
+                lhs_ty: self.get_type(),
+                lhs_span: self.span()?,
+                rhs_ty: rhs.get_type(),
+                rhs_span: rhs.span()?,
+            })
         }
     }
 
@@ -2797,24 +2816,23 @@ impl Value {
             return lhs.operation(*span, Operator::Comparison(Comparison::NotEqual), op, rhs);
         }
 
+        if matches!(self, Value::Nothing { .. }) || matches!(rhs, Value::Nothing { .. }) {
+            return Ok(Value::Nothing { span });
+        }
+
         if let Some(ordering) = self.partial_cmp(rhs) {
             Ok(Value::Bool {
                 val: !matches!(ordering, Ordering::Equal),
                 span,
             })
         } else {
-            match (self, rhs) {
-                (Value::Nothing { .. }, _) | (_, Value::Nothing { .. }) => {
-                    Ok(Value::Bool { val: true, span })
-                }
-                _ => Err(ShellError::OperatorMismatch {
-                    op_span: op,
-                    lhs_ty: self.get_type(),
-                    lhs_span: self.span()?,
-                    rhs_ty: rhs.get_type(),
-                    rhs_span: rhs.span()?,
-                }),
-            }
+            Err(ShellError::OperatorMismatch {
+                op_span: op,
+                lhs_ty: self.get_type(),
+                lhs_span: self.span()?,
+                rhs_ty: rhs.get_type(),
+                rhs_span: rhs.span()?,
+            })
         }
     }
 
@@ -43,6 +43,111 @@ pub fn from_ics_call(call: &EvaluatedCall, input: &Value) -> Result<Value, Label
     })
 }
 
+/// Parses the handful of ICS property names whose values benefit from a structured
+/// representation instead of a raw string: date/time properties become `Value::Date`,
+/// and `RRULE` becomes a record of its `KEY=VALUE` components (values with a comma
+/// become a list). Everything else is left as a plain string, matching the original
+/// behavior. Time zone offsets declared via a `TZID` parameter are not resolved; values
+/// without a trailing `Z` are treated as UTC.
+fn typed_property_value(name: &str, raw: &str, span: Span) -> Value {
+    match name {
+        "DTSTART" | "DTEND" | "DTSTAMP" | "CREATED" | "LAST-MODIFIED" | "RECURRENCE-ID"
+        | "EXDATE" | "RDATE" => match parse_ics_datetime(raw) {
+            Some(date) => Value::Date { val: date, span },
+            None => Value::string(raw, span),
+        },
+        "RRULE" => parse_rrule(raw, span),
+        _ => Value::string(raw, span),
+    }
+}
+
+fn parse_ics_datetime(value: &str) -> Option<chrono::DateTime<chrono::FixedOffset>> {
+    let value = value.trim();
+
+    let naive = chrono::NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%SZ")
+        .or_else(|_| chrono::NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S"))
+        .ok()
+        .or_else(|| {
+            chrono::NaiveDate::parse_from_str(value, "%Y%m%d")
+                .ok()
+                .and_then(|date| date.and_hms_opt(0, 0, 0))
+        })?;
+
+    Some(chrono::DateTime::<chrono::Utc>::from_utc(naive, chrono::Utc).into())
+}
+
+fn parse_rrule(value: &str, span: Span) -> Value {
+    let mut cols = vec![];
+    let mut vals = vec![];
+
+    for part in value.split(';') {
+        let Some((key, val)) = part.split_once('=') else {
+            continue;
+        };
+
+        cols.push(key.to_string());
+        vals.push(if val.contains(',') {
+            Value::List {
+                vals: val.split(',').map(|v| Value::string(v, span)).collect(),
+                span,
+            }
+        } else {
+            Value::string(val, span)
+        });
+    }
+
+    Value::Record { cols, vals, span }
+}
+
+/// Gathers `ATTENDEE` properties into a table of their commonly used parameters, so
+/// callers don't have to pick them back out of the flat `properties` list by hand.
+fn attendees_to_value(properties: &[Property], span: Span) -> Value {
+    let vals = properties
+        .iter()
+        .filter(|prop| prop.name == "ATTENDEE")
+        .map(|prop| {
+            let email = prop
+                .value
+                .as_deref()
+                .unwrap_or_default()
+                .trim_start_matches("mailto:")
+                .trim_start_matches("MAILTO:")
+                .to_string();
+
+            let param = |key: &str| -> Value {
+                prop.params
+                    .as_ref()
+                    .and_then(|params| params.iter().find(|(name, _)| name == key))
+                    .and_then(|(_, values)| values.first())
+                    .map(|value| Value::string(value.clone(), span))
+                    .unwrap_or_else(|| Value::nothing(span))
+            };
+
+            Value::Record {
+                cols: vec![
+                    "email".to_string(),
+                    "cn".to_string(),
+                    "role".to_string(),
+                    "partstat".to_string(),
+                    "rsvp".to_string(),
+                    "cutype".to_string(),
+                ],
+                vals: vec![
+                    Value::string(email, span),
+                    param("CN"),
+                    param("ROLE"),
+                    param("PARTSTAT"),
+                    param("RSVP"),
+                    param("CUTYPE"),
+                ],
+                span,
+            }
+        })
+        .collect();
+
+    Value::List { vals, span }
+}
+
 pub fn examples() -> Vec<PluginExample> {
     vec![PluginExample {
         example: "'BEGIN:VCALENDAR
@@ -129,11 +234,13 @@ fn events_to_value(events: Vec<IcalEvent>, span: Span) -> Value {
             .into_iter()
             .map(|event| {
                 let mut row = IndexMap::new();
+                let attendees = attendees_to_value(&event.properties, span);
                 row.insert(
                     "properties".to_string(),
                     properties_to_value(event.properties, span),
                 );
                 row.insert("alarms".to_string(), alarms_to_value(event.alarms, span));
+                row.insert("attendees".to_string(), attendees);
                 Value::from(Spanned { item: row, span })
             })
             .collect::<Vec<Value>>(),
@@ -164,11 +271,13 @@ fn todos_to_value(todos: Vec<IcalTodo>, span: Span) -> Value {
             .into_iter()
             .map(|todo| {
                 let mut row = IndexMap::new();
+                let attendees = attendees_to_value(&todo.properties, span);
                 row.insert(
                     "properties".to_string(),
                     properties_to_value(todo.properties, span),
                 );
                 row.insert("alarms".to_string(), alarms_to_value(todo.alarms, span));
+                row.insert("attendees".to_string(), attendees);
                 Value::from(Spanned { item: row, span })
             })
             .collect::<Vec<Value>>(),
@@ -255,14 +364,14 @@ fn properties_to_value(properties: Vec<Property>, span: Span) -> Value {
             .map(|prop| {
                 let mut row = IndexMap::new();
 
+                let value = match &prop.value {
+                    Some(val) => typed_property_value(&prop.name, val, span),
+                    None => Value::nothing(span),
+                };
                 let name = Value::String {
                     val: prop.name,
                     span,
                 };
-                let value = match prop.value {
-                    Some(val) => Value::String { val, span },
-                    None => Value::nothing(span),
-                };
                 let params = match prop.params {
                     Some(param_list) => params_to_value(param_list, span),
                     None => Value::nothing(span),
@@ -62,7 +62,23 @@ END:VCARD' | from vcf"
                             ],
                             vals: vec![
                                 Value::test_string("N"),
-                                Value::test_string("Foo"),
+                                Value::Record {
+                                    cols: vec![
+                                        "family".to_string(),
+                                        "given".to_string(),
+                                        "additional".to_string(),
+                                        "prefixes".to_string(),
+                                        "suffixes".to_string(),
+                                    ],
+                                    vals: vec![
+                                        Value::test_string("Foo"),
+                                        Value::test_string(""),
+                                        Value::test_string(""),
+                                        Value::test_string(""),
+                                        Value::test_string(""),
+                                    ],
+                                    span: Span::test_data(),
+                                },
                                 Value::Nothing {
                                     span: Span::test_data(),
                                 },
@@ -118,6 +134,54 @@ fn contact_to_value(contact: VcardContact, span: Span) -> Value {
     Value::from(Spanned { item: row, span })
 }
 
+/// Parses the vCard property names whose values benefit from a structured representation:
+/// `BDAY` becomes a `Value::Date`, and `N` (the structured name) becomes a record of its
+/// semicolon-delimited components. Everything else is left as a plain string, matching the
+/// original behavior.
+fn typed_property_value(name: &str, raw: &str, span: Span) -> Value {
+    match name {
+        "BDAY" => match parse_vcf_date(raw) {
+            Some(date) => Value::Date { val: date, span },
+            None => Value::string(raw, span),
+        },
+        "N" => parse_structured_name(raw, span),
+        _ => Value::string(raw, span),
+    }
+}
+
+fn parse_vcf_date(value: &str) -> Option<chrono::DateTime<chrono::FixedOffset>> {
+    let value = value.trim();
+
+    let naive = chrono::NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%SZ")
+        .or_else(|_| chrono::NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S"))
+        .ok()
+        .or_else(|| {
+            chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                .or_else(|_| chrono::NaiveDate::parse_from_str(value, "%Y%m%d"))
+                .ok()
+                .and_then(|date| date.and_hms_opt(0, 0, 0))
+        })?;
+
+    Some(chrono::DateTime::<chrono::Utc>::from_utc(naive, chrono::Utc).into())
+}
+
+fn parse_structured_name(value: &str, span: Span) -> Value {
+    let parts: Vec<&str> = value.split(';').collect();
+    let part = |i: usize| Value::string(parts.get(i).copied().unwrap_or(""), span);
+
+    Value::Record {
+        cols: vec![
+            "family".to_string(),
+            "given".to_string(),
+            "additional".to_string(),
+            "prefixes".to_string(),
+            "suffixes".to_string(),
+        ],
+        vals: vec![part(0), part(1), part(2), part(3), part(4)],
+        span,
+    }
+}
+
 fn properties_to_value(properties: Vec<Property>, span: Span) -> Value {
     Value::List {
         vals: properties
@@ -125,14 +189,14 @@ fn properties_to_value(properties: Vec<Property>, span: Span) -> Value {
             .map(|prop| {
                 let mut row = IndexMap::new();
 
+                let value = match &prop.value {
+                    Some(val) => typed_property_value(&prop.name, val, span),
+                    None => Value::Nothing { span },
+                };
                 let name = Value::String {
                     val: prop.name,
                     span,
                 };
-                let value = match prop.value {
-                    Some(val) => Value::String { val, span },
-                    None => Value::Nothing { span },
-                };
                 let params = match prop.params {
                     Some(param_list) => params_to_value(param_list, span),
                     None => Value::Nothing { span },
@@ -1,8 +1,10 @@
 mod from;
+mod to;
 
 use from::{eml, ics, ini, vcf};
 use nu_plugin::{EvaluatedCall, LabeledError, Plugin};
 use nu_protocol::{Category, PluginSignature, SyntaxShape, Type, Value};
+use to::ics as to_ics;
 
 pub struct FromCmds;
 
@@ -35,6 +37,11 @@ impl Plugin for FromCmds {
                 .usage("Parse text as .ini and create table.")
                 .plugin_examples(ini::examples())
                 .category(Category::Formats),
+            PluginSignature::build(to_ics::CMD_NAME)
+                .input_output_types(vec![(Type::Table(vec![]), Type::String)])
+                .usage("Convert a table back into .ics text.")
+                .plugin_examples(to_ics::examples())
+                .category(Category::Formats),
         ]
     }
 
@@ -49,6 +56,7 @@ impl Plugin for FromCmds {
             ics::CMD_NAME => ics::from_ics_call(call, input),
             vcf::CMD_NAME => vcf::from_vcf_call(call, input),
             ini::CMD_NAME => ini::from_ini_call(call, input),
+            to_ics::CMD_NAME => to_ics::to_ics_call(call, input),
             _ => Err(LabeledError {
                 label: "Plugin call with wrong name signature".into(),
                 msg: "the signature used to call the plugin does not match any name in the plugin signature vector".into(),
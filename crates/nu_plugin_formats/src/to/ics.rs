@@ -0,0 +1,180 @@
+use nu_plugin::{EvaluatedCall, LabeledError};
+use nu_protocol::{PluginExample, Span, Value};
+
+pub const CMD_NAME: &str = "to ics";
+
+pub fn to_ics_call(call: &EvaluatedCall, input: &Value) -> Result<Value, LabeledError> {
+    let span = input.span().unwrap_or(call.head);
+
+    let calendars: Vec<&Value> = match input {
+        Value::List { vals, .. } => vals.iter().collect(),
+        other => vec![other],
+    };
+
+    let mut output = String::new();
+    for calendar in calendars {
+        output.push_str(&render_calendar(calendar));
+    }
+
+    Ok(Value::String { val: output, span })
+}
+
+pub fn examples() -> Vec<PluginExample> {
+    vec![PluginExample {
+        example: "{properties: [], events: [], alarms: [], to-Dos: [], journals: [], free-busys: [], timezones: []} | to ics".into(),
+        description: "Converts a table back into ics formatted text".into(),
+        result: Some(Value::test_string("BEGIN:VCALENDAR\nEND:VCALENDAR\n")),
+    }]
+}
+
+/// Looks up a column by name on a record, returning `None` for anything that
+/// isn't a record or doesn't have that column (rather than erroring), so a
+/// partially built table still renders whatever it has.
+fn field<'a>(value: &'a Value, name: &str) -> Option<&'a Value> {
+    match value {
+        Value::Record { cols, vals, .. } => cols
+            .iter()
+            .position(|col| col == name)
+            .and_then(|i| vals.get(i)),
+        _ => None,
+    }
+}
+
+fn list_field<'a>(value: &'a Value, name: &str) -> Vec<&'a Value> {
+    match field(value, name) {
+        Some(Value::List { vals, .. }) => vals.iter().collect(),
+        _ => vec![],
+    }
+}
+
+fn render_calendar(calendar: &Value) -> String {
+    let mut out = String::from("BEGIN:VCALENDAR\n");
+
+    if let Some(properties) = field(calendar, "properties") {
+        out.push_str(&render_properties(properties));
+    }
+    for event in list_field(calendar, "events") {
+        out.push_str(&render_component("VEVENT", event, true));
+    }
+    for todo in list_field(calendar, "to-Dos") {
+        out.push_str(&render_component("VTODO", todo, true));
+    }
+    for journal in list_field(calendar, "journals") {
+        out.push_str(&render_component("VJOURNAL", journal, false));
+    }
+    for free_busy in list_field(calendar, "free-busys") {
+        out.push_str(&render_component("VFREEBUSY", free_busy, false));
+    }
+    for timezone in list_field(calendar, "timezones") {
+        out.push_str(&render_timezone(timezone));
+    }
+
+    out.push_str("END:VCALENDAR\n");
+    out
+}
+
+/// Renders a VEVENT/VTODO/VJOURNAL/VFREEBUSY-shaped record. `ATTENDEE` properties are carried
+/// in the `properties` list itself (as `from ics` leaves them there alongside the `attendees`
+/// convenience table), so the `attendees` field is read-only and not rendered separately here.
+fn render_component(kind: &str, value: &Value, has_alarms: bool) -> String {
+    let mut out = format!("BEGIN:{kind}\n");
+
+    if let Some(properties) = field(value, "properties") {
+        out.push_str(&render_properties(properties));
+    }
+    if has_alarms {
+        for alarm in list_field(value, "alarms") {
+            out.push_str(&render_component("VALARM", alarm, false));
+        }
+    }
+
+    out.push_str(&format!("END:{kind}\n"));
+    out
+}
+
+/// Renders a VTIMEZONE-shaped record. The parser that `from ics` builds on doesn't retain
+/// whether a given transition was declared as `STANDARD` or `DAYLIGHT`, so every transition is
+/// written back out as `STANDARD`; re-parsed output will not distinguish daylight-saving rules.
+fn render_timezone(value: &Value) -> String {
+    let mut out = String::from("BEGIN:VTIMEZONE\n");
+
+    if let Some(properties) = field(value, "properties") {
+        out.push_str(&render_properties(properties));
+    }
+    for transition in list_field(value, "transitions") {
+        out.push_str("BEGIN:STANDARD\n");
+        if let Some(properties) = field(transition, "properties") {
+            out.push_str(&render_properties(properties));
+        }
+        out.push_str("END:STANDARD\n");
+    }
+
+    out.push_str("END:VTIMEZONE\n");
+    out
+}
+
+fn render_properties(properties: &Value) -> String {
+    let mut out = String::new();
+    if let Value::List { vals, .. } = properties {
+        for prop in vals {
+            out.push_str(&render_property(prop));
+        }
+    }
+    out
+}
+
+fn render_property(prop: &Value) -> String {
+    let Some(Value::String { val: name, .. }) = field(prop, "name") else {
+        return String::new();
+    };
+
+    let mut line = name.clone();
+
+    if let Some(Value::Record { cols, vals, .. }) = field(prop, "params") {
+        for (col, val) in cols.iter().zip(vals.iter()) {
+            line.push(';');
+            line.push_str(col);
+            line.push('=');
+            line.push_str(&raw_value_list(val));
+        }
+    }
+
+    line.push(':');
+    if let Some(value) = field(prop, "value") {
+        line.push_str(&property_value_to_raw(value));
+    }
+    line.push('\n');
+    line
+}
+
+/// Renders a property's value: dates go back to the `%Y%m%dT%H%M%SZ` form `from ics` parses,
+/// an `RRULE`-shaped record goes back to `KEY=VALUE;...`, and everything else is rendered as
+/// its plain string form.
+fn property_value_to_raw(value: &Value) -> String {
+    match value {
+        Value::Date { val, .. } => val.format("%Y%m%dT%H%M%SZ").to_string(),
+        Value::Record { cols, vals, .. } => cols
+            .iter()
+            .zip(vals.iter())
+            .map(|(key, val)| format!("{key}={}", raw_value_list(val)))
+            .collect::<Vec<_>>()
+            .join(";"),
+        other => raw_value(other),
+    }
+}
+
+fn raw_value_list(value: &Value) -> String {
+    match value {
+        Value::List { vals, .. } => vals.iter().map(raw_value).collect::<Vec<_>>().join(","),
+        other => raw_value(other),
+    }
+}
+
+fn raw_value(value: &Value) -> String {
+    match value {
+        Value::String { val, .. } => val.clone(),
+        Value::Date { val, .. } => val.format("%Y%m%dT%H%M%SZ").to_string(),
+        Value::Nothing { .. } => String::new(),
+        other => other.into_string(",", &Default::default()),
+    }
+}
@@ -63,15 +63,17 @@ pub(crate) fn parse_commandline_args(
         let mut working_set = StateWorkingSet::new(engine_state);
         working_set.add_decl(Box::new(Nu));
 
-        let (output, err) = parse(
+        let (output, errors) = parse(
             &mut working_set,
             None,
             commandline_args.as_bytes(),
             false,
             &[],
         );
-        if let Some(err) = err {
-            report_error(&working_set, &err);
+        if !errors.is_empty() {
+            for err in &errors {
+                report_error(&working_set, err);
+            }
 
             std::process::exit(1);
         }